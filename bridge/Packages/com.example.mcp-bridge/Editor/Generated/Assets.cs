@@ -24,48 +24,48 @@ namespace Mcp.Unity.V1 {
     static AssetsReflection() {
       byte[] descriptorData = global::System.Convert.FromBase64String(
           string.Concat(
-            "ChltY3AvdW5pdHkvdjEvYXNzZXRzLnByb3RvEgxtY3AudW5pdHkudjEiTAoS",
+            "ChltY3AvdW5pdHkvdjEvYXNzZXRzLnByb3RvEgxtY3AudW5pdHkudjEiWwoS",
             "SW1wb3J0QXNzZXRSZXF1ZXN0Eg0KBXBhdGhzGAEgAygJEhEKCXJlY3Vyc2l2",
-            "ZRgCIAEoCBIUCgxhdXRvX3JlZnJlc2gYAyABKAgiTAoRSW1wb3J0QXNzZXRS",
-            "ZXN1bHQSDAoEcGF0aBgBIAEoCRIMCgRndWlkGAIgASgJEgoKAm9rGAMgASgI",
-            "Eg8KB21lc3NhZ2UYBCABKAkiRwoTSW1wb3J0QXNzZXRSZXNwb25zZRIwCgdy",
-            "ZXN1bHRzGAEgAygLMh8ubWNwLnVuaXR5LnYxLkltcG9ydEFzc2V0UmVzdWx0",
-            "IjYKEE1vdmVBc3NldFJlcXVlc3QSEQoJZnJvbV9wYXRoGAEgASgJEg8KB3Rv",
-            "X3BhdGgYAiABKAkiQgoRTW92ZUFzc2V0UmVzcG9uc2USCgoCb2sYASABKAgS",
-            "DwoHbWVzc2FnZRgCIAEoCRIQCghuZXdfZ3VpZBgDIAEoCSIxChJEZWxldGVB",
-            "c3NldFJlcXVlc3QSDQoFcGF0aHMYASADKAkSDAoEc29mdBgCIAEoCCI2ChNE",
-            "ZWxldGVBc3NldFJlc3BvbnNlEg8KB2RlbGV0ZWQYASADKAkSDgoGZmFpbGVk",
-            "GAIgAygJIh8KDlJlZnJlc2hSZXF1ZXN0Eg0KBWZvcmNlGAEgASgIIh0KD1Jl",
-            "ZnJlc2hSZXNwb25zZRIKCgJvaxgBIAEoCCIiChFHdWlkVG9QYXRoUmVxdWVz",
-            "dBINCgVndWlkcxgBIAMoCSJ4ChJHdWlkVG9QYXRoUmVzcG9uc2USNgoDbWFw",
-            "GAEgAygLMikubWNwLnVuaXR5LnYxLkd1aWRUb1BhdGhSZXNwb25zZS5NYXBF",
-            "bnRyeRoqCghNYXBFbnRyeRILCgNrZXkYASABKAkSDQoFdmFsdWUYAiABKAk6",
-            "AjgBIiIKEVBhdGhUb0d1aWRSZXF1ZXN0Eg0KBXBhdGhzGAEgAygJIngKElBh",
-            "dGhUb0d1aWRSZXNwb25zZRI2CgNtYXAYASADKAsyKS5tY3AudW5pdHkudjEu",
-            "UGF0aFRvR3VpZFJlc3BvbnNlLk1hcEVudHJ5GioKCE1hcEVudHJ5EgsKA2tl",
-            "eRgBIAEoCRINCgV2YWx1ZRgCIAEoCToCOAEiwwIKDUFzc2V0c1JlcXVlc3QS",
-            "MgoGaW1wb3J0GAEgASgLMiAubWNwLnVuaXR5LnYxLkltcG9ydEFzc2V0UmVx",
-            "dWVzdEgAEi4KBG1vdmUYAiABKAsyHi5tY3AudW5pdHkudjEuTW92ZUFzc2V0",
-            "UmVxdWVzdEgAEjIKBmRlbGV0ZRgDIAEoCzIgLm1jcC51bml0eS52MS5EZWxl",
-            "dGVBc3NldFJlcXVlc3RIABIvCgdyZWZyZXNoGAQgASgLMhwubWNwLnVuaXR5",
-            "LnYxLlJlZnJlc2hSZXF1ZXN0SAASLgoDZzJwGAUgASgLMh8ubWNwLnVuaXR5",
-            "LnYxLkd1aWRUb1BhdGhSZXF1ZXN0SAASLgoDcDJnGAYgASgLMh8ubWNwLnVu",
-            "aXR5LnYxLlBhdGhUb0d1aWRSZXF1ZXN0SABCCQoHcGF5bG9hZCLwAgoOQXNz",
-            "ZXRzUmVzcG9uc2USEwoLc3RhdHVzX2NvZGUYASABKAUSDwoHbWVzc2FnZRgC",
-            "IAEoCRIzCgZpbXBvcnQYCiABKAsyIS5tY3AudW5pdHkudjEuSW1wb3J0QXNz",
-            "ZXRSZXNwb25zZUgAEi8KBG1vdmUYCyABKAsyHy5tY3AudW5pdHkudjEuTW92",
-            "ZUFzc2V0UmVzcG9uc2VIABIzCgZkZWxldGUYDCABKAsyIS5tY3AudW5pdHku",
-            "djEuRGVsZXRlQXNzZXRSZXNwb25zZUgAEjAKB3JlZnJlc2gYDSABKAsyHS5t",
-            "Y3AudW5pdHkudjEuUmVmcmVzaFJlc3BvbnNlSAASLwoDZzJwGA4gASgLMiAu",
-            "bWNwLnVuaXR5LnYxLkd1aWRUb1BhdGhSZXNwb25zZUgAEi8KA3AyZxgPIAEo",
-            "CzIgLm1jcC51bml0eS52MS5QYXRoVG9HdWlkUmVzcG9uc2VIAEIJCgdwYXls",
-            "b2FkYgZwcm90bzM="));
+            "ZRgCIAEoCBIUCgxhdXRvX3JlZnJlc2gYAyABKAgSDQoFb3BfaWQYBCABKAki",
+            "TAoRSW1wb3J0QXNzZXRSZXN1bHQSDAoEcGF0aBgBIAEoCRIMCgRndWlkGAIg",
+            "ASgJEgoKAm9rGAMgASgIEg8KB21lc3NhZ2UYBCABKAkiVgoTSW1wb3J0QXNz",
+            "ZXRSZXNwb25zZRIwCgdyZXN1bHRzGAEgAygLMh8ubWNwLnVuaXR5LnYxLklt",
+            "cG9ydEFzc2V0UmVzdWx0Eg0KBW9wX2lkGAIgASgJIjYKEE1vdmVBc3NldFJl",
+            "cXVlc3QSEQoJZnJvbV9wYXRoGAEgASgJEg8KB3RvX3BhdGgYAiABKAkiQgoR",
+            "TW92ZUFzc2V0UmVzcG9uc2USCgoCb2sYASABKAgSDwoHbWVzc2FnZRgCIAEo",
+            "CRIQCghuZXdfZ3VpZBgDIAEoCSIxChJEZWxldGVBc3NldFJlcXVlc3QSDQoF",
+            "cGF0aHMYASADKAkSDAoEc29mdBgCIAEoCCI2ChNEZWxldGVBc3NldFJlc3Bv",
+            "bnNlEg8KB2RlbGV0ZWQYASADKAkSDgoGZmFpbGVkGAIgAygJIh8KDlJlZnJl",
+            "c2hSZXF1ZXN0Eg0KBWZvcmNlGAEgASgIIh0KD1JlZnJlc2hSZXNwb25zZRIK",
+            "CgJvaxgBIAEoCCIiChFHdWlkVG9QYXRoUmVxdWVzdBINCgVndWlkcxgBIAMo",
+            "CSJ4ChJHdWlkVG9QYXRoUmVzcG9uc2USNgoDbWFwGAEgAygLMikubWNwLnVu",
+            "aXR5LnYxLkd1aWRUb1BhdGhSZXNwb25zZS5NYXBFbnRyeRoqCghNYXBFbnRy",
+            "eRILCgNrZXkYASABKAkSDQoFdmFsdWUYAiABKAk6AjgBIiIKEVBhdGhUb0d1",
+            "aWRSZXF1ZXN0Eg0KBXBhdGhzGAEgAygJIngKElBhdGhUb0d1aWRSZXNwb25z",
+            "ZRI2CgNtYXAYASADKAsyKS5tY3AudW5pdHkudjEuUGF0aFRvR3VpZFJlc3Bv",
+            "bnNlLk1hcEVudHJ5GioKCE1hcEVudHJ5EgsKA2tleRgBIAEoCRINCgV2YWx1",
+            "ZRgCIAEoCToCOAEiwwIKDUFzc2V0c1JlcXVlc3QSMgoGaW1wb3J0GAEgASgL",
+            "MiAubWNwLnVuaXR5LnYxLkltcG9ydEFzc2V0UmVxdWVzdEgAEi4KBG1vdmUY",
+            "AiABKAsyHi5tY3AudW5pdHkudjEuTW92ZUFzc2V0UmVxdWVzdEgAEjIKBmRl",
+            "bGV0ZRgDIAEoCzIgLm1jcC51bml0eS52MS5EZWxldGVBc3NldFJlcXVlc3RI",
+            "ABIvCgdyZWZyZXNoGAQgASgLMhwubWNwLnVuaXR5LnYxLlJlZnJlc2hSZXF1",
+            "ZXN0SAASLgoDZzJwGAUgASgLMh8ubWNwLnVuaXR5LnYxLkd1aWRUb1BhdGhS",
+            "ZXF1ZXN0SAASLgoDcDJnGAYgASgLMh8ubWNwLnVuaXR5LnYxLlBhdGhUb0d1",
+            "aWRSZXF1ZXN0SABCCQoHcGF5bG9hZCLwAgoOQXNzZXRzUmVzcG9uc2USEwoL",
+            "c3RhdHVzX2NvZGUYASABKAUSDwoHbWVzc2FnZRgCIAEoCRIzCgZpbXBvcnQY",
+            "CiABKAsyIS5tY3AudW5pdHkudjEuSW1wb3J0QXNzZXRSZXNwb25zZUgAEi8K",
+            "BG1vdmUYCyABKAsyHy5tY3AudW5pdHkudjEuTW92ZUFzc2V0UmVzcG9uc2VI",
+            "ABIzCgZkZWxldGUYDCABKAsyIS5tY3AudW5pdHkudjEuRGVsZXRlQXNzZXRS",
+            "ZXNwb25zZUgAEjAKB3JlZnJlc2gYDSABKAsyHS5tY3AudW5pdHkudjEuUmVm",
+            "cmVzaFJlc3BvbnNlSAASLwoDZzJwGA4gASgLMiAubWNwLnVuaXR5LnYxLkd1",
+            "aWRUb1BhdGhSZXNwb25zZUgAEi8KA3AyZxgPIAEoCzIgLm1jcC51bml0eS52",
+            "MS5QYXRoVG9HdWlkUmVzcG9uc2VIAEIJCgdwYXlsb2FkYgZwcm90bzM="));
       descriptor = pbr::FileDescriptor.FromGeneratedCode(descriptorData,
           new pbr::FileDescriptor[] { },
           new pbr::GeneratedClrTypeInfo(null, null, new pbr::GeneratedClrTypeInfo[] {
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.ImportAssetRequest), global::Mcp.Unity.V1.ImportAssetRequest.Parser, new[]{ "Paths", "Recursive", "AutoRefresh" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.ImportAssetRequest), global::Mcp.Unity.V1.ImportAssetRequest.Parser, new[]{ "Paths", "Recursive", "AutoRefresh", "OpId" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.ImportAssetResult), global::Mcp.Unity.V1.ImportAssetResult.Parser, new[]{ "Path", "Guid", "Ok", "Message" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.ImportAssetResponse), global::Mcp.Unity.V1.ImportAssetResponse.Parser, new[]{ "Results" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.ImportAssetResponse), global::Mcp.Unity.V1.ImportAssetResponse.Parser, new[]{ "Results", "OpId" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.MoveAssetRequest), global::Mcp.Unity.V1.MoveAssetRequest.Parser, new[]{ "FromPath", "ToPath" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.MoveAssetResponse), global::Mcp.Unity.V1.MoveAssetResponse.Parser, new[]{ "Ok", "Message", "NewGuid" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.DeleteAssetRequest), global::Mcp.Unity.V1.DeleteAssetRequest.Parser, new[]{ "Paths", "Soft" }, null, null, null, null),
@@ -125,6 +125,7 @@ namespace Mcp.Unity.V1 {
       paths_ = other.paths_.Clone();
       recursive_ = other.recursive_;
       autoRefresh_ = other.autoRefresh_;
+      opId_ = other.opId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -169,6 +170,21 @@ namespace Mcp.Unity.V1 {
       }
     }
 
+    /// <summary>Field number for the "op_id" field.</summary>
+    public const int OpIdFieldNumber = 4;
+    private string opId_ = "";
+    /// <summary>
+    /// id to report the operation's events under; empty = bridge assigns
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string OpId {
+      get { return opId_; }
+      set {
+        opId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
@@ -187,6 +203,7 @@ namespace Mcp.Unity.V1 {
       if(!paths_.Equals(other.paths_)) return false;
       if (Recursive != other.Recursive) return false;
       if (AutoRefresh != other.AutoRefresh) return false;
+      if (OpId != other.OpId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -197,6 +214,7 @@ namespace Mcp.Unity.V1 {
       hash ^= paths_.GetHashCode();
       if (Recursive != false) hash ^= Recursive.GetHashCode();
       if (AutoRefresh != false) hash ^= AutoRefresh.GetHashCode();
+      if (OpId.Length != 0) hash ^= OpId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -224,6 +242,10 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(24);
         output.WriteBool(AutoRefresh);
       }
+      if (OpId.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -243,6 +265,10 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(24);
         output.WriteBool(AutoRefresh);
       }
+      if (OpId.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -260,6 +286,9 @@ namespace Mcp.Unity.V1 {
       if (AutoRefresh != false) {
         size += 1 + 1;
       }
+      if (OpId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(OpId);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -279,6 +308,9 @@ namespace Mcp.Unity.V1 {
       if (other.AutoRefresh != false) {
         AutoRefresh = other.AutoRefresh;
       }
+      if (other.OpId.Length != 0) {
+        OpId = other.OpId;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -310,6 +342,10 @@ namespace Mcp.Unity.V1 {
             AutoRefresh = input.ReadBool();
             break;
           }
+          case 34: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     #endif
@@ -341,6 +377,10 @@ namespace Mcp.Unity.V1 {
             AutoRefresh = input.ReadBool();
             break;
           }
+          case 34: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     }
@@ -693,6 +733,7 @@ namespace Mcp.Unity.V1 {
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public ImportAssetResponse(ImportAssetResponse other) : this() {
       results_ = other.results_.Clone();
+      opId_ = other.opId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -713,6 +754,21 @@ namespace Mcp.Unity.V1 {
       get { return results_; }
     }
 
+    /// <summary>Field number for the "op_id" field.</summary>
+    public const int OpIdFieldNumber = 2;
+    private string opId_ = "";
+    /// <summary>
+    /// id the operation's events were reported under
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string OpId {
+      get { return opId_; }
+      set {
+        opId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
@@ -729,6 +785,7 @@ namespace Mcp.Unity.V1 {
         return true;
       }
       if(!results_.Equals(other.results_)) return false;
+      if (OpId != other.OpId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -737,6 +794,7 @@ namespace Mcp.Unity.V1 {
     public override int GetHashCode() {
       int hash = 1;
       hash ^= results_.GetHashCode();
+      if (OpId.Length != 0) hash ^= OpId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -756,6 +814,10 @@ namespace Mcp.Unity.V1 {
       output.WriteRawMessage(this);
     #else
       results_.WriteTo(output, _repeated_results_codec);
+      if (OpId.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -767,6 +829,10 @@ namespace Mcp.Unity.V1 {
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
       results_.WriteTo(ref output, _repeated_results_codec);
+      if (OpId.Length != 0) {
+        output.WriteRawTag(18);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -778,6 +844,9 @@ namespace Mcp.Unity.V1 {
     public int CalculateSize() {
       int size = 0;
       size += results_.CalculateSize(_repeated_results_codec);
+      if (OpId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(OpId);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -791,6 +860,9 @@ namespace Mcp.Unity.V1 {
         return;
       }
       results_.Add(other.results_);
+      if (other.OpId.Length != 0) {
+        OpId = other.OpId;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -814,6 +886,10 @@ namespace Mcp.Unity.V1 {
             results_.AddEntriesFrom(input, _repeated_results_codec);
             break;
           }
+          case 18: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     #endif
@@ -837,6 +913,10 @@ namespace Mcp.Unity.V1 {
             results_.AddEntriesFrom(ref input, _repeated_results_codec);
             break;
           }
+          case 18: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     }
@@ -27,45 +27,47 @@ namespace Mcp.Unity.V1 {
             "ChhtY3AvdW5pdHkvdjEvYnVpbGQucHJvdG8SDG1jcC51bml0eS52MSJvCg1C",
             "dWlsZFZhcmlhbnRzEhQKDGFyY2hpdGVjdHVyZRgBIAEoCRIMCgRhYmlzGAIg",
             "AygJEhMKC2RldmVsb3BtZW50GAMgASgIEg4KBmlsMmNwcBgEIAEoCBIVCg1z",
-            "dHJpcF9zeW1ib2xzGAUgASgIIpoCChJCdWlsZFBsYXllclJlcXVlc3QSLQoI",
+            "dHJpcF9zeW1ib2xzGAUgASgIIqkCChJCdWlsZFBsYXllclJlcXVlc3QSLQoI",
             "cGxhdGZvcm0YASABKA4yGy5tY3AudW5pdHkudjEuQnVpbGRQbGF0Zm9ybRIT",
             "CgtvdXRwdXRfcGF0aBgCIAEoCRIOCgZzY2VuZXMYAyADKAkSLQoIdmFyaWFu",
             "dHMYBCABKAsyGy5tY3AudW5pdHkudjEuQnVpbGRWYXJpYW50cxJLCg5kZWZp",
             "bmVfc3ltYm9scxgFIAMoCzIzLm1jcC51bml0eS52MS5CdWlsZFBsYXllclJl",
-            "cXVlc3QuRGVmaW5lU3ltYm9sc0VudHJ5GjQKEkRlZmluZVN5bWJvbHNFbnRy",
-            "eRILCgNrZXkYASABKAkSDQoFdmFsdWUYAiABKAk6AjgBIo0BChNCdWlsZFBs",
-            "YXllclJlc3BvbnNlEhMKC3N0YXR1c19jb2RlGAEgASgFEg8KB21lc3NhZ2UY",
-            "AiABKAkSEwoLb3V0cHV0X3BhdGgYAyABKAkSFQoNYnVpbGRfdGltZV9tcxgE",
-            "IAEoBBISCgpzaXplX2J5dGVzGAUgASgEEhAKCHdhcm5pbmdzGAYgAygJIncK",
-            "GEJ1aWxkQXNzZXRCdW5kbGVzUmVxdWVzdBIYChBvdXRwdXRfZGlyZWN0b3J5",
-            "GAEgASgJEhUKDWRldGVybWluaXN0aWMYAiABKAgSEwoLY2h1bmtfYmFzZWQY",
-            "AyABKAgSFQoNZm9yY2VfcmVidWlsZBgEIAEoCCJyChlCdWlsZEFzc2V0QnVu",
-            "ZGxlc1Jlc3BvbnNlEhMKC3N0YXR1c19jb2RlGAEgASgFEg8KB21lc3NhZ2UY",
-            "AiABKAkSGAoQb3V0cHV0X2RpcmVjdG9yeRgDIAEoCRIVCg1idWlsZF90aW1l",
-            "X21zGAQgASgEIogBCgxCdWlsZFJlcXVlc3QSMgoGcGxheWVyGAEgASgLMiAu",
-            "bWNwLnVuaXR5LnYxLkJ1aWxkUGxheWVyUmVxdWVzdEgAEjkKB2J1bmRsZXMY",
-            "AiABKAsyJi5tY3AudW5pdHkudjEuQnVpbGRBc3NldEJ1bmRsZXNSZXF1ZXN0",
-            "SABCCQoHcGF5bG9hZCKLAQoNQnVpbGRSZXNwb25zZRIzCgZwbGF5ZXIYASAB",
-            "KAsyIS5tY3AudW5pdHkudjEuQnVpbGRQbGF5ZXJSZXNwb25zZUgAEjoKB2J1",
-            "bmRsZXMYAiABKAsyJy5tY3AudW5pdHkudjEuQnVpbGRBc3NldEJ1bmRsZXNS",
-            "ZXNwb25zZUgAQgkKB3BheWxvYWQqjgEKDUJ1aWxkUGxhdGZvcm0SEgoOQlBf",
-            "VU5TUEVDSUZJRUQQABIbChdCUF9TVEFOREFMT05FX1dJTkRPV1M2NBABEhUK",
-            "EUJQX1NUQU5EQUxPTkVfT1NYEAISGQoVQlBfU1RBTkRBTE9ORV9MSU5VWDY0",
-            "EAMSDgoKQlBfQU5EUk9JRBAKEgoKBkJQX0lPUxALMoMCCgVCdWlsZBJSCgtC",
-            "dWlsZFBsYXllchIgLm1jcC51bml0eS52MS5CdWlsZFBsYXllclJlcXVlc3Qa",
-            "IS5tY3AudW5pdHkudjEuQnVpbGRQbGF5ZXJSZXNwb25zZRJkChFCdWlsZEFz",
-            "c2V0QnVuZGxlcxImLm1jcC51bml0eS52MS5CdWlsZEFzc2V0QnVuZGxlc1Jl",
-            "cXVlc3QaJy5tY3AudW5pdHkudjEuQnVpbGRBc3NldEJ1bmRsZXNSZXNwb25z",
-            "ZRJACgVCdWlsZBIaLm1jcC51bml0eS52MS5CdWlsZFJlcXVlc3QaGy5tY3Au",
-            "dW5pdHkudjEuQnVpbGRSZXNwb25zZWIGcHJvdG8z"));
+            "cXVlc3QuRGVmaW5lU3ltYm9sc0VudHJ5Eg0KBW9wX2lkGAYgASgJGjQKEkRl",
+            "ZmluZVN5bWJvbHNFbnRyeRILCgNrZXkYASABKAkSDQoFdmFsdWUYAiABKAk6",
+            "AjgBIpwBChNCdWlsZFBsYXllclJlc3BvbnNlEhMKC3N0YXR1c19jb2RlGAEg",
+            "ASgFEg8KB21lc3NhZ2UYAiABKAkSEwoLb3V0cHV0X3BhdGgYAyABKAkSFQoN",
+            "YnVpbGRfdGltZV9tcxgEIAEoBBISCgpzaXplX2J5dGVzGAUgASgEEhAKCHdh",
+            "cm5pbmdzGAYgAygJEg0KBW9wX2lkGAcgASgJIoYBChhCdWlsZEFzc2V0QnVu",
+            "ZGxlc1JlcXVlc3QSGAoQb3V0cHV0X2RpcmVjdG9yeRgBIAEoCRIVCg1kZXRl",
+            "cm1pbmlzdGljGAIgASgIEhMKC2NodW5rX2Jhc2VkGAMgASgIEhUKDWZvcmNl",
+            "X3JlYnVpbGQYBCABKAgSDQoFb3BfaWQYBSABKAkigQEKGUJ1aWxkQXNzZXRC",
+            "dW5kbGVzUmVzcG9uc2USEwoLc3RhdHVzX2NvZGUYASABKAUSDwoHbWVzc2Fn",
+            "ZRgCIAEoCRIYChBvdXRwdXRfZGlyZWN0b3J5GAMgASgJEhUKDWJ1aWxkX3Rp",
+            "bWVfbXMYBCABKAQSDQoFb3BfaWQYBSABKAkiiAEKDEJ1aWxkUmVxdWVzdBIy",
+            "CgZwbGF5ZXIYASABKAsyIC5tY3AudW5pdHkudjEuQnVpbGRQbGF5ZXJSZXF1",
+            "ZXN0SAASOQoHYnVuZGxlcxgCIAEoCzImLm1jcC51bml0eS52MS5CdWlsZEFz",
+            "c2V0QnVuZGxlc1JlcXVlc3RIAEIJCgdwYXlsb2FkIosBCg1CdWlsZFJlc3Bv",
+            "bnNlEjMKBnBsYXllchgBIAEoCzIhLm1jcC51bml0eS52MS5CdWlsZFBsYXll",
+            "clJlc3BvbnNlSAASOgoHYnVuZGxlcxgCIAEoCzInLm1jcC51bml0eS52MS5C",
+            "dWlsZEFzc2V0QnVuZGxlc1Jlc3BvbnNlSABCCQoHcGF5bG9hZCqOAQoNQnVp",
+            "bGRQbGF0Zm9ybRISCg5CUF9VTlNQRUNJRklFRBAAEhsKF0JQX1NUQU5EQUxP",
+            "TkVfV0lORE9XUzY0EAESFQoRQlBfU1RBTkRBTE9ORV9PU1gQAhIZChVCUF9T",
+            "VEFOREFMT05FX0xJTlVYNjQQAxIOCgpCUF9BTkRST0lEEAoSCgoGQlBfSU9T",
+            "EAsygwIKBUJ1aWxkElIKC0J1aWxkUGxheWVyEiAubWNwLnVuaXR5LnYxLkJ1",
+            "aWxkUGxheWVyUmVxdWVzdBohLm1jcC51bml0eS52MS5CdWlsZFBsYXllclJl",
+            "c3BvbnNlEmQKEUJ1aWxkQXNzZXRCdW5kbGVzEiYubWNwLnVuaXR5LnYxLkJ1",
+            "aWxkQXNzZXRCdW5kbGVzUmVxdWVzdBonLm1jcC51bml0eS52MS5CdWlsZEFz",
+            "c2V0QnVuZGxlc1Jlc3BvbnNlEkAKBUJ1aWxkEhoubWNwLnVuaXR5LnYxLkJ1",
+            "aWxkUmVxdWVzdBobLm1jcC51bml0eS52MS5CdWlsZFJlc3BvbnNlYgZwcm90",
+            "bzM="));
       descriptor = pbr::FileDescriptor.FromGeneratedCode(descriptorData,
           new pbr::FileDescriptor[] { },
           new pbr::GeneratedClrTypeInfo(new[] {typeof(global::Mcp.Unity.V1.BuildPlatform), }, null, new pbr::GeneratedClrTypeInfo[] {
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildVariants), global::Mcp.Unity.V1.BuildVariants.Parser, new[]{ "Architecture", "Abis", "Development", "Il2Cpp", "StripSymbols" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildPlayerRequest), global::Mcp.Unity.V1.BuildPlayerRequest.Parser, new[]{ "Platform", "OutputPath", "Scenes", "Variants", "DefineSymbols" }, null, null, null, new pbr::GeneratedClrTypeInfo[] { null, }),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildPlayerResponse), global::Mcp.Unity.V1.BuildPlayerResponse.Parser, new[]{ "StatusCode", "Message", "OutputPath", "BuildTimeMs", "SizeBytes", "Warnings" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildAssetBundlesRequest), global::Mcp.Unity.V1.BuildAssetBundlesRequest.Parser, new[]{ "OutputDirectory", "Deterministic", "ChunkBased", "ForceRebuild" }, null, null, null, null),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildAssetBundlesResponse), global::Mcp.Unity.V1.BuildAssetBundlesResponse.Parser, new[]{ "StatusCode", "Message", "OutputDirectory", "BuildTimeMs" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildPlayerRequest), global::Mcp.Unity.V1.BuildPlayerRequest.Parser, new[]{ "Platform", "OutputPath", "Scenes", "Variants", "DefineSymbols", "OpId" }, null, null, null, new pbr::GeneratedClrTypeInfo[] { null, }),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildPlayerResponse), global::Mcp.Unity.V1.BuildPlayerResponse.Parser, new[]{ "StatusCode", "Message", "OutputPath", "BuildTimeMs", "SizeBytes", "Warnings", "OpId" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildAssetBundlesRequest), global::Mcp.Unity.V1.BuildAssetBundlesRequest.Parser, new[]{ "OutputDirectory", "Deterministic", "ChunkBased", "ForceRebuild", "OpId" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildAssetBundlesResponse), global::Mcp.Unity.V1.BuildAssetBundlesResponse.Parser, new[]{ "StatusCode", "Message", "OutputDirectory", "BuildTimeMs", "OpId" }, null, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildRequest), global::Mcp.Unity.V1.BuildRequest.Parser, new[]{ "Player", "Bundles" }, new[]{ "Payload" }, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.BuildResponse), global::Mcp.Unity.V1.BuildResponse.Parser, new[]{ "Player", "Bundles" }, new[]{ "Payload" }, null, null, null)
           }));
@@ -488,6 +490,7 @@ namespace Mcp.Unity.V1 {
       scenes_ = other.scenes_.Clone();
       variants_ = other.variants_ != null ? other.variants_.Clone() : null;
       defineSymbols_ = other.defineSymbols_.Clone();
+      opId_ = other.opId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -567,6 +570,21 @@ namespace Mcp.Unity.V1 {
       get { return defineSymbols_; }
     }
 
+    /// <summary>Field number for the "op_id" field.</summary>
+    public const int OpIdFieldNumber = 6;
+    private string opId_ = "";
+    /// <summary>
+    /// id to report the operation's events under; empty = bridge assigns
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string OpId {
+      get { return opId_; }
+      set {
+        opId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
@@ -587,6 +605,7 @@ namespace Mcp.Unity.V1 {
       if(!scenes_.Equals(other.scenes_)) return false;
       if (!object.Equals(Variants, other.Variants)) return false;
       if (!DefineSymbols.Equals(other.DefineSymbols)) return false;
+      if (OpId != other.OpId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -599,6 +618,7 @@ namespace Mcp.Unity.V1 {
       hash ^= scenes_.GetHashCode();
       if (variants_ != null) hash ^= Variants.GetHashCode();
       hash ^= DefineSymbols.GetHashCode();
+      if (OpId.Length != 0) hash ^= OpId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -631,6 +651,10 @@ namespace Mcp.Unity.V1 {
         output.WriteMessage(Variants);
       }
       defineSymbols_.WriteTo(output, _map_defineSymbols_codec);
+      if (OpId.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -655,6 +679,10 @@ namespace Mcp.Unity.V1 {
         output.WriteMessage(Variants);
       }
       defineSymbols_.WriteTo(ref output, _map_defineSymbols_codec);
+      if (OpId.Length != 0) {
+        output.WriteRawTag(50);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -676,6 +704,9 @@ namespace Mcp.Unity.V1 {
         size += 1 + pb::CodedOutputStream.ComputeMessageSize(Variants);
       }
       size += defineSymbols_.CalculateSize(_map_defineSymbols_codec);
+      if (OpId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(OpId);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -702,6 +733,9 @@ namespace Mcp.Unity.V1 {
         Variants.MergeFrom(other.Variants);
       }
       defineSymbols_.MergeFrom(other.defineSymbols_);
+      if (other.OpId.Length != 0) {
+        OpId = other.OpId;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -744,6 +778,10 @@ namespace Mcp.Unity.V1 {
             defineSymbols_.AddEntriesFrom(input, _map_defineSymbols_codec);
             break;
           }
+          case 50: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     #endif
@@ -786,6 +824,10 @@ namespace Mcp.Unity.V1 {
             defineSymbols_.AddEntriesFrom(ref input, _map_defineSymbols_codec);
             break;
           }
+          case 50: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     }
@@ -837,6 +879,7 @@ namespace Mcp.Unity.V1 {
       buildTimeMs_ = other.buildTimeMs_;
       sizeBytes_ = other.sizeBytes_;
       warnings_ = other.warnings_.Clone();
+      opId_ = other.opId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -926,6 +969,21 @@ namespace Mcp.Unity.V1 {
       get { return warnings_; }
     }
 
+    /// <summary>Field number for the "op_id" field.</summary>
+    public const int OpIdFieldNumber = 7;
+    private string opId_ = "";
+    /// <summary>
+    /// id the operation's events were reported under
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string OpId {
+      get { return opId_; }
+      set {
+        opId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
@@ -947,6 +1005,7 @@ namespace Mcp.Unity.V1 {
       if (BuildTimeMs != other.BuildTimeMs) return false;
       if (SizeBytes != other.SizeBytes) return false;
       if(!warnings_.Equals(other.warnings_)) return false;
+      if (OpId != other.OpId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -960,6 +1019,7 @@ namespace Mcp.Unity.V1 {
       if (BuildTimeMs != 0UL) hash ^= BuildTimeMs.GetHashCode();
       if (SizeBytes != 0UL) hash ^= SizeBytes.GetHashCode();
       hash ^= warnings_.GetHashCode();
+      if (OpId.Length != 0) hash ^= OpId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -999,6 +1059,10 @@ namespace Mcp.Unity.V1 {
         output.WriteUInt64(SizeBytes);
       }
       warnings_.WriteTo(output, _repeated_warnings_codec);
+      if (OpId.Length != 0) {
+        output.WriteRawTag(58);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -1030,6 +1094,10 @@ namespace Mcp.Unity.V1 {
         output.WriteUInt64(SizeBytes);
       }
       warnings_.WriteTo(ref output, _repeated_warnings_codec);
+      if (OpId.Length != 0) {
+        output.WriteRawTag(58);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -1056,6 +1124,9 @@ namespace Mcp.Unity.V1 {
         size += 1 + pb::CodedOutputStream.ComputeUInt64Size(SizeBytes);
       }
       size += warnings_.CalculateSize(_repeated_warnings_codec);
+      if (OpId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(OpId);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -1084,6 +1155,9 @@ namespace Mcp.Unity.V1 {
         SizeBytes = other.SizeBytes;
       }
       warnings_.Add(other.warnings_);
+      if (other.OpId.Length != 0) {
+        OpId = other.OpId;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -1127,6 +1201,10 @@ namespace Mcp.Unity.V1 {
             warnings_.AddEntriesFrom(input, _repeated_warnings_codec);
             break;
           }
+          case 58: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     #endif
@@ -1170,6 +1248,10 @@ namespace Mcp.Unity.V1 {
             warnings_.AddEntriesFrom(ref input, _repeated_warnings_codec);
             break;
           }
+          case 58: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     }
@@ -1219,6 +1301,7 @@ namespace Mcp.Unity.V1 {
       deterministic_ = other.deterministic_;
       chunkBased_ = other.chunkBased_;
       forceRebuild_ = other.forceRebuild_;
+      opId_ = other.opId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -1288,6 +1371,21 @@ namespace Mcp.Unity.V1 {
       }
     }
 
+    /// <summary>Field number for the "op_id" field.</summary>
+    public const int OpIdFieldNumber = 5;
+    private string opId_ = "";
+    /// <summary>
+    /// id to report the operation's events under; empty = bridge assigns
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string OpId {
+      get { return opId_; }
+      set {
+        opId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
@@ -1307,6 +1405,7 @@ namespace Mcp.Unity.V1 {
       if (Deterministic != other.Deterministic) return false;
       if (ChunkBased != other.ChunkBased) return false;
       if (ForceRebuild != other.ForceRebuild) return false;
+      if (OpId != other.OpId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -1318,6 +1417,7 @@ namespace Mcp.Unity.V1 {
       if (Deterministic != false) hash ^= Deterministic.GetHashCode();
       if (ChunkBased != false) hash ^= ChunkBased.GetHashCode();
       if (ForceRebuild != false) hash ^= ForceRebuild.GetHashCode();
+      if (OpId.Length != 0) hash ^= OpId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1352,6 +1452,10 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(32);
         output.WriteBool(ForceRebuild);
       }
+      if (OpId.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -1378,6 +1482,10 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(32);
         output.WriteBool(ForceRebuild);
       }
+      if (OpId.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -1400,6 +1508,9 @@ namespace Mcp.Unity.V1 {
       if (ForceRebuild != false) {
         size += 1 + 1;
       }
+      if (OpId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(OpId);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -1424,6 +1535,9 @@ namespace Mcp.Unity.V1 {
       if (other.ForceRebuild != false) {
         ForceRebuild = other.ForceRebuild;
       }
+      if (other.OpId.Length != 0) {
+        OpId = other.OpId;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -1459,6 +1573,10 @@ namespace Mcp.Unity.V1 {
             ForceRebuild = input.ReadBool();
             break;
           }
+          case 42: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     #endif
@@ -1494,6 +1612,10 @@ namespace Mcp.Unity.V1 {
             ForceRebuild = input.ReadBool();
             break;
           }
+          case 42: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     }
@@ -1543,6 +1665,7 @@ namespace Mcp.Unity.V1 {
       message_ = other.message_;
       outputDirectory_ = other.outputDirectory_;
       buildTimeMs_ = other.buildTimeMs_;
+      opId_ = other.opId_;
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
     }
 
@@ -1600,6 +1723,21 @@ namespace Mcp.Unity.V1 {
       }
     }
 
+    /// <summary>Field number for the "op_id" field.</summary>
+    public const int OpIdFieldNumber = 5;
+    private string opId_ = "";
+    /// <summary>
+    /// id the operation's events were reported under
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public string OpId {
+      get { return opId_; }
+      set {
+        opId_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public override bool Equals(object other) {
@@ -1619,6 +1757,7 @@ namespace Mcp.Unity.V1 {
       if (Message != other.Message) return false;
       if (OutputDirectory != other.OutputDirectory) return false;
       if (BuildTimeMs != other.BuildTimeMs) return false;
+      if (OpId != other.OpId) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
 
@@ -1630,6 +1769,7 @@ namespace Mcp.Unity.V1 {
       if (Message.Length != 0) hash ^= Message.GetHashCode();
       if (OutputDirectory.Length != 0) hash ^= OutputDirectory.GetHashCode();
       if (BuildTimeMs != 0UL) hash ^= BuildTimeMs.GetHashCode();
+      if (OpId.Length != 0) hash ^= OpId.GetHashCode();
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
       }
@@ -1664,6 +1804,10 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(32);
         output.WriteUInt64(BuildTimeMs);
       }
+      if (OpId.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -1690,6 +1834,10 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(32);
         output.WriteUInt64(BuildTimeMs);
       }
+      if (OpId.Length != 0) {
+        output.WriteRawTag(42);
+        output.WriteString(OpId);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -1712,6 +1860,9 @@ namespace Mcp.Unity.V1 {
       if (BuildTimeMs != 0UL) {
         size += 1 + pb::CodedOutputStream.ComputeUInt64Size(BuildTimeMs);
       }
+      if (OpId.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeStringSize(OpId);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -1736,6 +1887,9 @@ namespace Mcp.Unity.V1 {
       if (other.BuildTimeMs != 0UL) {
         BuildTimeMs = other.BuildTimeMs;
       }
+      if (other.OpId.Length != 0) {
+        OpId = other.OpId;
+      }
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
     }
 
@@ -1771,6 +1925,10 @@ namespace Mcp.Unity.V1 {
             BuildTimeMs = input.ReadUInt64();
             break;
           }
+          case 42: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     #endif
@@ -1806,6 +1964,10 @@ namespace Mcp.Unity.V1 {
             BuildTimeMs = input.ReadUInt64();
             break;
           }
+          case 42: {
+            OpId = input.ReadString();
+            break;
+          }
         }
       }
     }
@@ -29,59 +29,72 @@ namespace Mcp.Unity.V1 {
             "dHJvbC5wcm90bxoZbWNwL3VuaXR5L3YxL2Fzc2V0cy5wcm90bxoZbWNwL3Vu",
             "aXR5L3YxL3ByZWZhYi5wcm90bxoYbWNwL3VuaXR5L3YxL2J1aWxkLnByb3Rv",
             "Gh1tY3AvdW5pdHkvdjEvb3BlcmF0aW9ucy5wcm90bxoZbWNwL3VuaXR5L3Yx",
-            "L2V2ZW50cy5wcm90bxocbWNwL3VuaXR5L3YxL2NvbXBvbmVudC5wcm90byKy",
-            "AQoLSXBjRW52ZWxvcGUSFgoOY29ycmVsYXRpb25faWQYASABKAkSKwoHcmVx",
-            "dWVzdBgCIAEoCzIYLm1jcC51bml0eS52MS5JcGNSZXF1ZXN0SAASLQoIcmVz",
-            "cG9uc2UYAyABKAsyGS5tY3AudW5pdHkudjEuSXBjUmVzcG9uc2VIABInCgVl",
-            "dmVudBgEIAEoCzIWLm1jcC51bml0eS52MS5JcGNFdmVudEgAQgYKBGtpbmQi",
-            "+gYKCklwY1JlcXVlc3QSLQoGaGVhbHRoGAEgASgLMhsubWNwLnVuaXR5LnYx",
-            "LkhlYWx0aFJlcXVlc3RIABIsCg1nZXRfcGxheV9tb2RlGAogASgLMhMubWNw",
-            "LnVuaXR5LnYxLkVtcHR5SAASOQoNc2V0X3BsYXlfbW9kZRgLIAEoCzIgLm1j",
-            "cC51bml0eS52MS5TZXRQbGF5TW9kZVJlcXVlc3RIABJNChdnZXRfY29tcGls",
-            "ZV9kaWFnbm9zdGljcxgMIAEoCzIqLm1jcC51bml0eS52MS5HZXRDb21waWxl",
-            "RGlhZ25vc3RpY3NSZXF1ZXN0SAASRwoUZ2V0X3Byb2plY3Rfc2V0dGluZ3MY",
-            "DSABKAsyJy5tY3AudW5pdHkudjEuR2V0UHJvamVjdFNldHRpbmdzUmVxdWVz",
-            "dEgAEkcKFHNldF9wcm9qZWN0X3NldHRpbmdzGA4gASgLMicubWNwLnVuaXR5",
-            "LnYxLlNldFByb2plY3RTZXR0aW5nc1JlcXVlc3RIABItCgZzY2VuZXMYDyAB",
-            "KAsyGy5tY3AudW5pdHkudjEuU2NlbmVzUmVxdWVzdEgAEkEKEWV4ZWN1dGVf",
-            "bWVudV9pdGVtGBAgASgLMiQubWNwLnVuaXR5LnYxLkV4ZWN1dGVNZW51SXRl",
-            "bVJlcXVlc3RIABI4Cgxmb2N1c193aW5kb3cYESABKAsyIC5tY3AudW5pdHku",
-            "djEuRm9jdXNXaW5kb3dSZXF1ZXN0SAASLQoGYXNzZXRzGBQgASgLMhsubWNw",
-            "LnVuaXR5LnYxLkFzc2V0c1JlcXVlc3RIABItCgZwcmVmYWIYFSABKAsyGy5t",
-            "Y3AudW5pdHkudjEuUHJlZmFiUmVxdWVzdEgAEisKBWJ1aWxkGB4gASgLMhou",
-            "bWNwLnVuaXR5LnYxLkJ1aWxkUmVxdWVzdEgAEjoKDW9wZXJhdGlvbl9nZXQY",
-            "KCABKAsyIS5tY3AudW5pdHkudjEuT3BlcmF0aW9uR2V0UmVxdWVzdEgAEkAK",
-            "EG9wZXJhdGlvbl9jYW5jZWwYKSABKAsyJC5tY3AudW5pdHkudjEuT3BlcmF0",
-            "aW9uQ2FuY2VsUmVxdWVzdEgAEjMKCWNvbXBvbmVudBgyIAEoCzIeLm1jcC51",
-            "bml0eS52MS5Db21wb25lbnRSZXF1ZXN0SABCCQoHcGF5bG9hZCKvBwoLSXBj",
-            "UmVzcG9uc2USFgoOY29ycmVsYXRpb25faWQYASABKAkSLgoGaGVhbHRoGAIg",
-            "ASgLMhwubWNwLnVuaXR5LnYxLkhlYWx0aFJlc3BvbnNlSAASOgoNZ2V0X3Bs",
-            "YXlfbW9kZRgKIAEoCzIhLm1jcC51bml0eS52MS5HZXRQbGF5TW9kZVJlc3Bv",
-            "bnNlSAASOgoNc2V0X3BsYXlfbW9kZRgLIAEoCzIhLm1jcC51bml0eS52MS5T",
-            "ZXRQbGF5TW9kZVJlc3BvbnNlSAASTgoXZ2V0X2NvbXBpbGVfZGlhZ25vc3Rp",
-            "Y3MYDCABKAsyKy5tY3AudW5pdHkudjEuR2V0Q29tcGlsZURpYWdub3N0aWNz",
-            "UmVzcG9uc2VIABJIChRnZXRfcHJvamVjdF9zZXR0aW5ncxgNIAEoCzIoLm1j",
-            "cC51bml0eS52MS5HZXRQcm9qZWN0U2V0dGluZ3NSZXNwb25zZUgAEkgKFHNl",
-            "dF9wcm9qZWN0X3NldHRpbmdzGA4gASgLMigubWNwLnVuaXR5LnYxLlNldFBy",
-            "b2plY3RTZXR0aW5nc1Jlc3BvbnNlSAASLgoGc2NlbmVzGA8gASgLMhwubWNw",
-            "LnVuaXR5LnYxLlNjZW5lc1Jlc3BvbnNlSAASQgoRZXhlY3V0ZV9tZW51X2l0",
-            "ZW0YECABKAsyJS5tY3AudW5pdHkudjEuRXhlY3V0ZU1lbnVJdGVtUmVzcG9u",
-            "c2VIABI5Cgxmb2N1c193aW5kb3cYESABKAsyIS5tY3AudW5pdHkudjEuRm9j",
-            "dXNXaW5kb3dSZXNwb25zZUgAEi4KBmFzc2V0cxgUIAEoCzIcLm1jcC51bml0",
-            "eS52MS5Bc3NldHNSZXNwb25zZUgAEi4KBnByZWZhYhgVIAEoCzIcLm1jcC51",
-            "bml0eS52MS5QcmVmYWJSZXNwb25zZUgAEiwKBWJ1aWxkGB4gASgLMhsubWNw",
-            "LnVuaXR5LnYxLkJ1aWxkUmVzcG9uc2VIABI7Cg1vcGVyYXRpb25fZ2V0GCgg",
-            "ASgLMiIubWNwLnVuaXR5LnYxLk9wZXJhdGlvbkdldFJlc3BvbnNlSAASQQoQ",
-            "b3BlcmF0aW9uX2NhbmNlbBgpIAEoCzIlLm1jcC51bml0eS52MS5PcGVyYXRp",
-            "b25DYW5jZWxSZXNwb25zZUgAEjQKCWNvbXBvbmVudBgyIAEoCzIfLm1jcC51",
-            "bml0eS52MS5Db21wb25lbnRSZXNwb25zZUgAQgkKB3BheWxvYWQigQEKCElw",
-            "Y0V2ZW50EhcKD21vbm90b25pY190c19ucxgBIAEoAxIlCgNsb2cYCiABKAsy",
-            "Fi5tY3AudW5pdHkudjEuTG9nRXZlbnRIABIqCgJvcBgLIAEoCzIcLm1jcC51",
-            "bml0eS52MS5PcGVyYXRpb25FdmVudEgAQgkKB3BheWxvYWRiBnByb3RvMw=="));
+            "L2V2ZW50cy5wcm90bxocbWNwL3VuaXR5L3YxL2NvbXBvbmVudC5wcm90bxoe",
+            "bWNwL3VuaXR5L3YxL2lwY19jb250cm9sLnByb3RvItECCgtJcGNFbnZlbG9w",
+            "ZRIWCg5jb3JyZWxhdGlvbl9pZBgBIAEoCRIrCgdyZXF1ZXN0GAIgASgLMhgu",
+            "bWNwLnVuaXR5LnYxLklwY1JlcXVlc3RIABItCghyZXNwb25zZRgDIAEoCzIZ",
+            "Lm1jcC51bml0eS52MS5JcGNSZXNwb25zZUgAEicKBWV2ZW50GAQgASgLMhYu",
+            "bWNwLnVuaXR5LnYxLklwY0V2ZW50SAASKwoHY29udHJvbBgFIAEoCzIYLm1j",
+            "cC51bml0eS52MS5JcGNDb250cm9sSAASNgoKY29tcHJlc3NlZBgGIAEoCzIg",
+            "Lm1jcC51bml0eS52MS5Db21wcmVzc2VkRW52ZWxvcGVIABI4Cg5yZXNwb25z",
+            "ZV9jaHVuaxgHIAEoCzIeLm1jcC51bml0eS52MS5JcGNSZXNwb25zZUNodW5r",
+            "SABCBgoEa2luZCJRChBJcGNSZXNwb25zZUNodW5rEg0KBWluZGV4GAEgASgN",
+            "EhIKCnRvdGFsX3NpemUYAiABKAQSDAoEbGFzdBgDIAEoCBIMCgRkYXRhGAQg",
+            "ASgMIrsBChJDb21wcmVzc2VkRW52ZWxvcGUSPQoJYWxnb3JpdGhtGAEgASgO",
+            "MioubWNwLnVuaXR5LnYxLkNvbXByZXNzZWRFbnZlbG9wZS5BbGdvcml0aG0S",
+            "GQoRdW5jb21wcmVzc2VkX3NpemUYAiABKA0SDAoEZGF0YRgDIAEoDCI9CglB",
+            "bGdvcml0aG0SGQoVQUxHT1JJVEhNX1VOU1BFQ0lGSUVEEAASCwoHREVGTEFU",
+            "RRABEggKBFpTVEQQAiL6BgoKSXBjUmVxdWVzdBItCgZoZWFsdGgYASABKAsy",
+            "Gy5tY3AudW5pdHkudjEuSGVhbHRoUmVxdWVzdEgAEiwKDWdldF9wbGF5X21v",
+            "ZGUYCiABKAsyEy5tY3AudW5pdHkudjEuRW1wdHlIABI5Cg1zZXRfcGxheV9t",
+            "b2RlGAsgASgLMiAubWNwLnVuaXR5LnYxLlNldFBsYXlNb2RlUmVxdWVzdEgA",
+            "Ek0KF2dldF9jb21waWxlX2RpYWdub3N0aWNzGAwgASgLMioubWNwLnVuaXR5",
+            "LnYxLkdldENvbXBpbGVEaWFnbm9zdGljc1JlcXVlc3RIABJHChRnZXRfcHJv",
+            "amVjdF9zZXR0aW5ncxgNIAEoCzInLm1jcC51bml0eS52MS5HZXRQcm9qZWN0",
+            "U2V0dGluZ3NSZXF1ZXN0SAASRwoUc2V0X3Byb2plY3Rfc2V0dGluZ3MYDiAB",
+            "KAsyJy5tY3AudW5pdHkudjEuU2V0UHJvamVjdFNldHRpbmdzUmVxdWVzdEgA",
+            "Ei0KBnNjZW5lcxgPIAEoCzIbLm1jcC51bml0eS52MS5TY2VuZXNSZXF1ZXN0",
+            "SAASQQoRZXhlY3V0ZV9tZW51X2l0ZW0YECABKAsyJC5tY3AudW5pdHkudjEu",
+            "RXhlY3V0ZU1lbnVJdGVtUmVxdWVzdEgAEjgKDGZvY3VzX3dpbmRvdxgRIAEo",
+            "CzIgLm1jcC51bml0eS52MS5Gb2N1c1dpbmRvd1JlcXVlc3RIABItCgZhc3Nl",
+            "dHMYFCABKAsyGy5tY3AudW5pdHkudjEuQXNzZXRzUmVxdWVzdEgAEi0KBnBy",
+            "ZWZhYhgVIAEoCzIbLm1jcC51bml0eS52MS5QcmVmYWJSZXF1ZXN0SAASKwoF",
+            "YnVpbGQYHiABKAsyGi5tY3AudW5pdHkudjEuQnVpbGRSZXF1ZXN0SAASOgoN",
+            "b3BlcmF0aW9uX2dldBgoIAEoCzIhLm1jcC51bml0eS52MS5PcGVyYXRpb25H",
+            "ZXRSZXF1ZXN0SAASQAoQb3BlcmF0aW9uX2NhbmNlbBgpIAEoCzIkLm1jcC51",
+            "bml0eS52MS5PcGVyYXRpb25DYW5jZWxSZXF1ZXN0SAASMwoJY29tcG9uZW50",
+            "GDIgASgLMh4ubWNwLnVuaXR5LnYxLkNvbXBvbmVudFJlcXVlc3RIAEIJCgdw",
+            "YXlsb2FkIq8HCgtJcGNSZXNwb25zZRIWCg5jb3JyZWxhdGlvbl9pZBgBIAEo",
+            "CRIuCgZoZWFsdGgYAiABKAsyHC5tY3AudW5pdHkudjEuSGVhbHRoUmVzcG9u",
+            "c2VIABI6Cg1nZXRfcGxheV9tb2RlGAogASgLMiEubWNwLnVuaXR5LnYxLkdl",
+            "dFBsYXlNb2RlUmVzcG9uc2VIABI6Cg1zZXRfcGxheV9tb2RlGAsgASgLMiEu",
+            "bWNwLnVuaXR5LnYxLlNldFBsYXlNb2RlUmVzcG9uc2VIABJOChdnZXRfY29t",
+            "cGlsZV9kaWFnbm9zdGljcxgMIAEoCzIrLm1jcC51bml0eS52MS5HZXRDb21w",
+            "aWxlRGlhZ25vc3RpY3NSZXNwb25zZUgAEkgKFGdldF9wcm9qZWN0X3NldHRp",
+            "bmdzGA0gASgLMigubWNwLnVuaXR5LnYxLkdldFByb2plY3RTZXR0aW5nc1Jl",
+            "c3BvbnNlSAASSAoUc2V0X3Byb2plY3Rfc2V0dGluZ3MYDiABKAsyKC5tY3Au",
+            "dW5pdHkudjEuU2V0UHJvamVjdFNldHRpbmdzUmVzcG9uc2VIABIuCgZzY2Vu",
+            "ZXMYDyABKAsyHC5tY3AudW5pdHkudjEuU2NlbmVzUmVzcG9uc2VIABJCChFl",
+            "eGVjdXRlX21lbnVfaXRlbRgQIAEoCzIlLm1jcC51bml0eS52MS5FeGVjdXRl",
+            "TWVudUl0ZW1SZXNwb25zZUgAEjkKDGZvY3VzX3dpbmRvdxgRIAEoCzIhLm1j",
+            "cC51bml0eS52MS5Gb2N1c1dpbmRvd1Jlc3BvbnNlSAASLgoGYXNzZXRzGBQg",
+            "ASgLMhwubWNwLnVuaXR5LnYxLkFzc2V0c1Jlc3BvbnNlSAASLgoGcHJlZmFi",
+            "GBUgASgLMhwubWNwLnVuaXR5LnYxLlByZWZhYlJlc3BvbnNlSAASLAoFYnVp",
+            "bGQYHiABKAsyGy5tY3AudW5pdHkudjEuQnVpbGRSZXNwb25zZUgAEjsKDW9w",
+            "ZXJhdGlvbl9nZXQYKCABKAsyIi5tY3AudW5pdHkudjEuT3BlcmF0aW9uR2V0",
+            "UmVzcG9uc2VIABJBChBvcGVyYXRpb25fY2FuY2VsGCkgASgLMiUubWNwLnVu",
+            "aXR5LnYxLk9wZXJhdGlvbkNhbmNlbFJlc3BvbnNlSAASNAoJY29tcG9uZW50",
+            "GDIgASgLMh8ubWNwLnVuaXR5LnYxLkNvbXBvbmVudFJlc3BvbnNlSABCCQoH",
+            "cGF5bG9hZCKBAQoISXBjRXZlbnQSFwoPbW9ub3RvbmljX3RzX25zGAEgASgD",
+            "EiUKA2xvZxgKIAEoCzIWLm1jcC51bml0eS52MS5Mb2dFdmVudEgAEioKAm9w",
+            "GAsgASgLMhwubWNwLnVuaXR5LnYxLk9wZXJhdGlvbkV2ZW50SABCCQoHcGF5",
+            "bG9hZGIGcHJvdG8z"));
       descriptor = pbr::FileDescriptor.FromGeneratedCode(descriptorData,
-          new pbr::FileDescriptor[] { global::Mcp.Unity.V1.CommonReflection.Descriptor, global::Mcp.Unity.V1.EditorControlReflection.Descriptor, global::Mcp.Unity.V1.AssetsReflection.Descriptor, global::Mcp.Unity.V1.PrefabReflection.Descriptor, global::Mcp.Unity.V1.BuildReflection.Descriptor, global::Mcp.Unity.V1.OperationsReflection.Descriptor, global::Mcp.Unity.V1.EventsReflection.Descriptor, global::Mcp.Unity.V1.ComponentReflection.Descriptor, },
+          new pbr::FileDescriptor[] { global::Mcp.Unity.V1.CommonReflection.Descriptor, global::Mcp.Unity.V1.EditorControlReflection.Descriptor, global::Mcp.Unity.V1.AssetsReflection.Descriptor, global::Mcp.Unity.V1.PrefabReflection.Descriptor, global::Mcp.Unity.V1.BuildReflection.Descriptor, global::Mcp.Unity.V1.OperationsReflection.Descriptor, global::Mcp.Unity.V1.EventsReflection.Descriptor, global::Mcp.Unity.V1.ComponentReflection.Descriptor, global::Mcp.Unity.V1.IpcControlReflection.Descriptor, },
           new pbr::GeneratedClrTypeInfo(null, null, new pbr::GeneratedClrTypeInfo[] {
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcEnvelope), global::Mcp.Unity.V1.IpcEnvelope.Parser, new[]{ "CorrelationId", "Request", "Response", "Event" }, new[]{ "Kind" }, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcEnvelope), global::Mcp.Unity.V1.IpcEnvelope.Parser, new[]{ "CorrelationId", "Request", "Response", "Event", "Control", "Compressed", "ResponseChunk" }, new[]{ "Kind" }, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcResponseChunk), global::Mcp.Unity.V1.IpcResponseChunk.Parser, new[]{ "Index", "TotalSize", "Last", "Data" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.CompressedEnvelope), global::Mcp.Unity.V1.CompressedEnvelope.Parser, new[]{ "Algorithm", "UncompressedSize", "Data" }, null, new[]{ typeof(global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm) }, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcRequest), global::Mcp.Unity.V1.IpcRequest.Parser, new[]{ "Health", "GetPlayMode", "SetPlayMode", "GetCompileDiagnostics", "GetProjectSettings", "SetProjectSettings", "Scenes", "ExecuteMenuItem", "FocusWindow", "Assets", "Prefab", "Build", "OperationGet", "OperationCancel", "Component" }, new[]{ "Payload" }, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcResponse), global::Mcp.Unity.V1.IpcResponse.Parser, new[]{ "CorrelationId", "Health", "GetPlayMode", "SetPlayMode", "GetCompileDiagnostics", "GetProjectSettings", "SetProjectSettings", "Scenes", "ExecuteMenuItem", "FocusWindow", "Assets", "Prefab", "Build", "OperationGet", "OperationCancel", "Component" }, new[]{ "Payload" }, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcEvent), global::Mcp.Unity.V1.IpcEvent.Parser, new[]{ "MonotonicTsNs", "Log", "Op" }, new[]{ "Payload" }, null, null, null)
@@ -140,6 +153,15 @@ namespace Mcp.Unity.V1 {
         case KindOneofCase.Event:
           Event = other.Event.Clone();
           break;
+        case KindOneofCase.Control:
+          Control = other.Control.Clone();
+          break;
+        case KindOneofCase.Compressed:
+          Compressed = other.Compressed.Clone();
+          break;
+        case KindOneofCase.ResponseChunk:
+          ResponseChunk = other.ResponseChunk.Clone();
+          break;
       }
 
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
@@ -202,6 +224,51 @@ namespace Mcp.Unity.V1 {
       }
     }
 
+    /// <summary>Field number for the "control" field.</summary>
+    public const int ControlFieldNumber = 5;
+    /// <summary>
+    /// Post-handshake control frames (heartbeat ping/pong)
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::Mcp.Unity.V1.IpcControl Control {
+      get { return kindCase_ == KindOneofCase.Control ? (global::Mcp.Unity.V1.IpcControl) kind_ : null; }
+      set {
+        kind_ = value;
+        kindCase_ = value == null ? KindOneofCase.None : KindOneofCase.Control;
+      }
+    }
+
+    /// <summary>Field number for the "compressed" field.</summary>
+    public const int CompressedFieldNumber = 6;
+    /// <summary>
+    /// Whole envelope compressed ("ipc.compression"); only used above a size threshold
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::Mcp.Unity.V1.CompressedEnvelope Compressed {
+      get { return kindCase_ == KindOneofCase.Compressed ? (global::Mcp.Unity.V1.CompressedEnvelope) kind_ : null; }
+      set {
+        kind_ = value;
+        kindCase_ = value == null ? KindOneofCase.None : KindOneofCase.Compressed;
+      }
+    }
+
+    /// <summary>Field number for the "response_chunk" field.</summary>
+    public const int ResponseChunkFieldNumber = 7;
+    /// <summary>
+    /// Piece of a response too large for one frame ("ipc.chunking")
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::Mcp.Unity.V1.IpcResponseChunk ResponseChunk {
+      get { return kindCase_ == KindOneofCase.ResponseChunk ? (global::Mcp.Unity.V1.IpcResponseChunk) kind_ : null; }
+      set {
+        kind_ = value;
+        kindCase_ = value == null ? KindOneofCase.None : KindOneofCase.ResponseChunk;
+      }
+    }
+
     private object kind_;
     /// <summary>Enum of possible cases for the "kind" oneof.</summary>
     public enum KindOneofCase {
@@ -209,6 +276,9 @@ namespace Mcp.Unity.V1 {
       Request = 2,
       Response = 3,
       Event = 4,
+      Control = 5,
+      Compressed = 6,
+      ResponseChunk = 7,
     }
     private KindOneofCase kindCase_ = KindOneofCase.None;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -243,6 +313,9 @@ namespace Mcp.Unity.V1 {
       if (!object.Equals(Request, other.Request)) return false;
       if (!object.Equals(Response, other.Response)) return false;
       if (!object.Equals(Event, other.Event)) return false;
+      if (!object.Equals(Control, other.Control)) return false;
+      if (!object.Equals(Compressed, other.Compressed)) return false;
+      if (!object.Equals(ResponseChunk, other.ResponseChunk)) return false;
       if (KindCase != other.KindCase) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
@@ -255,6 +328,9 @@ namespace Mcp.Unity.V1 {
       if (kindCase_ == KindOneofCase.Request) hash ^= Request.GetHashCode();
       if (kindCase_ == KindOneofCase.Response) hash ^= Response.GetHashCode();
       if (kindCase_ == KindOneofCase.Event) hash ^= Event.GetHashCode();
+      if (kindCase_ == KindOneofCase.Control) hash ^= Control.GetHashCode();
+      if (kindCase_ == KindOneofCase.Compressed) hash ^= Compressed.GetHashCode();
+      if (kindCase_ == KindOneofCase.ResponseChunk) hash ^= ResponseChunk.GetHashCode();
       hash ^= (int) kindCase_;
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
@@ -290,6 +366,18 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(34);
         output.WriteMessage(Event);
       }
+      if (kindCase_ == KindOneofCase.Control) {
+        output.WriteRawTag(42);
+        output.WriteMessage(Control);
+      }
+      if (kindCase_ == KindOneofCase.Compressed) {
+        output.WriteRawTag(50);
+        output.WriteMessage(Compressed);
+      }
+      if (kindCase_ == KindOneofCase.ResponseChunk) {
+        output.WriteRawTag(58);
+        output.WriteMessage(ResponseChunk);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -316,6 +404,18 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(34);
         output.WriteMessage(Event);
       }
+      if (kindCase_ == KindOneofCase.Control) {
+        output.WriteRawTag(42);
+        output.WriteMessage(Control);
+      }
+      if (kindCase_ == KindOneofCase.Compressed) {
+        output.WriteRawTag(50);
+        output.WriteMessage(Compressed);
+      }
+      if (kindCase_ == KindOneofCase.ResponseChunk) {
+        output.WriteRawTag(58);
+        output.WriteMessage(ResponseChunk);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -338,6 +438,15 @@ namespace Mcp.Unity.V1 {
       if (kindCase_ == KindOneofCase.Event) {
         size += 1 + pb::CodedOutputStream.ComputeMessageSize(Event);
       }
+      if (kindCase_ == KindOneofCase.Control) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Control);
+      }
+      if (kindCase_ == KindOneofCase.Compressed) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Compressed);
+      }
+      if (kindCase_ == KindOneofCase.ResponseChunk) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(ResponseChunk);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -372,6 +481,24 @@ namespace Mcp.Unity.V1 {
           }
           Event.MergeFrom(other.Event);
           break;
+        case KindOneofCase.Control:
+          if (Control == null) {
+            Control = new global::Mcp.Unity.V1.IpcControl();
+          }
+          Control.MergeFrom(other.Control);
+          break;
+        case KindOneofCase.Compressed:
+          if (Compressed == null) {
+            Compressed = new global::Mcp.Unity.V1.CompressedEnvelope();
+          }
+          Compressed.MergeFrom(other.Compressed);
+          break;
+        case KindOneofCase.ResponseChunk:
+          if (ResponseChunk == null) {
+            ResponseChunk = new global::Mcp.Unity.V1.IpcResponseChunk();
+          }
+          ResponseChunk.MergeFrom(other.ResponseChunk);
+          break;
       }
 
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
@@ -424,6 +551,33 @@ namespace Mcp.Unity.V1 {
             Event = subBuilder;
             break;
           }
+          case 42: {
+            global::Mcp.Unity.V1.IpcControl subBuilder = new global::Mcp.Unity.V1.IpcControl();
+            if (kindCase_ == KindOneofCase.Control) {
+              subBuilder.MergeFrom(Control);
+            }
+            input.ReadMessage(subBuilder);
+            Control = subBuilder;
+            break;
+          }
+          case 50: {
+            global::Mcp.Unity.V1.CompressedEnvelope subBuilder = new global::Mcp.Unity.V1.CompressedEnvelope();
+            if (kindCase_ == KindOneofCase.Compressed) {
+              subBuilder.MergeFrom(Compressed);
+            }
+            input.ReadMessage(subBuilder);
+            Compressed = subBuilder;
+            break;
+          }
+          case 58: {
+            global::Mcp.Unity.V1.IpcResponseChunk subBuilder = new global::Mcp.Unity.V1.IpcResponseChunk();
+            if (kindCase_ == KindOneofCase.ResponseChunk) {
+              subBuilder.MergeFrom(ResponseChunk);
+            }
+            input.ReadMessage(subBuilder);
+            ResponseChunk = subBuilder;
+            break;
+          }
         }
       }
     #endif
@@ -474,6 +628,353 @@ namespace Mcp.Unity.V1 {
             Event = subBuilder;
             break;
           }
+          case 42: {
+            global::Mcp.Unity.V1.IpcControl subBuilder = new global::Mcp.Unity.V1.IpcControl();
+            if (kindCase_ == KindOneofCase.Control) {
+              subBuilder.MergeFrom(Control);
+            }
+            input.ReadMessage(subBuilder);
+            Control = subBuilder;
+            break;
+          }
+          case 50: {
+            global::Mcp.Unity.V1.CompressedEnvelope subBuilder = new global::Mcp.Unity.V1.CompressedEnvelope();
+            if (kindCase_ == KindOneofCase.Compressed) {
+              subBuilder.MergeFrom(Compressed);
+            }
+            input.ReadMessage(subBuilder);
+            Compressed = subBuilder;
+            break;
+          }
+          case 58: {
+            global::Mcp.Unity.V1.IpcResponseChunk subBuilder = new global::Mcp.Unity.V1.IpcResponseChunk();
+            if (kindCase_ == KindOneofCase.ResponseChunk) {
+              subBuilder.MergeFrom(ResponseChunk);
+            }
+            input.ReadMessage(subBuilder);
+            ResponseChunk = subBuilder;
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  /// <summary>
+  /// Ordered piece of a large response. All chunks of a response share the
+  /// envelope correlation_id; concatenating `data` in index order yields the
+  /// serialized IpcResponse.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class IpcResponseChunk : pb::IMessage<IpcResponseChunk>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<IpcResponseChunk> _parser = new pb::MessageParser<IpcResponseChunk>(() => new IpcResponseChunk());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<IpcResponseChunk> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[1]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcResponseChunk() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcResponseChunk(IpcResponseChunk other) : this() {
+      index_ = other.index_;
+      totalSize_ = other.totalSize_;
+      last_ = other.last_;
+      data_ = other.data_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcResponseChunk Clone() {
+      return new IpcResponseChunk(this);
+    }
+
+    /// <summary>Field number for the "index" field.</summary>
+    public const int IndexFieldNumber = 1;
+    private uint index_;
+    /// <summary>
+    /// 0-based, consecutive
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint Index {
+      get { return index_; }
+      set {
+        index_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "total_size" field.</summary>
+    public const int TotalSizeFieldNumber = 2;
+    private ulong totalSize_;
+    /// <summary>
+    /// size of the whole serialized IpcResponse
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong TotalSize {
+      get { return totalSize_; }
+      set {
+        totalSize_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "last" field.</summary>
+    public const int LastFieldNumber = 3;
+    private bool last_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Last {
+      get { return last_; }
+      set {
+        last_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "data" field.</summary>
+    public const int DataFieldNumber = 4;
+    private pb::ByteString data_ = pb::ByteString.Empty;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pb::ByteString Data {
+      get { return data_; }
+      set {
+        data_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as IpcResponseChunk);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(IpcResponseChunk other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Index != other.Index) return false;
+      if (TotalSize != other.TotalSize) return false;
+      if (Last != other.Last) return false;
+      if (Data != other.Data) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Index != 0) hash ^= Index.GetHashCode();
+      if (TotalSize != 0UL) hash ^= TotalSize.GetHashCode();
+      if (Last != false) hash ^= Last.GetHashCode();
+      if (Data.Length != 0) hash ^= Data.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Index != 0) {
+        output.WriteRawTag(8);
+        output.WriteUInt32(Index);
+      }
+      if (TotalSize != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(TotalSize);
+      }
+      if (Last != false) {
+        output.WriteRawTag(24);
+        output.WriteBool(Last);
+      }
+      if (Data.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteBytes(Data);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Index != 0) {
+        output.WriteRawTag(8);
+        output.WriteUInt32(Index);
+      }
+      if (TotalSize != 0UL) {
+        output.WriteRawTag(16);
+        output.WriteUInt64(TotalSize);
+      }
+      if (Last != false) {
+        output.WriteRawTag(24);
+        output.WriteBool(Last);
+      }
+      if (Data.Length != 0) {
+        output.WriteRawTag(34);
+        output.WriteBytes(Data);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Index != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(Index);
+      }
+      if (TotalSize != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(TotalSize);
+      }
+      if (Last != false) {
+        size += 1 + 1;
+      }
+      if (Data.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeBytesSize(Data);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(IpcResponseChunk other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Index != 0) {
+        Index = other.Index;
+      }
+      if (other.TotalSize != 0UL) {
+        TotalSize = other.TotalSize;
+      }
+      if (other.Last != false) {
+        Last = other.Last;
+      }
+      if (other.Data.Length != 0) {
+        Data = other.Data;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Index = input.ReadUInt32();
+            break;
+          }
+          case 16: {
+            TotalSize = input.ReadUInt64();
+            break;
+          }
+          case 24: {
+            Last = input.ReadBool();
+            break;
+          }
+          case 34: {
+            Data = input.ReadBytes();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Index = input.ReadUInt32();
+            break;
+          }
+          case 16: {
+            TotalSize = input.ReadUInt64();
+            break;
+          }
+          case 24: {
+            Last = input.ReadBool();
+            break;
+          }
+          case 34: {
+            Data = input.ReadBytes();
+            break;
+          }
         }
       }
     }
@@ -481,6 +982,302 @@ namespace Mcp.Unity.V1 {
 
   }
 
+  /// <summary>
+  /// Compressed form of a serialized IpcEnvelope. The outer envelope keeps the
+  /// correlation_id so the frame can be attributed without decompressing it.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class CompressedEnvelope : pb::IMessage<CompressedEnvelope>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<CompressedEnvelope> _parser = new pb::MessageParser<CompressedEnvelope>(() => new CompressedEnvelope());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<CompressedEnvelope> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[2]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CompressedEnvelope() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CompressedEnvelope(CompressedEnvelope other) : this() {
+      algorithm_ = other.algorithm_;
+      uncompressedSize_ = other.uncompressedSize_;
+      data_ = other.data_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public CompressedEnvelope Clone() {
+      return new CompressedEnvelope(this);
+    }
+
+    /// <summary>Field number for the "algorithm" field.</summary>
+    public const int AlgorithmFieldNumber = 1;
+    private global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm algorithm_ = global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm.Unspecified;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm Algorithm {
+      get { return algorithm_; }
+      set {
+        algorithm_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "uncompressed_size" field.</summary>
+    public const int UncompressedSizeFieldNumber = 2;
+    private uint uncompressedSize_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public uint UncompressedSize {
+      get { return uncompressedSize_; }
+      set {
+        uncompressedSize_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "data" field.</summary>
+    public const int DataFieldNumber = 3;
+    private pb::ByteString data_ = pb::ByteString.Empty;
+    /// <summary>
+    /// serialized IpcEnvelope
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public pb::ByteString Data {
+      get { return data_; }
+      set {
+        data_ = pb::ProtoPreconditions.CheckNotNull(value, "value");
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as CompressedEnvelope);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(CompressedEnvelope other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Algorithm != other.Algorithm) return false;
+      if (UncompressedSize != other.UncompressedSize) return false;
+      if (Data != other.Data) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Algorithm != global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm.Unspecified) hash ^= Algorithm.GetHashCode();
+      if (UncompressedSize != 0) hash ^= UncompressedSize.GetHashCode();
+      if (Data.Length != 0) hash ^= Data.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Algorithm != global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm.Unspecified) {
+        output.WriteRawTag(8);
+        output.WriteEnum((int) Algorithm);
+      }
+      if (UncompressedSize != 0) {
+        output.WriteRawTag(16);
+        output.WriteUInt32(UncompressedSize);
+      }
+      if (Data.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteBytes(Data);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Algorithm != global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm.Unspecified) {
+        output.WriteRawTag(8);
+        output.WriteEnum((int) Algorithm);
+      }
+      if (UncompressedSize != 0) {
+        output.WriteRawTag(16);
+        output.WriteUInt32(UncompressedSize);
+      }
+      if (Data.Length != 0) {
+        output.WriteRawTag(26);
+        output.WriteBytes(Data);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Algorithm != global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm.Unspecified) {
+        size += 1 + pb::CodedOutputStream.ComputeEnumSize((int) Algorithm);
+      }
+      if (UncompressedSize != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt32Size(UncompressedSize);
+      }
+      if (Data.Length != 0) {
+        size += 1 + pb::CodedOutputStream.ComputeBytesSize(Data);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(CompressedEnvelope other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Algorithm != global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm.Unspecified) {
+        Algorithm = other.Algorithm;
+      }
+      if (other.UncompressedSize != 0) {
+        UncompressedSize = other.UncompressedSize;
+      }
+      if (other.Data.Length != 0) {
+        Data = other.Data;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Algorithm = (global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm) input.ReadEnum();
+            break;
+          }
+          case 16: {
+            UncompressedSize = input.ReadUInt32();
+            break;
+          }
+          case 26: {
+            Data = input.ReadBytes();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Algorithm = (global::Mcp.Unity.V1.CompressedEnvelope.Types.Algorithm) input.ReadEnum();
+            break;
+          }
+          case 16: {
+            UncompressedSize = input.ReadUInt32();
+            break;
+          }
+          case 26: {
+            Data = input.ReadBytes();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+    #region Nested types
+    /// <summary>Container for nested types declared in the CompressedEnvelope message type.</summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static partial class Types {
+      public enum Algorithm {
+        [pbr::OriginalName("ALGORITHM_UNSPECIFIED")] Unspecified = 0,
+        /// <summary>
+        /// raw deflate (RFC 1951)
+        /// </summary>
+        [pbr::OriginalName("DEFLATE")] Deflate = 1,
+        [pbr::OriginalName("ZSTD")] Zstd = 2,
+      }
+
+    }
+    #endregion
+
+  }
+
   /// <summary>
   /// Request message with typed payloads
   /// </summary>
@@ -499,7 +1296,7 @@ namespace Mcp.Unity.V1 {
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
-      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[1]; }
+      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[3]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -1506,7 +2303,7 @@ namespace Mcp.Unity.V1 {
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
-      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[2]; }
+      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[4]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -2553,7 +3350,7 @@ namespace Mcp.Unity.V1 {
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
     [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
     public static pbr::MessageDescriptor Descriptor {
-      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[3]; }
+      get { return global::Mcp.Unity.V1.IpcReflection.Descriptor.MessageTypes[5]; }
     }
 
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
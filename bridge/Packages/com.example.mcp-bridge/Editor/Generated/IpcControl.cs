@@ -25,33 +25,39 @@ namespace Mcp.Unity.V1 {
       byte[] descriptorData = global::System.Convert.FromBase64String(
           string.Concat(
             "Ch5tY3AvdW5pdHkvdjEvaXBjX2NvbnRyb2wucHJvdG8SDG1jcC51bml0eS52",
-            "MSKVAQoKSXBjQ29udHJvbBInCgVoZWxsbxgBIAEoCzIWLm1jcC51bml0eS52",
+            "MSLjAQoKSXBjQ29udHJvbBInCgVoZWxsbxgBIAEoCzIWLm1jcC51bml0eS52",
             "MS5JcGNIZWxsb0gAEisKB3dlbGNvbWUYAiABKAsyGC5tY3AudW5pdHkudjEu",
             "SXBjV2VsY29tZUgAEikKBnJlamVjdBgDIAEoCzIXLm1jcC51bml0eS52MS5J",
-            "cGNSZWplY3RIAEIGCgRraW5kIuUBCghJcGNIZWxsbxINCgV0b2tlbhgBIAEo",
-            "CRITCgtpcGNfdmVyc2lvbhgCIAEoCRIQCghmZWF0dXJlcxgDIAMoCRITCgtz",
-            "Y2hlbWFfaGFzaBgEIAEoDBITCgtjbGllbnRfbmFtZRgGIAEoCRIWCg5jbGll",
-            "bnRfdmVyc2lvbhgHIAEoCRIuCgRtZXRhGAggAygLMiAubWNwLnVuaXR5LnYx",
-            "LklwY0hlbGxvLk1ldGFFbnRyeRorCglNZXRhRW50cnkSCwoDa2V5GAEgASgJ",
-            "Eg0KBXZhbHVlGAIgASgJOgI4AUoECAUQBiKJAgoKSXBjV2VsY29tZRITCgtp",
-            "cGNfdmVyc2lvbhgBIAEoCRIZChFhY2NlcHRlZF9mZWF0dXJlcxgCIAMoCRIT",
-            "CgtzY2hlbWFfaGFzaBgDIAEoDBITCgtzZXJ2ZXJfbmFtZRgEIAEoCRIWCg5z",
-            "ZXJ2ZXJfdmVyc2lvbhgFIAEoCRIWCg5lZGl0b3JfdmVyc2lvbhgGIAEoCRIS",
-            "CgpzZXNzaW9uX2lkGAcgASgJEjAKBG1ldGEYCCADKAsyIi5tY3AudW5pdHku",
-            "djEuSXBjV2VsY29tZS5NZXRhRW50cnkaKwoJTWV0YUVudHJ5EgsKA2tleRgB",
-            "IAEoCRINCgV2YWx1ZRgCIAEoCToCOAEixgEKCUlwY1JlamVjdBIqCgRjb2Rl",
-            "GAEgASgOMhwubWNwLnVuaXR5LnYxLklwY1JlamVjdC5Db2RlEg8KB21lc3Nh",
-            "Z2UYAiABKAkifAoEQ29kZRITCg9VTkFVVEhFTlRJQ0FURUQQABIXChNGQUlM",
-            "RURfUFJFQ09ORElUSU9OEAESFQoRUEVSTUlTU0lPTl9ERU5JRUQQAhIQCgxP",
-            "VVRfT0ZfUkFOR0UQAxIMCghJTlRFUk5BTBAEEg8KC1VOQVZBSUxBQkxFEAVi",
-            "BnByb3RvMw=="));
+            "cGNSZWplY3RIABIlCgRwaW5nGAQgASgLMhUubWNwLnVuaXR5LnYxLklwY1Bp",
+            "bmdIABIlCgRwb25nGAUgASgLMhUubWNwLnVuaXR5LnYxLklwY1BvbmdIAEIG",
+            "CgRraW5kIuUBCghJcGNIZWxsbxINCgV0b2tlbhgBIAEoCRITCgtpcGNfdmVy",
+            "c2lvbhgCIAEoCRIQCghmZWF0dXJlcxgDIAMoCRITCgtzY2hlbWFfaGFzaBgE",
+            "IAEoDBITCgtjbGllbnRfbmFtZRgGIAEoCRIWCg5jbGllbnRfdmVyc2lvbhgH",
+            "IAEoCRIuCgRtZXRhGAggAygLMiAubWNwLnVuaXR5LnYxLklwY0hlbGxvLk1l",
+            "dGFFbnRyeRorCglNZXRhRW50cnkSCwoDa2V5GAEgASgJEg0KBXZhbHVlGAIg",
+            "ASgJOgI4AUoECAUQBiKJAgoKSXBjV2VsY29tZRITCgtpcGNfdmVyc2lvbhgB",
+            "IAEoCRIZChFhY2NlcHRlZF9mZWF0dXJlcxgCIAMoCRITCgtzY2hlbWFfaGFz",
+            "aBgDIAEoDBITCgtzZXJ2ZXJfbmFtZRgEIAEoCRIWCg5zZXJ2ZXJfdmVyc2lv",
+            "bhgFIAEoCRIWCg5lZGl0b3JfdmVyc2lvbhgGIAEoCRISCgpzZXNzaW9uX2lk",
+            "GAcgASgJEjAKBG1ldGEYCCADKAsyIi5tY3AudW5pdHkudjEuSXBjV2VsY29t",
+            "ZS5NZXRhRW50cnkaKwoJTWV0YUVudHJ5EgsKA2tleRgBIAEoCRINCgV2YWx1",
+            "ZRgCIAEoCToCOAEixgEKCUlwY1JlamVjdBIqCgRjb2RlGAEgASgOMhwubWNw",
+            "LnVuaXR5LnYxLklwY1JlamVjdC5Db2RlEg8KB21lc3NhZ2UYAiABKAkifAoE",
+            "Q29kZRITCg9VTkFVVEhFTlRJQ0FURUQQABIXChNGQUlMRURfUFJFQ09ORElU",
+            "SU9OEAESFQoRUEVSTUlTU0lPTl9ERU5JRUQQAhIQCgxPVVRfT0ZfUkFOR0UQ",
+            "AxIMCghJTlRFUk5BTBAEEg8KC1VOQVZBSUxBQkxFEAUiMwoHSXBjUGluZxIN",
+            "CgVub25jZRgBIAEoBBIZChFzZW50X21vbm90b25pY19ucxgCIAEoAyI4CgdJ",
+            "cGNQb25nEg0KBW5vbmNlGAEgASgEEh4KFnBpbmdfc2VudF9tb25vdG9uaWNf",
+            "bnMYAiABKANiBnByb3RvMw=="));
       descriptor = pbr::FileDescriptor.FromGeneratedCode(descriptorData,
           new pbr::FileDescriptor[] { },
           new pbr::GeneratedClrTypeInfo(null, null, new pbr::GeneratedClrTypeInfo[] {
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcControl), global::Mcp.Unity.V1.IpcControl.Parser, new[]{ "Hello", "Welcome", "Reject" }, new[]{ "Kind" }, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcControl), global::Mcp.Unity.V1.IpcControl.Parser, new[]{ "Hello", "Welcome", "Reject", "Ping", "Pong" }, new[]{ "Kind" }, null, null, null),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcHello), global::Mcp.Unity.V1.IpcHello.Parser, new[]{ "Token", "IpcVersion", "Features", "SchemaHash", "ClientName", "ClientVersion", "Meta" }, null, null, null, new pbr::GeneratedClrTypeInfo[] { null, }),
             new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcWelcome), global::Mcp.Unity.V1.IpcWelcome.Parser, new[]{ "IpcVersion", "AcceptedFeatures", "SchemaHash", "ServerName", "ServerVersion", "EditorVersion", "SessionId", "Meta" }, null, null, null, new pbr::GeneratedClrTypeInfo[] { null, }),
-            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcReject), global::Mcp.Unity.V1.IpcReject.Parser, new[]{ "Code", "Message" }, null, new[]{ typeof(global::Mcp.Unity.V1.IpcReject.Types.Code) }, null, null)
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcReject), global::Mcp.Unity.V1.IpcReject.Parser, new[]{ "Code", "Message" }, null, new[]{ typeof(global::Mcp.Unity.V1.IpcReject.Types.Code) }, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcPing), global::Mcp.Unity.V1.IpcPing.Parser, new[]{ "Nonce", "SentMonotonicNs" }, null, null, null, null),
+            new pbr::GeneratedClrTypeInfo(typeof(global::Mcp.Unity.V1.IpcPong), global::Mcp.Unity.V1.IpcPong.Parser, new[]{ "Nonce", "PingSentMonotonicNs" }, null, null, null, null)
           }));
     }
     #endregion
@@ -103,6 +109,12 @@ namespace Mcp.Unity.V1 {
         case KindOneofCase.Reject:
           Reject = other.Reject.Clone();
           break;
+        case KindOneofCase.Ping:
+          Ping = other.Ping.Clone();
+          break;
+        case KindOneofCase.Pong:
+          Pong = other.Pong.Clone();
+          break;
       }
 
       _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
@@ -150,6 +162,33 @@ namespace Mcp.Unity.V1 {
       }
     }
 
+    /// <summary>Field number for the "ping" field.</summary>
+    public const int PingFieldNumber = 4;
+    /// <summary>
+    /// Heartbeat (after handshake, negotiated via "ipc.heartbeat")
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::Mcp.Unity.V1.IpcPing Ping {
+      get { return kindCase_ == KindOneofCase.Ping ? (global::Mcp.Unity.V1.IpcPing) kind_ : null; }
+      set {
+        kind_ = value;
+        kindCase_ = value == null ? KindOneofCase.None : KindOneofCase.Ping;
+      }
+    }
+
+    /// <summary>Field number for the "pong" field.</summary>
+    public const int PongFieldNumber = 5;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public global::Mcp.Unity.V1.IpcPong Pong {
+      get { return kindCase_ == KindOneofCase.Pong ? (global::Mcp.Unity.V1.IpcPong) kind_ : null; }
+      set {
+        kind_ = value;
+        kindCase_ = value == null ? KindOneofCase.None : KindOneofCase.Pong;
+      }
+    }
+
     private object kind_;
     /// <summary>Enum of possible cases for the "kind" oneof.</summary>
     public enum KindOneofCase {
@@ -157,6 +196,8 @@ namespace Mcp.Unity.V1 {
       Hello = 1,
       Welcome = 2,
       Reject = 3,
+      Ping = 4,
+      Pong = 5,
     }
     private KindOneofCase kindCase_ = KindOneofCase.None;
     [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
@@ -190,6 +231,8 @@ namespace Mcp.Unity.V1 {
       if (!object.Equals(Hello, other.Hello)) return false;
       if (!object.Equals(Welcome, other.Welcome)) return false;
       if (!object.Equals(Reject, other.Reject)) return false;
+      if (!object.Equals(Ping, other.Ping)) return false;
+      if (!object.Equals(Pong, other.Pong)) return false;
       if (KindCase != other.KindCase) return false;
       return Equals(_unknownFields, other._unknownFields);
     }
@@ -201,6 +244,8 @@ namespace Mcp.Unity.V1 {
       if (kindCase_ == KindOneofCase.Hello) hash ^= Hello.GetHashCode();
       if (kindCase_ == KindOneofCase.Welcome) hash ^= Welcome.GetHashCode();
       if (kindCase_ == KindOneofCase.Reject) hash ^= Reject.GetHashCode();
+      if (kindCase_ == KindOneofCase.Ping) hash ^= Ping.GetHashCode();
+      if (kindCase_ == KindOneofCase.Pong) hash ^= Pong.GetHashCode();
       hash ^= (int) kindCase_;
       if (_unknownFields != null) {
         hash ^= _unknownFields.GetHashCode();
@@ -232,6 +277,14 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(26);
         output.WriteMessage(Reject);
       }
+      if (kindCase_ == KindOneofCase.Ping) {
+        output.WriteRawTag(34);
+        output.WriteMessage(Ping);
+      }
+      if (kindCase_ == KindOneofCase.Pong) {
+        output.WriteRawTag(42);
+        output.WriteMessage(Pong);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(output);
       }
@@ -254,6 +307,14 @@ namespace Mcp.Unity.V1 {
         output.WriteRawTag(26);
         output.WriteMessage(Reject);
       }
+      if (kindCase_ == KindOneofCase.Ping) {
+        output.WriteRawTag(34);
+        output.WriteMessage(Ping);
+      }
+      if (kindCase_ == KindOneofCase.Pong) {
+        output.WriteRawTag(42);
+        output.WriteMessage(Pong);
+      }
       if (_unknownFields != null) {
         _unknownFields.WriteTo(ref output);
       }
@@ -273,6 +334,12 @@ namespace Mcp.Unity.V1 {
       if (kindCase_ == KindOneofCase.Reject) {
         size += 1 + pb::CodedOutputStream.ComputeMessageSize(Reject);
       }
+      if (kindCase_ == KindOneofCase.Ping) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Ping);
+      }
+      if (kindCase_ == KindOneofCase.Pong) {
+        size += 1 + pb::CodedOutputStream.ComputeMessageSize(Pong);
+      }
       if (_unknownFields != null) {
         size += _unknownFields.CalculateSize();
       }
@@ -304,6 +371,18 @@ namespace Mcp.Unity.V1 {
           }
           Reject.MergeFrom(other.Reject);
           break;
+        case KindOneofCase.Ping:
+          if (Ping == null) {
+            Ping = new global::Mcp.Unity.V1.IpcPing();
+          }
+          Ping.MergeFrom(other.Ping);
+          break;
+        case KindOneofCase.Pong:
+          if (Pong == null) {
+            Pong = new global::Mcp.Unity.V1.IpcPong();
+          }
+          Pong.MergeFrom(other.Pong);
+          break;
       }
 
       _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
@@ -352,6 +431,24 @@ namespace Mcp.Unity.V1 {
             Reject = subBuilder;
             break;
           }
+          case 34: {
+            global::Mcp.Unity.V1.IpcPing subBuilder = new global::Mcp.Unity.V1.IpcPing();
+            if (kindCase_ == KindOneofCase.Ping) {
+              subBuilder.MergeFrom(Ping);
+            }
+            input.ReadMessage(subBuilder);
+            Ping = subBuilder;
+            break;
+          }
+          case 42: {
+            global::Mcp.Unity.V1.IpcPong subBuilder = new global::Mcp.Unity.V1.IpcPong();
+            if (kindCase_ == KindOneofCase.Pong) {
+              subBuilder.MergeFrom(Pong);
+            }
+            input.ReadMessage(subBuilder);
+            Pong = subBuilder;
+            break;
+          }
         }
       }
     #endif
@@ -398,6 +495,24 @@ namespace Mcp.Unity.V1 {
             Reject = subBuilder;
             break;
           }
+          case 34: {
+            global::Mcp.Unity.V1.IpcPing subBuilder = new global::Mcp.Unity.V1.IpcPing();
+            if (kindCase_ == KindOneofCase.Ping) {
+              subBuilder.MergeFrom(Ping);
+            }
+            input.ReadMessage(subBuilder);
+            Ping = subBuilder;
+            break;
+          }
+          case 42: {
+            global::Mcp.Unity.V1.IpcPong subBuilder = new global::Mcp.Unity.V1.IpcPong();
+            if (kindCase_ == KindOneofCase.Pong) {
+              subBuilder.MergeFrom(Pong);
+            }
+            input.ReadMessage(subBuilder);
+            Pong = subBuilder;
+            break;
+          }
         }
       }
     }
@@ -1551,6 +1666,489 @@ namespace Mcp.Unity.V1 {
 
   }
 
+  /// <summary>
+  /// Keepalive probe. Either side may send it once "ipc.heartbeat" is negotiated;
+  /// the peer must answer with an IpcPong echoing the nonce.
+  /// </summary>
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class IpcPing : pb::IMessage<IpcPing>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<IpcPing> _parser = new pb::MessageParser<IpcPing>(() => new IpcPing());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<IpcPing> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::Mcp.Unity.V1.IpcControlReflection.Descriptor.MessageTypes[4]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcPing() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcPing(IpcPing other) : this() {
+      nonce_ = other.nonce_;
+      sentMonotonicNs_ = other.sentMonotonicNs_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcPing Clone() {
+      return new IpcPing(this);
+    }
+
+    /// <summary>Field number for the "nonce" field.</summary>
+    public const int NonceFieldNumber = 1;
+    private ulong nonce_;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Nonce {
+      get { return nonce_; }
+      set {
+        nonce_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "sent_monotonic_ns" field.</summary>
+    public const int SentMonotonicNsFieldNumber = 2;
+    private long sentMonotonicNs_;
+    /// <summary>
+    /// sender clock; echoed back for RTT
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public long SentMonotonicNs {
+      get { return sentMonotonicNs_; }
+      set {
+        sentMonotonicNs_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as IpcPing);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(IpcPing other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Nonce != other.Nonce) return false;
+      if (SentMonotonicNs != other.SentMonotonicNs) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Nonce != 0UL) hash ^= Nonce.GetHashCode();
+      if (SentMonotonicNs != 0L) hash ^= SentMonotonicNs.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Nonce != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Nonce);
+      }
+      if (SentMonotonicNs != 0L) {
+        output.WriteRawTag(16);
+        output.WriteInt64(SentMonotonicNs);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Nonce != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Nonce);
+      }
+      if (SentMonotonicNs != 0L) {
+        output.WriteRawTag(16);
+        output.WriteInt64(SentMonotonicNs);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Nonce != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Nonce);
+      }
+      if (SentMonotonicNs != 0L) {
+        size += 1 + pb::CodedOutputStream.ComputeInt64Size(SentMonotonicNs);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(IpcPing other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Nonce != 0UL) {
+        Nonce = other.Nonce;
+      }
+      if (other.SentMonotonicNs != 0L) {
+        SentMonotonicNs = other.SentMonotonicNs;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Nonce = input.ReadUInt64();
+            break;
+          }
+          case 16: {
+            SentMonotonicNs = input.ReadInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Nonce = input.ReadUInt64();
+            break;
+          }
+          case 16: {
+            SentMonotonicNs = input.ReadInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
+  [global::System.Diagnostics.DebuggerDisplayAttribute("{ToString(),nq}")]
+  public sealed partial class IpcPong : pb::IMessage<IpcPong>
+  #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      , pb::IBufferMessage
+  #endif
+  {
+    private static readonly pb::MessageParser<IpcPong> _parser = new pb::MessageParser<IpcPong>(() => new IpcPong());
+    private pb::UnknownFieldSet _unknownFields;
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pb::MessageParser<IpcPong> Parser { get { return _parser; } }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public static pbr::MessageDescriptor Descriptor {
+      get { return global::Mcp.Unity.V1.IpcControlReflection.Descriptor.MessageTypes[5]; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    pbr::MessageDescriptor pb::IMessage.Descriptor {
+      get { return Descriptor; }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcPong() {
+      OnConstruction();
+    }
+
+    partial void OnConstruction();
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcPong(IpcPong other) : this() {
+      nonce_ = other.nonce_;
+      pingSentMonotonicNs_ = other.pingSentMonotonicNs_;
+      _unknownFields = pb::UnknownFieldSet.Clone(other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public IpcPong Clone() {
+      return new IpcPong(this);
+    }
+
+    /// <summary>Field number for the "nonce" field.</summary>
+    public const int NonceFieldNumber = 1;
+    private ulong nonce_;
+    /// <summary>
+    /// echoed from IpcPing
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public ulong Nonce {
+      get { return nonce_; }
+      set {
+        nonce_ = value;
+      }
+    }
+
+    /// <summary>Field number for the "ping_sent_monotonic_ns" field.</summary>
+    public const int PingSentMonotonicNsFieldNumber = 2;
+    private long pingSentMonotonicNs_;
+    /// <summary>
+    /// echoed from IpcPing
+    /// </summary>
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public long PingSentMonotonicNs {
+      get { return pingSentMonotonicNs_; }
+      set {
+        pingSentMonotonicNs_ = value;
+      }
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override bool Equals(object other) {
+      return Equals(other as IpcPong);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public bool Equals(IpcPong other) {
+      if (ReferenceEquals(other, null)) {
+        return false;
+      }
+      if (ReferenceEquals(other, this)) {
+        return true;
+      }
+      if (Nonce != other.Nonce) return false;
+      if (PingSentMonotonicNs != other.PingSentMonotonicNs) return false;
+      return Equals(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override int GetHashCode() {
+      int hash = 1;
+      if (Nonce != 0UL) hash ^= Nonce.GetHashCode();
+      if (PingSentMonotonicNs != 0L) hash ^= PingSentMonotonicNs.GetHashCode();
+      if (_unknownFields != null) {
+        hash ^= _unknownFields.GetHashCode();
+      }
+      return hash;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public override string ToString() {
+      return pb::JsonFormatter.ToDiagnosticString(this);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void WriteTo(pb::CodedOutputStream output) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      output.WriteRawMessage(this);
+    #else
+      if (Nonce != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Nonce);
+      }
+      if (PingSentMonotonicNs != 0L) {
+        output.WriteRawTag(16);
+        output.WriteInt64(PingSentMonotonicNs);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(output);
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalWriteTo(ref pb::WriteContext output) {
+      if (Nonce != 0UL) {
+        output.WriteRawTag(8);
+        output.WriteUInt64(Nonce);
+      }
+      if (PingSentMonotonicNs != 0L) {
+        output.WriteRawTag(16);
+        output.WriteInt64(PingSentMonotonicNs);
+      }
+      if (_unknownFields != null) {
+        _unknownFields.WriteTo(ref output);
+      }
+    }
+    #endif
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public int CalculateSize() {
+      int size = 0;
+      if (Nonce != 0UL) {
+        size += 1 + pb::CodedOutputStream.ComputeUInt64Size(Nonce);
+      }
+      if (PingSentMonotonicNs != 0L) {
+        size += 1 + pb::CodedOutputStream.ComputeInt64Size(PingSentMonotonicNs);
+      }
+      if (_unknownFields != null) {
+        size += _unknownFields.CalculateSize();
+      }
+      return size;
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(IpcPong other) {
+      if (other == null) {
+        return;
+      }
+      if (other.Nonce != 0UL) {
+        Nonce = other.Nonce;
+      }
+      if (other.PingSentMonotonicNs != 0L) {
+        PingSentMonotonicNs = other.PingSentMonotonicNs;
+      }
+      _unknownFields = pb::UnknownFieldSet.MergeFrom(_unknownFields, other._unknownFields);
+    }
+
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    public void MergeFrom(pb::CodedInputStream input) {
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+      input.ReadRawMessage(this);
+    #else
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, input);
+            break;
+          case 8: {
+            Nonce = input.ReadUInt64();
+            break;
+          }
+          case 16: {
+            PingSentMonotonicNs = input.ReadInt64();
+            break;
+          }
+        }
+      }
+    #endif
+    }
+
+    #if !GOOGLE_PROTOBUF_REFSTRUCT_COMPATIBILITY_MODE
+    [global::System.Diagnostics.DebuggerNonUserCodeAttribute]
+    [global::System.CodeDom.Compiler.GeneratedCode("protoc", null)]
+    void pb::IBufferMessage.InternalMergeFrom(ref pb::ParseContext input) {
+      uint tag;
+      while ((tag = input.ReadTag()) != 0) {
+      if ((tag & 7) == 4) {
+        // Abort on any end group tag.
+        return;
+      }
+      switch(tag) {
+          default:
+            _unknownFields = pb::UnknownFieldSet.MergeFieldFrom(_unknownFields, ref input);
+            break;
+          case 8: {
+            Nonce = input.ReadUInt64();
+            break;
+          }
+          case 16: {
+            PingSentMonotonicNs = input.ReadInt64();
+            break;
+          }
+        }
+      }
+    }
+    #endif
+
+  }
+
   #endregion
 
 }
@@ -7,11 +7,11 @@ namespace Mcp.Unity.V1.Generated
 {
     internal static class Schema
     {
-        public const string SCHEMA_HASH_HEX = "a12145b52d796a6e4399ab160fc672cdc70f3e7587406bfc4e87001d476901b1";
+        public const string SCHEMA_HASH_HEX = "b1f899a4e2d6ba961ad71704f53401c48c3d07d486eca30f9cc75b3f181c85b2";
         
         public static byte[] SchemaHashBytes => new byte[]
         {
-            161,  33,  69,  181,  45,  121,  106,  110,  67,  153,  171,  22,  15,  198,  114,  205,  199,  15,  62,  117,  135,  64,  107,  252,  78,  135,  0,  29,  71,  105,  1,  177
+            177,  248,  153,  164,  226,  214,  186,  150,  26,  215,  23,  4,  245,  52,  1,  196,  140,  61,  7,  212,  134,  236,  163,  15,  156,  199,  91,  63,  24,  28,  133,  178
         };
     }
 }
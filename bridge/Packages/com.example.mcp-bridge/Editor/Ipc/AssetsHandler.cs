@@ -42,13 +42,18 @@ namespace Mcp.Unity.V1.Ipc
         private static Pb.AssetsResponse Import(Pb.ImportAssetRequest r)
         {
             // Long operation: track progress via OperationTracker
-            string op = OperationTracker.Start("Import", $"Import {r.Paths.Count} items");
+            string op = OperationTracker.Start("Import", $"Import {r.Paths.Count} items", cancellable: true, requestedId: r.OpId);
             try 
             {
                 var results = new List<Pb.ImportAssetResult>(r.Paths.Count);
                 int done = 0;
                 foreach (var p in r.Paths)
                 {
+                    if (OperationTracker.IsCancellationRequested(op))
+                    {
+                        OperationTracker.Complete(op, 1, "cancelled");
+                        return new Pb.AssetsResponse { StatusCode = 1, Message = "cancelled", Import = new Pb.ImportAssetResponse { Results = { results }, OpId = op } };
+                    }
                     if (!IsValidUnityPath(p)) 
                     { 
                         results.Add(new Pb.ImportAssetResult{ Path = p, Ok = false, Message = "invalid path"}); 
@@ -75,7 +80,7 @@ namespace Mcp.Unity.V1.Ipc
                     OperationTracker.Progress(op, (int)(100.0 * done / Math.Max(1, r.Paths.Count)));
                 }
                 OperationTracker.Complete(op, 0, "OK");
-                return new Pb.AssetsResponse { StatusCode = 0, Import = new Pb.ImportAssetResponse { Results = { results } } };
+                return new Pb.AssetsResponse { StatusCode = 0, Import = new Pb.ImportAssetResponse { Results = { results }, OpId = op } };
             } 
             catch (Exception ex) 
             {
@@ -74,62 +74,75 @@ namespace Mcp.Unity.V1.Ipc
                 if (scenes.Length == 0) 
                     return new Pb.BuildPlayerResponse { StatusCode = 2, Message = "no scenes" };
 
-                // 4. Platform switching
-                // TODO(UNITY_API): touches EditorUserBuildSettings — must run on main via EditorDispatcher
-                if (EditorUserBuildSettings.activeBuildTarget != target)
+                // 4. Start the operation: cancellable until BuildPipeline takes over
+                string op = OperationTracker.Start("BuildPlayer", $"{target} -> {outPath}", cancellable: true, requestedId: r.OpId);
+                try
                 {
-                    if (!EditorUserBuildSettings.SwitchActiveBuildTarget(group, target))
-                        return new Pb.BuildPlayerResponse {
-                            StatusCode = 9,
-                            Message = $"failed to switch build target to {target}"
-                        };
-                }
+                    // 5. Platform switching
+                    // TODO(UNITY_API): touches EditorUserBuildSettings — must run on main via EditorDispatcher
+                    if (EditorUserBuildSettings.activeBuildTarget != target)
+                    {
+                        OperationTracker.Progress(op, 0, "Switching platform");
+                        if (!EditorUserBuildSettings.SwitchActiveBuildTarget(group, target))
+                        {
+                            var msg = $"failed to switch build target to {target}";
+                            OperationTracker.Complete(op, 9, msg);
+                            return new Pb.BuildPlayerResponse { StatusCode = 9, Message = msg, OpId = op };
+                        }
+                    }
+                    if (OperationTracker.IsCancellationRequested(op)) return Cancelled(op, outPath);
 
-                // 4.5. Variant-specific settings
-                // TODO(UNITY_API): touches PlayerSettings/EditorUserBuildSettings — must run on main via EditorDispatcher
-                var namedBuildTarget = NamedBuildTarget.FromBuildTargetGroup(group);
-                if (r.Variants?.Il2Cpp == true)
-                    PlayerSettings.SetScriptingBackend(namedBuildTarget, ScriptingImplementation.IL2CPP);
-                else
-                    PlayerSettings.SetScriptingBackend(namedBuildTarget, ScriptingImplementation.Mono2x);
+                    // 5.5. Variant-specific settings
+                    // TODO(UNITY_API): touches PlayerSettings/EditorUserBuildSettings — must run on main via EditorDispatcher
+                    var namedBuildTarget = NamedBuildTarget.FromBuildTargetGroup(group);
+                    if (r.Variants?.Il2Cpp == true)
+                        PlayerSettings.SetScriptingBackend(namedBuildTarget, ScriptingImplementation.IL2CPP);
+                    else
+                        PlayerSettings.SetScriptingBackend(namedBuildTarget, ScriptingImplementation.Mono2x);
 
-                if (r.Variants?.StripSymbols == true)
-                    PlayerSettings.stripEngineCode = true;
-                else
-                    PlayerSettings.stripEngineCode = false;
+                    if (r.Variants?.StripSymbols == true)
+                        PlayerSettings.stripEngineCode = true;
+                    else
+                        PlayerSettings.stripEngineCode = false;
 
-                // 5. Build options setup
-                var buildOptions = BuildOptions.None;
-                if (r.Variants?.Development == true) buildOptions |= BuildOptions.Development;
+                    // 6. Build options setup
+                    var buildOptions = BuildOptions.None;
+                    if (r.Variants?.Development == true) buildOptions |= BuildOptions.Development;
 
-                var bpo = new BuildPlayerOptions
-                {
-                    scenes = scenes,
-                    target = target,
-                    locationPathName = outPath,
-                    options = buildOptions,
-                };
+                    var bpo = new BuildPlayerOptions
+                    {
+                        scenes = scenes,
+                        target = target,
+                        locationPathName = outPath,
+                        options = buildOptions,
+                    };
 
-                // 6. Execute build with OperationTracker
-                string op = OperationTracker.Start("BuildPlayer", $"{target} -> {outPath}");
-                var t0 = DateTime.UtcNow;
-                
-                // TODO(UNITY_API): touches BuildPipeline — must run on main via EditorDispatcher
-                BuildReport report = BuildPipeline.BuildPlayer(bpo);
-                var summary = report.summary;
-                var ok = summary.result == BuildResult.Succeeded;
-                
-                OperationTracker.Complete(op, ok ? 0 : 13, summary.result.ToString());
-                
-                return new Pb.BuildPlayerResponse
+                    // 7. Execute build; BuildPipeline cannot be interrupted, so stop accepting cancellation
+                    if (!OperationTracker.TryEnterUncancellable(op)) return Cancelled(op, outPath);
+
+                    // TODO(UNITY_API): touches BuildPipeline — must run on main via EditorDispatcher
+                    BuildReport report = BuildPipeline.BuildPlayer(bpo);
+                    var summary = report.summary;
+                    var ok = summary.result == BuildResult.Succeeded;
+
+                    OperationTracker.Complete(op, ok ? 0 : 13, summary.result.ToString());
+
+                    return new Pb.BuildPlayerResponse
+                    {
+                        StatusCode = ok ? 0 : 13,
+                        Message = ok ? "OK" : summary.result.ToString(),
+                        OutputPath = outPath,
+                        BuildTimeMs = (ulong)summary.totalTime.TotalMilliseconds,
+                        SizeBytes = (ulong)Math.Max(0, (long)summary.totalSize),
+                        OpId = op,
+                        // Warnings = { /* optionally collect from report */ },
+                    };
+                }
+                catch (Exception ex)
                 {
-                    StatusCode = ok ? 0 : 13,
-                    Message = ok ? "OK" : summary.result.ToString(),
-                    OutputPath = outPath,
-                    BuildTimeMs = (ulong)summary.totalTime.TotalMilliseconds,
-                    SizeBytes = (ulong)Math.Max(0, (long)summary.totalSize),
-                    // Warnings = { /* optionally collect from report */ },
-                };
+                    OperationTracker.Complete(op, 13, ex.Message);
+                    return new Pb.BuildPlayerResponse { StatusCode = 13, Message = ex.Message, OpId = op };
+                }
             }
             catch (Exception ex)
             {
@@ -137,8 +150,15 @@ namespace Mcp.Unity.V1.Ipc
             }
         }
 
+        private static Pb.BuildPlayerResponse Cancelled(string op, string outPath)
+        {
+            OperationTracker.Complete(op, 1, "cancelled");
+            return new Pb.BuildPlayerResponse { StatusCode = 1, Message = "cancelled", OutputPath = outPath, OpId = op };
+        }
+
         /// <summary>
-        /// Build Asset Bundles implementation
+        /// Build Asset Bundles implementation. Not cancellable: BuildAssetBundles starts right away
+        /// and cannot be interrupted.
         /// </summary>
         private static Pb.BuildAssetBundlesResponse BuildBundles(Pb.BuildAssetBundlesRequest r)
         {
@@ -155,7 +175,7 @@ namespace Mcp.Unity.V1.Ipc
                 if (r.ForceRebuild) opts |= BuildAssetBundleOptions.ForceRebuildAssetBundle;
 
                 // 3. Execute build with OperationTracker
-                string op = OperationTracker.Start("BuildBundles", outDir);
+                string op = OperationTracker.Start("BuildBundles", outDir, requestedId: r.OpId);
                 var t0 = DateTime.UtcNow;
                 
                 // Use current active target (as per investigation results)
@@ -171,6 +191,7 @@ namespace Mcp.Unity.V1.Ipc
                     Message = "OK",
                     OutputDirectory = outDir,
                     BuildTimeMs = (ulong)(DateTime.UtcNow - t0).TotalMilliseconds,
+                    OpId = op,
                 };
             }
             catch (Exception ex)
@@ -30,6 +30,7 @@ namespace Mcp.Unity.V1.Ipc
         private static readonly Dictionary<Stream, Bridge.Editor.Ipc.FeatureGuard> _negotiatedFeatures = new();
         private static readonly ConcurrentDictionary<Stream, SemaphoreSlim> _writeLocks = new();
         private static readonly Dictionary<Stream, TcpClient> _clientsByStream = new();
+        private static readonly Dictionary<Stream, int> _chunkSizes = new();
         private static string _cachedToken;
         private static int _cachedPort;
 
@@ -83,6 +84,9 @@ namespace Mcp.Unity.V1.Ipc
 
                 _acceptLoopTask = Task.Run(() => AcceptConnectionsAsync(_cancellationTokenSource.Token));
 
+                // Let the MCP server discover this editor (see MCP_DISCOVERY_DIR)
+                DiscoveryDescriptor.Write(CurrentPort);
+
                 Debug.Log("[EditorIpcServer] IPC server started successfully");
             }
             catch (Exception ex)
@@ -106,6 +110,7 @@ namespace Mcp.Unity.V1.Ipc
 
             try
             {
+                DiscoveryDescriptor.Delete();
                 _cancellationTokenSource?.Cancel();
 
                 lock (_streamLock)
@@ -118,6 +123,7 @@ namespace Mcp.Unity.V1.Ipc
                     _clientsByStream.Clear();
                     _activeStreams.Clear();
                     _negotiatedFeatures.Clear();
+                    _chunkSizes.Clear();
                     _writeLocks.Clear();
                 }
 
@@ -274,6 +280,7 @@ namespace Mcp.Unity.V1.Ipc
 
                     // Step 3: Send welcome and register features
                     await SendWelcomeAsync(stream, controlMessage.Welcome);
+                    RegisterChunking(stream, hello, controlMessage.Welcome);
                     Debug.Log($"[EditorIpcServer] T01 Handshake completed: session={hello.ClientName}");
 
                     // Step 4: Register the stream as active
@@ -331,14 +338,23 @@ namespace Mcp.Unity.V1.Ipc
                     }
                     if (frame == null) break; // Connection closed
 
-                    var envelope = EnvelopeCodec.Decode(frame);
+                    var envelope = EnvelopeCodec.Inflate(EnvelopeCodec.Decode(frame));
+                    if (envelope.Control != null)
+                    {
+                        await HandleControlFrameAsync(stream, envelope.Control);
+                        continue;
+                    }
                     if (envelope.Request == null)
                     {
                         Debug.LogWarning("[EditorIpcServer] Received non-request message, ignoring");
                         continue;
                     }
 
-                    await DispatchRequestAsync(stream, envelope.CorrelationId, envelope.Request);
+                    // Never await the handler here: the loop must keep reading so that pings are
+                    // answered and OperationGet/OperationCancel get through while a build or import runs.
+                    // Handlers start in arrival order but may finish in any order, so responses
+                    // on a connection are not FIFO; the client matches them by correlation id.
+                    _ = DispatchRequestAsync(stream, envelope.CorrelationId, envelope.Request);
                 }
             }
             catch (Exception ex)
@@ -349,6 +365,28 @@ namespace Mcp.Unity.V1.Ipc
             Debug.Log("[EditorIpcServer] Request processing loop ended");
         }
 
+        /// <summary>
+        /// Answer heartbeat pings ("ipc.heartbeat") by echoing nonce and timestamp
+        /// </summary>
+        private static async Task HandleControlFrameAsync(Stream stream, IpcControl control)
+        {
+            if (control.KindCase != IpcControl.KindOneofCase.Ping)
+            {
+                return;
+            }
+
+            var pong = new IpcControl
+            {
+                Pong = new IpcPong
+                {
+                    Nonce = control.Ping.Nonce,
+                    PingSentMonotonicNs = control.Ping.SentMonotonicNs
+                }
+            };
+            var bytes = EnvelopeCodec.Encode(EnvelopeCodec.CreateControl(pong));
+            await WriteFrameThreadSafe(stream, bytes);
+        }
+
         /// <summary>
         /// Dispatch a request to the appropriate handler
         /// </summary>
@@ -402,6 +440,22 @@ namespace Mcp.Unity.V1.Ipc
                         await HandleComponentRequest(stream, correlationId, request.Component);
                         break;
 
+                    case IpcRequest.PayloadOneofCase.OperationGet:
+                        await SendResponseAsync(stream, new IpcResponse
+                        {
+                            CorrelationId = correlationId,
+                            OperationGet = OperationTracker.Get(request.OperationGet.Id)
+                        });
+                        break;
+
+                    case IpcRequest.PayloadOneofCase.OperationCancel:
+                        await SendResponseAsync(stream, new IpcResponse
+                        {
+                            CorrelationId = correlationId,
+                            OperationCancel = new OperationCancelResponse { Accepted = OperationTracker.TryCancel(request.OperationCancel.Id) }
+                        });
+                        break;
+
 
                     default:
                         Debug.LogWarning($"[EditorIpcServer] Unhandled request type: {request.PayloadCase}");
@@ -799,7 +853,14 @@ namespace Mcp.Unity.V1.Ipc
             var unityVersion = Application.unityVersion;
             var platformString = Application.platform.ToString();
 
-            return new IpcWelcome
+            // "ipc.compression": we only speak deflate; drop the flag if the client cannot
+            var compressionFlag = Bridge.Editor.Ipc.FeatureFlag.IpcCompression.ToWireString();
+            if (acceptedFeatures.Contains(compressionFlag) && !ClientOffersDeflate(hello))
+            {
+                acceptedFeatures.Remove(compressionFlag);
+            }
+
+            var welcome = new IpcWelcome
             {
                 IpcVersion = hello.IpcVersion,
                 AcceptedFeatures = { acceptedFeatures },
@@ -810,6 +871,60 @@ namespace Mcp.Unity.V1.Ipc
                 SessionId = Guid.NewGuid().ToString(),
                 Meta = { { "platform", platformString } }
             };
+            if (acceptedFeatures.Contains(compressionFlag))
+            {
+                welcome.Meta["compression"] = EnvelopeCodec.DeflateAlgorithm;
+            }
+            return welcome;
+        }
+
+        /// <summary>
+        /// Whether hello meta "compression" (comma separated, absent = deflate) lists deflate
+        /// </summary>
+        private static bool ClientOffersDeflate(IpcHello hello)
+        {
+            if (!hello.Meta.TryGetValue("compression", out var offered))
+            {
+                return true;
+            }
+            return offered.Split(',')
+                .Any(a => a.Trim().Equals(EnvelopeCodec.DeflateAlgorithm, StringComparison.OrdinalIgnoreCase));
+        }
+
+        /// <summary>
+        /// Remember the chunk size for "ipc.chunking", kept below the client's frame limit (hello meta "max_frame_bytes")
+        /// </summary>
+        private static void RegisterChunking(Stream stream, IpcHello hello, IpcWelcome welcome)
+        {
+            if (!welcome.AcceptedFeatures.Contains(Bridge.Editor.Ipc.FeatureFlag.IpcChunking.ToWireString()))
+            {
+                return;
+            }
+
+            var chunkSize = EnvelopeCodec.DefaultChunkSize;
+            if (hello.Meta.TryGetValue("max_frame_bytes", out var raw) && int.TryParse(raw, out var maxFrame))
+            {
+                // Leave room for the envelope and chunk headers
+                chunkSize = Math.Max(1024, Math.Min(chunkSize, maxFrame - 1024));
+            }
+            lock (_streamLock)
+            {
+                _chunkSizes[stream] = chunkSize;
+            }
+        }
+
+        /// <summary>
+        /// Encode an outgoing envelope, compressing it if this connection negotiated "ipc.compression"
+        /// </summary>
+        internal static byte[] EncodeForStream(Stream stream, IpcEnvelope envelope)
+        {
+            Bridge.Editor.Ipc.FeatureGuard features;
+            lock (_streamLock)
+            {
+                _negotiatedFeatures.TryGetValue(stream, out features);
+            }
+            var compress = features?.IsFeatureEnabled(Bridge.Editor.Ipc.FeatureFlag.IpcCompression) == true;
+            return EnvelopeCodec.Encode(envelope, compress);
         }
 
         /// <summary>
@@ -863,8 +978,23 @@ namespace Mcp.Unity.V1.Ipc
         /// </summary>
         private static async Task SendResponseAsync(Stream stream, IpcResponse response)
         {
+            int chunkSize;
+            lock (_streamLock)
+            {
+                _chunkSizes.TryGetValue(stream, out chunkSize);
+            }
+            if (chunkSize > 0 && response.CalculateSize() > chunkSize)
+            {
+                // Chunks share the correlation id; the client reassembles them in order
+                foreach (var chunk in EnvelopeCodec.CreateResponseChunks(response.CorrelationId, response, chunkSize))
+                {
+                    await WriteFrameThreadSafe(stream, EncodeForStream(stream, chunk));
+                }
+                return;
+            }
+
             var envelope = EnvelopeCodec.CreateResponse(response.CorrelationId, response);
-            var bytes = EnvelopeCodec.Encode(envelope);
+            var bytes = EncodeForStream(stream, envelope);
             await WriteFrameThreadSafe(stream, bytes);
         }
 
@@ -1169,6 +1299,7 @@ namespace Mcp.Unity.V1.Ipc
             {
                 _activeStreams.Remove(stream);
                 _negotiatedFeatures.Remove(stream);
+                _chunkSizes.Remove(stream);
                 _writeLocks.TryRemove(stream, out _);
 
                 if (_clientsByStream.Remove(stream, out var client))
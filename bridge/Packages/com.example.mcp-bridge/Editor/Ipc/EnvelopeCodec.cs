@@ -1,11 +1,29 @@
 // Unity MCP Bridge - IPC Envelope Codec
 // Provides serialization/deserialization for IpcEnvelope messages
+using System.Collections.Generic;
+using System.IO;
+using System.IO.Compression;
 using Google.Protobuf;
 
 namespace Mcp.Unity.V1.Ipc
 {
     internal static class EnvelopeCodec
     {
+        /// <summary>
+        /// Envelopes smaller than this are sent uncompressed even when "ipc.compression" is negotiated
+        /// </summary>
+        public const int CompressionThreshold = 1024;
+
+        /// <summary>
+        /// Wire name of the only algorithm the bridge implements (raw RFC 1951 deflate)
+        /// </summary>
+        public const string DeflateAlgorithm = "deflate";
+
+        /// <summary>
+        /// Largest chunk payload used for "ipc.chunking" unless the client asks for less
+        /// </summary>
+        public const int DefaultChunkSize = 1024 * 1024;
+
         /// <summary>
         /// Encode IpcEnvelope to byte array
         /// </summary>
@@ -36,6 +54,100 @@ namespace Mcp.Unity.V1.Ipc
             return IpcEnvelope.Parser.ParseFrom(bytes, offset, length);
         }
 
+        /// <summary>
+        /// Encode IpcEnvelope, wrapping it in a deflate CompressedEnvelope when
+        /// compression is negotiated, the envelope is large enough and it actually shrinks
+        /// </summary>
+        public static byte[] Encode(IpcEnvelope envelope, bool compress)
+        {
+            var raw = Encode(envelope);
+            if (!compress || raw.Length < CompressionThreshold)
+            {
+                return raw;
+            }
+
+            byte[] data;
+            using (var output = new MemoryStream())
+            {
+                using (var deflate = new DeflateStream(output, CompressionLevel.Fastest, leaveOpen: true))
+                {
+                    deflate.Write(raw, 0, raw.Length);
+                }
+                data = output.ToArray();
+            }
+
+            var wrapped = new IpcEnvelope
+            {
+                CorrelationId = envelope.CorrelationId,
+                Compressed = new CompressedEnvelope
+                {
+                    Algorithm = CompressedEnvelope.Types.Algorithm.Deflate,
+                    UncompressedSize = (uint)raw.Length,
+                    Data = ByteString.CopyFrom(data)
+                }
+            };
+            return wrapped.CalculateSize() < raw.Length ? wrapped.ToByteArray() : raw;
+        }
+
+        /// <summary>
+        /// Unwrap a CompressedEnvelope; other envelopes are returned unchanged
+        /// </summary>
+        public static IpcEnvelope Inflate(IpcEnvelope envelope)
+        {
+            if (envelope.KindCase != IpcEnvelope.KindOneofCase.Compressed)
+            {
+                return envelope;
+            }
+
+            var compressed = envelope.Compressed;
+            if (compressed.Algorithm != CompressedEnvelope.Types.Algorithm.Deflate)
+            {
+                throw new InvalidDataException($"Unsupported compression algorithm: {compressed.Algorithm}");
+            }
+            if (compressed.UncompressedSize > Framing.MaxFrameSize)
+            {
+                throw new InvalidDataException($"Compressed envelope too large: {compressed.UncompressedSize} bytes");
+            }
+
+            var raw = new byte[compressed.UncompressedSize];
+            using (var deflate = new DeflateStream(new MemoryStream(compressed.Data.ToByteArray()), CompressionMode.Decompress))
+            {
+                var read = 0;
+                while (read < raw.Length)
+                {
+                    var n = deflate.Read(raw, read, raw.Length - read);
+                    if (n == 0) throw new InvalidDataException("Compressed envelope is truncated");
+                    read += n;
+                }
+            }
+            return Decode(raw);
+        }
+
+        /// <summary>
+        /// Split a response into ordered IpcResponseChunk envelopes of at most chunkSize data bytes
+        /// </summary>
+        public static IEnumerable<IpcEnvelope> CreateResponseChunks(string correlationId, IpcResponse response, int chunkSize)
+        {
+            var bytes = response.ToByteArray();
+            var count = System.Math.Max(1, (bytes.Length + chunkSize - 1) / chunkSize);
+            for (var i = 0; i < count; i++)
+            {
+                var offset = i * chunkSize;
+                var length = System.Math.Min(chunkSize, bytes.Length - offset);
+                yield return new IpcEnvelope
+                {
+                    CorrelationId = correlationId ?? string.Empty,
+                    ResponseChunk = new IpcResponseChunk
+                    {
+                        Index = (uint)i,
+                        TotalSize = (ulong)bytes.Length,
+                        Last = i + 1 == count,
+                        Data = ByteString.CopyFrom(bytes, offset, length)
+                    }
+                };
+            }
+        }
+
         /// <summary>
         /// Create a request envelope with correlation ID
         /// </summary>
@@ -71,5 +183,17 @@ namespace Mcp.Unity.V1.Ipc
                 Event = eventMessage
             };
         }
+
+        /// <summary>
+        /// Create a post-handshake control envelope (heartbeat ping/pong)
+        /// </summary>
+        public static IpcEnvelope CreateControl(IpcControl control)
+        {
+            return new IpcEnvelope
+            {
+                CorrelationId = string.Empty,
+                Control = control
+            };
+        }
     }
 }
\ No newline at end of file
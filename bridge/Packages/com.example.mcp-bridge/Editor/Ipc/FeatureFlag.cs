@@ -13,6 +13,9 @@ namespace Bridge.Editor.Ipc
         EventsLog,
         OpsProgress,
         ComponentsBasic,
+        IpcHeartbeat,
+        IpcCompression,
+        IpcChunking,
         AssetsAdvanced,
         BuildFull,
         EventsFull,
@@ -29,6 +32,9 @@ namespace Bridge.Editor.Ipc
             { "events.log", FeatureFlag.EventsLog },
             { "ops.progress", FeatureFlag.OpsProgress },
             { "components.basic", FeatureFlag.ComponentsBasic },
+            { "ipc.heartbeat", FeatureFlag.IpcHeartbeat },
+            { "ipc.compression", FeatureFlag.IpcCompression },
+            { "ipc.chunking", FeatureFlag.IpcChunking },
             { "assets.advanced", FeatureFlag.AssetsAdvanced },
             { "build.full", FeatureFlag.BuildFull },
             { "events.full", FeatureFlag.EventsFull },
@@ -59,6 +65,9 @@ namespace Bridge.Editor.Ipc
                 FeatureFlag.EventsLog,
                 FeatureFlag.OpsProgress,
                 FeatureFlag.ComponentsBasic,
+                FeatureFlag.IpcHeartbeat,
+                FeatureFlag.IpcCompression,
+                FeatureFlag.IpcChunking,
                 // Note: AssetsAdvanced, BuildFull, EventsFull not yet implemented
             };
         }
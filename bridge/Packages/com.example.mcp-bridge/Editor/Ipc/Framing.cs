@@ -9,7 +9,7 @@ namespace Mcp.Unity.V1.Ipc
 {
     internal static class Framing
     {
-        private const int MaxFrameSize = 64 * 1024 * 1024; // 64MB max frame size
+        internal const int MaxFrameSize = 64 * 1024 * 1024; // 64MB max frame size
 
         /// <summary>
         /// Write a frame with 4-byte big-endian length prefix followed by payload
@@ -0,0 +1,142 @@
+// Unity MCP Bridge - Discovery Descriptor
+// Publishes this editor's endpoint so the MCP server can find it without configuration
+#if UNITY_EDITOR
+using System;
+using System.Diagnostics;
+using System.IO;
+using System.Runtime.InteropServices;
+using UnityEngine;
+using Debug = UnityEngine.Debug;
+
+namespace Bridge.Editor.Ipc.Infra
+{
+    /// <summary>
+    /// Writes one JSON file per running editor to the discovery directory and removes it on shutdown.
+    /// The directory (0700) and file (0600) are private to the user: the server ignores
+    /// descriptors other users could have written.
+    /// </summary>
+    internal static class DiscoveryDescriptor
+    {
+        [Serializable]
+        private class Descriptor
+        {
+            public string endpoint;
+            public int pid;
+            public string project_name;
+            public string project_path;
+            public string unity_version;
+            public string started_at;
+        }
+
+        private static string _writtenPath;
+
+        // chmod modes: 0700 for the directory, 0600 for the descriptor
+        private const uint UserOnlyDirectory = 0x1C0;
+        private const uint UserOnlyFile = 0x180;
+
+        [DllImport("libc", SetLastError = true)]
+        private static extern int chmod(string path, uint mode);
+
+        private static bool IsWindows => Environment.OSVersion.Platform == PlatformID.Win32NT;
+
+        /// <summary>
+        /// MCP_DISCOVERY_DIR, else $XDG_RUNTIME_DIR/unity-mcp/editors, else
+        /// %LOCALAPPDATA%\unity-mcp\editors on Windows and ~/.unity-mcp/editors elsewhere
+        /// (same rule as the server's default_discovery_dir).
+        /// </summary>
+        public static string Directory
+        {
+            get
+            {
+                var dir = Environment.GetEnvironmentVariable("MCP_DISCOVERY_DIR");
+                if (!string.IsNullOrEmpty(dir)) return dir;
+
+                var runtime = Environment.GetEnvironmentVariable("XDG_RUNTIME_DIR");
+                if (!string.IsNullOrEmpty(runtime)) return Path.Combine(runtime, "unity-mcp", "editors");
+
+                if (IsWindows)
+                {
+                    var local = Environment.GetFolderPath(Environment.SpecialFolder.LocalApplicationData);
+                    return Path.Combine(string.IsNullOrEmpty(local) ? Path.GetTempPath() : local, "unity-mcp", "editors");
+                }
+
+                var home = Environment.GetEnvironmentVariable("HOME");
+                return Path.Combine(string.IsNullOrEmpty(home) ? Path.GetTempPath() : home, ".unity-mcp", "editors");
+            }
+        }
+
+        /// <summary>
+        /// Restrict a path to the current user (no-op on Windows, where the per-user profile ACL applies).
+        /// </summary>
+        private static void RestrictToUser(string path, uint mode)
+        {
+            if (IsWindows) return;
+            if (chmod(path, mode) != 0)
+            {
+                throw new IOException($"chmod {Convert.ToString(mode, 8)} {path} failed (errno {Marshal.GetLastWin32Error()})");
+            }
+        }
+
+        /// <summary>
+        /// Publish the endpoint the server is listening on. Must be called on the main thread.
+        /// </summary>
+        public static void Write(int port)
+        {
+            try
+            {
+                var projectRoot = Path.GetDirectoryName(Application.dataPath) ?? string.Empty;
+                var pid = Process.GetCurrentProcess().Id;
+                var descriptor = new Descriptor
+                {
+                    endpoint = $"tcp://127.0.0.1:{port}",
+                    pid = pid,
+                    project_name = string.IsNullOrEmpty(projectRoot) ? string.Empty : Path.GetFileName(projectRoot),
+                    project_path = projectRoot.Replace('\\', '/'),
+                    unity_version = Application.unityVersion,
+                    started_at = DateTime.UtcNow.ToString("o"),
+                };
+
+                var dir = Directory;
+                System.IO.Directory.CreateDirectory(dir);
+                RestrictToUser(dir, UserOnlyDirectory);
+                var path = Path.Combine(dir, $"{pid}.json");
+
+                // Write to a temp file and rename so readers never see a partial descriptor.
+                // The file is made private before any content is written to it.
+                var tmp = path + ".tmp";
+                File.WriteAllText(tmp, string.Empty);
+                RestrictToUser(tmp, UserOnlyFile);
+                File.WriteAllText(tmp, JsonUtility.ToJson(descriptor, true));
+                if (File.Exists(path)) File.Delete(path);
+                File.Move(tmp, path);
+                _writtenPath = path;
+
+                Debug.Log($"[DiscoveryDescriptor] Published {descriptor.endpoint} to {path}");
+            }
+            catch (Exception ex)
+            {
+                Debug.LogWarning($"[DiscoveryDescriptor] Failed to write descriptor: {ex.Message}");
+            }
+        }
+
+        /// <summary>
+        /// Remove the descriptor written by <see cref="Write"/>, if any.
+        /// </summary>
+        public static void Delete()
+        {
+            var path = _writtenPath;
+            _writtenPath = null;
+            if (string.IsNullOrEmpty(path)) return;
+
+            try
+            {
+                if (File.Exists(path)) File.Delete(path);
+            }
+            catch (Exception ex)
+            {
+                Debug.LogWarning($"[DiscoveryDescriptor] Failed to delete descriptor: {ex.Message}");
+            }
+        }
+    }
+}
+#endif
@@ -44,7 +44,7 @@ namespace Mcp.Unity.V1.Ipc
                         try
                         {
                             var env = new Pb.IpcEnvelope { Event = ev };
-                            var bytes = EnvelopeCodec.Encode(env);
+                            var bytes = EditorIpcServer.EncodeForStream(stream, env);
                             await EditorIpcServer.WriteFrameThreadSafe(stream, bytes);
                         }
                         catch (System.Exception ex)
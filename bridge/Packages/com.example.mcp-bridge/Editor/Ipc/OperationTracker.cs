@@ -1,15 +1,38 @@
 // Unity MCP Bridge - Operation Tracker
 // Simple API to wrap long tasks and emit START/PROGRESS/COMPLETE events
 using System;
+using System.Collections.Concurrent;
 using Pb = Mcp.Unity.V1;
 
 namespace Mcp.Unity.V1.Ipc
 {
     internal static class OperationTracker
     {
-        public static string Start(string kind, string message)
+        private sealed class Entry
         {
-            string id = Guid.NewGuid().ToString("n");
+            public string Kind;
+            public string State;     // running | completed | failed | cancelled
+            public string Message;
+            public bool Cancellable;
+            public volatile bool CancelRequested;
+        }
+
+        private static readonly ConcurrentDictionary<string, Entry> _ops = new ConcurrentDictionary<string, Entry>();
+        private const int MaxFinishedEntries = 256;
+
+        /// <summary>
+        /// Start an operation. <paramref name="requestedId"/> is the op_id the client chose in its
+        /// request so it can route events before the response; empty or already used ids get a new one.
+        /// </summary>
+        public static string Start(string kind, string message, bool cancellable = false, string requestedId = null)
+        {
+            var entry = new Entry { Kind = kind, State = "running", Message = message, Cancellable = cancellable };
+            string id = requestedId;
+            if (string.IsNullOrEmpty(id) || !_ops.TryAdd(id, entry))
+            {
+                id = Guid.NewGuid().ToString("n");
+                _ops[id] = entry;
+            }
             Publish(new Pb.OperationEvent 
             { 
                 OpId = id, 
@@ -32,6 +55,12 @@ namespace Mcp.Unity.V1.Ipc
 
         public static void Complete(string id, int code, string msg = "")
         {
+            if (_ops.TryGetValue(id, out var entry))
+            {
+                entry.State = entry.CancelRequested ? "cancelled" : (code == 0 ? "completed" : "failed");
+                entry.Message = msg;
+            }
+            PruneFinished();
             Publish(new Pb.OperationEvent 
             { 
                 OpId = id, 
@@ -42,6 +71,61 @@ namespace Mcp.Unity.V1.Ipc
             });
         }
 
+        /// <summary>
+        /// Snapshot of an operation for OperationGetRequest. Unknown ids report state "unknown".
+        /// </summary>
+        public static Pb.OperationGetResponse Get(string id)
+        {
+            if (_ops.TryGetValue(id, out var entry))
+            {
+                return new Pb.OperationGetResponse { Id = id, State = entry.State, Message = $"{entry.Kind}: {entry.Message}" };
+            }
+            return new Pb.OperationGetResponse { Id = id, State = "unknown", Message = "operation not found" };
+        }
+
+        /// <summary>
+        /// Request cooperative cancellation. Only running, cancellable operations accept it.
+        /// </summary>
+        public static bool TryCancel(string id)
+        {
+            if (!_ops.TryGetValue(id, out var entry)) return false;
+            lock (entry)
+            {
+                if (entry.State != "running" || !entry.Cancellable) return false;
+                entry.CancelRequested = true;
+                return true;
+            }
+        }
+
+        /// <summary>
+        /// Enter the part of an operation that cannot be interrupted (e.g. BuildPipeline).
+        /// Returns false when cancellation was already requested; later TryCancel calls are refused.
+        /// </summary>
+        public static bool TryEnterUncancellable(string id)
+        {
+            if (!_ops.TryGetValue(id, out var entry)) return true;
+            lock (entry)
+            {
+                if (entry.CancelRequested) return false;
+                entry.Cancellable = false;
+                return true;
+            }
+        }
+
+        public static bool IsCancellationRequested(string id)
+        {
+            return _ops.TryGetValue(id, out var entry) && entry.CancelRequested;
+        }
+
+        private static void PruneFinished()
+        {
+            if (_ops.Count <= MaxFinishedEntries) return;
+            foreach (var kv in _ops)
+            {
+                if (kv.Value.State != "running") _ops.TryRemove(kv.Key, out _);
+            }
+        }
+
         private static void Publish(Pb.OperationEvent op)
         {
             var ev = new Pb.IpcEvent 
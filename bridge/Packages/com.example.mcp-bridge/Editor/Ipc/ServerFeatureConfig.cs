@@ -40,6 +40,9 @@ namespace Bridge.Editor.Ipc
             features.Add(FeatureFlag.EventsLog);
             features.Add(FeatureFlag.OpsProgress);
             features.Add(FeatureFlag.ComponentsBasic);
+            features.Add(FeatureFlag.IpcHeartbeat);
+            features.Add(FeatureFlag.IpcCompression);
+            features.Add(FeatureFlag.IpcChunking);
             
             // Conditionally enabled features (using cached value)
             if (_isBuildSystemAvailable)
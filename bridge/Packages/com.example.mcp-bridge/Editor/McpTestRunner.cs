@@ -20,6 +20,7 @@ namespace MCP.Editor
         public int timeoutSec;
         public int maxItems;
         public bool includePassed;
+        public string opId;      // Operation id the server claimed for progress events
     }
 
     [Serializable]
@@ -92,6 +93,12 @@ namespace MCP.Editor
         // For sequential execution when mode == "all"
         private static Queue<TestMode> phaseQueue;
         private static bool multiPhase;
+        // Progress reported as OperationEvents under the request's opId
+        private static string currentOpId;
+        private static int phaseCount;
+        private static int phasesDone;
+        private static int phaseTestCount;
+        private static int phaseTestsFinished;
 
         static McpTestRunner()
         {
@@ -222,6 +229,10 @@ namespace MCP.Editor
                 {
                     phaseQueue.Enqueue(GetTestMode(request.mode));
                 }
+                phaseCount = phaseQueue.Count;
+                phasesDone = 0;
+                currentOpId = Mcp.Unity.V1.Ipc.OperationTracker.Start(
+                    "RunTests", $"Test run {request.runId} ({request.mode})", requestedId: request.opId);
 
                 // Phase 2: Write status file to indicate test started (optional for debugging)
                 if (EnableFileOutput)
@@ -237,6 +248,7 @@ namespace MCP.Editor
             catch (Exception e)
             {
                 Debug.LogError($"[McpTestRunner] Failed to execute test run: {e.Message}");
+                CompleteOperation(13, e.Message);
                 
                 // Create error results - ensure currentResults is never null
                 currentResults = new TestResults
@@ -316,6 +328,8 @@ namespace MCP.Editor
             {
                 // ITestAdaptor may represent a suite; children count may not be loaded yet
                 Debug.Log($"[McpTestRunner] Test run started: {runStarted.FullName}");
+                phaseTestsFinished = 0;
+                phaseTestCount = runStarted.TestCaseCount;
             }
             catch (Exception)
             {
@@ -337,6 +351,10 @@ namespace MCP.Editor
                 {
                     collectedResults.Add(result);
                 }
+                if (!testFinished.Test.IsSuite)
+                {
+                    ReportProgress(testFinished.Test.FullName);
+                }
             }
             catch (Exception e)
             {
@@ -383,6 +401,7 @@ namespace MCP.Editor
                 {
                     // Just keep accumulating results; do not finalize yet
                     Debug.Log("[McpTestRunner] Starting next phase...");
+                    phasesDone++;
                     ExecuteNextPhase();
                     return;
                 }
@@ -412,6 +431,9 @@ namespace MCP.Editor
 
                 currentResults.tests = filteredResults.ToArray();
 
+                var summary = currentResults.summary;
+                CompleteOperation(0, $"{summary.passed} passed, {summary.failed} failed, {summary.skipped} skipped");
+
                 // Phase 2: Send IPC notification with results (primary communication)
                 if (EnableIpcCommunication)
                 {
@@ -432,6 +454,7 @@ namespace MCP.Editor
             {
                 Debug.LogError($"[McpTestRunner] Failed to finalize test run: {e.Message}");
                 Debug.LogError($"[McpTestRunner] Exception details: {e.StackTrace}");
+                CompleteOperation(13, e.Message);
                 
                 // Enhanced error handling: Ensure safe state even on exception
                 try
@@ -498,6 +521,26 @@ namespace MCP.Editor
             }
         }
 
+        /// <summary>
+        /// Publish progress of the current run; phases of "all" mode share the range evenly.
+        /// 100 is left to the COMPLETE event.
+        /// </summary>
+        private static void ReportProgress(string testName)
+        {
+            if (currentOpId == null) return;
+            phaseTestsFinished++;
+            float phase = phaseTestCount > 0 ? Math.Min(1f, (float)phaseTestsFinished / phaseTestCount) : 0f;
+            int pct = (int)(100f * (phasesDone + phase) / Math.Max(1, phaseCount));
+            Mcp.Unity.V1.Ipc.OperationTracker.Progress(currentOpId, Math.Min(pct, 99), testName);
+        }
+
+        private static void CompleteOperation(int code, string message)
+        {
+            if (currentOpId == null) return;
+            Mcp.Unity.V1.Ipc.OperationTracker.Complete(currentOpId, code, message);
+            currentOpId = null;
+        }
+
         private static TestResult CreateTestResult(ITestAdaptor test, ITestResultAdaptor result)
         {
             try
@@ -35,7 +35,7 @@ namespace Mcp.Unity.V1.Ipc.Tests
         {
             // Save original token value for restoration
             _originalToken = UnityEditor.EditorUserSettings.GetConfigValue("MCP.IpcToken");
-            Debug.Log($"[CrossThreadDiagnosticsTests] Saved original token: {(_originalToken ?? "(null)")}");
+            Debug.Log($"[CrossThreadDiagnosticsTests] Saved original token ({(string.IsNullOrEmpty(_originalToken) ? "unset" : "set")})");
             
             // Set test token in EditorUserSettings for authentication
             UnityEditor.EditorUserSettings.SetConfigValue("MCP.IpcToken", "test-token");
@@ -65,7 +65,7 @@ namespace Mcp.Unity.V1.Ipc.Tests
             
             // 元のトークン値を復元
             UnityEditor.EditorUserSettings.SetConfigValue("MCP.IpcToken", _originalToken ?? "");
-            Debug.Log($"[CrossThreadDiagnosticsTests] Restored original token: {(_originalToken ?? "(null)")}");
+            Debug.Log("[CrossThreadDiagnosticsTests] Restored original token");
             
             // 設定変更後のサーバー再起動（元の設定で）
             EditorIpcServer.ReloadConfiguration();
@@ -28,12 +28,12 @@ namespace Bridge.Editor.Tests
         {
             // Save original token value for restoration
             _originalToken = UnityEditor.EditorUserSettings.GetConfigValue("MCP.IpcToken");
-            Debug.Log($"[HandshakeRefactorTests] Saved original token: {(_originalToken ?? "(null)")}");
+            Debug.Log($"[HandshakeRefactorTests] Saved original token ({(string.IsNullOrEmpty(_originalToken) ? "unset" : "set")})");
             
             // Set up test token for IPC authentication
             var testToken = "test-token";
             UnityEditor.EditorUserSettings.SetConfigValue("MCP.IpcToken", testToken);
-            Debug.Log($"[HandshakeRefactorTests] Set test token: {testToken}");
+            Debug.Log("[HandshakeRefactorTests] Set test token in EditorUserSettings");
 
             // Restart IPC server to pick up new token configuration
             if (EditorIpcServer.IsRunning)
@@ -90,7 +90,7 @@ namespace Bridge.Editor.Tests
             
             // 元のトークン値を復元
             UnityEditor.EditorUserSettings.SetConfigValue("MCP.IpcToken", _originalToken ?? "");
-            Debug.Log($"[HandshakeRefactorTests] Restored original token: {(_originalToken ?? "(null)")}");
+            Debug.Log("[HandshakeRefactorTests] Restored original token");
             
             // 設定変更後のサーバー再起動（元の設定で）
             EditorIpcServer.ReloadConfiguration();
@@ -34,8 +34,8 @@ protoc \
   "$PROTO_ROOT"/mcp/unity/v1/ipc_control.proto
 
 # Generate SchemaHash.cs from Rust SCHEMA_HASH
-SCHEMA_HASH_RS="$REPO_ROOT/server/src/generated/schema_hash.rs"
-SCHEMA_PB="$REPO_ROOT/server/src/generated/schema.pb"
+SCHEMA_HASH_RS="$REPO_ROOT/server/crates/unity-ipc/src/generated/schema_hash.rs"
+SCHEMA_PB="$REPO_ROOT/server/crates/unity-ipc/src/generated/schema.pb"
 SCHEMA_HASH_CS="$OUT/SchemaHash.cs"
 
 # Check if schema.pb exists as fallback
@@ -1,21 +1,39 @@
 // Example to test Unity IPC Server from Rust
-// Run with: cargo run --example test_unity_ipc
-use server::ipc::{client::IpcClient, path::IpcConfig};
+// Run with: cargo run -p unity-ipc --example test_unity_ipc
 use std::time::Duration;
+use unity_ipc::{
+    chunking,
+    client::IpcClient,
+    framing,
+    path::{self, IpcConfig},
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     println!("Testing connection to Unity EditorIpcServer...");
 
     // Configure client to connect to Unity TCP server on 127.0.0.1:7777
+    let defaults = IpcConfig::default();
     let cfg = IpcConfig {
         endpoint: Some("tcp://127.0.0.1:7777".to_string()),
-        token: Some("test-token".to_string()),
+        // MCP_IPC_TOKEN / MCP_IPC_TOKEN_FILE / the Unity project's MCP.IpcToken
+        token: defaults.token,
+        token_file: defaults.token_file,
+        unity_project: defaults.unity_project,
         connect_timeout: Duration::from_secs(10),
         handshake_timeout: Duration::from_secs(5),
         total_handshake_timeout: Duration::from_secs(15),
         call_timeout: Duration::from_secs(10),
         max_reconnect_attempts: Some(3), // Allow retries for example
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: Some(path::DEFAULT_COMPRESSION_THRESHOLD),
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: defaults.tls,
+        record: defaults.record,
     };
 
     println!("Attempting to connect to tcp://127.0.0.1:7777...");
@@ -1,23 +1,42 @@
 // Tail Unity log events (~10s) via Direct IPC
-// Run with: cargo run --example unity_log_tail
-use server::generated::mcp::unity::v1 as pb;
-use server::ipc::{client::IpcClient, features::FeatureFlag, path::IpcConfig};
+// Run with: cargo run -p unity-ipc --example unity_log_tail
 use std::time::Duration;
 use tokio::time;
+use unity_ipc::generated::mcp::unity::v1 as pb;
+use unity_ipc::{
+    chunking,
+    client::IpcClient,
+    features::FeatureFlag,
+    framing,
+    path::{self, IpcConfig},
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     println!("[unity_log_tail] Connecting to Unity EditorIpcServer...");
 
-    // Minimal configuration. Adjust token/endpoint if needed.
+    // Minimal configuration. Adjust the endpoint if needed.
+    let defaults = IpcConfig::default();
     let cfg = IpcConfig {
         endpoint: Some("tcp://127.0.0.1:7777".to_string()),
-        token: Some("test-token".to_string()),
+        // MCP_IPC_TOKEN / MCP_IPC_TOKEN_FILE / the Unity project's MCP.IpcToken
+        token: defaults.token,
+        token_file: defaults.token_file,
+        unity_project: defaults.unity_project,
         connect_timeout: Duration::from_secs(10),
         handshake_timeout: Duration::from_secs(5),
         total_handshake_timeout: Duration::from_secs(15),
         call_timeout: Duration::from_secs(10),
         max_reconnect_attempts: Some(3),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: Some(path::DEFAULT_COMPRESSION_THRESHOLD),
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: defaults.tls,
+        record: defaults.record,
     };
 
     // Connect
@@ -0,0 +1,229 @@
+//! Responses split across several frames ("ipc.chunking").
+//!
+//! スクリーンショットやビルドレポートのように 1 フレームの上限を超える応答は、
+//! 同じ correlation_id を持つ `IpcResponseChunk` の列として送られる。
+//! 各チャンクの `data` を index 順に連結すると、シリアライズ済みの `IpcResponse` になる。
+
+use bytes::{Bytes, BytesMut};
+use prost::Message;
+use std::collections::HashMap;
+use thiserror::Error;
+
+use crate::generated::mcp::unity::v1 as pb;
+
+/// Default upper bound for a reassembled response.
+pub const DEFAULT_MAX_RESPONSE_LEN: usize = 256 * 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum ChunkError {
+    #[error("response of {size} bytes exceeds limit of {limit} bytes")]
+    TooLarge { size: u64, limit: usize },
+    #[error("out of order chunk: expected index {expected}, got {got}")]
+    OutOfOrder { expected: u32, got: u32 },
+    #[error("chunk data ({received} bytes) does not match total_size {total_size}")]
+    SizeMismatch { received: u64, total_size: u64 },
+    #[error("decode error: {0}")]
+    Decode(#[from] prost::DecodeError),
+}
+
+/// Result of feeding one chunk to the [`Reassembler`].
+#[derive(Debug)]
+pub enum Progress {
+    Partial { received: u64, total_size: u64 },
+    Complete(pb::IpcResponse),
+}
+
+#[derive(Debug)]
+struct Partial {
+    next_index: u32,
+    total_size: u64,
+    buf: BytesMut,
+}
+
+/// Per-connection reassembly of chunked responses, keyed by correlation id.
+#[derive(Debug)]
+pub struct Reassembler {
+    max_len: usize,
+    partial: HashMap<String, Partial>,
+}
+
+impl Reassembler {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            max_len,
+            partial: HashMap::new(),
+        }
+    }
+
+    /// Add a chunk. Completed or failed responses drop their state.
+    pub fn push(
+        &mut self,
+        correlation_id: &str,
+        chunk: &pb::IpcResponseChunk,
+    ) -> Result<Progress, ChunkError> {
+        let result = self.push_inner(correlation_id, chunk);
+        if !matches!(result, Ok(Progress::Partial { .. })) {
+            self.partial.remove(correlation_id);
+        }
+        result
+    }
+
+    fn push_inner(
+        &mut self,
+        correlation_id: &str,
+        chunk: &pb::IpcResponseChunk,
+    ) -> Result<Progress, ChunkError> {
+        if chunk.total_size > self.max_len as u64 {
+            return Err(ChunkError::TooLarge {
+                size: chunk.total_size,
+                limit: self.max_len,
+            });
+        }
+        let partial = self
+            .partial
+            .entry(correlation_id.to_string())
+            .or_insert_with(|| Partial {
+                next_index: 0,
+                total_size: chunk.total_size,
+                buf: BytesMut::new(),
+            });
+        if chunk.index != partial.next_index {
+            return Err(ChunkError::OutOfOrder {
+                expected: partial.next_index,
+                got: chunk.index,
+            });
+        }
+        let received = (partial.buf.len() + chunk.data.len()) as u64;
+        if received > partial.total_size || chunk.total_size != partial.total_size {
+            return Err(ChunkError::SizeMismatch {
+                received,
+                total_size: partial.total_size,
+            });
+        }
+        partial.buf.extend_from_slice(&chunk.data);
+        partial.next_index += 1;
+
+        if !chunk.last {
+            return Ok(Progress::Partial {
+                received,
+                total_size: partial.total_size,
+            });
+        }
+        if received != partial.total_size {
+            return Err(ChunkError::SizeMismatch {
+                received,
+                total_size: partial.total_size,
+            });
+        }
+        let bytes = std::mem::take(&mut partial.buf).freeze();
+        Ok(Progress::Complete(pb::IpcResponse::decode(bytes)?))
+    }
+
+    /// Forget a response nobody is waiting for any more.
+    pub fn discard(&mut self, correlation_id: &str) {
+        self.partial.remove(correlation_id);
+    }
+}
+
+/// Split a response into chunk envelopes of at most `chunk_size` data bytes each.
+pub fn split_response(
+    correlation_id: &str,
+    response: &pb::IpcResponse,
+    chunk_size: usize,
+) -> Vec<pb::IpcEnvelope> {
+    let bytes = Bytes::from(response.encode_to_vec());
+    let total_size = bytes.len() as u64;
+    let chunk_size = chunk_size.max(1);
+    let count = bytes.len().div_ceil(chunk_size).max(1);
+    (0..count)
+        .map(|i| {
+            let start = i * chunk_size;
+            let end = (start + chunk_size).min(bytes.len());
+            pb::IpcEnvelope {
+                correlation_id: correlation_id.to_string(),
+                kind: Some(pb::ipc_envelope::Kind::ResponseChunk(
+                    pb::IpcResponseChunk {
+                        index: i as u32,
+                        total_size,
+                        last: i + 1 == count,
+                        data: bytes.slice(start..end).to_vec(),
+                    },
+                )),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health_response(version: String) -> pb::IpcResponse {
+        pb::IpcResponse {
+            correlation_id: "cid".to_string(),
+            payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                version,
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn chunk_of(env: &pb::IpcEnvelope) -> &pb::IpcResponseChunk {
+        match &env.kind {
+            Some(pb::ipc_envelope::Kind::ResponseChunk(c)) => c,
+            other => panic!("expected chunk, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_split_and_reassemble() {
+        let resp = health_response("v".repeat(10_000));
+        let envs = split_response("cid", &resp, 1024);
+        assert_eq!(envs.len(), resp.encoded_len().div_ceil(1024));
+
+        let mut r = Reassembler::new(DEFAULT_MAX_RESPONSE_LEN);
+        let (last, rest) = envs.split_last().unwrap();
+        for env in rest {
+            assert!(matches!(
+                r.push("cid", chunk_of(env)).unwrap(),
+                Progress::Partial { .. }
+            ));
+        }
+        match r.push("cid", chunk_of(last)).unwrap() {
+            Progress::Complete(done) => assert_eq!(done, resp),
+            other => panic!("expected complete, got {other:?}"),
+        }
+        assert!(r.partial.is_empty());
+    }
+
+    #[test]
+    fn test_out_of_order_chunk_fails_response() {
+        let envs = split_response("cid", &health_response("v".repeat(4096)), 1024);
+        let mut r = Reassembler::new(DEFAULT_MAX_RESPONSE_LEN);
+        r.push("cid", chunk_of(&envs[0])).unwrap();
+        let err = r.push("cid", chunk_of(&envs[2])).unwrap_err();
+        assert!(matches!(
+            err,
+            ChunkError::OutOfOrder {
+                expected: 1,
+                got: 2
+            }
+        ));
+        assert!(r.partial.is_empty());
+    }
+
+    #[test]
+    fn test_total_size_over_limit_is_rejected_up_front() {
+        let envs = split_response("cid", &health_response("v".repeat(4096)), 1024);
+        let mut r = Reassembler::new(1024);
+        let err = r.push("cid", chunk_of(&envs[0])).unwrap_err();
+        assert!(matches!(err, ChunkError::TooLarge { limit: 1024, .. }));
+    }
+
+    #[test]
+    fn test_small_response_is_a_single_last_chunk() {
+        let envs = split_response("cid", &health_response("1.0".into()), 1024);
+        assert_eq!(envs.len(), 1);
+        assert!(chunk_of(&envs[0]).last);
+    }
+}
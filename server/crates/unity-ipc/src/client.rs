@@ -0,0 +1,1852 @@
+use bytes::Bytes;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net,
+    sync::{Mutex, broadcast, mpsc, oneshot, watch},
+    time,
+};
+use tokio_util::{codec::Framed, sync::CancellationToken};
+
+// Trait for stream types that can be used with IPC
+trait IpcStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+// Implement for common stream types
+impl<T> IpcStream for T where T: AsyncRead + AsyncWrite + Unpin + Send {}
+
+use super::{
+    chunking::{ChunkError, Progress, Reassembler},
+    codec::{self, Compression},
+    dispatch::IpcCall,
+    features::{FeatureFlag, FeatureSet},
+    framing::{Frame, FrameCodec, OversizedFrame},
+    heartbeat::{self, Heartbeat},
+    path::{Endpoint, IpcConfig},
+    record::Recorder,
+    tap::Direction,
+    tls,
+    token::{self, ResolvedToken},
+    ws,
+};
+use crate::generated::mcp::unity::v1 as pb;
+
+#[derive(Debug, Error)]
+pub enum IpcError {
+    #[error("connect timeout")]
+    ConnectTimeout,
+    #[error("handshake failed: {0}")]
+    Handshake(String),
+    #[error("authentication failed: {0}")]
+    Authentication(String),
+    #[error("version incompatible: {0}")]
+    VersionIncompatible(String),
+    #[error("schema mismatch: {0}")]
+    SchemaMismatch(String),
+    #[error("server unavailable: {0}")]
+    ServerUnavailable(String),
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
+    #[error("unsupported feature: {0}")]
+    UnsupportedFeature(String),
+    #[error("failed precondition: {0}")]
+    FailedPrecondition(String),
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("internal bridge error: {0}")]
+    Internal(String),
+    #[error("unexpected response: {0}")]
+    UnexpectedResponse(String),
+    #[error("bridge status {code}: {message}")]
+    Status { code: i32, message: String },
+    #[error("io: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("tls: {0}")]
+    Tls(String),
+    #[error("websocket: {0}")]
+    WebSocket(String),
+    #[error("codec: {0}")]
+    Codec(#[from] super::codec::CodecError),
+    #[error("request timeout")]
+    RequestTimeout,
+    #[error(
+        "frame too large: {size} bytes exceeds limit of {limit} bytes (correlation_id={correlation_id})"
+    )]
+    FrameTooLarge {
+        correlation_id: String,
+        size: usize,
+        limit: usize,
+    },
+    #[error("chunked response: {0}")]
+    Chunk(#[from] ChunkError),
+    #[error("cancelled")]
+    Cancelled,
+    #[error("closed")]
+    Closed,
+}
+
+/// Canonical status codes in the `status_code` field of bridge responses (gRPC numbering).
+pub mod status {
+    pub const OK: i32 = 0;
+    pub const INVALID_ARGUMENT: i32 = 2;
+    pub const NOT_FOUND: i32 = 5;
+    pub const FAILED_PRECONDITION: i32 = 9;
+    pub const INTERNAL: i32 = 13;
+}
+
+impl IpcError {
+    /// Error for a bridge response whose `status_code` is not what the caller expected.
+    ///
+    /// An OK status here means the payload did not match the request.
+    pub fn from_status(code: i32, message: String) -> Self {
+        match code {
+            status::OK => Self::UnexpectedResponse(message),
+            status::INVALID_ARGUMENT => Self::InvalidArgument(message),
+            status::NOT_FOUND => Self::NotFound(message),
+            status::FAILED_PRECONDITION => Self::FailedPrecondition(message),
+            status::INTERNAL => Self::Internal(message),
+            code => Self::Status { code, message },
+        }
+    }
+
+    /// Canonical status code of a failure reported by the bridge, if this is one.
+    pub fn status_code(&self) -> Option<i32> {
+        match self {
+            Self::InvalidArgument(_) => Some(status::INVALID_ARGUMENT),
+            Self::NotFound(_) => Some(status::NOT_FOUND),
+            Self::FailedPrecondition(_) => Some(status::FAILED_PRECONDITION),
+            Self::Internal(_) => Some(status::INTERNAL),
+            Self::Status { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+/// One step of a response read through [`IpcClient::request_streaming`].
+#[derive(Debug)]
+pub enum ResponsePart {
+    /// Raw bytes of a chunked response ("ipc.chunking"), in order.
+    Chunk {
+        index: u32,
+        data: Bytes,
+        received: u64,
+        total_size: u64,
+    },
+    /// The decoded response; always the last part.
+    Complete(pb::IpcResponse),
+}
+
+/// Where the reader delivers a response.
+#[derive(Debug)]
+enum Pending {
+    Unary(oneshot::Sender<Result<pb::IpcResponse, IpcError>>),
+    // Unbounded so a slow consumer never stalls the connection's reader;
+    // the total is capped by `max_response_len`
+    Streaming(mpsc::UnboundedSender<Result<ResponsePart, IpcError>>),
+}
+
+impl Pending {
+    fn complete(self, result: Result<pb::IpcResponse, IpcError>) {
+        match self {
+            Self::Unary(tx) => {
+                let _ = tx.send(result);
+            }
+            Self::Streaming(tx) => {
+                let _ = tx.send(result.map(ResponsePart::Complete));
+            }
+        }
+    }
+}
+
+/// Response delivered incrementally; see [`IpcClient::request_streaming`].
+#[derive(Debug)]
+pub struct ResponseStream {
+    inner: Arc<Inner>,
+    correlation_id: String,
+    rx: mpsc::UnboundedReceiver<Result<ResponsePart, IpcError>>,
+    deadline: time::Instant,
+    cancel: CancellationToken,
+    done: bool,
+}
+
+impl ResponseStream {
+    pub fn correlation_id(&self) -> &str {
+        &self.correlation_id
+    }
+
+    /// Next part of the response; `None` after the final response or an error.
+    pub async fn next(&mut self) -> Option<Result<ResponsePart, IpcError>> {
+        if self.done {
+            return None;
+        }
+        let part = tokio::select! {
+            res = time::timeout_at(self.deadline, self.rx.recv()) => match res {
+                Ok(Some(part)) => part,
+                Ok(None) => Err(IpcError::Closed),
+                Err(_elapsed) => {
+                    self.inner.pending.lock().await.remove(&self.correlation_id);
+                    Err(IpcError::RequestTimeout)
+                }
+            },
+            _ = self.cancel.cancelled() => {
+                self.inner.pending.lock().await.remove(&self.correlation_id);
+                Err(IpcError::Cancelled)
+            }
+        };
+        self.done = !matches!(part, Ok(ResponsePart::Chunk { .. }));
+        Some(part)
+    }
+
+    /// Skip the remaining chunks and wait for the decoded response.
+    pub async fn into_response(mut self) -> Result<pb::IpcResponse, IpcError> {
+        while let Some(part) = self.next().await {
+            if let ResponsePart::Complete(resp) = part? {
+                return Ok(resp);
+            }
+        }
+        Err(IpcError::Closed)
+    }
+}
+
+/// Events of one operation, ending with its `COMPLETE`; see [`IpcClient::subscribe_operation`].
+#[derive(Debug)]
+pub struct OperationSubscription {
+    client: IpcClient,
+    rx: broadcast::Receiver<pb::IpcEvent>,
+    /// `None` until the next START when following whichever operation starts next
+    op_id: Option<String>,
+    /// Ask the bridge whether the operation already finished before waiting for events
+    recheck: bool,
+    done: bool,
+}
+
+impl OperationSubscription {
+    fn new(client: IpcClient, op_id: Option<String>) -> Self {
+        Self {
+            rx: client.events(),
+            recheck: op_id.is_some(),
+            client,
+            op_id,
+            done: false,
+        }
+    }
+
+    /// The followed operation; `None` while waiting for the next START.
+    pub fn op_id(&self) -> Option<&str> {
+        self.op_id.as_deref()
+    }
+
+    /// Next event of the operation; `None` after `COMPLETE`.
+    ///
+    /// Also `None` when `OperationGet` reports the operation already finished and its
+    /// `COMPLETE` is no longer available: asked on the first call, and again whenever
+    /// the receiver lagged and may have dropped events.
+    pub async fn next(&mut self) -> Option<pb::OperationEvent> {
+        while !self.done {
+            if std::mem::take(&mut self.recheck) && self.finished_on_bridge().await {
+                // COMPLETE may still be buffered; otherwise it is gone
+                let complete = self.buffered_complete();
+                self.done = true;
+                return complete;
+            }
+            let event = match self.rx.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    tracing::warn!(op_id = ?self.op_id, "operation subscription lagged {} events", n);
+                    self.recheck = self.op_id.is_some();
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            if let Some(op) = self.accept(event) {
+                return Some(op);
+            }
+        }
+        self.done = true;
+        None
+    }
+
+    /// Wait for the `COMPLETE` event, skipping progress.
+    pub async fn completion(mut self) -> Option<pb::OperationEvent> {
+        let mut last = None;
+        while let Some(op) = self.next().await {
+            last = Some(op);
+        }
+        last.filter(|op| op.kind == pb::operation_event::Kind::Complete as i32)
+    }
+
+    /// `event` if it belongs to the followed operation (or is the START it waits for).
+    fn accept(&mut self, event: pb::IpcEvent) -> Option<pb::OperationEvent> {
+        let Some(pb::ipc_event::Payload::Op(op)) = event.payload else {
+            return None;
+        };
+        match &self.op_id {
+            Some(op_id) if *op_id == op.op_id => {}
+            None if op.kind == pb::operation_event::Kind::Start as i32 => {
+                self.op_id = Some(op.op_id.clone());
+            }
+            _ => return None,
+        }
+        self.done = op.kind == pb::operation_event::Kind::Complete as i32;
+        Some(op)
+    }
+
+    fn buffered_complete(&mut self) -> Option<pb::OperationEvent> {
+        loop {
+            let event = match self.rx.try_recv() {
+                Ok(event) => event,
+                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => return None,
+            };
+            if let Some(op) = self.accept(event)
+                && self.done
+            {
+                return Some(op);
+            }
+        }
+    }
+
+    /// Whether `OperationGet` reports a final state; failures to ask count as "no".
+    async fn finished_on_bridge(&self) -> bool {
+        let Some(op_id) = &self.op_id else {
+            return false;
+        };
+        let timeout = self.client.inner.cfg.call_timeout;
+        match self.client.operation_get(op_id.clone(), timeout).await {
+            Ok(op) => matches!(op.state.as_str(), "completed" | "failed" | "cancelled"),
+            Err(e) => {
+                tracing::debug!(op_id = %op_id, "OperationGet for subscription failed: {}", e);
+                false
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IpcClient {
+    inner: Arc<Inner>,
+    handles: Arc<Handles>,
+    // Requests issued through this handle stop waiting once the token is cancelled
+    cancel: Option<CancellationToken>,
+}
+
+/// Shared by every handle of a client; the last one to go shuts the connection down.
+#[derive(Debug, Default)]
+struct Handles {
+    shutdown: CancellationToken,
+}
+
+impl Drop for Handles {
+    fn drop(&mut self) {
+        self.shutdown.cancel();
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    cfg: IpcConfig,
+    // Token sent in hello; re-resolved from its sources when the bridge rejects it
+    token: Mutex<Option<ResolvedToken>>,
+    corr: AtomicU64,
+    #[allow(dead_code)] // Used in spawn_io but not visible to derive
+    pending: Mutex<HashMap<String, Pending>>,
+    events_tx: broadcast::Sender<pb::IpcEvent>,
+    // Write side: we use an mpsc channel to serialize outgoing frames
+    tx: Mutex<mpsc::Sender<Bytes>>,
+    // Features accepted in the latest handshake; receivers see changes across reconnects
+    negotiated_features: watch::Sender<FeatureSet>,
+    // Round-trip time of the latest answered heartbeat on the current connection
+    last_rtt: Mutex<Option<Duration>>,
+    // Algorithm agreed for "ipc.compression" on the current connection
+    compression: Mutex<Option<Compression>>,
+    // Capture file every frame is copied to ("ipc.record")
+    recorder: Option<Recorder>,
+}
+
+impl IpcClient {
+    pub async fn connect(cfg: IpcConfig) -> Result<Self, IpcError> {
+        let endpoint = cfg.resolved_endpoint();
+        let (writer_tx, writer_rx) = mpsc::channel::<Bytes>(1024);
+        let (events_tx, _events_rx) = broadcast::channel(1024);
+        let token = resolve_token(&cfg)?;
+        let recorder = cfg
+            .record
+            .as_deref()
+            .map(Recorder::open)
+            .transpose()
+            .map_err(|e| IpcError::Io(std::io::Error::other(e.to_string())))?;
+
+        let inner = Arc::new(Inner {
+            cfg,
+            token: Mutex::new(token),
+            corr: AtomicU64::new(rand::random()),
+            pending: Mutex::new(HashMap::new()),
+            events_tx,
+            tx: Mutex::new(writer_tx),
+            negotiated_features: watch::Sender::new(FeatureSet::new()),
+            last_rtt: Mutex::new(None),
+            compression: Mutex::new(None),
+            recorder,
+        });
+
+        // Spawn reconnection supervisor task
+        let handles = Arc::new(Handles::default());
+        Self::spawn_supervisor(inner.clone(), endpoint, writer_rx, handles.shutdown.clone())
+            .await?;
+        Ok(Self {
+            inner,
+            handles,
+            cancel: None,
+        })
+    }
+
+    /// Handle sharing this connection whose requests are abandoned when `ct` is cancelled.
+    ///
+    /// A cancelled request is removed from the pending table and fails with
+    /// [`IpcError::Cancelled`]; the bridge is not told (see `operation_cancel`).
+    pub fn with_cancellation(&self, ct: CancellationToken) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            handles: self.handles.clone(),
+            cancel: Some(ct),
+        }
+    }
+
+    /// Where this client connects (`ipc.endpoint`, resolved).
+    pub fn endpoint(&self) -> Endpoint {
+        self.inner.cfg.resolved_endpoint()
+    }
+
+    pub fn events(&self) -> broadcast::Receiver<pb::IpcEvent> {
+        self.inner.events_tx.subscribe()
+    }
+
+    /// Follow the events of operation `op_id`.
+    ///
+    /// Only events received after this call are seen. Subscribe before sending a request
+    /// that carries `op_id` (e.g. `BuildPlayerRequest::op_id`) to see all of them; an
+    /// operation that already finished ends the subscription right away.
+    pub fn subscribe_operation(&self, op_id: impl Into<String>) -> OperationSubscription {
+        OperationSubscription::new(self.without_cancellation(), Some(op_id.into()))
+    }
+
+    /// Follow the next operation that starts, e.g. the one a request sent after this call
+    /// starts when the bridge picks its op_id.
+    pub fn subscribe_next_operation(&self) -> OperationSubscription {
+        OperationSubscription::new(self.without_cancellation(), None)
+    }
+
+    fn without_cancellation(&self) -> Self {
+        Self {
+            cancel: None,
+            ..self.clone()
+        }
+    }
+
+    fn next_cid(&self) -> String {
+        format!("{:016x}", self.inner.corr.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub async fn request(
+        &self,
+        req: pb::IpcRequest,
+        timeout: Duration,
+    ) -> Result<pb::IpcResponse, IpcError> {
+        let cancel = self.cancel.clone().unwrap_or_default();
+        if cancel.is_cancelled() {
+            return Err(IpcError::Cancelled);
+        }
+
+        let cid = self.next_cid();
+        let (tx, rx) = oneshot::channel();
+        self.send_request(&cid, req, Pending::Unary(tx)).await?;
+
+        tokio::select! {
+            res = time::timeout(timeout, rx) => match res {
+                Ok(Ok(result)) => result,
+                Ok(Err(_canceled)) => Err(IpcError::Closed),
+                Err(_elapsed) => {
+                    self.inner.pending.lock().await.remove(&cid);
+                    Err(IpcError::RequestTimeout)
+                }
+            },
+            _ = cancel.cancelled() => {
+                self.inner.pending.lock().await.remove(&cid);
+                Err(IpcError::Cancelled)
+            }
+        }
+    }
+
+    /// Send a request whose response is handed over chunk by chunk as it arrives.
+    ///
+    /// Useful for payloads that span many frames (screenshots, build reports, scene
+    /// dumps). Responses that are not chunked yield just [`ResponsePart::Complete`].
+    /// `timeout` bounds the whole response, not each chunk.
+    pub async fn request_streaming(
+        &self,
+        req: pb::IpcRequest,
+        timeout: Duration,
+    ) -> Result<ResponseStream, IpcError> {
+        let cancel = self.cancel.clone().unwrap_or_default();
+        if cancel.is_cancelled() {
+            return Err(IpcError::Cancelled);
+        }
+
+        let cid = self.next_cid();
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.send_request(&cid, req, Pending::Streaming(tx)).await?;
+        Ok(ResponseStream {
+            inner: self.inner.clone(),
+            correlation_id: cid,
+            rx,
+            deadline: time::Instant::now() + timeout,
+            cancel,
+            done: false,
+        })
+    }
+
+    /// Encode `req`, register where its response goes and queue it for the writer.
+    async fn send_request(
+        &self,
+        cid: &str,
+        req: pb::IpcRequest,
+        pending: Pending,
+    ) -> Result<(), IpcError> {
+        let env = pb::IpcEnvelope {
+            correlation_id: cid.to_string(),
+            kind: Some(pb::ipc_envelope::Kind::Request(req)),
+        };
+        let compression = *self.inner.compression.lock().await;
+        let bytes = match (compression, self.inner.cfg.compression_threshold) {
+            (Some(c), Some(threshold)) => codec::encode_envelope_compressed(&env, c, threshold)?,
+            _ => codec::encode_envelope(&env)?,
+        };
+        // The bridge would never see an oversized request; fail it here instead
+        if bytes.len() > self.inner.cfg.max_frame_len {
+            return Err(IpcError::FrameTooLarge {
+                correlation_id: cid.to_string(),
+                size: bytes.len(),
+                limit: self.inner.cfg.max_frame_len,
+            });
+        }
+
+        self.inner
+            .pending
+            .lock()
+            .await
+            .insert(cid.to_string(), pending);
+        // Clone sender under lock so we don't hold the mutex across await
+        let tx_clone = { self.inner.tx.lock().await.clone() };
+        if tx_clone.send(bytes).await.is_err() {
+            self.inner.pending.lock().await.remove(cid);
+            return Err(IpcError::Closed);
+        }
+        Ok(())
+    }
+
+    /// Send a typed request with its default timeout (see [`IpcCall`]).
+    pub async fn call<R: IpcCall>(&self, req: R) -> Result<R::Response, IpcError> {
+        self.call_with_timeout(req, R::DEFAULT_TIMEOUT).await
+    }
+
+    /// Send a typed request, check its feature flag and unwrap the matching response.
+    pub async fn call_with_timeout<R: IpcCall>(
+        &self,
+        req: R,
+        timeout: Duration,
+    ) -> Result<R::Response, IpcError> {
+        if let Some(feature) = R::FEATURE
+            && !self.has_feature(feature.clone()).await
+        {
+            return Err(IpcError::UnsupportedFeature(format!(
+                "{} feature not negotiated",
+                feature
+            )));
+        }
+        let resp = self.request(req.into_request(), timeout).await?;
+        R::from_response(resp.payload)
+    }
+
+    pub async fn health(&self, timeout: Duration) -> Result<pb::HealthResponse, IpcError> {
+        self.call_with_timeout(pb::HealthRequest {}, timeout).await
+    }
+
+    pub async fn has_feature(&self, feature: FeatureFlag) -> bool {
+        self.inner.negotiated_features.borrow().contains(&feature)
+    }
+
+    pub async fn get_negotiated_features(&self) -> FeatureSet {
+        self.inner.negotiated_features.borrow().clone()
+    }
+
+    /// Watch the negotiated feature set.
+    ///
+    /// The receiver is only notified when a handshake (e.g. after a reconnect)
+    /// yields a different set than before.
+    pub fn watch_negotiated_features(&self) -> watch::Receiver<FeatureSet> {
+        self.inner.negotiated_features.subscribe()
+    }
+
+    /// Heartbeat round-trip time measured on the current connection.
+    ///
+    /// `None` until the first pong arrives, or when "ipc.heartbeat" was not negotiated.
+    pub async fn last_rtt(&self) -> Option<Duration> {
+        *self.inner.last_rtt.lock().await
+    }
+
+    pub async fn execute_menu_item(
+        &self,
+        path: String,
+        timeout: Duration,
+    ) -> Result<pb::ExecuteMenuItemResponse, IpcError> {
+        self.call_with_timeout(pb::ExecuteMenuItemRequest { path }, timeout)
+            .await
+    }
+
+    pub async fn focus_window(
+        &self,
+        window_type: String,
+        timeout: Duration,
+    ) -> Result<pb::FocusWindowResponse, IpcError> {
+        self.call_with_timeout(pb::FocusWindowRequest { window_type }, timeout)
+            .await
+    }
+
+    pub async fn assets_import(
+        &self,
+        paths: Vec<String>,
+        recursive: bool,
+        auto_refresh: bool,
+        timeout: Duration,
+    ) -> Result<pb::ImportAssetResponse, IpcError> {
+        let req = pb::ImportAssetRequest {
+            paths,
+            recursive,
+            auto_refresh,
+            op_id: String::new(),
+        };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn assets_move(
+        &self,
+        from_path: String,
+        to_path: String,
+        timeout: Duration,
+    ) -> Result<pb::MoveAssetResponse, IpcError> {
+        let req = pb::MoveAssetRequest { from_path, to_path };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn assets_delete(
+        &self,
+        paths: Vec<String>,
+        soft: bool,
+        timeout: Duration,
+    ) -> Result<pb::DeleteAssetResponse, IpcError> {
+        self.call_with_timeout(pb::DeleteAssetRequest { paths, soft }, timeout)
+            .await
+    }
+
+    pub async fn assets_refresh(
+        &self,
+        force: bool,
+        timeout: Duration,
+    ) -> Result<pb::RefreshResponse, IpcError> {
+        self.call_with_timeout(pb::RefreshRequest { force }, timeout)
+            .await
+    }
+
+    pub async fn assets_guid_to_path(
+        &self,
+        guids: Vec<String>,
+        timeout: Duration,
+    ) -> Result<pb::GuidToPathResponse, IpcError> {
+        self.call_with_timeout(pb::GuidToPathRequest { guids }, timeout)
+            .await
+    }
+
+    pub async fn assets_path_to_guid(
+        &self,
+        paths: Vec<String>,
+        timeout: Duration,
+    ) -> Result<pb::PathToGuidResponse, IpcError> {
+        self.call_with_timeout(pb::PathToGuidRequest { paths }, timeout)
+            .await
+    }
+
+    pub async fn component_add(
+        &self,
+        game_object: String,
+        component: String,
+        timeout: Duration,
+    ) -> Result<pb::AddComponentResponse, IpcError> {
+        let req = pb::AddComponentRequest {
+            game_object,
+            component,
+        };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn component_get(
+        &self,
+        game_object: String,
+        timeout: Duration,
+    ) -> Result<pb::GetComponentsResponse, IpcError> {
+        self.call_with_timeout(pb::GetComponentsRequest { game_object }, timeout)
+            .await
+    }
+
+    pub async fn component_remove(
+        &self,
+        game_object: String,
+        component: String,
+        timeout: Duration,
+    ) -> Result<pb::RemoveComponentResponse, IpcError> {
+        let req = pb::RemoveComponentRequest {
+            game_object,
+            component,
+        };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn scenes_open(
+        &self,
+        path: String,
+        additive: bool,
+        timeout: Duration,
+    ) -> Result<pb::OpenSceneResponse, IpcError> {
+        self.call_with_timeout(pb::OpenSceneRequest { path, additive }, timeout)
+            .await
+    }
+
+    pub async fn scenes_save(
+        &self,
+        path: String,
+        timeout: Duration,
+    ) -> Result<pb::SaveSceneResponse, IpcError> {
+        self.call_with_timeout(pb::SaveSceneRequest { path }, timeout)
+            .await
+    }
+
+    pub async fn scenes_get_open_scenes(
+        &self,
+        timeout: Duration,
+    ) -> Result<pb::GetOpenScenesResponse, IpcError> {
+        self.call_with_timeout(pb::GetOpenScenesRequest {}, timeout)
+            .await
+    }
+
+    pub async fn scenes_set_active_scene(
+        &self,
+        path: String,
+        timeout: Duration,
+    ) -> Result<pb::SetActiveSceneResponse, IpcError> {
+        self.call_with_timeout(pb::SetActiveSceneRequest { path }, timeout)
+            .await
+    }
+
+    pub async fn prefab_create(
+        &self,
+        game_object_path: String,
+        prefab_path: String,
+        timeout: Duration,
+    ) -> Result<pb::CreatePrefabResponse, IpcError> {
+        let req = pb::CreatePrefabRequest {
+            game_object_path,
+            prefab_path,
+        };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn prefab_update(
+        &self,
+        game_object_path: String,
+        prefab_path: String,
+        timeout: Duration,
+    ) -> Result<pb::UpdatePrefabResponse, IpcError> {
+        let req = pb::UpdatePrefabRequest {
+            game_object_path,
+            prefab_path,
+        };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn prefab_apply_overrides(
+        &self,
+        instance_path: String,
+        timeout: Duration,
+    ) -> Result<pb::ApplyPrefabOverridesResponse, IpcError> {
+        let req = pb::ApplyPrefabOverridesRequest { instance_path };
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn build_player(
+        &self,
+        req: pb::BuildPlayerRequest,
+        timeout: Duration,
+    ) -> Result<pb::BuildPlayerResponse, IpcError> {
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn build_bundles(
+        &self,
+        req: pb::BuildAssetBundlesRequest,
+        timeout: Duration,
+    ) -> Result<pb::BuildAssetBundlesResponse, IpcError> {
+        self.call_with_timeout(req, timeout).await
+    }
+
+    pub async fn operation_get(
+        &self,
+        id: String,
+        timeout: Duration,
+    ) -> Result<pb::OperationGetResponse, IpcError> {
+        self.call_with_timeout(pb::OperationGetRequest { id }, timeout)
+            .await
+    }
+
+    pub async fn operation_cancel(
+        &self,
+        id: String,
+        timeout: Duration,
+    ) -> Result<pb::OperationCancelResponse, IpcError> {
+        self.call_with_timeout(pb::OperationCancelRequest { id }, timeout)
+            .await
+    }
+
+    /// Connect and keep reconnecting until `shutdown` (all handles were dropped).
+    async fn spawn_supervisor(
+        inner: Arc<Inner>,
+        endpoint: Endpoint,
+        writer_rx: mpsc::Receiver<Bytes>,
+        shutdown: CancellationToken,
+    ) -> Result<(), IpcError> {
+        // Initial connection attempt
+        let mut connection = Self::spawn_io(inner.clone(), endpoint.clone(), writer_rx).await?;
+
+        // Spawn supervisor task for reconnection
+        let inner_clone = inner.clone();
+        tokio::spawn(async move {
+            let initial_backoff_ms = inner_clone.cfg.reconnect_initial_backoff.as_millis() as u64;
+            let max_backoff_ms = inner_clone.cfg.reconnect_max_backoff.as_millis() as u64;
+            let mut backoff_ms = initial_backoff_ms;
+
+            loop {
+                // Reader/writer cancel this token as soon as the connection goes away
+                tokio::select! {
+                    _ = connection.cancelled() => {}
+                    _ = shutdown.cancelled() => {
+                        // Stops reader, writer and heartbeat, which release `inner`
+                        connection.cancel();
+                        tracing::debug!("IPC client dropped, connection closed");
+                        return;
+                    }
+                }
+                tracing::warn!("IPC connection lost, attempting reconnect...");
+                Self::fail_pending(&inner_clone).await;
+
+                // Reconnection loop: first attempt immediately, then exponential backoff
+                loop {
+                    // Create new writer channel for reconnection
+                    let (new_writer_tx, new_writer_rx) = mpsc::channel::<Bytes>(1024);
+
+                    let connect =
+                        Self::spawn_io(inner_clone.clone(), endpoint.clone(), new_writer_rx);
+                    let attempt = tokio::select! {
+                        _ = shutdown.cancelled() => return,
+                        attempt = connect => attempt,
+                    };
+                    match attempt {
+                        Ok(token) => {
+                            tracing::info!("IPC reconnection successful");
+                            // Reset backoff on successful connection
+                            backoff_ms = initial_backoff_ms;
+
+                            // Update the writer channel in inner so future sends go to the new connection
+                            {
+                                let mut guard = inner_clone.tx.lock().await;
+                                *guard = new_writer_tx;
+                            }
+                            connection = token;
+                            break;
+                        }
+                        Err(e) => {
+                            tracing::warn!(
+                                "IPC reconnection failed: {}, retrying in {}ms",
+                                e,
+                                backoff_ms
+                            );
+                            if matches!(e, IpcError::Authentication(_)) {
+                                Self::reload_token(&inner_clone).await;
+                            }
+                            tokio::select! {
+                                _ = shutdown.cancelled() => return,
+                                _ = tokio::time::sleep(Duration::from_millis(backoff_ms)) => {}
+                            }
+                            // Exponential backoff with jitter
+                            backoff_ms = std::cmp::min(backoff_ms * 2, max_backoff_ms);
+                            let jitter = rand::random::<u64>() % (backoff_ms / 4 + 1);
+                            backoff_ms += jitter;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Re-read the token after the bridge rejected it (e.g. it was regenerated in Unity).
+    async fn reload_token(inner: &Inner) {
+        let fresh = match token::resolve(&inner.cfg) {
+            Ok(fresh) => fresh,
+            Err(e) => {
+                tracing::warn!("Could not reload IPC token: {}", e);
+                return;
+            }
+        };
+        let mut current = inner.token.lock().await;
+        if *current != fresh {
+            match &fresh {
+                Some(t) => tracing::info!("Reloaded IPC token from {}", t.source),
+                None => tracing::warn!("IPC token no longer available from any source"),
+            }
+        }
+        *current = fresh;
+    }
+
+    /// Fail every in-flight request with [`IpcError::Closed`].
+    ///
+    /// Dropping the response senders wakes the waiting `request` calls immediately.
+    async fn fail_pending(inner: &Inner) {
+        let mut pending = inner.pending.lock().await;
+        if !pending.is_empty() {
+            tracing::warn!(
+                "Failing {} in-flight IPC request(s): connection closed",
+                pending.len()
+            );
+        }
+        pending.clear();
+    }
+
+    /// Fail the request an oversized frame was answering; the connection stays up.
+    async fn fail_oversized(inner: &Inner, frame: OversizedFrame) {
+        let limit = inner.cfg.max_frame_len;
+        let Some(correlation_id) = frame.correlation_id else {
+            tracing::warn!(
+                "Dropped IPC frame of {} bytes (limit {} bytes) without correlation id",
+                frame.len,
+                limit
+            );
+            return;
+        };
+        tracing::warn!(
+            "Dropped IPC frame of {} bytes (limit {} bytes) for correlation_id={}",
+            frame.len,
+            limit,
+            correlation_id
+        );
+        if let Some(pending) = inner.pending.lock().await.remove(&correlation_id) {
+            pending.complete(Err(IpcError::FrameTooLarge {
+                correlation_id,
+                size: frame.len,
+                limit,
+            }));
+        }
+    }
+
+    /// Feed one chunk of a chunked response to its caller.
+    async fn on_response_chunk(
+        inner: &Inner,
+        chunks: &mut Reassembler,
+        correlation_id: String,
+        chunk: pb::IpcResponseChunk,
+    ) {
+        let mut pending = inner.pending.lock().await;
+        let Some(entry) = pending.remove(&correlation_id) else {
+            // Timed out, cancelled or already failed
+            chunks.discard(&correlation_id);
+            return;
+        };
+        let progress = chunks.push(&correlation_id, &chunk);
+        let part = |received| ResponsePart::Chunk {
+            index: chunk.index,
+            data: Bytes::from(chunk.data),
+            received,
+            total_size: chunk.total_size,
+        };
+        let result = match progress {
+            Ok(Progress::Partial { received, .. }) => {
+                if let Pending::Streaming(tx) = &entry
+                    && tx.send(Ok(part(received))).is_err()
+                {
+                    // Stream dropped by the caller
+                    chunks.discard(&correlation_id);
+                    return;
+                }
+                pending.insert(correlation_id, entry);
+                return;
+            }
+            Ok(Progress::Complete(resp)) => {
+                if let Pending::Streaming(tx) = &entry {
+                    let _ = tx.send(Ok(part(chunk.total_size)));
+                }
+                Ok(resp)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed chunked IPC response (correlation_id={}): {}",
+                    correlation_id,
+                    e
+                );
+                Err(IpcError::Chunk(e))
+            }
+        };
+        entry.complete(result);
+    }
+
+    async fn spawn_io(
+        inner: Arc<Inner>,
+        endpoint: Endpoint,
+        mut writer_rx: mpsc::Receiver<Bytes>,
+    ) -> Result<CancellationToken, IpcError> {
+        // 1) connect
+        let io = connect_endpoint(&endpoint, &inner.cfg).await?;
+        let mut framed = Framed::new(io, FrameCodec::new(inner.cfg.max_frame_len));
+        let recording = inner.recorder.as_ref().map(|r| Arc::new(r.connection()));
+
+        // 2) T01 handshake
+        let mut desired_features = FeatureSet::supported_by_client();
+        let mut meta = create_default_meta();
+        // Lets the bridge size its chunks ("ipc.chunking") below our frame limit
+        meta.insert(
+            "max_frame_bytes".to_string(),
+            inner.cfg.max_frame_len.to_string(),
+        );
+        if inner.cfg.compression_threshold.is_some() {
+            // Preferred first; the bridge answers with its pick in welcome meta
+            meta.insert(
+                "compression".to_string(),
+                [Compression::Zstd, Compression::Deflate]
+                    .map(|c| c.as_str())
+                    .join(","),
+            );
+        } else {
+            desired_features.remove(&FeatureFlag::IpcCompression);
+        }
+        let hello = pb::IpcHello {
+            token: inner
+                .token
+                .lock()
+                .await
+                .as_ref()
+                .map(|t| t.value.clone())
+                .unwrap_or_default(),
+            ipc_version: "1.0".to_string(),
+            features: desired_features.to_strings(),
+            schema_hash: codec::schema_hash(),
+            client_name: "unity-mcp-rs".to_string(),
+            client_version: env!("CARGO_PKG_VERSION").to_string(),
+            meta,
+        };
+        let control = pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Hello(hello)),
+        };
+        let hello_bytes = codec::encode_control(&control)?;
+        use futures::{SinkExt, StreamExt};
+        if let Some(recording) = &recording {
+            recording.frame(Direction::ToBridge, &hello_bytes);
+        }
+        framed.send(hello_bytes).await.map_err(IpcError::Io)?;
+
+        // 3) Read welcome/reject response within ipc.handshake_timeout_ms
+        let welcome = time::timeout(inner.cfg.handshake_timeout, async {
+            match framed.next().await {
+                Some(Ok(Frame::Data(bytes))) => {
+                    let bytes = bytes.freeze();
+                    if let Some(recording) = &recording {
+                        recording.frame(Direction::ToClient, &bytes);
+                    }
+                    let control = codec::decode_control(bytes)?;
+                    Self::handle_handshake_response(control).await
+                }
+                Some(Ok(Frame::Oversized(o))) => Err(IpcError::Handshake(format!(
+                    "welcome frame of {} bytes exceeds limit",
+                    o.len
+                ))),
+                Some(Err(e)) => Err(IpcError::Io(e)),
+                None => Err(IpcError::Handshake("no welcome response".into())),
+            }
+        })
+        .await
+        .map_err(|_| IpcError::ConnectTimeout)??;
+
+        // Process negotiated features
+        let negotiated = FeatureSet::from_strings(&welcome.accepted_features);
+        inner.negotiated_features.send_if_modified(|features| {
+            let changed = *features != negotiated;
+            *features = negotiated.clone();
+            changed
+        });
+        *inner.last_rtt.lock().await = None;
+        // A bridge that accepts the flag without naming an algorithm speaks deflate
+        let compression = negotiated.contains(&FeatureFlag::IpcCompression).then(|| {
+            welcome
+                .meta
+                .get("compression")
+                .and_then(|name| Compression::from_name(name))
+                .unwrap_or(Compression::Deflate)
+        });
+        *inner.compression.lock().await = compression;
+
+        // 4) Log successful handshake
+        tracing::info!(
+            "T01 Handshake OK: version={}, features={:?}, session={}, server={} {}",
+            welcome.ipc_version,
+            negotiated.to_strings(),
+            welcome.session_id,
+            welcome.server_name,
+            welcome.server_version
+        );
+
+        // 5) spawn writer and reader; either one cancels `closed` when the connection ends
+        let closed = CancellationToken::new();
+        // Heartbeat frames bypass the request channel, which is swapped on reconnect
+        let (control_tx, mut control_rx) = mpsc::channel::<Bytes>(16);
+        let (writer, reader) = framed.split();
+        let writer_closed = closed.clone();
+        let writer_recording = recording.clone();
+        tokio::spawn(async move {
+            let mut writer = writer;
+            loop {
+                let bytes = tokio::select! {
+                    _ = writer_closed.cancelled() => break,
+                    Some(bytes) = control_rx.recv() => bytes,
+                    next = writer_rx.recv() => match next {
+                        Some(bytes) => bytes,
+                        None => break,
+                    },
+                };
+                if let Some(recording) = &writer_recording {
+                    recording.frame(Direction::ToBridge, &bytes);
+                }
+                if let Err(e) = writer.send(bytes).await {
+                    tracing::debug!("IPC writer stopped: {}", e);
+                    break;
+                }
+            }
+            // Dropping writer_rx makes later sends fail fast with IpcError::Closed
+            writer_closed.cancel();
+        });
+
+        // 6) spawn heartbeat if both sides speak it
+        let heartbeat = Arc::new(Heartbeat::new());
+        let interval = inner.cfg.heartbeat_interval;
+        if negotiated.contains(&FeatureFlag::IpcHeartbeat) && !interval.is_zero() {
+            tokio::spawn(heartbeat::run(
+                heartbeat.clone(),
+                interval,
+                inner.cfg.heartbeat_miss_threshold,
+                control_tx.clone(),
+                closed.clone(),
+            ));
+        }
+
+        // 7) spawn reader (responses/events/control frames)
+        let reader_closed = closed.clone();
+        tokio::spawn(async move {
+            let mut reader = reader;
+            let mut chunks = Reassembler::new(inner.cfg.max_response_len);
+            loop {
+                let frame = tokio::select! {
+                    _ = reader_closed.cancelled() => break,
+                    frame = reader.next() => frame,
+                };
+                let bytes = match frame {
+                    Some(Ok(Frame::Data(bytes))) => bytes,
+                    Some(Ok(Frame::Oversized(o))) => {
+                        Self::fail_oversized(&inner, o).await;
+                        continue;
+                    }
+                    _ => break,
+                };
+                let bytes = bytes.freeze();
+                if let Some(recording) = &recording {
+                    recording.frame(Direction::ToClient, &bytes);
+                }
+                let len = bytes.len();
+                let env = match codec::decode_envelope(bytes) {
+                    Ok(env) => env,
+                    Err(e) => {
+                        tracing::warn!("Dropped undecodable IPC frame ({} bytes): {}", len, e);
+                        continue;
+                    }
+                };
+                let correlation_id = env.correlation_id.clone();
+                let env = match codec::inflate_envelope(env, inner.cfg.max_frame_len) {
+                    Ok(env) => env,
+                    Err(codec::CodecError::TooLarge { size, .. }) => {
+                        let frame = OversizedFrame {
+                            len: size,
+                            correlation_id: Some(correlation_id).filter(|id| !id.is_empty()),
+                        };
+                        Self::fail_oversized(&inner, frame).await;
+                        continue;
+                    }
+                    Err(e) => {
+                        tracing::warn!(
+                            "Dropped undecodable IPC frame (correlation_id={}): {}",
+                            correlation_id,
+                            e
+                        );
+                        continue;
+                    }
+                };
+                match env.kind {
+                    Some(pb::ipc_envelope::Kind::Response(resp)) => {
+                        let mut pending = inner.pending.lock().await;
+                        if let Some(entry) = pending.remove(&resp.correlation_id) {
+                            entry.complete(Ok(resp));
+                        }
+                    }
+                    Some(pb::ipc_envelope::Kind::ResponseChunk(chunk)) => {
+                        Self::on_response_chunk(&inner, &mut chunks, correlation_id, chunk).await;
+                    }
+                    Some(pb::ipc_envelope::Kind::Event(ev)) => {
+                        let _ = inner.events_tx.send(ev);
+                    }
+                    Some(pb::ipc_envelope::Kind::Control(pb::IpcControl { kind: Some(kind) })) => {
+                        match kind {
+                            pb::ipc_control::Kind::Pong(pong) => {
+                                if let Some(rtt) = heartbeat.on_pong(&pong) {
+                                    tracing::trace!("IPC heartbeat rtt={:?}", rtt);
+                                    *inner.last_rtt.lock().await = Some(rtt);
+                                }
+                            }
+                            pb::ipc_control::Kind::Ping(ping) => {
+                                if let Some(bytes) =
+                                    heartbeat::encode_control_frame(heartbeat::pong_for(&ping))
+                                {
+                                    let _ = control_tx.try_send(bytes);
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            reader_closed.cancel();
+        });
+
+        Ok(closed)
+    }
+
+    async fn handle_handshake_response(
+        control: pb::IpcControl,
+    ) -> Result<pb::IpcWelcome, IpcError> {
+        match control.kind {
+            Some(pb::ipc_control::Kind::Welcome(w)) => Ok(w),
+            Some(pb::ipc_control::Kind::Reject(r)) => {
+                use pb::ipc_reject::Code;
+
+                match r.code() {
+                    Code::Unauthenticated => Err(IpcError::Authentication(r.message)),
+                    Code::OutOfRange => Err(IpcError::VersionIncompatible(r.message)),
+                    Code::FailedPrecondition => {
+                        if r.message.contains("schema") {
+                            Err(IpcError::SchemaMismatch(r.message))
+                        } else {
+                            Err(IpcError::FailedPrecondition(r.message))
+                        }
+                    }
+                    Code::Unavailable => Err(IpcError::ServerUnavailable(r.message)),
+                    Code::PermissionDenied => Err(IpcError::PermissionDenied(r.message)),
+                    Code::Internal => {
+                        Err(IpcError::Handshake(format!("server error: {}", r.message)))
+                    }
+                }
+            }
+            _ => Err(IpcError::Handshake("unexpected control response".into())),
+        }
+    }
+
+    /// One-shot handshake + Health on `cfg`'s endpoint, then disconnect.
+    ///
+    /// Unlike [`IpcClient::connect`] nothing is kept alive or reconnected, which is
+    /// what discovery needs to check a candidate. No optional features are offered,
+    /// so the bridge answers with a plain (unchunked, uncompressed) response.
+    pub async fn probe(cfg: &IpcConfig) -> Result<(pb::IpcWelcome, pb::HealthResponse), IpcError> {
+        use futures::{SinkExt, StreamExt};
+
+        let endpoint = cfg.resolved_endpoint();
+        let io = connect_endpoint(&endpoint, cfg).await?;
+        let mut framed = Framed::new(io, FrameCodec::new(cfg.max_frame_len));
+
+        let hello = pb::IpcHello {
+            token: resolve_token(cfg)?.map(|t| t.value).unwrap_or_default(),
+            ipc_version: "1.0".to_string(),
+            features: Vec::new(),
+            schema_hash: codec::schema_hash(),
+            client_name: "unity-mcp-rs".to_string(),
+            client_version: env!("CARGO_PKG_VERSION").to_string(),
+            meta: create_default_meta(),
+        };
+        let control = pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Hello(hello)),
+        };
+        framed
+            .send(codec::encode_control(&control)?)
+            .await
+            .map_err(IpcError::Io)?;
+
+        let exchange = async {
+            let welcome = match framed.next().await {
+                Some(Ok(Frame::Data(bytes))) => {
+                    Self::handle_handshake_response(codec::decode_control(bytes.freeze())?).await?
+                }
+                Some(Ok(Frame::Oversized(o))) => {
+                    return Err(IpcError::Handshake(format!(
+                        "welcome frame of {} bytes exceeds limit",
+                        o.len
+                    )));
+                }
+                Some(Err(e)) => return Err(IpcError::Io(e)),
+                None => return Err(IpcError::Handshake("no welcome response".into())),
+            };
+
+            let cid = uuid::Uuid::new_v4().to_string();
+            let env = pb::IpcEnvelope {
+                correlation_id: cid.clone(),
+                kind: Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::Health(pb::HealthRequest {})),
+                })),
+            };
+            framed
+                .send(codec::encode_envelope(&env)?)
+                .await
+                .map_err(IpcError::Io)?;
+
+            // Events may be pushed before the response; skip anything else
+            while let Some(frame) = framed.next().await {
+                let Frame::Data(bytes) = frame.map_err(IpcError::Io)? else {
+                    continue;
+                };
+                let env = codec::decode_envelope(bytes.freeze())?;
+                let Some(pb::ipc_envelope::Kind::Response(resp)) = env.kind else {
+                    continue;
+                };
+                if env.correlation_id != cid {
+                    continue;
+                }
+                return match resp.payload {
+                    Some(pb::ipc_response::Payload::Health(health)) => Ok((welcome, health)),
+                    _ => Err(IpcError::UnexpectedResponse("health probe".into())),
+                };
+            }
+            Err(IpcError::Closed)
+        };
+
+        time::timeout(cfg.total_handshake_timeout + cfg.call_timeout, exchange)
+            .await
+            .map_err(|_| IpcError::RequestTimeout)?
+    }
+
+    pub async fn connect_with_retry(cfg: IpcConfig) -> Result<Self, IpcError> {
+        let mut backoff_ms = cfg.reconnect_initial_backoff.as_millis() as u64;
+        let max_backoff_ms = cfg.reconnect_max_backoff.as_millis() as u64;
+        let max_attempts = cfg.max_reconnect_attempts.unwrap_or(10);
+
+        for attempt in 1..=max_attempts {
+            match Self::connect(cfg.clone()).await {
+                Ok(client) => return Ok(client),
+                Err(e) => {
+                    let should_retry = match &e {
+                        IpcError::ServerUnavailable(_) => true,
+                        IpcError::ConnectTimeout => true,
+                        IpcError::Io(_) => true,
+                        IpcError::Authentication(_) => false, // Don't retry auth errors
+                        IpcError::VersionIncompatible(_) => false, // Don't retry version errors
+                        IpcError::SchemaMismatch(_) => false, // Don't retry schema errors
+                        IpcError::UnsupportedFeature(_) => false, // Don't retry feature errors
+                        IpcError::PermissionDenied(_) => false, // Don't retry permission errors
+                        IpcError::FailedPrecondition(_) => false, // Don't retry precondition errors
+                        _ => false,
+                    };
+
+                    if !should_retry || attempt == max_attempts {
+                        return Err(e);
+                    }
+
+                    tracing::warn!(
+                        "Connection attempt {} failed: {}. Retrying in {}ms",
+                        attempt,
+                        e,
+                        backoff_ms
+                    );
+
+                    tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+
+                    // Exponential backoff with jitter
+                    let jitter = rand::random::<u64>() % (backoff_ms / 4 + 1);
+                    backoff_ms = std::cmp::min(backoff_ms * 2, max_backoff_ms) + jitter;
+                }
+            }
+        }
+
+        unreachable!()
+    }
+}
+
+/// Token from `cfg`'s sources; an unreadable source fails like a rejected token.
+fn resolve_token(cfg: &IpcConfig) -> Result<Option<ResolvedToken>, IpcError> {
+    let resolved = token::resolve(cfg).map_err(|e| IpcError::Authentication(e.to_string()))?;
+    if let Some(t) = &resolved {
+        tracing::debug!("Using IPC token from {}", t.source);
+    }
+    Ok(resolved)
+}
+
+fn create_default_meta() -> std::collections::HashMap<String, String> {
+    let mut meta = std::collections::HashMap::new();
+    meta.insert("os".to_string(), std::env::consts::OS.to_string());
+    meta.insert("arch".to_string(), std::env::consts::ARCH.to_string());
+    meta
+}
+
+// normalize_project_root: obsolete (field removed from T01)
+
+async fn connect_endpoint(
+    endpoint: &Endpoint,
+    cfg: &IpcConfig,
+) -> Result<Box<dyn IpcStream>, IpcError> {
+    use tokio::time::timeout as tokio_timeout;
+    let timeout = cfg.connect_timeout;
+    match endpoint {
+        #[cfg(unix)]
+        Endpoint::Unix(path) => {
+            let fut = net::UnixStream::connect(path);
+            let stream = tokio_timeout(timeout, fut)
+                .await
+                .map_err(|_| IpcError::ConnectTimeout)??;
+            Ok(Box::new(stream))
+        }
+        #[cfg(windows)]
+        Endpoint::Pipe(name) => {
+            use tokio::net::windows::named_pipe::ClientOptions;
+            let stream = ClientOptions::new().open(name).map_err(IpcError::Io)?;
+            Ok(Box::new(stream))
+        }
+        Endpoint::Tcp(addr) => {
+            let fut = net::TcpStream::connect(addr);
+            let stream = tokio_timeout(timeout, fut)
+                .await
+                .map_err(|_| IpcError::ConnectTimeout)??;
+            Ok(Box::new(stream))
+        }
+        Endpoint::Tls(addr) => {
+            // connect_timeout covers the TLS handshake as well
+            let fut = async {
+                let tcp = net::TcpStream::connect(addr).await?;
+                tls::connect(addr, &cfg.tls, tcp).await
+            };
+            let stream = tokio_timeout(timeout, fut)
+                .await
+                .map_err(|_| IpcError::ConnectTimeout)??;
+            Ok(Box::new(stream))
+        }
+        Endpoint::WebSocket(url) => {
+            // Length-prefixed frames travel as binary messages; see ipc::ws
+            let fut = async {
+                let target = ws::Target::parse(url)?;
+                let tcp = net::TcpStream::connect(&target.addr).await?;
+                let stream: Box<dyn IpcStream> = if target.secure {
+                    let tls = tls::connect(&target.addr, &cfg.tls, tcp).await?;
+                    Box::new(ws::handshake(url, tls).await?)
+                } else {
+                    Box::new(ws::handshake(url, tcp).await?)
+                };
+                Ok::<_, IpcError>(stream)
+            };
+            tokio_timeout(timeout, fut)
+                .await
+                .map_err(|_| IpcError::ConnectTimeout)?
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path::IpcConfig;
+    use futures::{SinkExt, StreamExt};
+
+    fn test_inner(
+        corr: u64,
+        events_tx: broadcast::Sender<pb::IpcEvent>,
+        tx: mpsc::Sender<Bytes>,
+    ) -> Arc<Inner> {
+        Arc::new(Inner {
+            cfg: IpcConfig::default(),
+            token: Mutex::new(None),
+            corr: AtomicU64::new(corr),
+            pending: Mutex::new(HashMap::new()),
+            events_tx,
+            tx: Mutex::new(tx),
+            negotiated_features: watch::Sender::new(FeatureSet::new()),
+            last_rtt: Mutex::new(None),
+            compression: Mutex::new(None),
+            recorder: None,
+        })
+    }
+
+    #[test]
+    fn test_next_cid_generates_unique_ids() {
+        let inner = test_inner(100, broadcast::channel(1).0, mpsc::channel(1).0);
+        let client = IpcClient {
+            inner,
+            handles: Default::default(),
+            cancel: None,
+        };
+
+        let cid1 = client.next_cid();
+        let cid2 = client.next_cid();
+        let cid3 = client.next_cid();
+
+        assert_ne!(cid1, cid2);
+        assert_ne!(cid2, cid3);
+        assert_ne!(cid1, cid3);
+    }
+
+    #[test]
+    fn test_next_cid_format() {
+        let inner = test_inner(
+            0x123456789abcdef0,
+            broadcast::channel(1).0,
+            mpsc::channel(1).0,
+        );
+        let client = IpcClient {
+            inner,
+            handles: Default::default(),
+            cancel: None,
+        };
+
+        let cid = client.next_cid();
+        assert_eq!(cid, "123456789abcdef0");
+    }
+
+    #[test]
+    fn test_ipc_error_display() {
+        let err = IpcError::ConnectTimeout;
+        assert_eq!(err.to_string(), "connect timeout");
+
+        let err = IpcError::Handshake("test error".to_string());
+        assert_eq!(err.to_string(), "handshake failed: test error");
+
+        let err = IpcError::RequestTimeout;
+        assert_eq!(err.to_string(), "request timeout");
+
+        let err = IpcError::Closed;
+        assert_eq!(err.to_string(), "closed");
+    }
+
+    #[test]
+    fn test_events_channel() {
+        let inner = test_inner(0, broadcast::channel(1).0, mpsc::channel(1).0);
+        let client = IpcClient {
+            inner,
+            handles: Default::default(),
+            cancel: None,
+        };
+
+        // Should be able to get event receiver
+        let _rx = client.events();
+        let _rx2 = client.events(); // Multiple receivers should work
+    }
+
+    /// Client whose bridge answers every `OperationGet` with the current `state`.
+    fn operation_client(
+        events_capacity: usize,
+    ) -> (
+        IpcClient,
+        broadcast::Sender<pb::IpcEvent>,
+        watch::Sender<&'static str>,
+    ) {
+        let (tx, mut writer_rx) = mpsc::channel::<Bytes>(16);
+        let events_tx = broadcast::channel(events_capacity).0;
+        let inner = test_inner(0, events_tx.clone(), tx);
+        let state = watch::Sender::new("running");
+        let (bridge, bridge_state) = (inner.clone(), state.subscribe());
+        tokio::spawn(async move {
+            while let Some(bytes) = writer_rx.recv().await {
+                let env = codec::decode_envelope(bytes).unwrap();
+                let Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::OperationGet(get)),
+                })) = env.kind
+                else {
+                    continue;
+                };
+                let op = pb::OperationGetResponse {
+                    id: get.id,
+                    state: bridge_state.borrow().to_string(),
+                    message: String::new(),
+                };
+                if let Some(pending) = bridge.pending.lock().await.remove(&env.correlation_id) {
+                    pending.complete(Ok(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::OperationGet(op)),
+                    }));
+                }
+            }
+        });
+        let client = IpcClient {
+            inner,
+            handles: Default::default(),
+            cancel: None,
+        };
+        (client, events_tx, state)
+    }
+
+    fn op(op_id: &str, kind: pb::operation_event::Kind) -> pb::IpcEvent {
+        pb::IpcEvent {
+            monotonic_ts_ns: 0,
+            payload: Some(pb::ipc_event::Payload::Op(pb::OperationEvent {
+                op_id: op_id.to_string(),
+                kind: kind as i32,
+                ..Default::default()
+            })),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operation_subscription_ends_at_complete() {
+        use pb::operation_event::Kind;
+
+        let (client, events_tx, _state) = operation_client(16);
+        let mut sub = client.subscribe_operation("op-1");
+        events_tx.send(op("op-1", Kind::Start)).unwrap();
+        events_tx.send(op("op-2", Kind::Complete)).unwrap();
+        events_tx.send(op("op-1", Kind::Progress)).unwrap();
+        events_tx.send(op("op-1", Kind::Complete)).unwrap();
+        events_tx.send(op("op-1", Kind::Progress)).unwrap();
+
+        let mut kinds = Vec::new();
+        while let Some(event) = sub.next().await {
+            assert_eq!(event.op_id, "op-1");
+            kinds.push(event.kind);
+        }
+        assert_eq!(
+            kinds,
+            [
+                Kind::Start as i32,
+                Kind::Progress as i32,
+                Kind::Complete as i32
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscription_to_finished_operation_ends_at_once() {
+        let (client, _events_tx, state) = operation_client(16);
+        state.send_replace("completed");
+
+        let mut sub = client.subscribe_operation("op-1");
+        let next = tokio::time::timeout(Duration::from_secs(2), sub.next()).await;
+        assert!(matches!(next, Ok(None)), "{next:?}");
+    }
+
+    #[tokio::test]
+    async fn test_subscription_rechecks_operation_after_lag() {
+        use pb::operation_event::Kind;
+
+        let (client, events_tx, state) = operation_client(2);
+        let mut sub = client.subscribe_operation("op-1");
+        events_tx.send(op("op-1", Kind::Start)).unwrap();
+        assert_eq!(sub.next().await.unwrap().kind, Kind::Start as i32);
+
+        // op-1's COMPLETE is pushed out of the buffer by other operations' events
+        events_tx.send(op("op-1", Kind::Complete)).unwrap();
+        for _ in 0..4 {
+            events_tx.send(op("op-2", Kind::Progress)).unwrap();
+        }
+        state.send_replace("completed");
+        let next = tokio::time::timeout(Duration::from_secs(2), sub.next()).await;
+        assert!(matches!(next, Ok(None)), "{next:?}");
+    }
+
+    #[tokio::test]
+    async fn test_subscription_after_lag_still_sees_buffered_complete() {
+        use pb::operation_event::Kind;
+
+        let (client, events_tx, state) = operation_client(2);
+        let mut sub = client.subscribe_operation("op-1");
+        for _ in 0..4 {
+            events_tx.send(op("op-2", Kind::Progress)).unwrap();
+        }
+        events_tx.send(op("op-1", Kind::Complete)).unwrap();
+        state.send_replace("completed");
+
+        // The bridge reports it finished, but the COMPLETE behind the lag is still there
+        let first = tokio::time::timeout(Duration::from_secs(2), sub.next()).await;
+        let complete = first.unwrap().expect("COMPLETE is still buffered");
+        assert_eq!(complete.kind, Kind::Complete as i32);
+        assert!(sub.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_next_operation_subscription_follows_next_start() {
+        use pb::operation_event::Kind;
+
+        let (client, events_tx, _state) = operation_client(16);
+        let sub = client.subscribe_next_operation();
+        assert_eq!(sub.op_id(), None);
+        events_tx.send(op("op-1", Kind::Progress)).unwrap();
+        events_tx.send(op("op-2", Kind::Start)).unwrap();
+        events_tx.send(op("op-3", Kind::Start)).unwrap();
+        events_tx.send(op("op-3", Kind::Complete)).unwrap();
+        events_tx.send(op("op-2", Kind::Complete)).unwrap();
+
+        let done = sub.completion().await.expect("op-2 completes");
+        assert_eq!(done.op_id, "op-2");
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_request_removes_pending_entry() {
+        let (tx, _writer_rx) = mpsc::channel(4);
+        let inner = test_inner(0, broadcast::channel(1).0, tx);
+        let ct = CancellationToken::new();
+        let client = IpcClient {
+            inner: inner.clone(),
+            handles: Default::default(),
+            cancel: None,
+        }
+        .with_cancellation(ct.clone());
+
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            ct.cancel();
+        });
+        let req = pb::IpcRequest {
+            payload: Some(pb::ipc_request::Payload::Health(pb::HealthRequest {})),
+        };
+        let result = client.request(req, Duration::from_secs(5)).await;
+        canceller.await.unwrap();
+
+        assert!(matches!(result, Err(IpcError::Cancelled)));
+        assert!(inner.pending.lock().await.is_empty());
+    }
+
+    /// Accept one connection, complete the handshake and return once the client closes it.
+    async fn accept_one(listener: &tokio::net::TcpListener) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut framed = crate::framing::into_framed(stream);
+        let Some(Ok(bytes)) = framed.next().await else {
+            return;
+        };
+        let Ok(pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Hello(hello)),
+        }) = codec::decode_control(bytes.freeze())
+        else {
+            return;
+        };
+        let welcome = pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                ipc_version: hello.ipc_version,
+                accepted_features: hello.features,
+                schema_hash: hello.schema_hash,
+                server_name: "mock".to_string(),
+                ..Default::default()
+            })),
+        };
+        let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+        while let Some(Ok(_)) = framed.next().await {}
+    }
+
+    fn mock_config(port: u16) -> IpcConfig {
+        IpcConfig {
+            endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+            token: Some("test-token".to_string()),
+            token_file: None,
+            unity_project: None,
+            reconnect_initial_backoff: Duration::from_millis(50),
+            ..IpcConfig::default()
+        }
+    }
+
+    async fn released(inner: &std::sync::Weak<Inner>) -> bool {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while inner.strong_count() > 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .is_ok()
+    }
+
+    #[tokio::test]
+    async fn test_connection_closes_when_last_handle_is_dropped() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let bridge = tokio::spawn(async move { accept_one(&listener).await });
+
+        let client = IpcClient::connect(mock_config(port)).await.unwrap();
+        let inner = Arc::downgrade(&client.inner);
+        let other = client.with_cancellation(CancellationToken::new());
+        drop(client);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!bridge.is_finished(), "closed while a handle was alive");
+
+        drop(other);
+        tokio::time::timeout(Duration::from_secs(5), bridge)
+            .await
+            .expect("connection stayed open")
+            .unwrap();
+        assert!(released(&inner).await, "supervisor kept running");
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_loop_stops_when_last_handle_is_dropped() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut framed = crate::framing::into_framed(stream);
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let hello = match codec::decode_control(bytes.freeze()).unwrap().kind {
+                Some(pb::ipc_control::Kind::Hello(hello)) => hello,
+                other => panic!("expected hello, got {other:?}"),
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    ..Default::default()
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+            // Go away for good: every reconnect attempt is refused
+            drop(framed);
+            drop(listener);
+        });
+
+        let client = IpcClient::connect(mock_config(port)).await.unwrap();
+        let inner = Arc::downgrade(&client.inner);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        drop(client);
+        assert!(released(&inner).await, "reconnect loop kept running");
+    }
+}
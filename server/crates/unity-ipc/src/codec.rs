@@ -0,0 +1,302 @@
+use bytes::Bytes;
+use prost::Message;
+use std::io::{Read, Write};
+use thiserror::Error;
+
+use crate::generated::mcp::unity::v1 as pb;
+use crate::generated::schema_hash::SCHEMA_HASH;
+
+#[derive(Debug, Error)]
+pub enum CodecError {
+    #[error("encode error: {0}")]
+    Encode(#[from] prost::EncodeError),
+    #[error("decode error: {0}")]
+    Decode(#[from] prost::DecodeError),
+    #[error("compression error: {0}")]
+    Compression(String),
+    #[error("decompressed envelope of {size} bytes exceeds limit of {limit} bytes")]
+    TooLarge { size: usize, limit: usize },
+}
+
+/// Algorithm negotiated for "ipc.compression" (welcome meta `compression`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    Deflate,
+    Zstd,
+}
+
+impl Compression {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deflate => "deflate",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    pub fn from_name(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "deflate" => Some(Self::Deflate),
+            "zstd" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+
+    fn algorithm(&self) -> pb::compressed_envelope::Algorithm {
+        match self {
+            Self::Deflate => pb::compressed_envelope::Algorithm::Deflate,
+            Self::Zstd => pb::compressed_envelope::Algorithm::Zstd,
+        }
+    }
+}
+
+pub fn encode_envelope(env: &pb::IpcEnvelope) -> Result<Bytes, CodecError> {
+    let mut buf = bytes::BytesMut::with_capacity(env.encoded_len());
+    env.encode(&mut buf)?;
+    Ok(buf.freeze())
+}
+
+pub fn decode_envelope(b: Bytes) -> Result<pb::IpcEnvelope, CodecError> {
+    pb::IpcEnvelope::decode(b).map_err(CodecError::Decode)
+}
+
+/// Encode an envelope, wrapping it in `CompressedEnvelope` when it is at least
+/// `threshold` bytes and compression actually makes it smaller.
+pub fn encode_envelope_compressed(
+    env: &pb::IpcEnvelope,
+    compression: Compression,
+    threshold: usize,
+) -> Result<Bytes, CodecError> {
+    let raw = encode_envelope(env)?;
+    if raw.len() < threshold {
+        return Ok(raw);
+    }
+    let data = match compression {
+        Compression::Deflate => {
+            let mut enc =
+                flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::fast());
+            enc.write_all(&raw)
+                .and_then(|_| enc.finish())
+                .map_err(|e| CodecError::Compression(e.to_string()))?
+        }
+        Compression::Zstd => {
+            zstd::bulk::compress(&raw, 3).map_err(|e| CodecError::Compression(e.to_string()))?
+        }
+    };
+    let wrapped = pb::IpcEnvelope {
+        correlation_id: env.correlation_id.clone(),
+        kind: Some(pb::ipc_envelope::Kind::Compressed(pb::CompressedEnvelope {
+            algorithm: compression.algorithm() as i32,
+            uncompressed_size: raw.len() as u32,
+            data,
+        })),
+    };
+    if wrapped.encoded_len() >= raw.len() {
+        return Ok(raw);
+    }
+    encode_envelope(&wrapped)
+}
+
+/// Unwrap a `CompressedEnvelope`; other envelopes are returned as-is.
+///
+/// `max_len` bounds the declared decompressed size so a small frame cannot
+/// inflate past the frame limit.
+pub fn inflate_envelope(
+    env: pb::IpcEnvelope,
+    max_len: usize,
+) -> Result<pb::IpcEnvelope, CodecError> {
+    let Some(pb::ipc_envelope::Kind::Compressed(c)) = env.kind else {
+        return Ok(env);
+    };
+    let size = c.uncompressed_size as usize;
+    if size > max_len {
+        return Err(CodecError::TooLarge {
+            size,
+            limit: max_len,
+        });
+    }
+    let raw = match c.algorithm() {
+        pb::compressed_envelope::Algorithm::Deflate => {
+            let mut out = Vec::with_capacity(size);
+            flate2::read::DeflateDecoder::new(&c.data[..])
+                .take(size as u64 + 1)
+                .read_to_end(&mut out)
+                .map_err(|e| CodecError::Compression(e.to_string()))?;
+            out
+        }
+        pb::compressed_envelope::Algorithm::Zstd => zstd::bulk::decompress(&c.data, size)
+            .map_err(|e| CodecError::Compression(e.to_string()))?,
+        pb::compressed_envelope::Algorithm::Unspecified => {
+            return Err(CodecError::Compression("unspecified algorithm".into()));
+        }
+    };
+    // The declared size bounds decompression; anything else is a corrupt frame
+    if raw.len() != size {
+        return Err(CodecError::Compression(format!(
+            "expected {size} bytes after decompression, got {}",
+            raw.len()
+        )));
+    }
+    let inner = decode_envelope(Bytes::from(raw))?;
+    if matches!(inner.kind, Some(pb::ipc_envelope::Kind::Compressed(_))) {
+        return Err(CodecError::Compression("nested compressed envelope".into()));
+    }
+    Ok(inner)
+}
+
+pub fn encode_control(control: &pb::IpcControl) -> Result<Bytes, CodecError> {
+    let mut buf = bytes::BytesMut::with_capacity(control.encoded_len());
+    control.encode(&mut buf)?;
+    Ok(buf.freeze())
+}
+
+pub fn decode_control(b: Bytes) -> Result<pb::IpcControl, CodecError> {
+    pb::IpcControl::decode(b).map_err(CodecError::Decode)
+}
+
+// Schema hash from build-time generated constant
+pub fn schema_hash() -> Vec<u8> {
+    SCHEMA_HASH.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let original = pb::IpcEnvelope {
+            correlation_id: "test-123".to_string(),
+            kind: Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                payload: Some(pb::ipc_request::Payload::Health(pb::HealthRequest {})),
+            })),
+        };
+
+        let encoded = encode_envelope(&original).expect("encoding should succeed");
+        let decoded = decode_envelope(encoded).expect("decoding should succeed");
+
+        assert_eq!(original.correlation_id, decoded.correlation_id);
+        match (original.kind, decoded.kind) {
+            (
+                Some(pb::ipc_envelope::Kind::Request(req1)),
+                Some(pb::ipc_envelope::Kind::Request(req2)),
+            ) => {
+                match (req1.payload, req2.payload) {
+                    (
+                        Some(pb::ipc_request::Payload::Health(_)),
+                        Some(pb::ipc_request::Payload::Health(_)),
+                    ) => {} // Success
+                    _ => panic!("Payload mismatch"),
+                }
+            }
+            _ => panic!("Kind mismatch"),
+        }
+    }
+
+    fn large_health_response(cid: &str) -> pb::IpcEnvelope {
+        pb::IpcEnvelope {
+            correlation_id: cid.to_string(),
+            kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                correlation_id: cid.to_string(),
+                payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                    version: "abc".repeat(4096),
+                    ..Default::default()
+                })),
+            })),
+        }
+    }
+
+    #[test]
+    fn test_compressed_envelope_roundtrip() {
+        for compression in [Compression::Deflate, Compression::Zstd] {
+            let original = large_health_response("cid-1");
+            let raw_len = encode_envelope(&original).unwrap().len();
+            let bytes = encode_envelope_compressed(&original, compression, 1024).unwrap();
+            assert!(bytes.len() < raw_len / 4, "{compression:?} did not shrink");
+
+            let wire = decode_envelope(bytes).unwrap();
+            assert_eq!(wire.correlation_id, "cid-1");
+            assert!(matches!(
+                wire.kind,
+                Some(pb::ipc_envelope::Kind::Compressed(_))
+            ));
+            assert_eq!(inflate_envelope(wire, usize::MAX).unwrap(), original);
+        }
+    }
+
+    #[test]
+    fn test_small_envelope_is_not_compressed() {
+        let env = pb::IpcEnvelope {
+            correlation_id: "small".to_string(),
+            kind: Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                payload: Some(pb::ipc_request::Payload::Health(pb::HealthRequest {})),
+            })),
+        };
+        let bytes = encode_envelope_compressed(&env, Compression::Zstd, 1024).unwrap();
+        assert_eq!(bytes, encode_envelope(&env).unwrap());
+        assert_eq!(
+            inflate_envelope(decode_envelope(bytes).unwrap(), 16).unwrap(),
+            env
+        );
+    }
+
+    #[test]
+    fn test_inflate_enforces_limit() {
+        let bytes =
+            encode_envelope_compressed(&large_health_response("big"), Compression::Deflate, 0)
+                .unwrap();
+        let err = inflate_envelope(decode_envelope(bytes).unwrap(), 1024).unwrap_err();
+        assert!(
+            matches!(err, CodecError::TooLarge { limit: 1024, .. }),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_compression_names() {
+        assert_eq!(Compression::from_name(" ZSTD"), Some(Compression::Zstd));
+        assert_eq!(
+            Compression::from_name("deflate"),
+            Some(Compression::Deflate)
+        );
+        assert_eq!(Compression::from_name("gzip"), None);
+        assert_eq!(Compression::Zstd.as_str(), "zstd");
+    }
+
+    #[test]
+    fn test_schema_hash() {
+        let hash = schema_hash();
+        assert_eq!(hash.len(), 32); // SHA-256 produces 32 bytes
+    }
+
+    #[test]
+    fn test_ipc_control_roundtrip() {
+        let hello = pb::IpcHello {
+            token: "test-token".to_string(),
+            ipc_version: "1.0".to_string(),
+            features: vec!["assets.basic".to_string()],
+            schema_hash: vec![1, 2, 3, 4],
+            client_name: "test-client".to_string(),
+            client_version: "0.1.0".to_string(),
+            meta: std::collections::HashMap::new(),
+        };
+
+        let control = pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Hello(hello.clone())),
+        };
+
+        let encoded = encode_control(&control).expect("encoding should succeed");
+        let decoded = decode_control(encoded).expect("decoding should succeed");
+
+        match decoded.kind {
+            Some(pb::ipc_control::Kind::Hello(decoded_hello)) => {
+                assert_eq!(hello.token, decoded_hello.token);
+                assert_eq!(hello.ipc_version, decoded_hello.ipc_version);
+                assert_eq!(hello.features, decoded_hello.features);
+                assert_eq!(hello.schema_hash, decoded_hello.schema_hash);
+                assert_eq!(hello.client_name, decoded_hello.client_name);
+                assert_eq!(hello.client_version, decoded_hello.client_version);
+            }
+            _ => panic!("Expected Hello variant"),
+        }
+    }
+}
@@ -0,0 +1,533 @@
+//! Discovery of running Unity Editors.
+//!
+//! 候補は 2 通りで集める:
+//! - ディスカバリディレクトリ（`MCP_DISCOVERY_DIR`、既定は [`default_discovery_dir`]）に
+//!   Bridge が起動時に書き出す `<pid>.json` 記述子
+//! - `MCP_DISCOVERY_PORTS`（例: `7777-7787`）で指定したポート範囲
+//!
+//! 各候補には一度だけ接続して hello/welcome と Health を行い、結果を返す。
+//! 記述子由来の候補は到達できなくても（Editor が異常終了して残った記述子など）結果に含め、
+//! ポート走査由来の候補は応答したものだけを返す。
+//!
+//! 候補が本物の Editor かはまだ分からないので、プローブではトークンを送らない。
+//! 記述子は自分だけがアクセスできるディレクトリ・ファイルのものに限り、エンドポイントも
+//! loopback の `tcp://` だけを受け付ける（他ユーザーが置いた記述子で接続先を誘導されないように）。
+
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use super::client::{IpcClient, IpcError};
+use super::path::{Endpoint, IpcConfig, host_is_loopback, parse_endpoint};
+use crate::settings;
+
+/// Default per-candidate probe timeout.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Upper bound on the number of ports one discovery run may scan.
+pub const MAX_SCAN_PORTS: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub struct DiscoveryConfig {
+    /// Directory of per-editor descriptor files (`None` disables descriptor discovery).
+    pub dir: Option<PathBuf>,
+    /// Ports to probe on `host` (empty disables the port scan).
+    pub ports: Vec<u16>,
+    pub host: String,
+    pub probe_timeout: Duration,
+    /// Token and frame settings used for the probe handshakes.
+    pub ipc: IpcConfig,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        // 空文字や "off" でディレクトリ走査を無効化できる
+        let dir = match settings::var("MCP_DISCOVERY_DIR") {
+            Some(raw) if raw.is_empty() || raw == "off" => None,
+            Some(raw) => Some(PathBuf::from(raw)),
+            None => Some(default_discovery_dir()),
+        };
+        let ports = settings::var("MCP_DISCOVERY_PORTS")
+            .and_then(|raw| match parse_port_ranges(&raw) {
+                Ok(ports) => Some(ports),
+                Err(e) => {
+                    tracing::warn!("Ignoring MCP_DISCOVERY_PORTS={:?}: {}", raw, e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        Self {
+            dir,
+            ports,
+            host: settings::var("MCP_DISCOVERY_HOST").unwrap_or_else(|| "127.0.0.1".to_string()),
+            probe_timeout: settings::var("MCP_DISCOVERY_PROBE_TIMEOUT_MS")
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(DEFAULT_PROBE_TIMEOUT),
+            ipc: IpcConfig::default(),
+        }
+    }
+}
+
+/// Where the bridge writes descriptors unless `MCP_DISCOVERY_DIR` says otherwise
+/// (same rule as C# `DiscoveryDescriptor.Directory`).
+///
+/// `$XDG_RUNTIME_DIR/unity-mcp/editors` when set, else `%LOCALAPPDATA%\unity-mcp\editors`
+/// on Windows and `~/.unity-mcp/editors` elsewhere.
+pub fn default_discovery_dir() -> PathBuf {
+    let user_dir = |name: &str| {
+        env::var_os(name)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = match user_dir("XDG_RUNTIME_DIR") {
+        Some(runtime) => runtime.join("unity-mcp"),
+        None if cfg!(windows) => user_dir("LOCALAPPDATA")
+            .unwrap_or_else(env::temp_dir)
+            .join("unity-mcp"),
+        None => user_dir("HOME")
+            .unwrap_or_else(env::temp_dir)
+            .join(".unity-mcp"),
+    };
+    base.join("editors")
+}
+
+/// Create `dir` (and missing parents) accessible only by the current user.
+pub fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    let mut builder = std::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+        builder.mode(0o700);
+        builder.create(dir)?;
+        // 既存のディレクトリは mode が効かないので締め直す
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))
+    }
+    #[cfg(not(unix))]
+    builder.create(dir)
+}
+
+/// Write `<dir>/<pid>.json` readable only by the current user, replacing it atomically.
+pub fn write_descriptor(dir: &Path, descriptor: &EditorDescriptor) -> std::io::Result<PathBuf> {
+    create_private_dir(dir)?;
+    let pid = descriptor.pid.unwrap_or_else(std::process::id);
+    let path = dir.join(format!("{pid}.json"));
+    let tmp = dir.join(format!("{pid}.json.tmp"));
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let json = serde_json::to_vec_pretty(descriptor).map_err(std::io::Error::other)?;
+    std::io::Write::write_all(&mut options.open(&tmp)?, &json)?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(path)
+}
+
+/// Refuse paths that are not owned by the current user or that other users can access.
+#[cfg(unix)]
+fn check_private(metadata: &std::fs::Metadata) -> Result<(), String> {
+    use std::os::unix::fs::MetadataExt;
+    // SAFETY: getuid has no preconditions and cannot fail
+    let uid = unsafe { libc::getuid() };
+    if metadata.uid() != uid {
+        return Err(format!(
+            "owned by uid {} instead of {}",
+            metadata.uid(),
+            uid
+        ));
+    }
+    let mode = metadata.mode() & 0o777;
+    if mode & 0o077 != 0 {
+        return Err(format!("accessible by other users (mode {mode:03o})"));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_private(_metadata: &std::fs::Metadata) -> Result<(), String> {
+    Ok(())
+}
+
+/// Descriptor endpoints must be plain TCP on this machine.
+fn check_descriptor_endpoint(raw: &str) -> Result<String, String> {
+    match parse_endpoint(raw.trim()) {
+        Endpoint::Tcp(addr) if host_is_loopback(&addr) => Ok(format!("tcp://{addr}")),
+        other => Err(format!("endpoint {other} is not a loopback tcp:// address")),
+    }
+}
+
+/// Parse a port list such as `7777-7787` or `7777,7790-7792`, dropping duplicates.
+pub fn parse_port_ranges(raw: &str) -> Result<Vec<u16>, String> {
+    let mut ports: Vec<u16> = Vec::new();
+    for part in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (start, end) = match part.split_once('-') {
+            Some((a, b)) => (parse_port(a)?, parse_port(b)?),
+            None => {
+                let p = parse_port(part)?;
+                (p, p)
+            }
+        };
+        if start > end {
+            return Err(format!("invalid port range {part:?}"));
+        }
+        for port in start..=end {
+            if !ports.contains(&port) {
+                ports.push(port);
+            }
+            if ports.len() > MAX_SCAN_PORTS {
+                return Err(format!("more than {MAX_SCAN_PORTS} ports requested"));
+            }
+        }
+    }
+    Ok(ports)
+}
+
+fn parse_port(s: &str) -> Result<u16, String> {
+    s.trim()
+        .parse::<u16>()
+        .ok()
+        .filter(|p| *p != 0)
+        .ok_or_else(|| format!("invalid port {:?}", s.trim()))
+}
+
+/// Contents of `<discovery dir>/<pid>.json`, written by the bridge on startup.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditorDescriptor {
+    pub endpoint: String,
+    pub pid: Option<u32>,
+    pub project_name: String,
+    pub project_path: String,
+    pub unity_version: String,
+    pub started_at: String,
+}
+
+/// How a candidate was found.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandidateSource {
+    Descriptor,
+    PortScan,
+}
+
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub endpoint: String,
+    pub source: CandidateSource,
+    pub descriptor_path: Option<PathBuf>,
+    pub descriptor: Option<EditorDescriptor>,
+}
+
+/// One candidate and what probing it revealed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveredEditor {
+    pub endpoint: String,
+    pub source: CandidateSource,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pid: Option<u32>,
+    pub reachable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unity_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Read every `*.json` descriptor in `dir`.
+///
+/// Unreadable, malformed or non-private files are skipped, as is the whole directory when
+/// another user owns it or can access it.
+pub async fn read_descriptors(dir: &Path) -> Vec<(PathBuf, EditorDescriptor)> {
+    match tokio::fs::metadata(dir).await.map(|m| check_private(&m)) {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            tracing::warn!("Ignoring discovery directory {}: {}", dir.display(), e);
+            return Vec::new();
+        }
+        Err(e) => {
+            tracing::debug!("Discovery directory {} not readable: {}", dir.display(), e);
+            return Vec::new();
+        }
+    }
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::debug!("Discovery directory {} not readable: {}", dir.display(), e);
+            return Vec::new();
+        }
+    };
+    let mut out = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        // symlink は辿らない（他人のファイルを指していても owner 検査をすり抜けないように）
+        let private = match tokio::fs::symlink_metadata(&path).await {
+            Ok(m) if !m.file_type().is_file() => Err("not a regular file".to_string()),
+            Ok(m) => check_private(&m),
+            Err(e) => Err(e.to_string()),
+        };
+        let parsed = match private {
+            Ok(()) => tokio::fs::read_to_string(&path)
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    serde_json::from_str::<EditorDescriptor>(&s).map_err(|e| e.to_string())
+                }),
+            Err(e) => Err(e),
+        };
+        let checked = parsed.and_then(|mut d| {
+            d.endpoint = check_descriptor_endpoint(&d.endpoint)?;
+            Ok(d)
+        });
+        match checked {
+            Ok(d) => out.push((path, d)),
+            Err(e) => tracing::warn!("Skipping descriptor {}: {}", path.display(), e),
+        }
+    }
+    out.sort_by(|a, b| a.0.cmp(&b.0));
+    out
+}
+
+/// Candidates from descriptors first, then the port range; duplicates keep the descriptor.
+pub async fn candidates(cfg: &DiscoveryConfig) -> Vec<Candidate> {
+    let mut out: Vec<Candidate> = Vec::new();
+    if let Some(dir) = &cfg.dir {
+        for (path, descriptor) in read_descriptors(dir).await {
+            let endpoint = descriptor.endpoint.clone();
+            if out.iter().any(|c| c.endpoint == endpoint) {
+                continue;
+            }
+            out.push(Candidate {
+                endpoint,
+                source: CandidateSource::Descriptor,
+                descriptor_path: Some(path),
+                descriptor: Some(descriptor),
+            });
+        }
+    }
+    for port in &cfg.ports {
+        let endpoint = format!("tcp://{}:{}", cfg.host, port);
+        if out.iter().any(|c| c.endpoint == endpoint) {
+            continue;
+        }
+        out.push(Candidate {
+            endpoint,
+            source: CandidateSource::PortScan,
+            descriptor_path: None,
+            descriptor: None,
+        });
+    }
+    out
+}
+
+/// Handshake with one candidate, without a token, and ask for Health.
+///
+/// A bridge that rejects the missing token is still reported as reachable; only
+/// connecting to it reveals its state.
+pub async fn probe(cfg: &DiscoveryConfig, candidate: Candidate) -> DiscoveredEditor {
+    let descriptor = candidate.descriptor.unwrap_or_default();
+    let non_empty = |s: String| Some(s).filter(|s| !s.is_empty());
+    let ipc = IpcConfig {
+        endpoint: Some(candidate.endpoint.clone()),
+        connect_timeout: cfg.probe_timeout,
+        total_handshake_timeout: cfg.probe_timeout,
+        call_timeout: cfg.probe_timeout,
+        token: None,
+        token_file: None,
+        unity_project: None,
+        ..cfg.ipc.clone()
+    };
+    let mut out = DiscoveredEditor {
+        endpoint: candidate.endpoint,
+        source: candidate.source,
+        descriptor_path: candidate.descriptor_path.map(|p| p.display().to_string()),
+        pid: descriptor.pid,
+        reachable: false,
+        ready: None,
+        project_name: non_empty(descriptor.project_name),
+        project_path: non_empty(descriptor.project_path),
+        unity_version: non_empty(descriptor.unity_version),
+        server_name: None,
+        error: None,
+    };
+    match IpcClient::probe(&ipc).await {
+        Ok((welcome, health)) => {
+            out.reachable = true;
+            out.ready = Some(health.ready);
+            out.server_name = non_empty(welcome.server_name);
+            // 表示用の情報は Editor 自身の申告を優先する。project_path はトークンの
+            // 読み出し先になるので、認証していない相手の申告より記述子を信用する
+            out.project_name = non_empty(health.project_name).or(out.project_name);
+            out.project_path = out.project_path.or(non_empty(health.project_path));
+            out.unity_version = non_empty(health.version).or(out.unity_version);
+        }
+        Err(IpcError::Authentication(_)) => out.reachable = true,
+        Err(e) => out.error = Some(e.to_string()),
+    }
+    out
+}
+
+/// Probe every candidate concurrently, in candidate order.
+pub async fn discover(cfg: &DiscoveryConfig) -> Vec<DiscoveredEditor> {
+    let probes = candidates(cfg)
+        .await
+        .into_iter()
+        .map(|candidate| probe(cfg, candidate));
+    futures::future::join_all(probes)
+        .await
+        .into_iter()
+        .filter(|e| e.reachable || e.source == CandidateSource::Descriptor)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_port_ranges() {
+        assert_eq!(parse_port_ranges("7777").unwrap(), vec![7777]);
+        assert_eq!(
+            parse_port_ranges(" 7777-7779, 7778 ,7790").unwrap(),
+            vec![7777, 7778, 7779, 7790]
+        );
+        assert!(parse_port_ranges("").unwrap().is_empty());
+        assert!(parse_port_ranges("7779-7777").is_err());
+        assert!(parse_port_ranges("0").is_err());
+        assert!(parse_port_ranges("70000").is_err());
+        assert!(parse_port_ranges("abc").is_err());
+        assert!(parse_port_ranges("1-2000").is_err());
+    }
+
+    #[test]
+    fn test_descriptor_tolerates_missing_fields() {
+        let d: EditorDescriptor = serde_json::from_str(
+            r#"{"endpoint":"tcp://127.0.0.1:7777","pid":42,"project_name":"Game","extra":1}"#,
+        )
+        .unwrap();
+        assert_eq!(d.endpoint, "tcp://127.0.0.1:7777");
+        assert_eq!(d.pid, Some(42));
+        assert_eq!(d.project_name, "Game");
+        assert!(d.project_path.is_empty());
+    }
+
+    /// Write `contents` to `dir/name` with owner-only permissions.
+    fn write_private(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+        }
+        path
+    }
+
+    #[tokio::test]
+    async fn test_candidates_prefer_descriptors_and_dedupe() {
+        let dir = tempfile::tempdir().unwrap();
+        create_private_dir(dir.path()).unwrap();
+        write_private(
+            dir.path(),
+            "100.json",
+            r#"{"endpoint":"127.0.0.1:7778","pid":100,"project_name":"Game"}"#,
+        );
+        write_private(dir.path(), "200.json", "not json");
+        write_private(dir.path(), "300.json.tmp", r#"{"endpoint":"x"}"#);
+
+        let cfg = DiscoveryConfig {
+            dir: Some(dir.path().to_path_buf()),
+            ports: vec![7777, 7778],
+            host: "127.0.0.1".to_string(),
+            probe_timeout: DEFAULT_PROBE_TIMEOUT,
+            ipc: IpcConfig::default(),
+        };
+        let found = candidates(&cfg).await;
+        let endpoints: Vec<_> = found.iter().map(|c| c.endpoint.as_str()).collect();
+        assert_eq!(
+            endpoints,
+            vec!["tcp://127.0.0.1:7778", "tcp://127.0.0.1:7777"]
+        );
+        assert_eq!(found[0].source, CandidateSource::Descriptor);
+        assert_eq!(found[1].source, CandidateSource::PortScan);
+
+        // A missing directory is not an error
+        let cfg = DiscoveryConfig {
+            dir: Some(dir.path().join("missing")),
+            ports: Vec::new(),
+            ..cfg
+        };
+        assert!(candidates(&cfg).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_descriptors_must_be_private_and_loopback() {
+        let dir = tempfile::tempdir().unwrap();
+        let descriptor = |endpoint: &str| EditorDescriptor {
+            endpoint: endpoint.to_string(),
+            project_path: "/work/Game".to_string(),
+            ..Default::default()
+        };
+        let written = write_descriptor(
+            dir.path(),
+            &EditorDescriptor {
+                pid: Some(1),
+                ..descriptor("tcp://127.0.0.1:7777")
+            },
+        )
+        .unwrap();
+        for (name, endpoint) in [
+            ("2.json", "tcp://203.0.113.9:7777"),
+            ("3.json", "tls://127.0.0.1:7777"),
+            ("4.json", "ws://127.0.0.1:7777/ipc"),
+        ] {
+            let json = serde_json::to_string(&descriptor(endpoint)).unwrap();
+            write_private(dir.path(), name, &json);
+        }
+
+        let found = read_descriptors(dir.path()).await;
+        let paths: Vec<_> = found.iter().map(|(p, _)| p.clone()).collect();
+        assert_eq!(paths, vec![written.clone()]);
+        assert_eq!(found[0].1.endpoint, "tcp://127.0.0.1:7777");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |p: &Path| std::fs::metadata(p).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(&written), 0o600);
+            assert_eq!(mode(dir.path()), 0o700);
+
+            // Group/other access on the file, then on the directory, hides the descriptor
+            std::fs::set_permissions(&written, std::fs::Permissions::from_mode(0o644)).unwrap();
+            assert!(read_descriptors(dir.path()).await.is_empty());
+            std::fs::set_permissions(&written, std::fs::Permissions::from_mode(0o600)).unwrap();
+            std::fs::set_permissions(dir.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+            assert!(read_descriptors(dir.path()).await.is_empty());
+            std::fs::set_permissions(dir.path(), std::fs::Permissions::from_mode(0o700)).unwrap();
+
+            // Symlinks are not followed
+            let target = write_private(
+                dir.path(),
+                "target.txt",
+                &serde_json::to_string(&descriptor("tcp://127.0.0.1:7778")).unwrap(),
+            );
+            std::os::unix::fs::symlink(&target, dir.path().join("5.json")).unwrap();
+            assert_eq!(read_descriptors(dir.path()).await.len(), 1);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! Typed request/response dispatch.
+//!
+//! 各リクエストメッセージは [`IpcCall`] を実装し、`IpcRequest` への包み方、
+//! 対応する応答バリアント、必要な機能フラグ、既定のタイムアウトを一か所で宣言する。
+//! 送信と応答の取り出しは [`IpcClient::call`](super::client::IpcClient::call) が共通で行う。
+//!
+//! 新しい proto ペイロードは下のマクロに 1 行足せばクライアントから呼べるようになる。
+
+use std::time::Duration;
+
+use super::client::{IpcError, status};
+use super::features::FeatureFlag;
+use crate::generated::mcp::unity::v1 as pb;
+
+/// Default timeout for calls that do not declare their own.
+pub const DEFAULT_CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A request message that can be sent to the bridge on its own.
+pub trait IpcCall: Sized + Send {
+    /// Message that answers this request.
+    type Response;
+
+    /// Short name used in error messages, e.g. "assets move".
+    const NAME: &'static str;
+    /// Feature the bridge must have accepted in the handshake.
+    const FEATURE: Option<FeatureFlag> = None;
+    /// Timeout used by [`IpcClient::call`](super::client::IpcClient::call).
+    const DEFAULT_TIMEOUT: Duration = DEFAULT_CALL_TIMEOUT;
+
+    fn into_request(self) -> pb::IpcRequest;
+
+    fn from_response(
+        payload: Option<pb::ipc_response::Payload>,
+    ) -> Result<Self::Response, IpcError>;
+}
+
+/// Grouped responses (assets, scenes, ...) report failures through a status code.
+trait GroupStatus {
+    fn status(&self) -> (i32, &str);
+}
+
+macro_rules! group_status {
+    ($($resp:ident),+ $(,)?) => {$(
+        impl GroupStatus for pb::$resp {
+            fn status(&self) -> (i32, &str) {
+                (self.status_code, &self.message)
+            }
+        }
+    )+};
+}
+
+group_status!(
+    AssetsResponse,
+    ScenesResponse,
+    ComponentResponse,
+    PrefabResponse
+);
+
+// Build results carry their status in the inner response
+impl GroupStatus for pb::BuildResponse {
+    fn status(&self) -> (i32, &str) {
+        (status::OK, "")
+    }
+}
+
+fn unexpected(name: &str) -> IpcError {
+    IpcError::UnexpectedResponse(name.to_string())
+}
+
+/// Requests carried directly in `IpcRequest.payload`.
+macro_rules! direct_calls {
+    ($(
+        $req:ident => $resp:ident as $variant:ident, $name:literal
+        $(, timeout = $timeout:expr)?;
+    )+) => {$(
+        impl IpcCall for pb::$req {
+            type Response = pb::$resp;
+            const NAME: &'static str = $name;
+            $(const DEFAULT_TIMEOUT: Duration = $timeout;)?
+
+            fn into_request(self) -> pb::IpcRequest {
+                pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::$variant(self)),
+                }
+            }
+
+            fn from_response(
+                payload: Option<pb::ipc_response::Payload>,
+            ) -> Result<Self::Response, IpcError> {
+                match payload {
+                    Some(pb::ipc_response::Payload::$variant(r)) => Ok(r),
+                    _ => Err(unexpected($name)),
+                }
+            }
+        }
+    )+};
+}
+
+/// Requests nested in a group message (`AssetsRequest`, `ScenesRequest`, ...).
+macro_rules! group_calls {
+    (
+        $group:ident($group_req:ident, $req_mod:ident; $resp_mod:ident),
+        feature = $feature:expr, timeout = $timeout:expr;
+        $( $req:ident => $resp:ident as $variant:ident, $name:literal; )+
+    ) => {$(
+        impl IpcCall for pb::$req {
+            type Response = pb::$resp;
+            const NAME: &'static str = $name;
+            const FEATURE: Option<FeatureFlag> = $feature;
+            const DEFAULT_TIMEOUT: Duration = $timeout;
+
+            fn into_request(self) -> pb::IpcRequest {
+                pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::$group(pb::$group_req {
+                        payload: Some(pb::$req_mod::Payload::$variant(self)),
+                    })),
+                }
+            }
+
+            fn from_response(
+                payload: Option<pb::ipc_response::Payload>,
+            ) -> Result<Self::Response, IpcError> {
+                let Some(pb::ipc_response::Payload::$group(group)) = payload else {
+                    return Err(unexpected($name));
+                };
+                let (code, message) = group.status();
+                if code == status::OK {
+                    if let Some(pb::$resp_mod::Payload::$variant(r)) = group.payload {
+                        return Ok(r);
+                    }
+                }
+                Err(IpcError::from_status(
+                    code,
+                    format!("{} failed: {}", $name, message),
+                ))
+            }
+        }
+    )+};
+}
+
+direct_calls! {
+    HealthRequest => HealthResponse as Health, "health", timeout = Duration::from_secs(5);
+    ExecuteMenuItemRequest => ExecuteMenuItemResponse as ExecuteMenuItem, "execute menu item";
+    FocusWindowRequest => FocusWindowResponse as FocusWindow, "focus window";
+    SetPlayModeRequest => SetPlayModeResponse as SetPlayMode, "set play mode";
+    GetCompileDiagnosticsRequest => GetCompileDiagnosticsResponse as GetCompileDiagnostics,
+        "get compile diagnostics";
+    GetProjectSettingsRequest => GetProjectSettingsResponse as GetProjectSettings,
+        "get project settings";
+    SetProjectSettingsRequest => SetProjectSettingsResponse as SetProjectSettings,
+        "set project settings";
+    OperationGetRequest => OperationGetResponse as OperationGet, "operation get",
+        timeout = Duration::from_secs(5);
+    OperationCancelRequest => OperationCancelResponse as OperationCancel, "operation cancel",
+        timeout = Duration::from_secs(5);
+}
+
+group_calls! {
+    Assets(AssetsRequest, assets_request; assets_response),
+    feature = Some(FeatureFlag::AssetsBasic), timeout = DEFAULT_CALL_TIMEOUT;
+    ImportAssetRequest => ImportAssetResponse as Import, "assets import";
+    MoveAssetRequest => MoveAssetResponse as Move, "assets move";
+    DeleteAssetRequest => DeleteAssetResponse as Delete, "assets delete";
+    RefreshRequest => RefreshResponse as Refresh, "assets refresh";
+    GuidToPathRequest => GuidToPathResponse as G2p, "assets g2p";
+    PathToGuidRequest => PathToGuidResponse as P2g, "assets p2g";
+}
+
+group_calls! {
+    Scenes(ScenesRequest, scenes_request; scenes_response),
+    feature = None, timeout = DEFAULT_CALL_TIMEOUT;
+    OpenSceneRequest => OpenSceneResponse as Open, "scene open";
+    SaveSceneRequest => SaveSceneResponse as Save, "scene save";
+    GetOpenScenesRequest => GetOpenScenesResponse as GetOpen, "scene get_open_scenes";
+    SetActiveSceneRequest => SetActiveSceneResponse as SetActive, "scene set_active";
+}
+
+group_calls! {
+    Component(ComponentRequest, component_request; component_response),
+    feature = Some(FeatureFlag::ComponentsBasic), timeout = DEFAULT_CALL_TIMEOUT;
+    AddComponentRequest => AddComponentResponse as Add, "component add";
+    GetComponentsRequest => GetComponentsResponse as Get, "component get";
+    RemoveComponentRequest => RemoveComponentResponse as Remove, "component remove";
+}
+
+group_calls! {
+    Prefab(PrefabRequest, prefab_request; prefab_response),
+    feature = Some(FeatureFlag::PrefabsBasic), timeout = DEFAULT_CALL_TIMEOUT;
+    CreatePrefabRequest => CreatePrefabResponse as Create, "prefab create";
+    UpdatePrefabRequest => UpdatePrefabResponse as Update, "prefab update";
+    ApplyPrefabOverridesRequest => ApplyPrefabOverridesResponse as ApplyOverrides,
+        "prefab apply overrides";
+}
+
+group_calls! {
+    Build(BuildRequest, build_request; build_response),
+    feature = Some(FeatureFlag::BuildMin), timeout = Duration::from_secs(1800);
+    BuildPlayerRequest => BuildPlayerResponse as Player, "build player";
+    BuildAssetBundlesRequest => BuildAssetBundlesResponse as Bundles, "build asset bundles";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assets_response(
+        status_code: i32,
+        payload: Option<pb::assets_response::Payload>,
+    ) -> Option<pb::ipc_response::Payload> {
+        Some(pb::ipc_response::Payload::Assets(pb::AssetsResponse {
+            status_code,
+            message: "boom".to_string(),
+            payload,
+        }))
+    }
+
+    #[test]
+    fn test_group_request_is_wrapped() {
+        let req = pb::MoveAssetRequest {
+            from_path: "Assets/A.png".into(),
+            to_path: "Assets/B.png".into(),
+        }
+        .into_request();
+        assert!(matches!(
+            req.payload,
+            Some(pb::ipc_request::Payload::Assets(pb::AssetsRequest {
+                payload: Some(pb::assets_request::Payload::Move(_))
+            }))
+        ));
+        assert_eq!(
+            <pb::MoveAssetRequest as IpcCall>::FEATURE,
+            Some(FeatureFlag::AssetsBasic)
+        );
+        assert_eq!(
+            <pb::BuildPlayerRequest as IpcCall>::DEFAULT_TIMEOUT,
+            Duration::from_secs(1800)
+        );
+        assert_eq!(<pb::OpenSceneRequest as IpcCall>::FEATURE, None);
+    }
+
+    #[test]
+    fn test_group_response_is_unwrapped() {
+        let ok = assets_response(
+            0,
+            Some(pb::assets_response::Payload::Move(pb::MoveAssetResponse {
+                ok: true,
+                ..Default::default()
+            })),
+        );
+        assert!(pb::MoveAssetRequest::from_response(ok).unwrap().ok);
+    }
+
+    #[test]
+    fn test_group_status_code_becomes_typed_error() {
+        let err = pb::MoveAssetRequest::from_response(assets_response(5, None)).unwrap_err();
+        assert!(
+            matches!(&err, IpcError::NotFound(m) if m == "assets move failed: boom"),
+            "{err}"
+        );
+
+        // OK status with the wrong inner payload is an unexpected response
+        let wrong = assets_response(
+            0,
+            Some(pb::assets_response::Payload::Refresh(
+                pb::RefreshResponse::default(),
+            )),
+        );
+        let err = pb::MoveAssetRequest::from_response(wrong).unwrap_err();
+        assert!(matches!(err, IpcError::UnexpectedResponse(_)), "{err}");
+    }
+
+    #[test]
+    fn test_direct_response_of_wrong_kind_is_rejected() {
+        let payload = Some(pb::ipc_response::Payload::FocusWindow(
+            pb::FocusWindowResponse::default(),
+        ));
+        let err = pb::HealthRequest::from_response(payload).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected response: health");
+    }
+}
@@ -19,6 +19,11 @@ pub enum FeatureFlag {
     // Components
     ComponentsBasic, // "components.basic" - add/get/remove components
 
+    // Transport
+    IpcHeartbeat,   // "ipc.heartbeat" - ping/pong keepalive control frames
+    IpcCompression, // "ipc.compression" - zstd/deflate compressed envelopes above a size threshold
+    IpcChunking,    // "ipc.chunking" - large responses split into ordered chunks
+
     // Future extensions
     AssetsAdvanced, // "assets.advanced" - asset streaming, dependencies
     BuildFull,      // "build.full" - full build pipeline with addressables
@@ -37,6 +42,9 @@ impl FeatureFlag {
             "events.log" => Self::EventsLog,
             "ops.progress" => Self::OpsProgress,
             "components.basic" => Self::ComponentsBasic,
+            "ipc.heartbeat" => Self::IpcHeartbeat,
+            "ipc.compression" => Self::IpcCompression,
+            "ipc.chunking" => Self::IpcChunking,
             "assets.advanced" => Self::AssetsAdvanced,
             "build.full" => Self::BuildFull,
             "events.full" => Self::EventsFull,
@@ -52,6 +60,9 @@ impl FeatureFlag {
             Self::EventsLog,
             Self::OpsProgress,
             Self::ComponentsBasic,
+            Self::IpcHeartbeat,
+            Self::IpcCompression,
+            Self::IpcChunking,
         ]
     }
 
@@ -70,6 +81,9 @@ impl fmt::Display for FeatureFlag {
             Self::EventsLog => "events.log",
             Self::OpsProgress => "ops.progress",
             Self::ComponentsBasic => "components.basic",
+            Self::IpcHeartbeat => "ipc.heartbeat",
+            Self::IpcCompression => "ipc.compression",
+            Self::IpcChunking => "ipc.chunking",
             Self::AssetsAdvanced => "assets.advanced",
             Self::BuildFull => "build.full",
             Self::EventsFull => "events.full",
@@ -79,7 +93,7 @@ impl fmt::Display for FeatureFlag {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FeatureSet {
     features: HashSet<FeatureFlag>,
 }
@@ -113,6 +127,11 @@ impl FeatureSet {
         Self { features }
     }
 
+    pub fn union(&self, other: &Self) -> Self {
+        let features = self.features.union(&other.features).cloned().collect();
+        Self { features }
+    }
+
     pub fn contains(&self, feature: &FeatureFlag) -> bool {
         self.features.contains(feature)
     }
@@ -121,6 +140,10 @@ impl FeatureSet {
         self.features.insert(feature);
     }
 
+    pub fn remove(&mut self, feature: &FeatureFlag) {
+        self.features.remove(feature);
+    }
+
     pub fn supported_by_client() -> Self {
         let features = FeatureFlag::is_supported_by_client().into_iter().collect();
         Self { features }
@@ -160,6 +183,18 @@ mod tests {
             FeatureFlag::from_string("components.basic"),
             FeatureFlag::ComponentsBasic
         );
+        assert_eq!(
+            FeatureFlag::from_string("ipc.heartbeat"),
+            FeatureFlag::IpcHeartbeat
+        );
+        assert_eq!(
+            FeatureFlag::from_string("ipc.compression"),
+            FeatureFlag::IpcCompression
+        );
+        assert_eq!(
+            FeatureFlag::from_string("ipc.chunking"),
+            FeatureFlag::IpcChunking
+        );
 
         match FeatureFlag::from_string("unknown.feature") {
             FeatureFlag::Unknown(s) => assert_eq!(s, "unknown.feature"),
@@ -175,6 +210,9 @@ mod tests {
         assert_eq!(FeatureFlag::EventsLog.to_string(), "events.log");
         assert_eq!(FeatureFlag::OpsProgress.to_string(), "ops.progress");
         assert_eq!(FeatureFlag::ComponentsBasic.to_string(), "components.basic");
+        assert_eq!(FeatureFlag::IpcHeartbeat.to_string(), "ipc.heartbeat");
+        assert_eq!(FeatureFlag::IpcCompression.to_string(), "ipc.compression");
+        assert_eq!(FeatureFlag::IpcChunking.to_string(), "ipc.chunking");
     }
 
     #[test]
@@ -225,6 +263,10 @@ mod tests {
         assert!(negotiated.contains(&FeatureFlag::EventsLog));
         assert!(!negotiated.contains(&FeatureFlag::BuildMin));
         assert!(!negotiated.contains(&FeatureFlag::OpsProgress));
+
+        let either = client_features.union(&server_features);
+        assert!(either.contains(&FeatureFlag::BuildMin));
+        assert!(either.contains(&FeatureFlag::OpsProgress));
     }
 
     #[test]
@@ -235,6 +277,9 @@ mod tests {
         assert!(client_features.contains(&FeatureFlag::BuildMin));
         assert!(client_features.contains(&FeatureFlag::EventsLog));
         assert!(client_features.contains(&FeatureFlag::OpsProgress));
+        assert!(client_features.contains(&FeatureFlag::IpcHeartbeat));
+        assert!(client_features.contains(&FeatureFlag::IpcCompression));
+        assert!(client_features.contains(&FeatureFlag::IpcChunking));
         assert!(!client_features.contains(&FeatureFlag::AssetsAdvanced));
     }
 }
@@ -0,0 +1,270 @@
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use std::io;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_util::codec::{Decoder, Encoder, Framed, LengthDelimitedCodec};
+
+pub type FramedIo<T> = Framed<T, LengthDelimitedCodec>;
+
+/// Default maximum frame payload (same as `LengthDelimitedCodec`'s default).
+pub const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+/// Length prefix: 4-byte big-endian, as written by the Unity bridge.
+const HEADER_LEN: usize = 4;
+
+/// How much of an oversized frame we look at to recover its correlation id.
+const CORRELATION_PEEK_LEN: usize = 256;
+
+pub fn codec() -> LengthDelimitedCodec {
+    codec_with_max_frame_len(DEFAULT_MAX_FRAME_LEN)
+}
+
+pub fn codec_with_max_frame_len(max_frame_len: usize) -> LengthDelimitedCodec {
+    LengthDelimitedCodec::builder()
+        .max_frame_length(max_frame_len)
+        .new_codec()
+}
+
+pub fn into_framed<T>(io: T) -> FramedIo<T>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    Framed::new(io, codec())
+}
+
+/// One frame read by [`FrameCodec`].
+#[derive(Debug)]
+pub enum Frame {
+    Data(BytesMut),
+    /// Frame over the limit; its payload is skipped without reading it into memory.
+    Oversized(OversizedFrame),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OversizedFrame {
+    pub len: usize,
+    /// `IpcEnvelope.correlation_id` if it could be read from the start of the frame
+    pub correlation_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DecodeState {
+    Head,
+    Data(usize),
+    Discard(usize),
+}
+
+/// Length-delimited codec that survives oversized frames.
+///
+/// `LengthDelimitedCodec` fails the stream on a frame over its limit, which tears down
+/// the whole IPC connection. This codec reports the frame as [`Frame::Oversized`],
+/// skips its payload and keeps decoding the frames after it.
+#[derive(Debug)]
+pub struct FrameCodec {
+    max_frame_len: usize,
+    state: DecodeState,
+}
+
+impl FrameCodec {
+    pub fn new(max_frame_len: usize) -> Self {
+        Self {
+            max_frame_len,
+            state: DecodeState::Head,
+        }
+    }
+
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+}
+
+impl Default for FrameCodec {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FRAME_LEN)
+    }
+}
+
+impl Decoder for FrameCodec {
+    type Item = Frame;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Frame>, io::Error> {
+        loop {
+            match self.state {
+                DecodeState::Head => {
+                    if src.len() < HEADER_LEN {
+                        return Ok(None);
+                    }
+                    let len = u32::from_be_bytes([src[0], src[1], src[2], src[3]]) as usize;
+                    if len > self.max_frame_len {
+                        let peek = len.min(CORRELATION_PEEK_LEN);
+                        if src.len() < HEADER_LEN + peek {
+                            src.reserve(HEADER_LEN + peek - src.len());
+                            return Ok(None);
+                        }
+                        let correlation_id =
+                            peek_correlation_id(&src[HEADER_LEN..HEADER_LEN + peek]);
+                        src.advance(HEADER_LEN);
+                        self.state = DecodeState::Discard(len);
+                        return Ok(Some(Frame::Oversized(OversizedFrame {
+                            len,
+                            correlation_id,
+                        })));
+                    }
+                    src.advance(HEADER_LEN);
+                    src.reserve(len);
+                    self.state = DecodeState::Data(len);
+                }
+                DecodeState::Data(len) => {
+                    if src.len() < len {
+                        return Ok(None);
+                    }
+                    self.state = DecodeState::Head;
+                    return Ok(Some(Frame::Data(src.split_to(len))));
+                }
+                DecodeState::Discard(remaining) => {
+                    let n = remaining.min(src.len());
+                    src.advance(n);
+                    if n < remaining {
+                        self.state = DecodeState::Discard(remaining - n);
+                        return Ok(None);
+                    }
+                    self.state = DecodeState::Head;
+                }
+            }
+        }
+    }
+}
+
+impl Encoder<Bytes> for FrameCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, data: Bytes, dst: &mut BytesMut) -> Result<(), io::Error> {
+        if data.len() > self.max_frame_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "frame of {} bytes exceeds limit of {} bytes",
+                    data.len(),
+                    self.max_frame_len
+                ),
+            ));
+        }
+        dst.reserve(HEADER_LEN + data.len());
+        dst.put_u32(data.len() as u32);
+        dst.extend_from_slice(&data);
+        Ok(())
+    }
+}
+
+/// Read `IpcEnvelope.correlation_id` (field 1, always encoded first) from a frame prefix.
+fn peek_correlation_id(mut prefix: &[u8]) -> Option<String> {
+    const CORRELATION_ID_TAG: u8 = (1 << 3) | 2; // field 1, length-delimited
+    if prefix.first() != Some(&CORRELATION_ID_TAG) {
+        return None;
+    }
+    prefix.advance(1);
+    let len = prost::encoding::decode_varint(&mut prefix).ok()? as usize;
+    let id = prefix.get(..len)?;
+    String::from_utf8(id.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec;
+    use crate::generated::mcp::unity::v1 as pb;
+
+    fn response_frame(correlation_id: &str, version: String) -> Bytes {
+        let env = pb::IpcEnvelope {
+            correlation_id: correlation_id.to_string(),
+            kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                correlation_id: correlation_id.to_string(),
+                payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                    version,
+                    ..Default::default()
+                })),
+            })),
+        };
+        codec::encode_envelope(&env).unwrap()
+    }
+
+    #[test]
+    fn test_oversized_frame_is_skipped_and_stream_continues() {
+        let mut codec = FrameCodec::new(128);
+        let mut buf = BytesMut::new();
+        codec
+            .encode(
+                response_frame("big", "x".repeat(1024)),
+                &mut BytesMut::new(),
+            )
+            .unwrap_err();
+
+        // Write the oversized frame with a permissive encoder, followed by a normal one
+        let mut wide = FrameCodec::new(usize::MAX);
+        let big = response_frame("cid-big", "x".repeat(1024));
+        let big_len = big.len();
+        wide.encode(big, &mut buf).unwrap();
+        wide.encode(response_frame("cid-small", "1.0".into()), &mut buf)
+            .unwrap();
+
+        match codec.decode(&mut buf).unwrap() {
+            Some(Frame::Oversized(o)) => {
+                assert_eq!(o.len, big_len);
+                assert_eq!(o.correlation_id.as_deref(), Some("cid-big"));
+            }
+            other => panic!("expected oversized frame, got {other:?}"),
+        }
+        match codec.decode(&mut buf).unwrap() {
+            Some(Frame::Data(bytes)) => {
+                let env = codec::decode_envelope(bytes.freeze()).unwrap();
+                assert_eq!(env.correlation_id, "cid-small");
+            }
+            other => panic!("expected data frame, got {other:?}"),
+        }
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_partial_frames_wait_for_more_data() {
+        let mut codec = FrameCodec::new(16);
+        let mut wire = BytesMut::new();
+        FrameCodec::new(usize::MAX)
+            .encode(Bytes::from(vec![7u8; 64]), &mut wire)
+            .unwrap();
+        FrameCodec::new(usize::MAX)
+            .encode(Bytes::from_static(b"ok"), &mut wire)
+            .unwrap();
+
+        // Feed the bytes one at a time
+        let mut buf = BytesMut::new();
+        let mut frames = Vec::new();
+        for b in wire {
+            buf.put_u8(b);
+            while let Some(frame) = codec.decode(&mut buf).unwrap() {
+                frames.push(frame);
+            }
+        }
+        assert_eq!(frames.len(), 2);
+        assert!(matches!(
+            &frames[0],
+            Frame::Oversized(OversizedFrame {
+                len: 64,
+                correlation_id: None
+            })
+        ));
+        assert!(matches!(&frames[1], Frame::Data(d) if &d[..] == b"ok"));
+    }
+
+    #[test]
+    fn test_frame_codec_matches_length_delimited_wire_format() {
+        let mut ours = BytesMut::new();
+        FrameCodec::default()
+            .encode(Bytes::from_static(b"hello"), &mut ours)
+            .unwrap();
+        let mut theirs = BytesMut::new();
+        codec()
+            .encode(Bytes::from_static(b"hello"), &mut theirs)
+            .unwrap();
+        assert_eq!(ours, theirs);
+    }
+}
@@ -305,6 +305,9 @@ pub struct ImportAssetRequest {
     pub recursive: bool,
     #[prost(bool, tag = "3")]
     pub auto_refresh: bool,
+    /// id to report the operation's events under; empty = bridge assigns
+    #[prost(string, tag = "4")]
+    pub op_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ImportAssetResult {
@@ -321,6 +324,9 @@ pub struct ImportAssetResult {
 pub struct ImportAssetResponse {
     #[prost(message, repeated, tag = "1")]
     pub results: ::prost::alloc::vec::Vec<ImportAssetResult>,
+    /// id the operation's events were reported under
+    #[prost(string, tag = "2")]
+    pub op_id: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct MoveAssetRequest {
@@ -567,6 +573,9 @@ pub struct BuildPlayerRequest {
         ::prost::alloc::string::String,
         ::prost::alloc::string::String,
     >,
+    /// id to report the operation's events under; empty = bridge assigns
+    #[prost(string, tag = "6")]
+    pub op_id: ::prost::alloc::string::String,
 }
 /// Player build response
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -586,6 +595,9 @@ pub struct BuildPlayerResponse {
     pub size_bytes: u64,
     #[prost(string, repeated, tag = "6")]
     pub warnings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// id the operation's events were reported under
+    #[prost(string, tag = "7")]
+    pub op_id: ::prost::alloc::string::String,
 }
 /// AssetBundles build request
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -602,6 +614,9 @@ pub struct BuildAssetBundlesRequest {
     /// ForceRebuildAssetBundle
     #[prost(bool, tag = "4")]
     pub force_rebuild: bool,
+    /// id to report the operation's events under; empty = bridge assigns
+    #[prost(string, tag = "5")]
+    pub op_id: ::prost::alloc::string::String,
 }
 /// AssetBundles build response
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
@@ -614,6 +629,9 @@ pub struct BuildAssetBundlesResponse {
     pub output_directory: ::prost::alloc::string::String,
     #[prost(uint64, tag = "4")]
     pub build_time_ms: u64,
+    /// id the operation's events were reported under
+    #[prost(string, tag = "5")]
+    pub op_id: ::prost::alloc::string::String,
 }
 /// Unified build request
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -915,155 +933,9 @@ pub mod component_response {
         Remove(super::RemoveComponentResponse),
     }
 }
-/// Top-level envelope for all IPC messages
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct IpcEnvelope {
-    /// Empty for handshake messages
-    #[prost(string, tag = "1")]
-    pub correlation_id: ::prost::alloc::string::String,
-    #[prost(oneof = "ipc_envelope::Kind", tags = "2, 3, 4")]
-    pub kind: ::core::option::Option<ipc_envelope::Kind>,
-}
-/// Nested message and enum types in `IpcEnvelope`.
-pub mod ipc_envelope {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Kind {
-        #[prost(message, tag = "2")]
-        Request(super::IpcRequest),
-        #[prost(message, tag = "3")]
-        Response(super::IpcResponse),
-        #[prost(message, tag = "4")]
-        Event(super::IpcEvent),
-    }
-}
-/// Request message with typed payloads
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct IpcRequest {
-    #[prost(
-        oneof = "ipc_request::Payload",
-        tags = "1, 10, 11, 12, 13, 14, 15, 16, 17, 20, 21, 30, 40, 41, 50"
-    )]
-    pub payload: ::core::option::Option<ipc_request::Payload>,
-}
-/// Nested message and enum types in `IpcRequest`.
-pub mod ipc_request {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Payload {
-        /// Health check
-        #[prost(message, tag = "1")]
-        Health(super::HealthRequest),
-        /// Editor control
-        #[prost(message, tag = "10")]
-        GetPlayMode(super::Empty),
-        #[prost(message, tag = "11")]
-        SetPlayMode(super::SetPlayModeRequest),
-        #[prost(message, tag = "12")]
-        GetCompileDiagnostics(super::GetCompileDiagnosticsRequest),
-        #[prost(message, tag = "13")]
-        GetProjectSettings(super::GetProjectSettingsRequest),
-        #[prost(message, tag = "14")]
-        SetProjectSettings(super::SetProjectSettingsRequest),
-        #[prost(message, tag = "15")]
-        Scenes(super::ScenesRequest),
-        #[prost(message, tag = "16")]
-        ExecuteMenuItem(super::ExecuteMenuItemRequest),
-        #[prost(message, tag = "17")]
-        FocusWindow(super::FocusWindowRequest),
-        /// Assets
-        #[prost(message, tag = "20")]
-        Assets(super::AssetsRequest),
-        /// Prefab
-        #[prost(message, tag = "21")]
-        Prefab(super::PrefabRequest),
-        /// Build
-        #[prost(message, tag = "30")]
-        Build(super::BuildRequest),
-        /// Operations
-        #[prost(message, tag = "40")]
-        OperationGet(super::OperationGetRequest),
-        #[prost(message, tag = "41")]
-        OperationCancel(super::OperationCancelRequest),
-        /// Components
-        #[prost(message, tag = "50")]
-        Component(super::ComponentRequest),
-    }
-}
-/// Response message with typed payloads
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct IpcResponse {
-    /// Matches the request correlation_id
-    #[prost(string, tag = "1")]
-    pub correlation_id: ::prost::alloc::string::String,
-    #[prost(
-        oneof = "ipc_response::Payload",
-        tags = "2, 10, 11, 12, 13, 14, 15, 16, 17, 20, 21, 30, 40, 41, 50"
-    )]
-    pub payload: ::core::option::Option<ipc_response::Payload>,
-}
-/// Nested message and enum types in `IpcResponse`.
-pub mod ipc_response {
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Payload {
-        /// Health check
-        #[prost(message, tag = "2")]
-        Health(super::HealthResponse),
-        /// Editor control
-        #[prost(message, tag = "10")]
-        GetPlayMode(super::GetPlayModeResponse),
-        #[prost(message, tag = "11")]
-        SetPlayMode(super::SetPlayModeResponse),
-        #[prost(message, tag = "12")]
-        GetCompileDiagnostics(super::GetCompileDiagnosticsResponse),
-        #[prost(message, tag = "13")]
-        GetProjectSettings(super::GetProjectSettingsResponse),
-        #[prost(message, tag = "14")]
-        SetProjectSettings(super::SetProjectSettingsResponse),
-        #[prost(message, tag = "15")]
-        Scenes(super::ScenesResponse),
-        #[prost(message, tag = "16")]
-        ExecuteMenuItem(super::ExecuteMenuItemResponse),
-        #[prost(message, tag = "17")]
-        FocusWindow(super::FocusWindowResponse),
-        /// Assets
-        #[prost(message, tag = "20")]
-        Assets(super::AssetsResponse),
-        /// Prefab
-        #[prost(message, tag = "21")]
-        Prefab(super::PrefabResponse),
-        /// Build
-        #[prost(message, tag = "30")]
-        Build(super::BuildResponse),
-        /// Operations
-        #[prost(message, tag = "40")]
-        OperationGet(super::OperationGetResponse),
-        #[prost(message, tag = "41")]
-        OperationCancel(super::OperationCancelResponse),
-        /// Components
-        #[prost(message, tag = "50")]
-        Component(super::ComponentResponse),
-    }
-}
-/// Event message for server-to-client notifications
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct IpcEvent {
-    #[prost(int64, tag = "1")]
-    pub monotonic_ts_ns: i64,
-    #[prost(oneof = "ipc_event::Payload", tags = "10, 11")]
-    pub payload: ::core::option::Option<ipc_event::Payload>,
-}
-/// Nested message and enum types in `IpcEvent`.
-pub mod ipc_event {
-    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
-    pub enum Payload {
-        #[prost(message, tag = "10")]
-        Log(super::LogEvent),
-        #[prost(message, tag = "11")]
-        Op(super::OperationEvent),
-    }
-}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IpcControl {
-    #[prost(oneof = "ipc_control::Kind", tags = "1, 2, 3")]
+    #[prost(oneof = "ipc_control::Kind", tags = "1, 2, 3, 4, 5")]
     pub kind: ::core::option::Option<ipc_control::Kind>,
 }
 /// Nested message and enum types in `IpcControl`.
@@ -1076,6 +948,11 @@ pub mod ipc_control {
         Welcome(super::IpcWelcome),
         #[prost(message, tag = "3")]
         Reject(super::IpcReject),
+        /// Heartbeat (after handshake, negotiated via "ipc.heartbeat")
+        #[prost(message, tag = "4")]
+        Ping(super::IpcPing),
+        #[prost(message, tag = "5")]
+        Pong(super::IpcPong),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -1209,6 +1086,251 @@ pub mod ipc_reject {
         }
     }
 }
+/// Keepalive probe. Either side may send it once "ipc.heartbeat" is negotiated;
+/// the peer must answer with an IpcPong echoing the nonce.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IpcPing {
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    /// sender clock; echoed back for RTT
+    #[prost(int64, tag = "2")]
+    pub sent_monotonic_ns: i64,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IpcPong {
+    /// echoed from IpcPing
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    /// echoed from IpcPing
+    #[prost(int64, tag = "2")]
+    pub ping_sent_monotonic_ns: i64,
+}
+/// Top-level envelope for all IPC messages
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IpcEnvelope {
+    /// Empty for handshake messages
+    #[prost(string, tag = "1")]
+    pub correlation_id: ::prost::alloc::string::String,
+    #[prost(oneof = "ipc_envelope::Kind", tags = "2, 3, 4, 5, 6, 7")]
+    pub kind: ::core::option::Option<ipc_envelope::Kind>,
+}
+/// Nested message and enum types in `IpcEnvelope`.
+pub mod ipc_envelope {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(message, tag = "2")]
+        Request(super::IpcRequest),
+        #[prost(message, tag = "3")]
+        Response(super::IpcResponse),
+        #[prost(message, tag = "4")]
+        Event(super::IpcEvent),
+        /// Post-handshake control frames (heartbeat ping/pong)
+        #[prost(message, tag = "5")]
+        Control(super::IpcControl),
+        /// Whole envelope compressed ("ipc.compression"); only used above a size threshold
+        #[prost(message, tag = "6")]
+        Compressed(super::CompressedEnvelope),
+        /// Piece of a response too large for one frame ("ipc.chunking")
+        #[prost(message, tag = "7")]
+        ResponseChunk(super::IpcResponseChunk),
+    }
+}
+/// Ordered piece of a large response. All chunks of a response share the
+/// envelope correlation_id; concatenating `data` in index order yields the
+/// serialized IpcResponse.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IpcResponseChunk {
+    /// 0-based, consecutive
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    /// size of the whole serialized IpcResponse
+    #[prost(uint64, tag = "2")]
+    pub total_size: u64,
+    #[prost(bool, tag = "3")]
+    pub last: bool,
+    #[prost(bytes = "vec", tag = "4")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// Compressed form of a serialized IpcEnvelope. The outer envelope keeps the
+/// correlation_id so the frame can be attributed without decompressing it.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CompressedEnvelope {
+    #[prost(enumeration = "compressed_envelope::Algorithm", tag = "1")]
+    pub algorithm: i32,
+    #[prost(uint32, tag = "2")]
+    pub uncompressed_size: u32,
+    /// serialized IpcEnvelope
+    #[prost(bytes = "vec", tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `CompressedEnvelope`.
+pub mod compressed_envelope {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Algorithm {
+        Unspecified = 0,
+        /// raw deflate (RFC 1951)
+        Deflate = 1,
+        Zstd = 2,
+    }
+    impl Algorithm {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "ALGORITHM_UNSPECIFIED",
+                Self::Deflate => "DEFLATE",
+                Self::Zstd => "ZSTD",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "ALGORITHM_UNSPECIFIED" => Some(Self::Unspecified),
+                "DEFLATE" => Some(Self::Deflate),
+                "ZSTD" => Some(Self::Zstd),
+                _ => None,
+            }
+        }
+    }
+}
+/// Request message with typed payloads
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IpcRequest {
+    #[prost(
+        oneof = "ipc_request::Payload",
+        tags = "1, 10, 11, 12, 13, 14, 15, 16, 17, 20, 21, 30, 40, 41, 50"
+    )]
+    pub payload: ::core::option::Option<ipc_request::Payload>,
+}
+/// Nested message and enum types in `IpcRequest`.
+pub mod ipc_request {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        /// Health check
+        #[prost(message, tag = "1")]
+        Health(super::HealthRequest),
+        /// Editor control
+        #[prost(message, tag = "10")]
+        GetPlayMode(super::Empty),
+        #[prost(message, tag = "11")]
+        SetPlayMode(super::SetPlayModeRequest),
+        #[prost(message, tag = "12")]
+        GetCompileDiagnostics(super::GetCompileDiagnosticsRequest),
+        #[prost(message, tag = "13")]
+        GetProjectSettings(super::GetProjectSettingsRequest),
+        #[prost(message, tag = "14")]
+        SetProjectSettings(super::SetProjectSettingsRequest),
+        #[prost(message, tag = "15")]
+        Scenes(super::ScenesRequest),
+        #[prost(message, tag = "16")]
+        ExecuteMenuItem(super::ExecuteMenuItemRequest),
+        #[prost(message, tag = "17")]
+        FocusWindow(super::FocusWindowRequest),
+        /// Assets
+        #[prost(message, tag = "20")]
+        Assets(super::AssetsRequest),
+        /// Prefab
+        #[prost(message, tag = "21")]
+        Prefab(super::PrefabRequest),
+        /// Build
+        #[prost(message, tag = "30")]
+        Build(super::BuildRequest),
+        /// Operations
+        #[prost(message, tag = "40")]
+        OperationGet(super::OperationGetRequest),
+        #[prost(message, tag = "41")]
+        OperationCancel(super::OperationCancelRequest),
+        /// Components
+        #[prost(message, tag = "50")]
+        Component(super::ComponentRequest),
+    }
+}
+/// Response message with typed payloads
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IpcResponse {
+    /// Matches the request correlation_id
+    #[prost(string, tag = "1")]
+    pub correlation_id: ::prost::alloc::string::String,
+    #[prost(
+        oneof = "ipc_response::Payload",
+        tags = "2, 10, 11, 12, 13, 14, 15, 16, 17, 20, 21, 30, 40, 41, 50"
+    )]
+    pub payload: ::core::option::Option<ipc_response::Payload>,
+}
+/// Nested message and enum types in `IpcResponse`.
+pub mod ipc_response {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Payload {
+        /// Health check
+        #[prost(message, tag = "2")]
+        Health(super::HealthResponse),
+        /// Editor control
+        #[prost(message, tag = "10")]
+        GetPlayMode(super::GetPlayModeResponse),
+        #[prost(message, tag = "11")]
+        SetPlayMode(super::SetPlayModeResponse),
+        #[prost(message, tag = "12")]
+        GetCompileDiagnostics(super::GetCompileDiagnosticsResponse),
+        #[prost(message, tag = "13")]
+        GetProjectSettings(super::GetProjectSettingsResponse),
+        #[prost(message, tag = "14")]
+        SetProjectSettings(super::SetProjectSettingsResponse),
+        #[prost(message, tag = "15")]
+        Scenes(super::ScenesResponse),
+        #[prost(message, tag = "16")]
+        ExecuteMenuItem(super::ExecuteMenuItemResponse),
+        #[prost(message, tag = "17")]
+        FocusWindow(super::FocusWindowResponse),
+        /// Assets
+        #[prost(message, tag = "20")]
+        Assets(super::AssetsResponse),
+        /// Prefab
+        #[prost(message, tag = "21")]
+        Prefab(super::PrefabResponse),
+        /// Build
+        #[prost(message, tag = "30")]
+        Build(super::BuildResponse),
+        /// Operations
+        #[prost(message, tag = "40")]
+        OperationGet(super::OperationGetResponse),
+        #[prost(message, tag = "41")]
+        OperationCancel(super::OperationCancelResponse),
+        /// Components
+        #[prost(message, tag = "50")]
+        Component(super::ComponentResponse),
+    }
+}
+/// Event message for server-to-client notifications
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IpcEvent {
+    #[prost(int64, tag = "1")]
+    pub monotonic_ts_ns: i64,
+    #[prost(oneof = "ipc_event::Payload", tags = "10, 11")]
+    pub payload: ::core::option::Option<ipc_event::Payload>,
+}
+/// Nested message and enum types in `IpcEvent`.
+pub mod ipc_event {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Payload {
+        #[prost(message, tag = "10")]
+        Log(super::LogEvent),
+        #[prost(message, tag = "11")]
+        Op(super::OperationEvent),
+    }
+}
 /// Test execution request
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct RunTestsRequest {
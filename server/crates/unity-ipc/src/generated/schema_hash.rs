@@ -0,0 +1 @@
+pub const SCHEMA_HASH: [u8; 32] = [177, 248, 153, 164, 226, 214, 186, 150, 26, 215, 23, 4, 245, 52, 1, 196, 140, 61, 7, 212, 134, 236, 163, 15, 156, 199, 91, 63, 24, 28, 133, 178];
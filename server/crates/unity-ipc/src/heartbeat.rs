@@ -0,0 +1,182 @@
+//! ping/pong keepalive over an established IPC connection ("ipc.heartbeat").
+//!
+//! 半開きの TCP 接続はリクエストがタイムアウトするまで気づけないため、
+//! 一定間隔で `IpcPing` を送り、`IpcPong` が連続して返らなければ接続断とみなす。
+
+use bytes::Bytes;
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tokio::{sync::mpsc, time};
+use tokio_util::sync::CancellationToken;
+
+use super::codec;
+use crate::generated::mcp::unity::v1 as pb;
+
+/// Per-connection heartbeat bookkeeping shared by the ping task and the reader.
+#[derive(Debug)]
+pub(crate) struct Heartbeat {
+    started: Instant,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    next_nonce: u64,
+    /// Ping still waiting for its pong: (nonce, sent at)
+    outstanding: Option<(u64, Instant)>,
+    missed: u32,
+}
+
+impl Heartbeat {
+    pub(crate) fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Start a new ping. An unanswered previous ping counts as missed.
+    ///
+    /// Returns the ping to send and the number of consecutive misses so far.
+    pub(crate) fn next_ping(&self) -> (pb::IpcPing, u32) {
+        let mut state = self.lock();
+        if state.outstanding.is_some() {
+            state.missed += 1;
+        }
+        state.next_nonce += 1;
+        let nonce = state.next_nonce;
+        let now = Instant::now();
+        state.outstanding = Some((nonce, now));
+        let ping = pb::IpcPing {
+            nonce,
+            sent_monotonic_ns: now.duration_since(self.started).as_nanos() as i64,
+        };
+        (ping, state.missed)
+    }
+
+    /// Record a pong; returns the round-trip time if it answers the outstanding ping.
+    pub(crate) fn on_pong(&self, pong: &pb::IpcPong) -> Option<Duration> {
+        let mut state = self.lock();
+        match state.outstanding {
+            Some((nonce, sent)) if nonce == pong.nonce => {
+                state.outstanding = None;
+                state.missed = 0;
+                Some(sent.elapsed())
+            }
+            // Late pong of a ping we already gave up on
+            _ => None,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Encode a control frame for the post-handshake envelope stream.
+pub(crate) fn encode_control_frame(kind: pb::ipc_control::Kind) -> Option<Bytes> {
+    let env = pb::IpcEnvelope {
+        correlation_id: String::new(),
+        kind: Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+            kind: Some(kind),
+        })),
+    };
+    codec::encode_envelope(&env).ok()
+}
+
+/// Answer a peer's ping by echoing its nonce and timestamp.
+pub(crate) fn pong_for(ping: &pb::IpcPing) -> pb::ipc_control::Kind {
+    pb::ipc_control::Kind::Pong(pb::IpcPong {
+        nonce: ping.nonce,
+        ping_sent_monotonic_ns: ping.sent_monotonic_ns,
+    })
+}
+
+/// Send pings every `interval` until `closed` is cancelled.
+///
+/// After `miss_threshold` consecutive unanswered pings the connection is declared
+/// dead by cancelling `closed`, which hands it to the reconnect supervisor.
+pub(crate) async fn run(
+    heartbeat: std::sync::Arc<Heartbeat>,
+    interval: Duration,
+    miss_threshold: u32,
+    control_tx: mpsc::Sender<Bytes>,
+    closed: CancellationToken,
+) {
+    let mut ticker = time::interval(interval);
+    ticker.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+    loop {
+        tokio::select! {
+            _ = closed.cancelled() => return,
+            _ = ticker.tick() => {}
+        }
+
+        let (ping, missed) = heartbeat.next_ping();
+        if missed >= miss_threshold {
+            tracing::warn!(
+                "IPC heartbeat: {} consecutive pings unanswered, dropping connection",
+                missed
+            );
+            closed.cancel();
+            return;
+        }
+        let Some(bytes) = encode_control_frame(pb::ipc_control::Kind::Ping(ping)) else {
+            continue;
+        };
+        if control_tx.send(bytes).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pong_resets_misses_and_reports_rtt() {
+        let hb = Heartbeat::new();
+        let (first, missed) = hb.next_ping();
+        assert_eq!(missed, 0);
+        let (second, missed) = hb.next_ping();
+        assert_eq!(missed, 1);
+        assert_ne!(first.nonce, second.nonce);
+
+        // A pong for the abandoned ping does not count
+        let late = pb::IpcPong {
+            nonce: first.nonce,
+            ping_sent_monotonic_ns: first.sent_monotonic_ns,
+        };
+        assert!(hb.on_pong(&late).is_none());
+
+        let pong = pb::IpcPong {
+            nonce: second.nonce,
+            ping_sent_monotonic_ns: second.sent_monotonic_ns,
+        };
+        assert!(hb.on_pong(&pong).is_some());
+        let (_, missed) = hb.next_ping();
+        assert_eq!(missed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_unanswered_pings_close_connection() {
+        let hb = std::sync::Arc::new(Heartbeat::new());
+        let (tx, mut rx) = mpsc::channel(16);
+        let closed = CancellationToken::new();
+        let task = tokio::spawn(run(hb, Duration::from_millis(10), 2, tx, closed.clone()));
+
+        time::timeout(Duration::from_secs(2), closed.cancelled())
+            .await
+            .expect("connection should be declared dead");
+        task.await.unwrap();
+
+        // Two pings went out before giving up
+        let mut pings = 0;
+        while rx.try_recv().is_ok() {
+            pings += 1;
+        }
+        assert_eq!(pings, 2);
+    }
+}
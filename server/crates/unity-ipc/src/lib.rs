@@ -0,0 +1,44 @@
+//! Client for the Unity MCP bridge IPC protocol.
+//!
+//! Unity Editor 上の Bridge に接続し、型付きのリクエスト（[`IpcClient::health`] など）、
+//! イベントの購読（[`IpcClient::events`]）、操作の進捗の購読
+//! （[`IpcClient::subscribe_operation`]）を提供する。MCP サーバー（`server` クレート）は
+//! このクレートの上に構築されている。
+//!
+//! 設定は既定で環境変数（`MCP_IPC_*`）から読む。[`settings::set_source`] で差し替えられる。
+
+#![allow(clippy::derive_partial_eq_without_eq)]
+
+// Prost generated modules
+pub mod generated {
+    pub mod mcp {
+        pub mod unity {
+            pub mod v1 {
+                include!("generated/mcp.unity.v1.rs");
+            }
+        }
+    }
+    pub mod schema_hash {
+        include!("generated/schema_hash.rs");
+    }
+}
+
+pub mod chunking;
+pub mod client;
+pub mod codec;
+pub mod discovery;
+pub mod dispatch;
+pub mod features;
+pub mod framing;
+mod heartbeat;
+pub mod path;
+pub mod record;
+pub mod settings;
+pub mod tap;
+pub mod tls;
+pub mod token;
+pub mod ws;
+
+pub use client::{IpcClient, IpcError, OperationSubscription};
+pub use generated::mcp::unity::v1 as pb;
+pub use path::IpcConfig;
@@ -0,0 +1,408 @@
+use std::path::PathBuf;
+use std::{env, fmt, time::Duration};
+
+use super::chunking::DEFAULT_MAX_RESPONSE_LEN;
+use super::framing::DEFAULT_MAX_FRAME_LEN;
+use super::tls::TlsOptions;
+use super::token;
+use crate::settings;
+
+/// Envelopes smaller than this are sent as-is even when compression is negotiated.
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
+
+#[derive(Debug, Clone)]
+pub enum Endpoint {
+    #[cfg(unix)]
+    Unix(PathBuf),
+    #[cfg(windows)]
+    Pipe(String),
+    Tcp(String),       // host:port (dev fallback)
+    Tls(String),       // host:port, TLS over TCP (IpcConfig::tls)
+    WebSocket(String), // full ws:// or wss:// URL
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(unix)]
+            Endpoint::Unix(p) => write!(f, "unix://{}", p.display()),
+            #[cfg(windows)]
+            Endpoint::Pipe(name) => write!(f, "pipe://{}", name),
+            Endpoint::Tcp(addr) => write!(f, "tcp://{}", addr),
+            Endpoint::Tls(addr) => write!(f, "tls://{}", addr),
+            Endpoint::WebSocket(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+impl Endpoint {
+    /// Whether the endpoint can only reach a process on this machine.
+    pub fn is_loopback(&self) -> bool {
+        match self {
+            #[cfg(unix)]
+            Endpoint::Unix(_) => true,
+            #[cfg(windows)]
+            Endpoint::Pipe(_) => true,
+            Endpoint::Tcp(addr) | Endpoint::Tls(addr) => host_is_loopback(addr),
+            Endpoint::WebSocket(url) => {
+                let rest = url.split_once("://").map_or(url.as_str(), |(_, r)| r);
+                host_is_loopback(rest.split('/').next().unwrap_or(rest))
+            }
+        }
+    }
+}
+
+/// Whether the host of `host:port`, `[v6]:port` or a bare host is this machine.
+pub fn host_is_loopback(addr: &str) -> bool {
+    let host = match addr.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(rest),
+        None => addr.rsplit_once(':').map_or(addr, |(host, _)| host),
+    };
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+#[derive(Clone)]
+pub struct IpcConfig {
+    pub endpoint: Option<String>, // raw string like "unix:///...", "pipe://...", "tcp://host:port", "ws://host:port/path"
+    pub token: Option<String>,
+    pub token_file: Option<PathBuf>, // token が無いときに読むファイル（他ユーザーから読めるものは拒否）
+    pub unity_project: Option<PathBuf>, // その次に EditorUserSettings の MCP.IpcToken を読むプロジェクト
+    pub connect_timeout: Duration,
+    pub handshake_timeout: Duration, // T01: hello送信後の応答待ち
+    pub total_handshake_timeout: Duration, // T01: 全体制限時間
+    pub call_timeout: Duration,
+    pub max_reconnect_attempts: Option<u32>, // Phase 3: 再接続試行回数制限
+    pub reconnect_initial_backoff: Duration, // 再接続の初回待ち時間（以後倍々）
+    pub reconnect_max_backoff: Duration,     // 再接続待ち時間の上限
+    pub heartbeat_interval: Duration,        // ping 送信間隔（ZERO で無効）
+    pub heartbeat_miss_threshold: u32,       // 連続で pong が欠けたら切断して再接続
+    pub max_frame_len: usize,                // 1 フレームの上限（超過分は読み捨てて接続は維持）
+    pub compression_threshold: Option<usize>, // これ以上のエンベロープを圧縮（None で ipc.compression を提示しない）
+    pub max_response_len: usize,              // チャンク分割された応答を再構成する際の上限
+    pub tls: TlsOptions, // tls:// / wss:// エンドポイントの CA・クライアント証明書
+    pub record: Option<PathBuf>, // 送受信したフレームを記録する JSON Lines ファイル（ipc.record）
+}
+
+impl Default for IpcConfig {
+    fn default() -> Self {
+        let is_ci = env::var("CI").is_ok();
+        Self {
+            endpoint: settings::var("MCP_IPC_ENDPOINT"),
+            // 正式: MCP_IPC_TOKEN。後方互換として誤記の MCP_IPC_TOKE も許容（config 側で解決）
+            token: settings::var("MCP_IPC_TOKEN"),
+            token_file: settings::var("MCP_IPC_TOKEN_FILE")
+                .filter(|p| !p.is_empty())
+                .map(PathBuf::from),
+            // 未指定ならカレントディレクトリを含む Unity プロジェクト、"off" で無効
+            unity_project: match settings::var("MCP_UNITY_PROJECT").as_deref() {
+                Some("off") => None,
+                Some(path) if !path.is_empty() => Some(PathBuf::from(path)),
+                _ => env::current_dir()
+                    .ok()
+                    .and_then(|cwd| token::find_unity_project(&cwd)),
+            },
+            // T01 準拠のタイムアウト設定
+            connect_timeout: Duration::from_millis(
+                settings::var("MCP_IPC_CONNECT_TIMEOUT_MS")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(if is_ci { 5000 } else { 2000 }),
+            ),
+            handshake_timeout: Duration::from_millis(
+                settings::var("MCP_IPC_HANDSHAKE_TIMEOUT_MS")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(2000),
+            ),
+            total_handshake_timeout: Duration::from_millis(
+                settings::var("MCP_IPC_TOTAL_HANDSHAKE_TIMEOUT_MS")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(if is_ci { 8000 } else { 3000 }),
+            ),
+            call_timeout: Duration::from_millis(
+                settings::var("MCP_IPC_CALL_TIMEOUT_MS")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(4000),
+            ),
+            max_reconnect_attempts: settings::var("MCP_IPC_MAX_RECONNECT_ATTEMPTS")
+                .and_then(|v| v.parse().ok())
+                .or(Some(10)), // Default to 10 attempts
+            reconnect_initial_backoff: Duration::from_millis(
+                settings::var("MCP_IPC_RECONNECT_INITIAL_BACKOFF_MS")
+                    .and_then(|v| v.parse().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or(200),
+            ),
+            reconnect_max_backoff: Duration::from_millis(
+                settings::var("MCP_IPC_RECONNECT_MAX_BACKOFF_MS")
+                    .and_then(|v| v.parse().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or(5000),
+            ),
+            heartbeat_interval: Duration::from_millis(
+                settings::var("MCP_IPC_HEARTBEAT_INTERVAL_MS")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(5000),
+            ),
+            heartbeat_miss_threshold: settings::var("MCP_IPC_HEARTBEAT_MISS_THRESHOLD")
+                .and_then(|v| v.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(3),
+            max_frame_len: settings::var("MCP_IPC_MAX_FRAME_BYTES")
+                .and_then(|v| v.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(DEFAULT_MAX_FRAME_LEN),
+            compression_threshold: match settings::var("MCP_IPC_COMPRESSION").as_deref() {
+                Some("off") | Some("0") | Some("false") => None,
+                _ => Some(
+                    settings::var("MCP_IPC_COMPRESSION_THRESHOLD_BYTES")
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(DEFAULT_COMPRESSION_THRESHOLD),
+                ),
+            },
+            max_response_len: settings::var("MCP_IPC_MAX_RESPONSE_BYTES")
+                .and_then(|v| v.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(DEFAULT_MAX_RESPONSE_LEN),
+            tls: TlsOptions::from_config(),
+            record: settings::var("MCP_IPC_RECORD")
+                .filter(|p| !p.is_empty())
+                .map(PathBuf::from),
+        }
+    }
+}
+
+// Hand-written so the token never ends up in logs
+impl fmt::Debug for IpcConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IpcConfig")
+            .field("endpoint", &self.endpoint)
+            .field("token", &self.token.as_ref().map(|_| "<redacted>"))
+            .field("token_file", &self.token_file)
+            .field("unity_project", &self.unity_project)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("handshake_timeout", &self.handshake_timeout)
+            .field("total_handshake_timeout", &self.total_handshake_timeout)
+            .field("call_timeout", &self.call_timeout)
+            .field("max_reconnect_attempts", &self.max_reconnect_attempts)
+            .field("reconnect_initial_backoff", &self.reconnect_initial_backoff)
+            .field("reconnect_max_backoff", &self.reconnect_max_backoff)
+            .field("heartbeat_interval", &self.heartbeat_interval)
+            .field("heartbeat_miss_threshold", &self.heartbeat_miss_threshold)
+            .field("max_frame_len", &self.max_frame_len)
+            .field("compression_threshold", &self.compression_threshold)
+            .field("max_response_len", &self.max_response_len)
+            .field("tls", &self.tls)
+            .field("record", &self.record)
+            .finish()
+    }
+}
+
+impl IpcConfig {
+    /// One config per Unity Editor to connect to.
+    ///
+    /// `MCP_IPC_ENDPOINTS` (comma separated) lists several editors; without it the
+    /// single `MCP_IPC_ENDPOINT` / default endpoint is used. All other settings are shared.
+    pub fn all_from_env() -> Vec<IpcConfig> {
+        let base = IpcConfig::default();
+        let endpoints = settings::var("MCP_IPC_ENDPOINTS")
+            .map(|raw| split_endpoints(&raw))
+            .unwrap_or_default();
+        if endpoints.is_empty() {
+            return vec![base];
+        }
+        endpoints
+            .into_iter()
+            .map(|endpoint| IpcConfig {
+                endpoint: Some(endpoint),
+                ..base.clone()
+            })
+            .collect()
+    }
+
+    /// Endpoint this config connects to, with the default applied.
+    pub fn resolved_endpoint(&self) -> Endpoint {
+        self.endpoint
+            .as_deref()
+            .map(parse_endpoint)
+            .unwrap_or_else(default_endpoint)
+    }
+}
+
+/// Split a comma separated endpoint list, dropping blanks and duplicates.
+pub fn split_endpoints(raw: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for endpoint in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        if !out.iter().any(|e| e == endpoint) {
+            out.push(endpoint.to_string());
+        }
+    }
+    out
+}
+
+pub fn default_endpoint() -> Endpoint {
+    if let Some(raw) = settings::var("MCP_IPC_ENDPOINT") {
+        return parse_endpoint(&raw);
+    }
+    // Use TCP as default for all platforms to match Unity bridge
+    Endpoint::Tcp("127.0.0.1:7777".to_string())
+}
+
+pub fn parse_endpoint(s: &str) -> Endpoint {
+    #[cfg(unix)]
+    {
+        if let Some(rest) = s.strip_prefix("unix://") {
+            return Endpoint::Unix(PathBuf::from(rest));
+        }
+    }
+    #[cfg(windows)]
+    {
+        if let Some(rest) = s.strip_prefix("pipe://") {
+            return Endpoint::Pipe(rest.to_string());
+        }
+    }
+    if let Some(rest) = s.strip_prefix("tcp://") {
+        return Endpoint::Tcp(rest.to_string());
+    }
+    if let Some(rest) = s.strip_prefix("tls://") {
+        return Endpoint::Tls(rest.to_string());
+    }
+    if s.starts_with("ws://") || s.starts_with("wss://") {
+        return Endpoint::WebSocket(s.to_string());
+    }
+    // Fallback: bare strings are treated as TCP host:port
+    Endpoint::Tcp(s.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_endpoint() {
+        let tcp = parse_endpoint("tcp://127.0.0.1:8080");
+        assert!(matches!(tcp, Endpoint::Tcp(addr) if addr == "127.0.0.1:8080"));
+
+        assert!(matches!(
+            parse_endpoint("tls://editor.lan:7777"),
+            Endpoint::Tls(addr) if addr == "editor.lan:7777"
+        ));
+        assert!(matches!(
+            parse_endpoint("wss://editor.lan:8443/unity"),
+            Endpoint::WebSocket(url) if url == "wss://editor.lan:8443/unity"
+        ));
+
+        let bare = parse_endpoint("localhost:3000");
+        assert!(matches!(bare, Endpoint::Tcp(addr) if addr == "localhost:3000"));
+
+        #[cfg(unix)]
+        {
+            let unix = parse_endpoint("unix:///tmp/test.sock");
+            assert!(matches!(
+                unix,
+                Endpoint::Unix(path) if path == std::path::Path::new("/tmp/test.sock")
+            ));
+        }
+
+        #[cfg(windows)]
+        {
+            let pipe = parse_endpoint("pipe://test-pipe");
+            assert!(matches!(pipe, Endpoint::Pipe(name) if name == "test-pipe"));
+        }
+    }
+
+    #[test]
+    fn test_endpoint_is_loopback() {
+        for local in [
+            "tcp://127.0.0.1:7777",
+            "tcp://localhost:7777",
+            "tcp://[::1]:7777",
+            "tls://127.0.0.2:7777",
+            "ws://127.0.0.1:7777/ipc",
+        ] {
+            assert!(parse_endpoint(local).is_loopback(), "{local}");
+        }
+        for remote in [
+            "tcp://10.0.0.5:7777",
+            "tls://editor.lan:7777",
+            "wss://editor.lan/ipc",
+            "tcp://0.0.0.0:7777",
+        ] {
+            assert!(!parse_endpoint(remote).is_loopback(), "{remote}");
+        }
+    }
+
+    #[test]
+    fn test_split_endpoints() {
+        assert_eq!(
+            split_endpoints(" tcp://127.0.0.1:7777, ,tcp://127.0.0.1:7778,tcp://127.0.0.1:7777"),
+            vec!["tcp://127.0.0.1:7777", "tcp://127.0.0.1:7778"]
+        );
+        assert!(split_endpoints(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_endpoint_display_round_trips() {
+        for raw in [
+            "tcp://127.0.0.1:7777",
+            "tcp://localhost:3000",
+            "tls://editor.lan:7777",
+            "ws://127.0.0.1:7780",
+            "wss://editor.lan:8443/unity",
+        ] {
+            assert_eq!(parse_endpoint(raw).to_string(), raw);
+        }
+        assert_eq!(
+            parse_endpoint("localhost:3000").to_string(),
+            "tcp://localhost:3000"
+        );
+    }
+
+    #[test]
+    fn test_ipc_config_default() {
+        let config = IpcConfig::default();
+        // CI環境でない場合のデフォルト値をテスト
+        if std::env::var("CI").is_err() {
+            assert_eq!(config.connect_timeout, Duration::from_millis(2000));
+            assert_eq!(config.total_handshake_timeout, Duration::from_millis(3000));
+        }
+        assert_eq!(config.handshake_timeout, Duration::from_millis(2000));
+        assert_eq!(config.call_timeout, Duration::from_millis(4000));
+        assert_eq!(config.heartbeat_interval, Duration::from_millis(5000));
+        assert_eq!(config.reconnect_initial_backoff, Duration::from_millis(200));
+        assert_eq!(config.reconnect_max_backoff, Duration::from_millis(5000));
+        assert_eq!(config.heartbeat_miss_threshold, 3);
+        assert_eq!(config.max_frame_len, DEFAULT_MAX_FRAME_LEN);
+        assert_eq!(config.max_response_len, DEFAULT_MAX_RESPONSE_LEN);
+        if std::env::var("MCP_IPC_COMPRESSION").is_err() {
+            assert_eq!(
+                config.compression_threshold,
+                Some(DEFAULT_COMPRESSION_THRESHOLD)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ipc_config_debug_redacts_token() {
+        let config = IpcConfig {
+            token: Some("s3cret".to_string()),
+            ..IpcConfig::default()
+        };
+        let debug = format!("{:?}", config);
+        assert!(debug.contains("<redacted>"), "{debug}");
+        assert!(!debug.contains("s3cret"), "{debug}");
+    }
+
+    #[test]
+    fn test_default_endpoint() {
+        let endpoint = default_endpoint();
+        match endpoint {
+            Endpoint::Tcp(addr) => {
+                assert_eq!(addr, "127.0.0.1:7777");
+            }
+            #[allow(unreachable_patterns)]
+            _ => panic!("Expected TCP endpoint, got {:?}", endpoint),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Session recording for `IpcClient` (`ipc.record`).
+//!
+//! クライアントが送受信したフレームを [`TapDecoder`](super::tap::TapDecoder) でデコードし、
+//! ipc-tap と同じ JSON Lines 形式のキャプチャに書き出す。ハートビートは記録しない。
+//! 記録したキャプチャは `sim::replay::ReplayBridge` で再生できる。
+//!
+//! 同じパスを指定したクライアント（複数 Editor や再接続）は 1 つのファイルを共有し、
+//! `connection` 番号で区別される。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use tokio::sync::{mpsc, oneshot};
+
+use super::tap::{CaptureError, CaptureWriter, Direction, RecordKind, TapDecoder};
+
+/// Recorders by path, so every client of the process appends to the same capture.
+static RECORDERS: LazyLock<Mutex<HashMap<PathBuf, Recorder>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+enum Observed {
+    Frame {
+        connection: u32,
+        direction: Direction,
+        frame: Bytes,
+        ts: DateTime<Utc>,
+    },
+    Closed(u32),
+    Flush(oneshot::Sender<()>),
+}
+
+/// Handle to a capture file being written in the background.
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    tx: mpsc::UnboundedSender<Observed>,
+    connections: Arc<AtomicU32>,
+}
+
+impl Recorder {
+    /// Recorder for `path`, creating (truncating) the file on first use in this process.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn open(path: &Path) -> Result<Self, CaptureError> {
+        let mut recorders = RECORDERS.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(recorder) = recorders.get(path)
+            && !recorder.tx.is_closed()
+        {
+            return Ok(recorder.clone());
+        }
+        let mut writer = CaptureWriter::create(path)?;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut decoder = TapDecoder::new();
+            while let Some(observed) = rx.recv().await {
+                let (connection, direction, frame, ts) = match observed {
+                    Observed::Frame {
+                        connection,
+                        direction,
+                        frame,
+                        ts,
+                    } => (connection, direction, frame, ts),
+                    Observed::Closed(connection) => {
+                        decoder.close(connection);
+                        continue;
+                    }
+                    Observed::Flush(done) => {
+                        let _ = done.send(());
+                        continue;
+                    }
+                };
+                let record = decoder.decode(connection, direction, &frame, ts);
+                if matches!(record.kind, RecordKind::Ping | RecordKind::Pong) {
+                    continue;
+                }
+                if let Err(e) = writer.write(&record) {
+                    tracing::warn!("IPC recording stopped: {}", e);
+                    break;
+                }
+            }
+        });
+        let recorder = Self {
+            tx,
+            connections: Arc::new(AtomicU32::new(0)),
+        };
+        recorders.insert(path.to_path_buf(), recorder.clone());
+        Ok(recorder)
+    }
+
+    /// Start recording a new connection, numbered from 1.
+    pub fn connection(&self) -> RecordedConnection {
+        RecordedConnection {
+            recorder: self.clone(),
+            connection: self.connections.fetch_add(1, Ordering::Relaxed) + 1,
+        }
+    }
+
+    /// Wait until everything recorded so far is in the file.
+    pub async fn flush(&self) {
+        let (done, written) = oneshot::channel();
+        if self.tx.send(Observed::Flush(done)).is_ok() {
+            let _ = written.await;
+        }
+    }
+}
+
+/// One client connection being recorded; the recorder forgets it when dropped.
+#[derive(Debug)]
+pub struct RecordedConnection {
+    recorder: Recorder,
+    connection: u32,
+}
+
+impl RecordedConnection {
+    /// Record a frame exactly as it went over the wire.
+    pub fn frame(&self, direction: Direction, frame: &Bytes) {
+        let _ = self.recorder.tx.send(Observed::Frame {
+            connection: self.connection,
+            direction,
+            frame: frame.clone(),
+            ts: Utc::now(),
+        });
+    }
+}
+
+impl Drop for RecordedConnection {
+    fn drop(&mut self) {
+        let _ = self.recorder.tx.send(Observed::Closed(self.connection));
+    }
+}
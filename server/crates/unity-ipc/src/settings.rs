@@ -0,0 +1,25 @@
+//! Where `IpcConfig::default()` and friends read their settings.
+//!
+//! 既定では環境変数をそのまま読む。アプリケーションが設定ファイルなどを重ねる場合は、
+//! 起動時に [`set_source`] で環境変数名から値を引く関数を登録する。
+
+use std::env;
+use std::sync::OnceLock;
+
+/// Looks up the setting read from the given env var name.
+pub type Source = fn(&str) -> Option<String>;
+
+static SOURCE: OnceLock<Source> = OnceLock::new();
+
+/// Read settings through `source` from now on; only the first call has an effect.
+pub fn set_source(source: Source) -> bool {
+    SOURCE.set(source).is_ok()
+}
+
+/// Value of the setting read from env var `env_name`.
+pub fn var(env_name: &str) -> Option<String> {
+    match SOURCE.get() {
+        Some(source) => source(env_name),
+        None => env::var(env_name).ok(),
+    }
+}
@@ -0,0 +1,666 @@
+//! IPC traffic tap: decodes bridge conversations into JSON records.
+//!
+//! クライアントと Bridge の間に透過プロキシとして入り、すべての `IpcControl` / `IpcEnvelope`
+//! を生成済みスキーマ（`schema.pb`）でデコードして、タイムスタンプ・correlation_id・
+//! 応答までのレイテンシ付きの [`TapRecord`] にする。圧縮フレームは展開し、チャンク応答は
+//! 最後のチャンクで組み立て直す。hello のトークンは伏せる。
+//!
+//! キャプチャは 1 行 1 レコードの JSON Lines（[`CaptureWriter`] / [`load_capture`]）。
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use base64::Engine;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::{SinkExt, StreamExt};
+use prost::Message;
+use prost_reflect::{DescriptorPool, DeserializeOptions, DynamicMessage, SerializeOptions, Value};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_util::codec::{FramedRead, FramedWrite};
+use tokio_util::sync::CancellationToken;
+
+use crate::chunking::{Progress, Reassembler};
+use crate::generated::mcp::unity::v1 as pb;
+use crate::{codec, framing};
+
+/// Frames are relayed and decoded up to this size.
+const MAX_TAP_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+static DESCRIPTORS: LazyLock<DescriptorPool> = LazyLock::new(|| {
+    DescriptorPool::decode(include_bytes!("generated/schema.pb").as_slice())
+        .expect("embedded schema.pb is a valid FileDescriptorSet")
+});
+
+/// Look up a message of the `mcp.unity.v1` package in the embedded schema.
+pub fn descriptor(name: &str) -> Option<prost_reflect::MessageDescriptor> {
+    DESCRIPTORS.get_message_by_name(&format!("mcp.unity.v1.{name}"))
+}
+
+#[derive(Debug, Error)]
+pub enum CaptureError {
+    #[error("failed to access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("{}:{line}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    ToBridge,
+    ToClient,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    Hello,
+    Welcome,
+    Reject,
+    Request,
+    Response,
+    /// A chunk before the last one; the last chunk is recorded as the whole response
+    ResponseChunk,
+    Event,
+    Ping,
+    Pong,
+    Undecodable,
+}
+
+/// One frame as seen on the wire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TapRecord {
+    pub ts: DateTime<Utc>,
+    pub connection: u32,
+    pub direction: Direction,
+    pub kind: RecordKind,
+    /// Frame payload size, before decompression
+    pub bytes: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Payload field of the request / response / event, e.g. `health` or `assets`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Time since the matching request, on responses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<String>,
+    /// Number of frames a chunked response arrived in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chunks: Option<u32>,
+    /// Decoded message in proto3 JSON form
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Base64 of frames that could not be decoded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<String>,
+}
+
+impl TapRecord {
+    fn new(
+        ts: DateTime<Utc>,
+        connection: u32,
+        direction: Direction,
+        kind: RecordKind,
+        bytes: usize,
+    ) -> Self {
+        Self {
+            ts,
+            connection,
+            direction,
+            kind,
+            bytes,
+            correlation_id: None,
+            method: None,
+            latency_ms: None,
+            compression: None,
+            chunks: None,
+            message: None,
+            error: None,
+            raw: None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Session {
+    /// Handshake frame already seen, per direction
+    to_bridge_started: bool,
+    to_client_started: bool,
+    requests: HashMap<String, DateTime<Utc>>,
+    chunks: HashMap<String, u32>,
+    reassembler: Option<Reassembler>,
+}
+
+/// Turns raw frames into [`TapRecord`]s, tracking per-connection state
+/// (handshake, outstanding requests, partial chunked responses).
+#[derive(Default)]
+pub struct TapDecoder {
+    sessions: HashMap<u32, Session>,
+}
+
+impl TapDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decode(
+        &mut self,
+        connection: u32,
+        direction: Direction,
+        frame: &[u8],
+        ts: DateTime<Utc>,
+    ) -> TapRecord {
+        let session = self.sessions.entry(connection).or_default();
+        let started = match direction {
+            Direction::ToBridge => &mut session.to_bridge_started,
+            Direction::ToClient => &mut session.to_client_started,
+        };
+        let handshake = !std::mem::replace(started, true);
+        let mut record = TapRecord::new(
+            ts,
+            connection,
+            direction,
+            RecordKind::Undecodable,
+            frame.len(),
+        );
+
+        let result = if handshake {
+            decode_control(frame, &mut record)
+        } else {
+            decode_envelope(session, frame, &mut record)
+        };
+        if let Err(e) = result {
+            record.kind = RecordKind::Undecodable;
+            record.error = Some(e);
+            record.raw = Some(base64::engine::general_purpose::STANDARD.encode(frame));
+        }
+        record
+    }
+
+    /// Forget a closed connection.
+    pub fn close(&mut self, connection: u32) {
+        self.sessions.remove(&connection);
+    }
+}
+
+fn decode_control(frame: &[u8], record: &mut TapRecord) -> Result<(), String> {
+    use pb::ipc_control::Kind;
+
+    let control = pb::IpcControl::decode(frame).map_err(|e| e.to_string())?;
+    let mut message = to_dynamic("IpcControl", &control)?;
+    record.kind = match control.kind {
+        Some(Kind::Hello(hello)) => {
+            if !hello.token.is_empty()
+                && let Some(Value::Message(hello)) = message.get_field_by_name_mut("hello")
+            {
+                hello.set_field_by_name("token", Value::String("***".into()));
+            }
+            RecordKind::Hello
+        }
+        Some(Kind::Welcome(_)) => RecordKind::Welcome,
+        Some(Kind::Reject(_)) => RecordKind::Reject,
+        Some(Kind::Ping(_)) => RecordKind::Ping,
+        Some(Kind::Pong(_)) => RecordKind::Pong,
+        None => return Err("empty control frame".into()),
+    };
+    record.message = Some(to_json(&message)?);
+    Ok(())
+}
+
+fn decode_envelope(
+    session: &mut Session,
+    frame: &[u8],
+    record: &mut TapRecord,
+) -> Result<(), String> {
+    use pb::ipc_envelope::Kind;
+
+    let env = codec::decode_envelope(Bytes::copy_from_slice(frame)).map_err(|e| e.to_string())?;
+    if let Some(Kind::Compressed(c)) = &env.kind {
+        record.compression = Some(c.algorithm().as_str_name().to_ascii_lowercase());
+    }
+    let env = codec::inflate_envelope(env, MAX_TAP_FRAME_LEN).map_err(|e| e.to_string())?;
+    let correlation_id = env.correlation_id;
+    if !correlation_id.is_empty() {
+        record.correlation_id = Some(correlation_id.clone());
+    }
+    match env.kind {
+        Some(Kind::Request(req)) => {
+            record.kind = RecordKind::Request;
+            session.requests.insert(correlation_id, record.ts);
+            describe(record, to_dynamic("IpcRequest", &req)?)
+        }
+        Some(Kind::Response(resp)) => finish_response(session, correlation_id, resp, record),
+        Some(Kind::ResponseChunk(chunk)) => {
+            *session.chunks.entry(correlation_id.clone()).or_default() += 1;
+            let progress = session
+                .reassembler
+                .get_or_insert_with(|| Reassembler::new(MAX_TAP_FRAME_LEN))
+                .push(&correlation_id, &chunk)
+                .map_err(|e| e.to_string())?;
+            match progress {
+                Progress::Partial {
+                    received,
+                    total_size,
+                } => {
+                    record.kind = RecordKind::ResponseChunk;
+                    record.message = Some(serde_json::json!({
+                        "index": chunk.index,
+                        "received": received,
+                        "totalSize": total_size,
+                    }));
+                    Ok(())
+                }
+                Progress::Complete(resp) => {
+                    record.chunks = session.chunks.remove(&correlation_id);
+                    finish_response(session, correlation_id, resp, record)
+                }
+            }
+        }
+        Some(Kind::Event(event)) => {
+            record.kind = RecordKind::Event;
+            describe(record, to_dynamic("IpcEvent", &event)?)
+        }
+        Some(Kind::Control(control)) => decode_control(&control.encode_to_vec(), record),
+        Some(Kind::Compressed(_)) => Err("nested compressed envelope".into()),
+        None => Err("empty envelope".into()),
+    }
+}
+
+fn finish_response(
+    session: &mut Session,
+    correlation_id: String,
+    resp: pb::IpcResponse,
+    record: &mut TapRecord,
+) -> Result<(), String> {
+    record.kind = RecordKind::Response;
+    if let Some(sent) = session.requests.remove(&correlation_id) {
+        let elapsed = record.ts - sent;
+        record.latency_ms = elapsed.num_microseconds().map(|us| us as f64 / 1000.0);
+    }
+    describe(record, to_dynamic("IpcResponse", &resp)?)
+}
+
+/// Fill in `method` (the set oneof field) and `message`.
+fn describe(record: &mut TapRecord, message: DynamicMessage) -> Result<(), String> {
+    record.method = message
+        .fields()
+        .find(|(field, _)| field.containing_oneof().is_some())
+        .map(|(field, _)| field.name().to_string());
+    record.message = Some(to_json(&message)?);
+    Ok(())
+}
+
+fn to_dynamic<M: Message>(name: &str, msg: &M) -> Result<DynamicMessage, String> {
+    let desc = descriptor(name).ok_or_else(|| format!("{name} missing from schema"))?;
+    DynamicMessage::decode(desc, msg.encode_to_vec().as_slice()).map_err(|e| e.to_string())
+}
+
+fn to_json(message: &DynamicMessage) -> Result<serde_json::Value, String> {
+    let options = SerializeOptions::new().stringify_64_bit_integers(false);
+    message
+        .serialize_with_options(serde_json::value::Serializer, &options)
+        .map_err(|e| e.to_string())
+}
+
+/// `msg` (an `mcp.unity.v1` message called `name`) in the JSON form used by records.
+pub fn message_to_json<M: Message>(name: &str, msg: &M) -> Result<serde_json::Value, String> {
+    to_json(&to_dynamic(name, msg)?)
+}
+
+/// Inverse of [`message_to_json`]. Fields the current schema does not know are ignored,
+/// so captures outlive additive schema changes.
+pub fn message_from_json<M: Message + Default>(
+    name: &str,
+    value: &serde_json::Value,
+) -> Result<M, String> {
+    let desc = descriptor(name).ok_or_else(|| format!("{name} missing from schema"))?;
+    let options = DeserializeOptions::new().deny_unknown_fields(false);
+    DynamicMessage::deserialize_with_options(desc, value, &options)
+        .map_err(|e| e.to_string())?
+        .transcode_to()
+        .map_err(|e| e.to_string())
+}
+
+/// Appends records to a JSON Lines capture file.
+pub struct CaptureWriter {
+    path: PathBuf,
+    out: BufWriter<File>,
+}
+
+impl CaptureWriter {
+    pub fn create(path: &Path) -> Result<Self, CaptureError> {
+        let file = File::create(path).map_err(|source| CaptureError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            out: BufWriter::new(file),
+        })
+    }
+
+    /// Write one record and flush, so a capture survives the tap being killed.
+    pub fn write(&mut self, record: &TapRecord) -> Result<(), CaptureError> {
+        serde_json::to_writer(&mut self.out, record)
+            .map_err(std::io::Error::from)
+            .and_then(|_| self.out.write_all(b"\n"))
+            .and_then(|_| self.out.flush())
+            .map_err(|source| CaptureError::Io {
+                path: self.path.clone(),
+                source,
+            })
+    }
+}
+
+/// Read a capture written by [`CaptureWriter`]. Blank lines are skipped.
+pub fn load_capture(path: &Path) -> Result<Vec<TapRecord>, CaptureError> {
+    let io_err = |source| CaptureError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let reader = BufReader::new(File::open(path).map_err(io_err)?);
+    let mut records = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(io_err)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record = serde_json::from_str(&line).map_err(|source| CaptureError::Parse {
+            path: path.to_path_buf(),
+            line: i + 1,
+            source,
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+enum Observed {
+    Frame {
+        connection: u32,
+        direction: Direction,
+        frame: Bytes,
+        ts: DateTime<Utc>,
+    },
+    Closed(u32),
+}
+
+/// Transparent TCP proxy that reports every frame it relays.
+pub struct IpcTap {
+    listener: TcpListener,
+    upstream: String,
+}
+
+impl IpcTap {
+    /// Listen on `addr` and relay to `upstream` (`host:port` or `tcp://host:port`).
+    pub async fn bind(addr: &str, upstream: &str) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        Ok(Self {
+            listener,
+            upstream: upstream
+                .strip_prefix("tcp://")
+                .unwrap_or(upstream)
+                .to_string(),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// `tcp://` endpoint clients should connect to.
+    pub fn endpoint(&self) -> String {
+        match self.local_addr() {
+            Ok(addr) => format!("tcp://{addr}"),
+            Err(_) => String::new(),
+        }
+    }
+
+    /// Accept connections until the task is dropped, sending a record per frame.
+    pub async fn run(self, records: mpsc::UnboundedSender<TapRecord>) {
+        let (observed_tx, mut observed) = mpsc::unbounded_channel();
+        // Decoding happens off the relay path so the tap adds no latency of its own
+        tokio::spawn(async move {
+            let mut decoder = TapDecoder::new();
+            while let Some(item) = observed.recv().await {
+                match item {
+                    Observed::Frame {
+                        connection,
+                        direction,
+                        frame,
+                        ts,
+                    } => {
+                        let record = decoder.decode(connection, direction, &frame, ts);
+                        if records.send(record).is_err() {
+                            break;
+                        }
+                    }
+                    Observed::Closed(connection) => decoder.close(connection),
+                }
+            }
+        });
+
+        let mut connection = 0;
+        loop {
+            let client = match self.listener.accept().await {
+                Ok((client, _)) => client,
+                Err(e) => {
+                    tracing::warn!("accept failed: {}", e);
+                    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            connection += 1;
+            let bridge = match TcpStream::connect(&self.upstream).await {
+                Ok(bridge) => bridge,
+                Err(e) => {
+                    tracing::warn!(
+                        "connection {}: upstream {}: {}",
+                        connection,
+                        self.upstream,
+                        e
+                    );
+                    continue;
+                }
+            };
+            let _ = client.set_nodelay(true);
+            let _ = bridge.set_nodelay(true);
+            tokio::spawn(relay(client, bridge, connection, observed_tx.clone()));
+        }
+    }
+}
+
+async fn relay(
+    client: TcpStream,
+    bridge: TcpStream,
+    connection: u32,
+    observed: mpsc::UnboundedSender<Observed>,
+) {
+    let (client_read, client_write) = client.into_split();
+    let (bridge_read, bridge_write) = bridge.into_split();
+    let closed = CancellationToken::new();
+    tokio::join!(
+        pump(
+            client_read,
+            bridge_write,
+            connection,
+            Direction::ToBridge,
+            observed.clone(),
+            closed.clone(),
+        ),
+        pump(
+            bridge_read,
+            client_write,
+            connection,
+            Direction::ToClient,
+            observed.clone(),
+            closed,
+        ),
+    );
+    let _ = observed.send(Observed::Closed(connection));
+}
+
+async fn pump<R, W>(
+    read: R,
+    write: W,
+    connection: u32,
+    direction: Direction,
+    observed: mpsc::UnboundedSender<Observed>,
+    closed: CancellationToken,
+) where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let codec = framing::codec_with_max_frame_len(MAX_TAP_FRAME_LEN);
+    let mut frames = FramedRead::new(read, codec.clone());
+    let mut sink = FramedWrite::new(write, codec);
+    loop {
+        let frame = tokio::select! {
+            _ = closed.cancelled() => break,
+            frame = frames.next() => match frame {
+                Some(Ok(frame)) => frame.freeze(),
+                _ => break,
+            },
+        };
+        let _ = observed.send(Observed::Frame {
+            connection,
+            direction,
+            frame: frame.clone(),
+            ts: Utc::now(),
+        });
+        if sink.send(frame).await.is_err() {
+            break;
+        }
+    }
+    closed.cancel();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope(correlation_id: &str, kind: pb::ipc_envelope::Kind) -> Vec<u8> {
+        pb::IpcEnvelope {
+            correlation_id: correlation_id.to_string(),
+            kind: Some(kind),
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn test_handshake_redacts_token() {
+        let mut decoder = TapDecoder::new();
+        let hello = pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Hello(pb::IpcHello {
+                token: "secret".into(),
+                ipc_version: "1.0".into(),
+                client_name: "unity-mcp-rs".into(),
+                ..Default::default()
+            })),
+        };
+        let record = decoder.decode(1, Direction::ToBridge, &hello.encode_to_vec(), Utc::now());
+        assert_eq!(record.kind, RecordKind::Hello);
+        let message = record.message.unwrap();
+        assert_eq!(message["hello"]["token"], "***");
+        assert_eq!(message["hello"]["clientName"], "unity-mcp-rs");
+        assert!(!message.to_string().contains("secret"));
+
+        // Only the first frame of a direction is a bare IpcControl
+        let record = decoder.decode(1, Direction::ToBridge, &hello.encode_to_vec(), Utc::now());
+        assert_eq!(record.kind, RecordKind::Undecodable);
+        assert!(record.raw.is_some());
+    }
+
+    #[test]
+    fn test_request_response_latency() {
+        use pb::ipc_envelope::Kind;
+
+        let mut decoder = TapDecoder::new();
+        let t0 = Utc::now();
+        decoder.decode(1, Direction::ToBridge, &[], t0);
+        decoder.decode(1, Direction::ToClient, &[], t0);
+
+        let req = pb::IpcRequest {
+            payload: Some(pb::ipc_request::Payload::Health(pb::HealthRequest {})),
+        };
+        let record = decoder.decode(
+            1,
+            Direction::ToBridge,
+            &envelope("c1", Kind::Request(req)),
+            t0,
+        );
+        assert_eq!(record.kind, RecordKind::Request);
+        assert_eq!(record.method.as_deref(), Some("health"));
+        assert_eq!(record.correlation_id.as_deref(), Some("c1"));
+
+        let resp = pb::IpcResponse {
+            correlation_id: "c1".into(),
+            payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                ready: true,
+                version: "1.0".into(),
+                ..Default::default()
+            })),
+        };
+        let t1 = t0 + chrono::Duration::milliseconds(42);
+        let record = decoder.decode(
+            1,
+            Direction::ToClient,
+            &envelope("c1", Kind::Response(resp)),
+            t1,
+        );
+        assert_eq!(record.kind, RecordKind::Response);
+        assert_eq!(record.method.as_deref(), Some("health"));
+        assert_eq!(record.latency_ms, Some(42.0));
+        assert_eq!(record.message.unwrap()["health"]["ready"], true);
+    }
+
+    #[test]
+    fn test_chunked_response_is_reassembled() {
+        let mut decoder = TapDecoder::new();
+        let ts = Utc::now();
+        decoder.decode(1, Direction::ToBridge, &[], ts);
+        decoder.decode(1, Direction::ToClient, &[], ts);
+
+        let resp = pb::IpcResponse {
+            correlation_id: "c2".into(),
+            payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                version: "x".repeat(100),
+                ..Default::default()
+            })),
+        };
+        let chunks = crate::chunking::split_response("c2", &resp, 40);
+        assert!(chunks.len() > 1);
+        let records: Vec<_> = chunks
+            .iter()
+            .map(|env| decoder.decode(1, Direction::ToClient, &env.encode_to_vec(), ts))
+            .collect();
+        let (last, partial) = records.split_last().unwrap();
+        assert!(partial.iter().all(|r| r.kind == RecordKind::ResponseChunk));
+        assert_eq!(last.kind, RecordKind::Response);
+        assert_eq!(last.chunks, Some(chunks.len() as u32));
+        assert_eq!(
+            last.message.as_ref().unwrap()["health"]["version"],
+            "x".repeat(100)
+        );
+    }
+}
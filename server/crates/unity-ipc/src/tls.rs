@@ -0,0 +1,198 @@
+//! TLS for `tls://host:port` endpoints.
+//!
+//! Bridge の証明書は `ipc.tls_ca_file`（PEM）で検証し、未指定なら OS の証明書ストアを使う。
+//! `ipc.tls_client_cert` と `ipc.tls_client_key` を両方指定するとクライアント証明書を提示する
+//! （相互認証）。ファイルは接続のたびに読み直すので、証明書を差し替えても再起動は不要。
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, ServerName, pem::PemObject};
+use tokio::net::TcpStream;
+use tokio_rustls::{TlsConnector, client::TlsStream, rustls};
+
+use super::client::IpcError;
+use crate::settings;
+
+/// Certificates and name checks for `tls://` endpoints.
+#[derive(Debug, Clone, Default)]
+pub struct TlsOptions {
+    /// PEM bundle of CAs the bridge certificate must chain to (default: system roots)
+    pub ca_file: Option<PathBuf>,
+    /// PEM client certificate chain for mutual authentication
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key of `client_cert`
+    pub client_key: Option<PathBuf>,
+    /// Name the bridge certificate must be issued for (default: the endpoint host)
+    pub server_name: Option<String>,
+}
+
+impl TlsOptions {
+    /// Options from `ipc.tls_*` (`MCP_IPC_TLS_*`).
+    pub fn from_config() -> Self {
+        let var = |name| settings::var(name).filter(|v| !v.is_empty());
+        Self {
+            ca_file: var("MCP_IPC_TLS_CA_FILE").map(PathBuf::from),
+            client_cert: var("MCP_IPC_TLS_CLIENT_CERT").map(PathBuf::from),
+            client_key: var("MCP_IPC_TLS_CLIENT_KEY").map(PathBuf::from),
+            server_name: var("MCP_IPC_TLS_SERVER_NAME"),
+        }
+    }
+
+    pub fn connector(&self) -> Result<TlsConnector, IpcError> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let builder = rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|e| IpcError::Tls(e.to_string()))?
+            .with_root_certificates(self.root_store()?);
+        let config = match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => builder
+                .with_client_auth_cert(load_certs(cert)?, load_key(key)?)
+                .map_err(|e| IpcError::Tls(format!("invalid client certificate: {e}")))?,
+            (None, None) => builder.with_no_client_auth(),
+            _ => {
+                return Err(IpcError::Tls(
+                    "ipc.tls_client_cert and ipc.tls_client_key must be set together".into(),
+                ));
+            }
+        };
+        Ok(TlsConnector::from(Arc::new(config)))
+    }
+
+    fn root_store(&self) -> Result<rustls::RootCertStore, IpcError> {
+        let mut roots = rustls::RootCertStore::empty();
+        match &self.ca_file {
+            Some(path) => {
+                for cert in load_certs(path)? {
+                    roots.add(cert).map_err(|e| {
+                        IpcError::Tls(format!("invalid CA certificate in {}: {e}", path.display()))
+                    })?;
+                }
+            }
+            None => {
+                let native = rustls_native_certs::load_native_certs();
+                for e in &native.errors {
+                    tracing::warn!("Could not load system CA certificates: {}", e);
+                }
+                roots.add_parsable_certificates(native.certs);
+            }
+        }
+        if roots.is_empty() {
+            return Err(IpcError::Tls(
+                "no CA certificates to verify the bridge with; set ipc.tls_ca_file".into(),
+            ));
+        }
+        Ok(roots)
+    }
+
+    fn server_name(&self, addr: &str) -> Result<ServerName<'static>, IpcError> {
+        let name = self.server_name.as_deref().unwrap_or_else(|| host_of(addr));
+        ServerName::try_from(name.to_string())
+            .map_err(|e| IpcError::Tls(format!("invalid server name {name:?}: {e}")))
+    }
+}
+
+/// Run the TLS handshake over an established TCP connection to `addr`.
+pub async fn connect(
+    addr: &str,
+    opts: &TlsOptions,
+    tcp: TcpStream,
+) -> Result<TlsStream<TcpStream>, IpcError> {
+    let connector = opts.connector()?;
+    let name = opts.server_name(addr)?;
+    connector
+        .connect(name, tcp)
+        .await
+        .map_err(|e| IpcError::Tls(format!("handshake with {addr} failed: {e}")))
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>, IpcError> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .and_then(|iter| iter.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| IpcError::Tls(format!("cannot read {}: {e}", path.display())))?;
+    if certs.is_empty() {
+        return Err(IpcError::Tls(format!(
+            "no certificates in {}",
+            path.display()
+        )));
+    }
+    Ok(certs)
+}
+
+fn load_key(path: &Path) -> Result<PrivateKeyDer<'static>, IpcError> {
+    PrivateKeyDer::from_pem_file(path)
+        .map_err(|e| IpcError::Tls(format!("cannot read private key {}: {e}", path.display())))
+}
+
+/// Host part of `host:port` or `[v6]:port`.
+fn host_of(addr: &str) -> &str {
+    let host = match addr.rsplit_once(':') {
+        Some((host, port)) if port.parse::<u16>().is_ok() => host,
+        _ => addr,
+    };
+    host.trim_start_matches('[').trim_end_matches(']')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_of() {
+        assert_eq!(host_of("editor.lan:7777"), "editor.lan");
+        assert_eq!(host_of("127.0.0.1:7777"), "127.0.0.1");
+        assert_eq!(host_of("[::1]:7777"), "::1");
+        assert_eq!(host_of("editor.lan"), "editor.lan");
+    }
+
+    #[test]
+    fn test_server_name_override() {
+        let opts = TlsOptions {
+            server_name: Some("unity.internal".to_string()),
+            ..TlsOptions::default()
+        };
+        assert_eq!(
+            opts.server_name("10.0.0.5:7777").unwrap(),
+            ServerName::try_from("unity.internal").unwrap()
+        );
+        assert!(matches!(
+            TlsOptions::default().server_name("10.0.0.5:7777").unwrap(),
+            ServerName::IpAddress(_)
+        ));
+    }
+
+    #[test]
+    fn test_client_cert_requires_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let ca = dir.path().join("ca.pem");
+        let key = rcgen::KeyPair::generate().unwrap();
+        let cert = rcgen::CertificateParams::new(vec!["localhost".to_string()])
+            .unwrap()
+            .self_signed(&key)
+            .unwrap();
+        std::fs::write(&ca, cert.pem()).unwrap();
+
+        let opts = TlsOptions {
+            ca_file: Some(ca.clone()),
+            client_cert: Some(ca.clone()),
+            ..TlsOptions::default()
+        };
+        let err = opts.connector().err().unwrap().to_string();
+        assert!(err.contains("must be set together"), "{err}");
+
+        let empty = dir.path().join("empty.pem");
+        std::fs::write(&empty, "").unwrap();
+        let opts = TlsOptions {
+            ca_file: Some(empty),
+            ..TlsOptions::default()
+        };
+        let err = opts.connector().err().unwrap().to_string();
+        assert!(err.contains("no certificates"), "{err}");
+
+        let opts = TlsOptions {
+            ca_file: Some(ca),
+            ..TlsOptions::default()
+        };
+        assert!(opts.connector().is_ok());
+    }
+}
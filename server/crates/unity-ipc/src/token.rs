@@ -0,0 +1,341 @@
+//! Where the IPC token comes from.
+//!
+//! 優先順位: `ipc.token`（`MCP_IPC_TOKEN`） > `ipc.token_file` > Unity プロジェクトの
+//! `UserSettings/EditorUserSettings.asset` に Bridge が保存している `MCP.IpcToken`。
+//! トークンの値はログや状態出力に出さず、出所（[`TokenSource`]）だけを表示する。
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use super::path::IpcConfig;
+
+/// Key the bridge reads with `EditorUserSettings.GetConfigValue`.
+pub const UNITY_TOKEN_KEY: &str = "MCP.IpcToken";
+
+/// Location of EditorUserSettings inside a Unity project.
+pub const UNITY_USER_SETTINGS: &str = "UserSettings/EditorUserSettings.asset";
+
+#[derive(Debug, Error)]
+pub enum TokenError {
+    #[error("cannot read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error(
+        "token file {path} is accessible by other users (mode {mode:03o}); restrict it with `chmod 600`"
+    )]
+    InsecurePermissions { path: PathBuf, mode: u32 },
+    #[error("token file {0} is empty")]
+    Empty(PathBuf),
+}
+
+/// Where a token was found; safe to log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// `ipc.token` from the environment, a config file or the command line
+    Config,
+    File(PathBuf),
+    UnityUserSettings(PathBuf),
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenSource::Config => write!(f, "ipc.token"),
+            TokenSource::File(path) => write!(f, "token file {}", path.display()),
+            TokenSource::UnityUserSettings(path) => {
+                write!(f, "{} in {}", UNITY_TOKEN_KEY, path.display())
+            }
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct ResolvedToken {
+    pub value: String,
+    pub source: TokenSource,
+}
+
+impl fmt::Debug for ResolvedToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResolvedToken")
+            .field("value", &"<redacted>")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+/// Token to send in `IpcHello`, read afresh from `cfg`'s sources.
+///
+/// `Ok(None)` means no source has a token; the bridge will then reject the handshake.
+pub fn resolve(cfg: &IpcConfig) -> Result<Option<ResolvedToken>, TokenError> {
+    if let Some(value) = cfg.token.as_deref().filter(|t| !t.is_empty()) {
+        return Ok(Some(ResolvedToken {
+            value: value.to_string(),
+            source: TokenSource::Config,
+        }));
+    }
+    if let Some(path) = &cfg.token_file {
+        return Ok(Some(ResolvedToken {
+            value: read_token_file(path)?,
+            source: TokenSource::File(path.clone()),
+        }));
+    }
+    if let Some(project) = &cfg.unity_project {
+        let path = project.join(UNITY_USER_SETTINGS);
+        if let Some(value) = read_unity_user_settings(&path)? {
+            return Ok(Some(ResolvedToken {
+                value,
+                source: TokenSource::UnityUserSettings(path),
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// Read a token file, refusing files that other users can read or write.
+pub fn read_token_file(path: &Path) -> Result<String, TokenError> {
+    let io_err = |source| TokenError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let metadata = std::fs::metadata(path).map_err(io_err)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Err(TokenError::InsecurePermissions {
+                path: path.to_path_buf(),
+                mode,
+            });
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = metadata;
+
+    let token = std::fs::read_to_string(path).map_err(io_err)?;
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(TokenError::Empty(path.to_path_buf()));
+    }
+    Ok(token.to_string())
+}
+
+/// `MCP.IpcToken` from an `EditorUserSettings.asset`; `None` if the file or key is missing.
+pub fn read_unity_user_settings(path: &Path) -> Result<Option<String>, TokenError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(parse_unity_user_settings(&text)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(TokenError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+/// Find `MCP.IpcToken` under `m_ConfigSettings` of an EditorUserSettings YAML document.
+///
+/// ```yaml
+///   m_ConfigSettings:
+///     MCP.IpcToken:
+///       value: 746573742d746f6b656e
+///       flags: 0
+/// ```
+///
+/// Unity stores the value hex-encoded; plain values (older editors) are used as-is.
+pub fn parse_unity_user_settings(text: &str) -> Option<String> {
+    let mut lines = text.lines();
+    let key_indent = loop {
+        let line = lines.next()?;
+        if line.trim() == format!("{UNITY_TOKEN_KEY}:") {
+            break indent_of(line);
+        }
+    };
+    for line in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if indent_of(line) <= key_indent {
+            return None;
+        }
+        if let Some(raw) = line.trim().strip_prefix("value:") {
+            let raw = raw.trim().trim_matches(|c| c == '"' || c == '\'');
+            let value = decode_hex(raw).unwrap_or_else(|| raw.to_string());
+            return Some(value).filter(|v| !v.is_empty());
+        }
+    }
+    None
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn decode_hex(raw: &str) -> Option<String> {
+    if raw.is_empty() || !raw.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..raw.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(raw.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+/// Nearest ancestor of `start` that is a Unity project (has `ProjectSettings/ProjectVersion.txt`).
+pub fn find_unity_project(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            dir.join("ProjectSettings")
+                .join("ProjectVersion.txt")
+                .is_file()
+        })
+        .map(Path::to_path_buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SETTINGS: &str = "%YAML 1.1
+%TAG !u! tag:unity3d.com,2011:
+--- !u!162 &1
+EditorUserSettings:
+  m_ObjectHideFlags: 0
+  serializedVersion: 4
+  m_ConfigSettings:
+    MCP.IpcPort:
+      value: 37373737
+      flags: 0
+    MCP.IpcToken:
+      value: 736563726574
+      flags: 0
+    RecentlyUsedSceneGuid-0:
+      value: 5a5757
+      flags: 0
+  m_VCAutomaticAdd: 1
+";
+
+    fn cfg() -> IpcConfig {
+        IpcConfig {
+            token: None,
+            token_file: None,
+            unity_project: None,
+            ..IpcConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_unity_user_settings() {
+        assert_eq!(
+            parse_unity_user_settings(SETTINGS).as_deref(),
+            Some("secret")
+        );
+        // Older editors store the value as-is
+        let plain = SETTINGS.replace("736563726574", "not-hex-token");
+        assert_eq!(
+            parse_unity_user_settings(&plain).as_deref(),
+            Some("not-hex-token")
+        );
+        let missing = SETTINGS.replace("MCP.IpcToken", "Other.Key");
+        assert_eq!(parse_unity_user_settings(&missing), None);
+        // A key without a value of its own does not pick up the next entry's
+        let empty = "  m_ConfigSettings:\n    MCP.IpcToken:\n    Next:\n      value: 41\n";
+        assert_eq!(parse_unity_user_settings(empty), None);
+    }
+
+    #[test]
+    fn test_resolve_precedence() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("Game");
+        std::fs::create_dir_all(project.join("UserSettings")).unwrap();
+        std::fs::write(project.join(UNITY_USER_SETTINGS), SETTINGS).unwrap();
+        let token_file = dir.path().join("token");
+        std::fs::write(&token_file, "from-file\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&token_file, std::fs::Permissions::from_mode(0o600)).unwrap();
+        }
+
+        let mut cfg = cfg();
+        assert_eq!(resolve(&cfg).unwrap(), None);
+
+        cfg.unity_project = Some(project.clone());
+        let token = resolve(&cfg).unwrap().unwrap();
+        assert_eq!(token.value, "secret");
+        assert_eq!(
+            token.source,
+            TokenSource::UnityUserSettings(project.join(UNITY_USER_SETTINGS))
+        );
+
+        cfg.token_file = Some(token_file.clone());
+        let token = resolve(&cfg).unwrap().unwrap();
+        assert_eq!(token.value, "from-file");
+        assert_eq!(token.source, TokenSource::File(token_file));
+
+        // An empty explicit token falls through to the other sources
+        cfg.token = Some(String::new());
+        assert_eq!(resolve(&cfg).unwrap().unwrap().value, "from-file");
+        cfg.token = Some("explicit".to_string());
+        let token = resolve(&cfg).unwrap().unwrap();
+        assert_eq!(token.source, TokenSource::Config);
+        assert!(!format!("{:?}", token).contains("explicit"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_token_file_permissions_are_checked() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        std::fs::write(&path, "s3cret").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let err = read_token_file(&path).unwrap_err();
+        assert!(matches!(
+            err,
+            TokenError::InsecurePermissions { mode: 0o644, .. }
+        ));
+        assert!(!err.to_string().contains("s3cret"));
+
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o400)).unwrap();
+        assert_eq!(read_token_file(&path).unwrap(), "s3cret");
+
+        std::fs::write(dir.path().join("empty"), "  \n").unwrap();
+        std::fs::set_permissions(
+            dir.path().join("empty"),
+            std::fs::Permissions::from_mode(0o600),
+        )
+        .unwrap();
+        assert!(matches!(
+            read_token_file(&dir.path().join("empty")),
+            Err(TokenError::Empty(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_unity_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("Game");
+        std::fs::create_dir_all(project.join("ProjectSettings")).unwrap();
+        std::fs::write(
+            project.join("ProjectSettings").join("ProjectVersion.txt"),
+            "m_EditorVersion: 6000.0.0f1\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(project.join("Assets").join("Scripts")).unwrap();
+
+        assert_eq!(
+            find_unity_project(&project.join("Assets").join("Scripts")),
+            Some(project.clone())
+        );
+        assert_eq!(find_unity_project(dir.path()), None);
+    }
+}
@@ -0,0 +1,179 @@
+//! WebSocket transport for `ws://` and `wss://` endpoints.
+//!
+//! 長さプレフィックス付きのフレームをそのまま binary メッセージで運ぶ。[`WsStream`] が
+//! WebSocket をバイトストリームに見せるので、ハンドシェイク・再接続・イベント処理は TCP と共通。
+//! メッセージ境界に意味はなく、フレームの区切りは常に長さプレフィックスで判断する。
+//! `wss://` の証明書設定は `tls://` と同じ `ipc.tls_*` を使う。
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use bytes::Bytes;
+use futures::{Sink, Stream};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio_tungstenite::{
+    WebSocketStream,
+    tungstenite::{self, Message, client::IntoClientRequest},
+};
+
+use super::client::IpcError;
+
+/// Where a `ws://` / `wss://` URL connects to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    /// `host:port`, with the scheme's default port applied
+    pub addr: String,
+    pub secure: bool,
+}
+
+impl Target {
+    pub fn parse(url: &str) -> Result<Self, IpcError> {
+        let request = url
+            .into_client_request()
+            .map_err(|e| IpcError::WebSocket(format!("invalid endpoint {url}: {e}")))?;
+        let uri = request.uri();
+        let secure = match uri.scheme_str() {
+            Some("ws") => false,
+            Some("wss") => true,
+            other => {
+                return Err(IpcError::WebSocket(format!(
+                    "unsupported scheme {other:?} in {url}"
+                )));
+            }
+        };
+        let host = uri
+            .host()
+            .ok_or_else(|| IpcError::WebSocket(format!("no host in {url}")))?;
+        let port = uri.port_u16().unwrap_or(if secure { 443 } else { 80 });
+        Ok(Self {
+            addr: format!("{host}:{port}"),
+            secure,
+        })
+    }
+}
+
+/// Run the WebSocket upgrade for `url` over an established connection.
+pub async fn handshake<S>(url: &str, stream: S) -> Result<WsStream<S>, IpcError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (ws, _) = tokio_tungstenite::client_async(url, stream)
+        .await
+        .map_err(|e| IpcError::WebSocket(format!("upgrade of {url} failed: {e}")))?;
+    Ok(WsStream::new(ws))
+}
+
+/// A WebSocket seen as a byte stream of binary messages.
+///
+/// Each write is sent as one binary message; reads return the payloads of received
+/// binary messages in order. A close frame reads as EOF and text messages are an error.
+pub struct WsStream<S> {
+    inner: WebSocketStream<S>,
+    read_buf: Bytes,
+}
+
+impl<S> WsStream<S> {
+    pub fn new(inner: WebSocketStream<S>) -> Self {
+        Self {
+            inner,
+            read_buf: Bytes::new(),
+        }
+    }
+}
+
+impl<S> AsyncRead for WsStream<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            if !self.read_buf.is_empty() {
+                let n = buf.remaining().min(self.read_buf.len());
+                let chunk = self.read_buf.split_to(n);
+                buf.put_slice(&chunk);
+                return Poll::Ready(Ok(()));
+            }
+            match ready!(Pin::new(&mut self.inner).poll_next(cx)) {
+                Some(Ok(Message::Binary(data))) => self.read_buf = data,
+                Some(Ok(Message::Close(_))) | None => return Poll::Ready(Ok(())),
+                Some(Ok(Message::Text(_))) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "text WebSocket message on an IPC connection",
+                    )));
+                }
+                // Pings are answered by tungstenite itself
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Poll::Ready(Err(into_io(e))),
+            }
+        }
+    }
+}
+
+impl<S> AsyncWrite for WsStream<S>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        ready!(Pin::new(&mut self.inner).poll_ready(cx)).map_err(into_io)?;
+        Pin::new(&mut self.inner)
+            .start_send(Message::Binary(Bytes::copy_from_slice(buf)))
+            .map_err(into_io)?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx).map_err(into_io)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx).map_err(into_io)
+    }
+}
+
+fn into_io(e: tungstenite::Error) -> io::Error {
+    match e {
+        tungstenite::Error::Io(e) => e,
+        tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => {
+            io::ErrorKind::BrokenPipe.into()
+        }
+        other => io::Error::other(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_default_ports() {
+        assert_eq!(
+            Target::parse("ws://editor.lan/unity").unwrap(),
+            Target {
+                addr: "editor.lan:80".to_string(),
+                secure: false
+            }
+        );
+        assert_eq!(
+            Target::parse("wss://editor.lan:8443/ipc").unwrap(),
+            Target {
+                addr: "editor.lan:8443".to_string(),
+                secure: true
+            }
+        );
+        assert_eq!(Target::parse("wss://[::1]").unwrap().addr, "[::1]:443");
+        assert!(Target::parse("http://editor.lan").is_err());
+    }
+}
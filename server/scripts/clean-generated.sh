@@ -6,7 +6,7 @@ set -euo pipefail
 
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 SERVER_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
-OUT_DIR="$SERVER_ROOT/src/generated"
+OUT_DIR="$SERVER_ROOT/crates/unity-ipc/src/generated"
 
 echo "[clean-generated.sh] Cleaning generated Protocol Buffer files..."
 echo "  Target directory: $OUT_DIR"
@@ -38,4 +38,4 @@ echo ""
 echo "Next steps:"
 echo "  1. Run: ./scripts/generate-rust-proto.sh"
 echo "  2. Test: cargo build"
-echo "  3. Commit: git add src/generated/ && git commit -m 'regenerate proto files'"
\ No newline at end of file
+echo "  3. Commit: git add crates/unity-ipc/src/generated/ && git commit -m 'regenerate proto files'"
\ No newline at end of file
@@ -9,7 +9,7 @@ SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 REPO_ROOT="$(cd "$SCRIPT_DIR/../.." && pwd)"
 SERVER_ROOT="$REPO_ROOT/server"
 PROTO_ROOT="$REPO_ROOT/proto"
-OUT_DIR="$SERVER_ROOT/src/generated"
+OUT_DIR="$SERVER_ROOT/crates/unity-ipc/src/generated"
 
 echo "[generate-rust-proto.sh] Starting Protocol Buffer generation..."
 echo "  Proto root: $PROTO_ROOT"
@@ -179,4 +179,4 @@ echo ""
 echo "Next steps:"
 echo "  1. Review the generated files"
 echo "  2. Test with: cd $SERVER_ROOT && cargo build"
-echo "  3. Commit changes: git add src/generated/ && git commit -m 'regenerate proto files'"
\ No newline at end of file
+echo "  3. Commit changes: git add crates/unity-ipc/src/generated/ && git commit -m 'regenerate proto files'"
\ No newline at end of file
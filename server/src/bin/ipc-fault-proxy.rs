@@ -0,0 +1,49 @@
+//! Fault-injecting proxy between the MCP server and a Unity bridge (or `unity-bridge-sim`).
+//!
+//! ```text
+//! cargo run --bin ipc-fault-proxy -- --upstream 127.0.0.1:7777 --scenario faults.toml
+//! MCP_IPC_ENDPOINT=tcp://127.0.0.1:7790 cargo run
+//! ```
+
+use clap::Parser;
+use server::{
+    observability,
+    sim::proxy::{FaultProxy, Scenario},
+};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(version, about = "Fault-injecting IPC proxy")]
+struct Cli {
+    /// Address the MCP server connects to
+    #[arg(long, default_value = "127.0.0.1:7790")]
+    listen: String,
+    /// Bridge to relay to (host:port or tcp://host:port)
+    #[arg(long, default_value = "127.0.0.1:7777")]
+    upstream: String,
+    /// TOML file with the [[rule]] entries to apply; frames pass unchanged without one
+    #[arg(long)]
+    scenario: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    observability::init_tracing();
+    let cli = Cli::parse();
+
+    let scenario = match &cli.scenario {
+        Some(path) => Scenario::load(path)?,
+        None => Scenario::default(),
+    };
+    let proxy = FaultProxy::bind(&cli.listen, &cli.upstream, scenario).await?;
+    tracing::info!(
+        "ipc-fault-proxy listening on {} -> {}",
+        proxy.endpoint(),
+        cli.upstream
+    );
+    tokio::select! {
+        _ = proxy.run() => {}
+        _ = tokio::signal::ctrl_c() => tracing::info!("shutting down"),
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//! Serves a recorded IPC session (`ipc.record` / `ipc-tap --capture`) in place of Unity.
+//!
+//! ```text
+//! MCP_IPC_RECORD=session.jsonl cargo run            # against a real editor
+//! cargo run --bin ipc-replay -- session.jsonl       # later, without Unity
+//! MCP_IPC_ENDPOINT=tcp://127.0.0.1:7777 cargo run
+//! ```
+
+use clap::Parser;
+use server::{observability, sim::replay::ReplayBridge};
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(version, about = "Replay a recorded IPC session as a Unity bridge")]
+struct Cli {
+    /// Capture file (JSON Lines)
+    capture: PathBuf,
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:7777")]
+    bind: String,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    observability::init_tracing();
+    let cli = Cli::parse();
+
+    let bridge = ReplayBridge::load(&cli.bind, &cli.capture).await?;
+    let handle = bridge.handle();
+    tracing::info!(
+        "ipc-replay serving {} on {}",
+        cli.capture.display(),
+        bridge.endpoint()
+    );
+    tokio::select! {
+        _ = bridge.run() => {}
+        _ = tokio::signal::ctrl_c() => tracing::info!("shutting down"),
+    }
+    let misses = handle.misses();
+    if !misses.is_empty() {
+        tracing::warn!("{} request(s) had no recorded response", misses.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//! Transparent proxy that prints every IPC frame between the MCP server and a bridge as JSON.
+//!
+//! ```text
+//! cargo run --bin ipc-tap -- --upstream 127.0.0.1:7777 --capture session.jsonl
+//! MCP_IPC_ENDPOINT=tcp://127.0.0.1:7791 cargo run
+//! ```
+
+use clap::Parser;
+use server::{
+    ipc::tap::{CaptureWriter, IpcTap},
+    observability,
+};
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+
+#[derive(Parser)]
+#[command(version, about = "IPC traffic tap and decoder")]
+struct Cli {
+    /// Address the MCP server connects to
+    #[arg(long, default_value = "127.0.0.1:7791")]
+    listen: String,
+    /// Bridge to relay to (host:port or tcp://host:port)
+    #[arg(long, default_value = "127.0.0.1:7777")]
+    upstream: String,
+    /// Also write every record to this JSON Lines file
+    #[arg(long)]
+    capture: Option<PathBuf>,
+    /// Pretty-print records instead of one line each
+    #[arg(long)]
+    pretty: bool,
+    /// Do not print records (only write the capture)
+    #[arg(long, short)]
+    quiet: bool,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    observability::init_tracing();
+    let cli = Cli::parse();
+
+    let mut capture = cli
+        .capture
+        .as_deref()
+        .map(CaptureWriter::create)
+        .transpose()?;
+    let tap = IpcTap::bind(&cli.listen, &cli.upstream).await?;
+    tracing::info!(
+        "ipc-tap listening on {} -> {}",
+        tap.endpoint(),
+        cli.upstream
+    );
+
+    let (tx, mut records) = mpsc::unbounded_channel();
+    let run = tokio::spawn(tap.run(tx));
+    loop {
+        let record = tokio::select! {
+            record = records.recv() => match record {
+                Some(record) => record,
+                None => break,
+            },
+            _ = tokio::signal::ctrl_c() => {
+                tracing::info!("shutting down");
+                break;
+            }
+        };
+        if let Some(capture) = capture.as_mut() {
+            capture.write(&record)?;
+        }
+        if !cli.quiet {
+            let line = if cli.pretty {
+                serde_json::to_string_pretty(&record)?
+            } else {
+                serde_json::to_string(&record)?
+            };
+            println!("{line}");
+        }
+    }
+    run.abort();
+    Ok(())
+}
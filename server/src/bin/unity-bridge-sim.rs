@@ -0,0 +1,92 @@
+//! Simulated Unity bridge for developing and testing the MCP server without Unity.
+//!
+//! ```text
+//! cargo run --bin unity-bridge-sim -- --bind 127.0.0.1:7777 --token dev
+//! MCP_IPC_ENDPOINT=tcp://127.0.0.1:7777 MCP_IPC_TOKEN=dev cargo run
+//! ```
+
+use clap::Parser;
+use server::{
+    ipc::discovery::{self, DiscoveryConfig, EditorDescriptor},
+    observability,
+    sim::{BridgeSim, Project, SimOptions},
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(version, about = "In-memory Unity bridge simulator")]
+struct Cli {
+    /// Address to listen on (port 0 picks a free port)
+    #[arg(long, default_value = "127.0.0.1:7777")]
+    bind: String,
+    /// Token clients must send in the hello; any token is accepted if omitted
+    #[arg(long)]
+    token: Option<String>,
+    /// Project name reported by Health
+    #[arg(long, default_value = "SimProject")]
+    project_name: String,
+    /// Project root reported by Health (default: /sim/<project-name>)
+    #[arg(long)]
+    project_path: Option<String>,
+    /// Duration of each build / import step in milliseconds
+    #[arg(long, default_value_t = 100)]
+    op_step_ms: u64,
+    /// Write a discovery descriptor so `editors` and auto-discovery find the simulator
+    #[arg(long)]
+    descriptor: bool,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    observability::init_tracing();
+    let cli = Cli::parse();
+
+    let project_path = cli
+        .project_path
+        .unwrap_or_else(|| format!("/sim/{}", cli.project_name));
+    let project = Project::sample(&cli.project_name, &project_path);
+    let options = SimOptions {
+        token: cli.token,
+        op_step: Duration::from_millis(cli.op_step_ms),
+        ..SimOptions::default()
+    };
+    let sim = BridgeSim::bind(&cli.bind, project.clone(), options).await?;
+    tracing::info!(
+        "unity-bridge-sim listening on {} (project {} at {})",
+        sim.endpoint(),
+        project.name,
+        project.path
+    );
+
+    let descriptor = match cli.descriptor {
+        true => Some(write_descriptor(&sim.endpoint(), &project)?),
+        false => None,
+    };
+
+    tokio::select! {
+        _ = sim.run() => {}
+        _ = tokio::signal::ctrl_c() => tracing::info!("shutting down"),
+    }
+    if let Some(path) = descriptor {
+        let _ = std::fs::remove_file(path);
+    }
+    Ok(())
+}
+
+fn write_descriptor(endpoint: &str, project: &Project) -> anyhow::Result<PathBuf> {
+    let Some(dir) = DiscoveryConfig::default().dir else {
+        anyhow::bail!("descriptor discovery is disabled (MCP_DISCOVERY_DIR)");
+    };
+    let descriptor = EditorDescriptor {
+        endpoint: endpoint.to_string(),
+        pid: Some(std::process::id()),
+        project_name: project.name.clone(),
+        project_path: project.path.clone(),
+        unity_version: project.unity_version.clone(),
+        started_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let path = discovery::write_descriptor(&dir, &descriptor)?;
+    tracing::info!("wrote discovery descriptor {}", path.display());
+    Ok(path)
+}
@@ -1 +0,0 @@
-pub const SCHEMA_HASH: [u8; 32] = [161, 33, 69, 181, 45, 121, 106, 110, 67, 153, 171, 22, 15, 198, 114, 205, 199, 15, 62, 117, 135, 64, 107, 252, 78, 135, 0, 29, 71, 105, 1, 177];
@@ -1,5 +0,0 @@
-pub mod client;
-pub mod codec;
-pub mod features;
-pub mod framing;
-pub mod path;
@@ -1,20 +1,6 @@
-// Re-export the prost generated modules for tests and other crates.
-#![allow(clippy::derive_partial_eq_without_eq)]
-pub mod generated {
-    pub mod mcp {
-        pub mod unity {
-            pub mod v1 {
-                include!("generated/mcp.unity.v1.rs");
-            }
-        }
-    }
-    pub mod schema_hash {
-        include!("generated/schema_hash.rs");
-    }
-}
-
-// IPC module for Unity bridge communication
-pub mod ipc;
+// IPC client for the Unity bridge lives in the unity-ipc crate; keep the old paths working
+pub use unity_ipc as ipc;
+pub use unity_ipc::generated;
 
 // MCP-related modules
 pub mod mcp;
@@ -23,3 +9,6 @@ pub mod observability;
 
 // Unified configuration module
 pub mod config;
+
+// In-memory Unity bridge for offline development (unity-bridge-sim)
+pub mod sim;
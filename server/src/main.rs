@@ -1,9 +1,376 @@
-use server::{mcp::service::McpService, observability};
+use anyhow::Context;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rmcp::model::CallToolRequestParam;
+use server::{
+    config::{self, Config, LoadOptions},
+    ipc::discovery::{self, DiscoveredEditor, DiscoveryConfig},
+    mcp::service::McpService,
+    mcp::tools::status::{BridgeStatusOut, EditorStatusOut},
+    observability,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(version, about = "MCP server for the Unity Editor")]
+struct Cli {
+    #[command(flatten)]
+    overrides: Overrides,
+    /// Without a subcommand the MCP server is started
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+/// Highest-precedence configuration layer (see `config show`).
+#[derive(Args)]
+struct Overrides {
+    /// Config file to use instead of the nearest unity-mcp.toml (env: UNITY_MCP_CONFIG)
+    #[arg(long, global = true, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Override a setting, e.g. --set ipc.call_timeout_ms=8000 (repeatable)
+    #[arg(long = "set", global = true, value_name = "KEY=VALUE")]
+    set: Vec<String>,
+    /// Same as --set ipc.endpoint=<ENDPOINT>
+    #[arg(long, global = true)]
+    endpoint: Option<String>,
+    /// Same as --set ipc.token_file=<FILE> (the token itself is not accepted on the command line)
+    #[arg(long, global = true, value_name = "FILE")]
+    token_file: Option<PathBuf>,
+    /// Same as --set logging.level=<LEVEL>
+    #[arg(long, global = true)]
+    log_level: Option<String>,
+}
+
+impl Overrides {
+    /// Files, then the flags on top.
+    fn load(self) -> anyhow::Result<Config> {
+        let mut config = Config::load(&LoadOptions::from_env(self.config))?;
+        let flags = [
+            ("ipc.endpoint", self.endpoint),
+            (
+                "ipc.token_file",
+                self.token_file.map(|p| p.display().to_string()),
+            ),
+            ("logging.level", self.log_level),
+        ];
+        for (key, value) in flags {
+            if let Some(value) = value {
+                config.set_override(&format!("{key}={value}"))?;
+            }
+        }
+        for assignment in &self.set {
+            config.set_override(assignment)?;
+        }
+        Ok(config)
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Start the MCP server (the default without a subcommand)
+    Serve {
+        /// Transport to serve MCP over (default: server.transport)
+        #[arg(long, value_enum)]
+        transport: Option<Transport>,
+        /// Listen address of the http transport (default: server.bind_addr)
+        #[arg(long, value_name = "ADDR")]
+        bind: Option<String>,
+    },
+    /// Show whether the Unity Bridge is reachable and which features it negotiated
+    Status {
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+        /// How long to wait for the editors to connect
+        #[arg(long, value_name = "MS", default_value_t = 5000)]
+        wait_ms: u64,
+    },
+    /// Invoke one tool, print its result and exit (non-zero if the tool failed)
+    Call {
+        /// Tool name, e.g. unity_execute_menu_item
+        tool: String,
+        /// Tool arguments as a JSON object
+        #[arg(long, value_name = "ARGS", default_value = "{}")]
+        json: String,
+        /// How long to wait for the editors to connect
+        #[arg(long, value_name = "MS", default_value_t = 5000)]
+        wait_ms: u64,
+    },
+    /// List running Unity Editors found via discovery descriptors and port ranges
+    Editors {
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+        /// Descriptor directory (default: MCP_DISCOVERY_DIR or ~/.unity-mcp/editors)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// Ports to scan, e.g. "7777-7787" (default: MCP_DISCOVERY_PORTS)
+        #[arg(long)]
+        ports: Option<String>,
+    },
+    /// Inspect the configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Transport {
+    Stdio,
+    Http,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print every effective setting and where its value came from
+    Show {
+        /// Print the results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
 
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let mut config = cli.overrides.load()?;
+    if let Some(Command::Serve { transport, bind }) = &cli.command {
+        if let Some(transport) = transport {
+            let name = match transport {
+                Transport::Stdio => "stdio",
+                Transport::Http => "http",
+            };
+            config.set_override(&format!("server.transport={name}"))?;
+        }
+        if let Some(bind) = bind {
+            config.set_override(&format!("server.bind_addr={bind}"))?;
+        }
+    }
+    config.validate()?;
+    config::install(config);
     observability::init_tracing();
+    if let Some(config) = config::current() {
+        for file in config.files() {
+            tracing::info!("Loaded configuration from {}", file.path.display());
+            for key in &file.unknown_keys {
+                tracing::warn!("Unknown setting `{}` in {}", key, file.path.display());
+            }
+        }
+    }
 
+    match cli.command {
+        None | Some(Command::Serve { .. }) => serve().await,
+        Some(Command::Status { json, wait_ms }) => status(json, wait_ms).await,
+        Some(Command::Call {
+            tool,
+            json,
+            wait_ms,
+        }) => call(tool, &json, wait_ms).await,
+        Some(Command::Editors { json, dir, ports }) => list_editors(json, dir, ports).await,
+        Some(Command::Config {
+            command: ConfigCommand::Show { json },
+        }) => show_config(json),
+    }
+}
+
+async fn serve() -> anyhow::Result<()> {
     let svc = McpService::new().await?;
-    svc.serve_stdio().await
+
+    // server.transport (MCP_TRANSPORT) selects the transport; stdio stays the default.
+    // Other values were rejected by Config::validate.
+    match config::var("MCP_TRANSPORT").as_deref() {
+        Some("http") | Some("streamable-http") => {
+            let bind_addr =
+                config::var("MCP_BIND_ADDR").unwrap_or_else(|| "127.0.0.1:8080".to_string());
+            svc.serve_http(&bind_addr).await
+        }
+        _ => svc.serve_stdio().await,
+    }
+}
+
+/// Call a tool through an in-process MCP session once the editors had `wait_ms` to connect.
+async fn call_once(
+    name: String,
+    arguments: serde_json::Map<String, serde_json::Value>,
+    wait_ms: u64,
+) -> anyhow::Result<rmcp::model::CallToolResult> {
+    let svc = McpService::new().await?;
+    let editors = svc.editors().clone();
+    let client = svc.serve_in_process().await?;
+    editors.wait_connected(Duration::from_millis(wait_ms)).await;
+
+    let result = client
+        .call_tool(CallToolRequestParam {
+            name: name.into(),
+            arguments: Some(arguments),
+        })
+        .await;
+    let _ = client.cancel().await;
+    Ok(result?)
+}
+
+/// Text of the first content item (tools answer with one JSON text item).
+fn result_text(result: &rmcp::model::CallToolResult) -> Option<&str> {
+    result
+        .content
+        .as_ref()?
+        .first()
+        .and_then(|c| c.as_text())
+        .map(|t| t.text.as_str())
+}
+
+async fn status(json: bool, wait_ms: u64) -> anyhow::Result<()> {
+    let result = call_once(
+        "unity_bridge_status".to_string(),
+        Default::default(),
+        wait_ms,
+    )
+    .await?;
+    let text = result_text(&result).unwrap_or("{}");
+    let status: BridgeStatusOut = serde_json::from_str(text)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        for editor in &status.editors {
+            println!("{}", describe_status(editor));
+        }
+    }
+
+    let connected = status.editors.iter().filter(|e| e.connected).count();
+    if connected < status.editors.len() {
+        anyhow::bail!(
+            "{} of {} Unity Editors connected",
+            connected,
+            status.editors.len()
+        );
+    }
+    Ok(())
+}
+
+async fn call(tool: String, json: &str, wait_ms: u64) -> anyhow::Result<()> {
+    let arguments = match serde_json::from_str(json)? {
+        serde_json::Value::Object(map) => map,
+        other => anyhow::bail!("--json must be a JSON object, got {}", other),
+    };
+    let result = call_once(tool.clone(), arguments, wait_ms).await?;
+
+    if let Some(structured) = &result.structured_content {
+        println!("{}", serde_json::to_string_pretty(structured)?);
+    } else {
+        let content = result.content.iter().flatten();
+        for text in content.filter_map(|c| c.as_text()) {
+            println!("{}", text.text);
+        }
+    }
+    if result.is_error == Some(true) {
+        anyhow::bail!("tool {} reported an error", tool);
+    }
+    Ok(())
+}
+
+async fn list_editors(
+    json: bool,
+    dir: Option<PathBuf>,
+    ports: Option<String>,
+) -> anyhow::Result<()> {
+    let mut cfg = DiscoveryConfig::default();
+    if dir.is_some() {
+        cfg.dir = dir;
+    }
+    if let Some(raw) = ports {
+        cfg.ports = discovery::parse_port_ranges(&raw).map_err(anyhow::Error::msg)?;
+    }
+
+    let editors = discovery::discover(&cfg).await;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&editors)?);
+    } else if editors.is_empty() {
+        println!("No Unity Editors found");
+    } else {
+        for editor in &editors {
+            println!("{}", describe(editor));
+        }
+    }
+    Ok(())
+}
+
+fn show_config(json: bool) -> anyhow::Result<()> {
+    let config = config::current().context("configuration was not installed")?;
+    let effective = config.effective();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&effective)?);
+        return Ok(());
+    }
+    for file in config.files() {
+        for key in &file.unknown_keys {
+            println!("# unknown setting `{}` in {}", key, file.path.display());
+        }
+    }
+    for entry in &effective {
+        let value = match &entry.value {
+            serde_json::Value::Null => "(unset)".to_string(),
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        println!("{:<40} {:<32} {}", entry.key, value, entry.source);
+    }
+    Ok(())
+}
+
+/// One line per connected editor: endpoint, state, project and negotiated features.
+fn describe_status(editor: &EditorStatusOut) -> String {
+    let state = if editor.connected {
+        "connected"
+    } else {
+        "disconnected"
+    };
+    let mut line = format!("{:<24} {:<12}", editor.endpoint, state);
+    if let Some(name) = &editor.project_name {
+        line.push_str(&format!(" {}", name));
+    }
+    if let Some(path) = &editor.project_path {
+        line.push_str(&format!(" ({})", path));
+    }
+    if let Some(features) = &editor.negotiated_features {
+        line.push_str(&format!(" features: {}", features.join(", ")));
+    }
+    if let Some(ms) = editor.latency_ms {
+        line.push_str(&format!(" latency: {:.1}ms", ms));
+    }
+    if !editor.connected
+        && let Some(err) = &editor.last_error
+    {
+        line.push_str(&format!(" error: {}", err));
+    }
+    line
+}
+
+/// One line per editor: endpoint, state, project and where it was found.
+fn describe(editor: &DiscoveredEditor) -> String {
+    let state = match (editor.reachable, editor.ready) {
+        (true, Some(false)) => "busy",
+        (true, Some(true)) => "ready",
+        (true, None) => "listening",
+        (false, _) => "unreachable",
+    };
+    let mut line = format!("{:<24} {:<12}", editor.endpoint, state);
+    if let Some(name) = &editor.project_name {
+        line.push_str(&format!(" {}", name));
+    }
+    if let Some(path) = &editor.project_path {
+        line.push_str(&format!(" ({})", path));
+    }
+    if let Some(version) = &editor.unity_version {
+        line.push_str(&format!(" Unity {}", version));
+    }
+    match &editor.descriptor_path {
+        Some(path) => line.push_str(&format!(" [descriptor {}]", path)),
+        None => line.push_str(" [port scan]"),
+    }
+    if let Some(err) = &editor.error {
+        line.push_str(&format!(" error: {}", err));
+    }
+    line
 }
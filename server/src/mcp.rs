@@ -1,2 +1,5 @@
+pub mod editors;
+pub mod http_guard;
 pub mod service;
+pub mod sessions;
 pub mod tools;
@@ -0,0 +1,328 @@
+//! Unity Editor connections known to the server.
+//!
+//! 接続先（`MCP_IPC_ENDPOINTS`）ごとに 1 スロットを持ち、接続後に `HealthResponse` の
+//! `project_name` / `project_path` を記録する。ツールの `project` 引数はこの名前・パス・
+//! エンドポイント文字列のいずれかで照合し、省略時は最初に設定された Editor を使う。
+
+use crate::ipc::client::IpcClient;
+use crate::ipc::features::FeatureSet;
+use rmcp::ErrorData as McpError;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+#[derive(Clone, Debug, Default)]
+pub struct BridgeState {
+    pub connected: bool,
+    pub attempt: u32,
+    pub last_error: Option<String>,
+    pub next_retry_ms: Option<u64>,
+    pub endpoint: String,
+    pub project_name: Option<String>,
+    pub project_path: Option<String>,
+}
+
+impl BridgeState {
+    /// Whether a tool's `project` selector refers to this editor.
+    fn matches(&self, selector: &str) -> bool {
+        if self.endpoint == selector {
+            return true;
+        }
+        if self
+            .project_name
+            .as_deref()
+            .is_some_and(|name| name.eq_ignore_ascii_case(selector))
+        {
+            return true;
+        }
+        self.project_path
+            .as_deref()
+            .is_some_and(|path| normalize_path(path) == normalize_path(selector))
+    }
+
+    /// Name shown in error messages: the project name once known, else the endpoint.
+    fn label(&self) -> &str {
+        self.project_name.as_deref().unwrap_or(&self.endpoint)
+    }
+}
+
+// Windows の区切り文字と末尾スラッシュの違いは同じパスとして扱う
+fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/").trim_end_matches('/').to_string()
+}
+
+/// One Unity Editor: its connection state and, once connected, its client.
+#[derive(Default)]
+pub struct EditorSlot {
+    state: RwLock<BridgeState>,
+    ipc: RwLock<Option<IpcClient>>,
+}
+
+impl EditorSlot {
+    pub async fn state(&self) -> BridgeState {
+        self.state.read().await.clone()
+    }
+
+    pub async fn ipc(&self) -> Option<IpcClient> {
+        self.ipc.read().await.clone()
+    }
+
+    pub(crate) async fn update_state(&self, f: impl FnOnce(&mut BridgeState)) {
+        f(&mut *self.state.write().await);
+    }
+
+    pub(crate) async fn set_ipc(&self, ipc: IpcClient) {
+        *self.ipc.write().await = Some(ipc);
+    }
+
+    /// Record which project the editor has open (empty strings mean unknown).
+    pub(crate) async fn set_project(&self, name: &str, path: &str) {
+        self.update_state(|s| {
+            s.project_name = Some(name.to_string()).filter(|n| !n.is_empty());
+            s.project_path = Some(path.to_string()).filter(|p| !p.is_empty());
+        })
+        .await;
+    }
+
+    /// The client, or the "not connected yet" error describing the last attempt.
+    pub async fn require_ipc(&self) -> Result<IpcClient, McpError> {
+        if let Some(c) = self.ipc().await {
+            return Ok(c);
+        }
+        let s = self.state().await;
+        let mut msg =
+            String::from("Unity Bridge not connected yet. Waiting for Unity Editor to start.");
+        if !s.endpoint.is_empty() {
+            msg.push_str(&format!(" endpoint={}", s.endpoint));
+        }
+        if let Some(err) = &s.last_error {
+            msg.push_str(&format!(" last_error={}", err));
+        }
+        if let Some(ms) = s.next_retry_ms {
+            msg.push_str(&format!(" next_retry_ms={}", ms));
+        }
+        Err(McpError::internal_error(msg, None))
+    }
+}
+
+/// Every editor the server connects to, in configuration order.
+#[derive(Clone, Default)]
+pub struct EditorRegistry {
+    slots: Arc<RwLock<Vec<Arc<EditorSlot>>>>,
+}
+
+impl EditorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an editor reachable at `endpoint`; the first one added is the default.
+    pub async fn add(&self, endpoint: String) -> Arc<EditorSlot> {
+        let slot = Arc::new(EditorSlot::default());
+        slot.update_state(|s| s.endpoint = endpoint).await;
+        self.slots.write().await.push(slot.clone());
+        slot
+    }
+
+    /// Register `endpoint` unless an editor with that endpoint already exists.
+    pub async fn add_if_absent(&self, endpoint: String) -> Option<Arc<EditorSlot>> {
+        let mut slots = self.slots.write().await;
+        for slot in slots.iter() {
+            if slot.state().await.endpoint == endpoint {
+                return None;
+            }
+        }
+        let slot = Arc::new(EditorSlot::default());
+        slot.update_state(|s| s.endpoint = endpoint).await;
+        slots.push(slot.clone());
+        Some(slot)
+    }
+
+    pub async fn contains(&self, endpoint: &str) -> bool {
+        for slot in self.slots().await {
+            if slot.state().await.endpoint == endpoint {
+                return true;
+            }
+        }
+        false
+    }
+
+    pub async fn slots(&self) -> Vec<Arc<EditorSlot>> {
+        self.slots.read().await.clone()
+    }
+
+    /// Editor selected by a tool's `project` argument (`None` = the first configured one).
+    pub async fn find(&self, project: Option<&str>) -> Result<Arc<EditorSlot>, McpError> {
+        let slots = self.slots().await;
+        let Some(selector) = project.map(str::trim).filter(|p| !p.is_empty()) else {
+            return slots.first().cloned().ok_or_else(|| {
+                McpError::internal_error("No Unity Editor endpoint configured", None)
+            });
+        };
+
+        let mut known = Vec::with_capacity(slots.len());
+        for slot in &slots {
+            let state = slot.state().await;
+            if state.matches(selector) {
+                return Ok(slot.clone());
+            }
+            known.push(state.label().to_string());
+        }
+        Err(McpError::invalid_params(
+            format!(
+                "Unknown Unity project \"{}\". Known editors: {}",
+                selector,
+                known.join(", ")
+            ),
+            Some(serde_json::json!({ "editors": known })),
+        ))
+    }
+
+    /// Client of the selected editor, failing if it is unknown or not connected.
+    pub async fn resolve(&self, project: Option<&str>) -> Result<IpcClient, McpError> {
+        self.find(project).await?.require_ipc().await
+    }
+
+    /// Wait until every editor is connected and has reported its project, or `timeout`.
+    ///
+    /// Returns how many editors are connected when the wait ends.
+    pub async fn wait_connected(&self, timeout: Duration) -> usize {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let mut connected = 0;
+            let mut settled = true;
+            for slot in self.slots().await {
+                if slot.ipc().await.is_some() {
+                    connected += 1;
+                    settled &= slot.state().await.project_name.is_some();
+                } else {
+                    settled = false;
+                }
+            }
+            if settled || tokio::time::Instant::now() >= deadline {
+                return connected;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    /// Features accepted by any connected editor; `None` while none is connected.
+    pub async fn negotiated_features(&self) -> Option<FeatureSet> {
+        let mut union: Option<FeatureSet> = None;
+        for slot in self.slots().await {
+            if let Some(ipc) = slot.ipc().await {
+                let features = ipc.get_negotiated_features().await;
+                union = Some(match union {
+                    Some(u) => u.union(&features),
+                    None => features,
+                });
+            }
+        }
+        union
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn registry() -> EditorRegistry {
+        let editors = EditorRegistry::new();
+        editors
+            .add("tcp://127.0.0.1:7777".to_string())
+            .await
+            .set_project("ClientGame", "/work/client-game/")
+            .await;
+        editors
+            .add("tcp://127.0.0.1:7778".to_string())
+            .await
+            .set_project("SharedPackages", r"C:\work\shared")
+            .await;
+        // Not connected yet: only reachable by endpoint
+        editors.add("tcp://127.0.0.1:7779".to_string()).await;
+        editors
+    }
+
+    async fn endpoint_of(editors: &EditorRegistry, project: Option<&str>) -> String {
+        editors.find(project).await.unwrap().state().await.endpoint
+    }
+
+    #[tokio::test]
+    async fn test_find_by_name_path_or_endpoint() {
+        let editors = registry().await;
+        assert_eq!(endpoint_of(&editors, None).await, "tcp://127.0.0.1:7777");
+        assert_eq!(
+            endpoint_of(&editors, Some("")).await,
+            "tcp://127.0.0.1:7777"
+        );
+        assert_eq!(
+            endpoint_of(&editors, Some("sharedpackages")).await,
+            "tcp://127.0.0.1:7778"
+        );
+        assert_eq!(
+            endpoint_of(&editors, Some("/work/client-game")).await,
+            "tcp://127.0.0.1:7777"
+        );
+        assert_eq!(
+            endpoint_of(&editors, Some("C:/work/shared/")).await,
+            "tcp://127.0.0.1:7778"
+        );
+        assert_eq!(
+            endpoint_of(&editors, Some("tcp://127.0.0.1:7779")).await,
+            "tcp://127.0.0.1:7779"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_project_lists_known_editors() {
+        let editors = registry().await;
+        let err = editors.resolve(Some("Other")).await.unwrap_err();
+        assert_eq!(err.code, rmcp::model::ErrorCode::INVALID_PARAMS);
+        assert!(
+            err.message
+                .contains("ClientGame, SharedPackages, tcp://127.0.0.1:7779"),
+            "{}",
+            err.message
+        );
+    }
+
+    #[tokio::test]
+    async fn test_disconnected_editor_reports_its_state() {
+        let editors = registry().await;
+        editors
+            .find(Some("SharedPackages"))
+            .await
+            .unwrap()
+            .update_state(|s| s.last_error = Some("connection refused".to_string()))
+            .await;
+        let err = editors.resolve(Some("SharedPackages")).await.unwrap_err();
+        assert_eq!(err.code, rmcp::model::ErrorCode::INTERNAL_ERROR);
+        assert!(err.message.starts_with("Unity Bridge not connected yet."));
+        assert!(err.message.contains("endpoint=tcp://127.0.0.1:7778"));
+        assert!(err.message.contains("last_error=connection refused"));
+
+        assert!(editors.negotiated_features().await.is_none());
+        assert!(editors.contains("tcp://127.0.0.1:7779").await);
+        assert!(
+            editors
+                .add_if_absent("tcp://127.0.0.1:7779".to_string())
+                .await
+                .is_none()
+        );
+        assert!(
+            editors
+                .add_if_absent("tcp://127.0.0.1:7780".to_string())
+                .await
+                .is_some()
+        );
+        assert!(editors.contains("tcp://127.0.0.1:7780").await);
+        assert!(
+            EditorRegistry::new()
+                .resolve(None)
+                .await
+                .unwrap_err()
+                .message
+                .contains("No Unity Editor endpoint configured")
+        );
+    }
+}
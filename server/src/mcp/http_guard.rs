@@ -0,0 +1,145 @@
+//! Streamable HTTP の入口チェック。
+//!
+//! ブラウザ経由の DNS rebinding を防ぐため、`Origin` ヘッダーが付いたリクエストは
+//! localhost 系のオリジンだけを通す。`server.http_token`（`MCP_HTTP_TOKEN`）を設定すると
+//! `Authorization: Bearer <token>` も必須になる。loopback 以外への bind は
+//! `server.allow_remote`（`MCP_ALLOW_REMOTE`）を有効にしたときだけ許可する。
+
+use crate::config;
+use crate::ipc::path::host_is_loopback;
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::{net::SocketAddr, sync::Arc};
+
+/// Settings the HTTP middleware checks every request against.
+#[derive(Clone, Default)]
+pub struct HttpGuard {
+    token: Option<Arc<str>>,
+}
+
+impl HttpGuard {
+    pub fn from_config() -> Self {
+        Self {
+            token: config::var("MCP_HTTP_TOKEN")
+                .filter(|t| !t.is_empty())
+                .map(Arc::from),
+        }
+    }
+
+    pub fn requires_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    fn check(&self, headers: &HeaderMap) -> Result<(), (StatusCode, &'static str)> {
+        let origin = headers.get(header::ORIGIN);
+        if origin.is_some_and(|o| !o.to_str().is_ok_and(origin_is_local)) {
+            return Err((StatusCode::FORBIDDEN, "Origin not allowed"));
+        }
+        if let Some(token) = &self.token {
+            let presented = headers
+                .get(header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "));
+            if !presented.is_some_and(|p| tokens_match(p, token)) {
+                return Err((StatusCode::UNAUTHORIZED, "Missing or invalid bearer token"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Axum middleware rejecting requests [`HttpGuard`] does not allow.
+pub async fn check_request(State(guard): State<HttpGuard>, req: Request, next: Next) -> Response {
+    match guard.check(req.headers()) {
+        Ok(()) => next.run(req).await,
+        Err((status, reason)) => {
+            tracing::warn!("Rejected HTTP request to {}: {}", req.uri().path(), reason);
+            let mut resp = (status, reason).into_response();
+            if status == StatusCode::UNAUTHORIZED {
+                resp.headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, "Bearer".parse().unwrap());
+            }
+            resp
+        }
+    }
+}
+
+/// Refuse to listen beyond this machine unless remote access was opted into.
+pub fn check_bind_addr(addr: SocketAddr) -> anyhow::Result<()> {
+    if addr.ip().is_loopback() || config::flag("MCP_ALLOW_REMOTE").unwrap_or(false) {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "refusing to serve MCP on non-loopback address {addr}; set server.allow_remote (MCP_ALLOW_REMOTE=on) to allow it"
+    )
+}
+
+// "http://localhost:3000" のようなオリジンの host が loopback か
+fn origin_is_local(origin: &str) -> bool {
+    origin
+        .split_once("://")
+        .is_some_and(|(_, rest)| !rest.contains('/') && host_is_loopback(rest))
+}
+
+// 長さ以外の情報を比較時間から漏らさない
+fn tokens_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin_is_local() {
+        for local in [
+            "http://localhost",
+            "http://localhost:3000",
+            "http://127.0.0.1:8080",
+            "https://[::1]:8443",
+        ] {
+            assert!(origin_is_local(local), "{local}");
+        }
+        for remote in [
+            "https://evil.example",
+            "http://localhost.evil.example",
+            "http://192.168.1.10:8080",
+            "null",
+        ] {
+            assert!(!origin_is_local(remote), "{remote}");
+        }
+    }
+
+    #[test]
+    fn test_bearer_token_is_required_when_configured() {
+        let guard = HttpGuard {
+            token: Some(Arc::from("s3cret")),
+        };
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            guard.check(&headers).unwrap_err().0,
+            StatusCode::UNAUTHORIZED
+        );
+
+        headers.insert(header::AUTHORIZATION, "Bearer wrong!".parse().unwrap());
+        assert_eq!(
+            guard.check(&headers).unwrap_err().0,
+            StatusCode::UNAUTHORIZED
+        );
+
+        headers.insert(header::AUTHORIZATION, "Bearer s3cret".parse().unwrap());
+        assert!(guard.check(&headers).is_ok());
+
+        headers.insert(header::ORIGIN, "https://evil.example".parse().unwrap());
+        assert_eq!(guard.check(&headers).unwrap_err().0, StatusCode::FORBIDDEN);
+    }
+}
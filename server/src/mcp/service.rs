@@ -1,19 +1,50 @@
-use crate::ipc::{client::IpcClient, path::IpcConfig};
+use crate::config;
+use crate::ipc::{
+    client::{IpcClient, IpcError},
+    features::FeatureFlag,
+    path::IpcConfig,
+};
+pub use crate::mcp::editors::BridgeState;
+use crate::mcp::editors::{EditorRegistry, EditorSlot};
+use crate::mcp::http_guard::{self, HttpGuard};
+use crate::mcp::sessions::{self, OperationClaim, SessionId, SessionLease, SessionRegistry};
 pub use crate::mcp::tools::build::{UnityBuildAssetBundlesRequest, UnityBuildPlayerRequest};
+use crate::mcp::tools::{self, UnavailableTools};
 use rmcp::{
-    ServerHandler, ServiceExt, handler::server::tool::ToolRouter, model::*, tool_handler,
-    transport::stdio,
+    RoleClient, RoleServer, ServerHandler, ServiceExt,
+    handler::server::tool::ToolCallContext,
+    handler::server::tool::ToolRouter,
+    model::*,
+    service::{NotificationContext, RequestContext, RunningService},
+    transport::{
+        stdio,
+        streamable_http_server::{
+            StreamableHttpServerConfig, StreamableHttpService, session::local::LocalSessionManager,
+        },
+    },
 };
 use std::{
     collections::{HashMap, HashSet},
+    path::PathBuf,
     sync::Arc,
     time::Duration,
 };
-use tokio::sync::{Mutex, RwLock, mpsc};
+use tokio::sync::Mutex;
+
+/// Path the Streamable HTTP endpoint is mounted at.
+pub const HTTP_MCP_PATH: &str = "/mcp";
+
+/// JSON-RPC error code for requests the client cancelled (same value as LSP's RequestCancelled).
+pub const REQUEST_CANCELLED: ErrorCode = ErrorCode(-32800);
+
+// Timeout for forwarding OperationCancelRequest after the MCP request was cancelled
+const CANCEL_FORWARD_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Timeout for the Health request that tells us which project an editor has open
+const PROJECT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
 
-// Type aliases to keep public struct types readable
-type NotificationPayload = (String, serde_json::Value);
-type NotificationSender = mpsc::UnboundedSender<NotificationPayload>;
+// Buffer size of the in-memory pipe between `serve_in_process` and its client
+const IN_PROCESS_PIPE_CAPACITY: usize = 64 * 1024;
 
 #[derive(Debug, Clone)]
 pub struct OperationState {
@@ -24,51 +55,58 @@ pub struct OperationState {
     pub message: String,
     pub payload_json: String,
     pub last_updated: std::time::Instant,
-}
-
-#[derive(Clone, Debug, Default)]
-pub struct BridgeState {
-    pub connected: bool,
-    pub attempt: u32,
-    pub last_error: Option<String>,
-    pub next_retry_ms: Option<u64>,
+    /// Endpoint of the editor that reported the operation
     pub endpoint: String,
 }
 
 #[derive(Clone)]
 pub struct McpService {
-    #[allow(dead_code)]
     tool_router: ToolRouter<Self>,
-    ipc: Arc<RwLock<Option<IpcClient>>>,
-    bridge_state: Arc<RwLock<BridgeState>>,
+    editors: EditorRegistry,
     operations: Arc<Mutex<HashMap<String, OperationState>>>,
-    notification_sender: Arc<Mutex<Option<NotificationSender>>>,
+    sessions: SessionRegistry,
+    session_id: SessionId,
+    /// Unregisters an HTTP session when rmcp drops its service (the session ended)
+    session_lease: Option<Arc<SessionLease>>,
     pub sent_finished_notifications: Arc<Mutex<HashSet<String>>>,
 }
 
 impl McpService {
     pub async fn new() -> anyhow::Result<Self> {
         let operations = Arc::new(Mutex::new(HashMap::new()));
-        let ipc_cell: Arc<RwLock<Option<IpcClient>>> = Arc::new(RwLock::new(None));
-        let bridge_state = Arc::new(RwLock::new(BridgeState::default()));
+        let editors = EditorRegistry::new();
+        let sessions = SessionRegistry::new();
 
-        // 接続スーパーバイザを起動（初回未接続でもMCPは起動継続）
-        Self::spawn_bridge_connector(ipc_cell.clone(), bridge_state.clone(), operations.clone())
-            .await;
+        // Editor ごとに接続スーパーバイザを起動（初回未接続でもMCPは起動継続）
+        for cfg in IpcConfig::all_from_env() {
+            let slot = editors.add(cfg.resolved_endpoint().to_string()).await;
+            Self::spawn_bridge_connector(cfg, slot, operations.clone(), sessions.clone()).await;
+        }
 
         Ok(Self {
-            tool_router: super::tools::make_tool_router(),
-            ipc: ipc_cell,
-            bridge_state,
+            tool_router: tools::make_tool_router(),
+            editors,
             operations,
-            notification_sender: Arc::new(Mutex::new(None)),
+            sessions,
+            session_id: sessions::next_session_id(),
+            session_lease: None,
             sent_finished_notifications: Arc::new(Mutex::new(HashSet::new())),
         })
     }
 
+    /// Clone that shares all state but represents a new MCP session.
+    fn for_new_session(&self) -> Self {
+        let mut svc = self.clone();
+        svc.session_id = sessions::next_session_id();
+        svc.session_lease = Some(Arc::new(svc.sessions.lease(svc.session_id)));
+        svc
+    }
+
     async fn spawn_event_processor(
         ipc: IpcClient,
+        endpoint: String,
         operations: Arc<Mutex<HashMap<String, OperationState>>>,
+        sessions: SessionRegistry,
     ) {
         use std::collections::HashMap;
         use std::time::{Duration, Instant};
@@ -96,7 +134,14 @@ impl McpService {
                             .await;
                         }
                         Some(crate::generated::mcp::unity::v1::ipc_event::Payload::Op(op)) => {
-                            Self::process_operation_event(op, operations.clone()).await;
+                            Self::process_operation_event(
+                                op,
+                                &ipc_clone,
+                                &endpoint,
+                                operations.clone(),
+                                &sessions,
+                            )
+                            .await;
                         }
                         None => {
                             tracing::warn!("Received empty event payload");
@@ -116,94 +161,115 @@ impl McpService {
     }
 
     async fn spawn_bridge_connector(
-        ipc_cell: Arc<RwLock<Option<IpcClient>>>,
-        bridge_state: Arc<RwLock<BridgeState>>,
+        cfg: IpcConfig,
+        slot: Arc<EditorSlot>,
         operations: Arc<Mutex<HashMap<String, OperationState>>>,
+        sessions: SessionRegistry,
     ) {
         tokio::spawn(async move {
-            let mut backoff_ms: u64 = 200;
-            const MAX_BACKOFF_MS: u64 = 5_000;
+            let mut backoff_ms = cfg.reconnect_initial_backoff.as_millis() as u64;
+            let max_backoff_ms = cfg.reconnect_max_backoff.as_millis() as u64;
             let mut attempt: u32 = 0;
 
             loop {
-                // 現在の設定から接続先を解決
-                let cfg = IpcConfig::default();
-                let endpoint_resolved = cfg
-                    .endpoint
-                    .as_deref()
-                    .map(super::super::ipc::path::parse_endpoint)
-                    .unwrap_or_else(super::super::ipc::path::default_endpoint);
-                let endpoint_str = match endpoint_resolved {
-                    #[cfg(unix)]
-                    super::super::ipc::path::Endpoint::Unix(ref p) => {
-                        format!("unix://{}", p.display())
-                    }
-                    #[cfg(windows)]
-                    super::super::ipc::path::Endpoint::Pipe(ref name) => {
-                        format!("pipe://{}", name)
-                    }
-                    super::super::ipc::path::Endpoint::Tcp(ref addr) => {
-                        format!("tcp://{}", addr)
-                    }
-                };
                 attempt = attempt.saturating_add(1);
-                {
-                    let mut s = bridge_state.write().await;
+                slot.update_state(|s| {
                     s.connected = false;
                     s.attempt = attempt;
                     s.next_retry_ms = None;
-                    s.endpoint = endpoint_str.clone();
-                }
+                })
+                .await;
 
-                match IpcClient::connect(cfg).await {
+                match IpcClient::connect(cfg.clone()).await {
                     Ok(ipc) => {
-                        {
-                            let mut s = bridge_state.write().await;
+                        slot.update_state(|s| {
                             s.connected = true;
                             s.last_error = None;
                             s.next_retry_ms = None;
-                            s.endpoint = endpoint_str.clone();
-                        }
-                        {
-                            let mut guard = ipc_cell.write().await;
-                            *guard = Some(ipc.clone());
-                        }
+                        })
+                        .await;
+                        slot.set_ipc(ipc.clone()).await;
 
                         // Unityイベント処理を起動
-                        Self::spawn_event_processor(ipc.clone(), operations.clone()).await;
+                        Self::spawn_event_processor(
+                            ipc.clone(),
+                            cfg.resolved_endpoint().to_string(),
+                            operations.clone(),
+                            sessions.clone(),
+                        )
+                        .await;
+                        Self::spawn_tool_list_watcher(ipc.clone(), sessions.clone());
+                        Self::spawn_project_probe(ipc.clone(), slot.clone());
 
                         // IpcClient内部のリコネクト監視に委譲。ここでは待機。
-                        tracing::info!("Unity Bridge connected. MCP tools are fully available.");
+                        tracing::info!(
+                            endpoint = %cfg.resolved_endpoint(),
+                            "Unity Bridge connected. MCP tools are fully available."
+                        );
                         return; // 初回接続後は終了（内部で切断検出→自動再接続）
                     }
                     Err(e) => {
                         let msg = e.to_string();
-                        {
-                            let mut s = bridge_state.write().await;
-                            s.last_error = Some(msg.clone());
-                        }
+                        slot.update_state(|s| s.last_error = Some(msg.clone()))
+                            .await;
                         tracing::warn!(
                             attempt,
                             backoff_ms,
+                            endpoint = %cfg.resolved_endpoint(),
                             "Unity Bridge connect failed (attempt {attempt}): {msg}. Retrying in {backoff_ms}ms"
                         );
 
                         // 次回リトライ予定を公開
-                        {
-                            let mut s = bridge_state.write().await;
-                            s.next_retry_ms = Some(backoff_ms);
-                        }
+                        slot.update_state(|s| s.next_retry_ms = Some(backoff_ms))
+                            .await;
 
                         tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
                         let jitter = rand::random::<u64>() % (backoff_ms / 4 + 1);
                         backoff_ms =
-                            std::cmp::min(backoff_ms.saturating_mul(2), MAX_BACKOFF_MS) + jitter;
+                            std::cmp::min(backoff_ms.saturating_mul(2), max_backoff_ms) + jitter;
                     }
                 }
             }
         });
     }
 
+    /// Ask a freshly connected editor which project it has open, for `project` selectors.
+    ///
+    /// Runs in the background so a bridge that is slow to answer does not delay tools
+    /// addressed by endpoint or to the default editor.
+    fn spawn_project_probe(ipc: IpcClient, slot: Arc<EditorSlot>) {
+        tokio::spawn(async move {
+            match ipc.health(PROJECT_PROBE_TIMEOUT).await {
+                Ok(health) => {
+                    tracing::info!(
+                        project_name = %health.project_name,
+                        project_path = %health.project_path,
+                        "Unity Editor project identified"
+                    );
+                    slot.set_project(&health.project_name, &health.project_path)
+                        .await;
+                }
+                Err(e) => tracing::warn!("Could not identify Unity Editor project: {}", e),
+            }
+        });
+    }
+
+    /// Tell every session to re-fetch `tools/list` whenever the negotiated features change.
+    ///
+    /// The list is unfiltered until the first handshake, so the first connection always
+    /// counts as a change; later ones only when a reconnect negotiates a different set.
+    fn spawn_tool_list_watcher(ipc: IpcClient, sessions: SessionRegistry) {
+        let mut rx = ipc.watch_negotiated_features();
+        tokio::spawn(async move {
+            rx.mark_changed();
+            while rx.changed().await.is_ok() {
+                let features = rx.borrow_and_update().to_strings();
+                tracing::info!(?features, "Negotiated features changed; tools/list updated");
+                sessions.notify_tool_list_changed().await;
+            }
+        });
+    }
+
     /// Process log events with throttling
     async fn process_log_event(
         log: crate::generated::mcp::unity::v1::LogEvent,
@@ -289,7 +355,10 @@ impl McpService {
 
     async fn process_operation_event(
         op: crate::generated::mcp::unity::v1::OperationEvent,
+        ipc: &IpcClient,
+        endpoint: &str,
         operations: Arc<Mutex<HashMap<String, OperationState>>>,
+        sessions: &SessionRegistry,
     ) {
         use crate::generated::mcp::unity::v1::operation_event::Kind;
 
@@ -301,6 +370,7 @@ impl McpService {
             message: op.message.clone(),
             payload_json: op.payload_json.clone(),
             last_updated: std::time::Instant::now(),
+            endpoint: endpoint.to_string(),
         };
 
         // Update operation state
@@ -353,6 +423,66 @@ impl McpService {
                 }
             }
         }
+
+        Self::route_operation_notification(&op, ipc, endpoint, sessions).await;
+    }
+
+    /// Deliver an operation event to the session that started it.
+    ///
+    /// Operations nobody claimed (e.g. triggered inside the Editor) are editor-wide and
+    /// only broadcast when `UNITY_MCP_BROADCAST_EDITOR_EVENTS=on`. An operation whose
+    /// request was cancelled before it started is cancelled on `ipc` now.
+    async fn route_operation_notification(
+        op: &crate::generated::mcp::unity::v1::OperationEvent,
+        ipc: &IpcClient,
+        endpoint: &str,
+        sessions: &SessionRegistry,
+    ) {
+        use crate::generated::mcp::unity::v1::operation_event::Kind;
+
+        let owner = sessions.attribute_operation(endpoint, &op.op_id);
+        if sessions.take_cancelled(endpoint, &op.op_id) && op.kind() != Kind::Complete {
+            let (ipc, op_id) = (ipc.clone(), op.op_id.clone());
+            // Don't hold up the event stream while the bridge answers
+            tokio::spawn(async move { forward_cancel(&ipc, &op_id).await });
+        }
+        sessions
+            .report_operation_progress(endpoint, &op.op_id, op.progress, &op.message)
+            .await;
+        if op.kind() == Kind::Complete {
+            sessions.finish_operation(endpoint, &op.op_id);
+        }
+        if !notifications_enabled() {
+            return;
+        }
+
+        let method = match op.kind() {
+            Kind::Start => "unity.operation.started",
+            Kind::Progress => "unity.operation.progress",
+            Kind::Complete => "unity.operation.finished",
+        };
+        let payload = serde_json::json!({
+            "event": method,
+            "eventVersion": 1,
+            "opId": op.op_id,
+            "progress": op.progress,
+            "code": op.code,
+            "message": op.message,
+            "payloadJson": op.payload_json,
+        });
+
+        match owner {
+            Some(session) => sessions.send_to(session, method, payload).await,
+            None if broadcast_editor_events_enabled() => sessions.broadcast(method, payload).await,
+            None => {
+                tracing::debug!(op_id = %op.op_id, "No session owns operation; not forwarded")
+            }
+        }
+    }
+
+    /// Number of initialized MCP sessions.
+    pub fn session_count(&self) -> usize {
+        self.sessions.session_count()
     }
 
     /// Get all active operations
@@ -368,63 +498,144 @@ impl McpService {
     }
 
     pub async fn serve_stdio(self) -> anyhow::Result<()> {
-        // Set up notification channel BEFORE moving `self` into `serve(..)`
-        let (tx, mut rx) = mpsc::unbounded_channel::<(String, serde_json::Value)>();
-        {
-            let mut sender = self.notification_sender.lock().await;
-            *sender = Some(tx);
-        }
+        let sessions = self.sessions.clone();
+        let session_id = self.session_id;
 
         let service = self.serve(stdio()).await?;
+        sessions.register(session_id, service.peer().clone());
+
+        service.waiting().await?;
+        sessions.unregister(session_id);
+        Ok(())
+    }
+
+    /// Serve MCP to a client in this process over an in-memory pipe.
+    ///
+    /// The one-shot CLI commands (`status`, `call`) use this so that they go through the
+    /// same tool router, feature gating and tool defaults as an external MCP client.
+    pub async fn serve_in_process(self) -> anyhow::Result<RunningService<RoleClient, ()>> {
+        let (client_io, server_io) = tokio::io::duplex(IN_PROCESS_PIPE_CAPACITY);
+        let sessions = self.sessions.clone();
+        let session_id = self.session_id;
 
-        // Spawn notification processor task
-        let service_clone = service.clone();
         tokio::spawn(async move {
-            while let Some((method, payload)) = rx.recv().await {
-                if let Err(e) = service_clone
-                    .notify_logging_message(LoggingMessageNotificationParam {
-                        level: LoggingLevel::Info,
-                        logger: Some("unity-tests".into()),
-                        data: payload,
-                    })
-                    .await
-                {
-                    tracing::warn!("Failed to send MCP notification {}: {}", method, e);
-                } else {
-                    tracing::info!("MCP notification sent: {}", method);
+            let service = match self.serve(server_io).await {
+                Ok(service) => service,
+                Err(e) => {
+                    tracing::warn!("In-process MCP session failed to start: {}", e);
+                    return;
                 }
-            }
+            };
+            sessions.register(session_id, service.peer().clone());
+            let _ = service.waiting().await;
+            sessions.unregister(session_id);
         });
 
-        service.waiting().await?;
+        Ok(().serve(client_io).await?)
+    }
+
+    /// Serve MCP over Streamable HTTP at `http://{bind_addr}/mcp`.
+    ///
+    /// Each HTTP session gets a clone of this service with its own session id, so every
+    /// connected client shares the same Unity Bridge connections and operation table
+    /// while notifications are routed per session. The session is unregistered when it
+    /// ends (DELETE, expiry or a closed transport) and rmcp drops its service.
+    ///
+    /// Requests are checked by [`http_guard`]: only localhost `Origin`s, the bearer token
+    /// when `server.http_token` is set, and non-loopback binds need `server.allow_remote`.
+    pub async fn serve_http(self, bind_addr: &str) -> anyhow::Result<()> {
+        let listener = tokio::net::TcpListener::bind(bind_addr).await?;
+        let local_addr = listener.local_addr()?;
+        http_guard::check_bind_addr(local_addr)?;
+        let guard = HttpGuard::from_config();
+        if !local_addr.ip().is_loopback() && !guard.requires_token() {
+            tracing::warn!(
+                "Serving MCP on {} without server.http_token; anyone who can reach it can drive the Unity Editor",
+                local_addr
+            );
+        }
+        tracing::info!(
+            "Streamable HTTP server listening on http://{}{}",
+            local_addr,
+            HTTP_MCP_PATH
+        );
+
+        let base = self.clone();
+        let http_service = StreamableHttpService::new(
+            move || Ok(base.for_new_session()),
+            Arc::new(LocalSessionManager::default()),
+            StreamableHttpServerConfig {
+                sse_keep_alive: Some(Duration::from_secs(15)),
+                stateful_mode: true,
+            },
+        );
+        let app = axum::Router::new()
+            .route_service(HTTP_MCP_PATH, http_service)
+            .layer(axum::middleware::from_fn_with_state(
+                guard,
+                http_guard::check_request,
+            ));
+
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async {
+                let _ = tokio::signal::ctrl_c().await;
+            })
+            .await?;
         Ok(())
     }
 
     // 接続必須の内部アクセサー（未接続時はMCPエラー相当の説明文を返すためにResult化）
     pub async fn require_ipc(&self) -> Result<IpcClient, rmcp::ErrorData> {
-        if let Some(c) = self.ipc.read().await.clone() {
-            Ok(c)
-        } else {
-            let s = self.bridge_state.read().await.clone();
-            let mut msg =
-                String::from("Unity Bridge not connected yet. Waiting for Unity Editor to start.");
-            if let Some(err) = &s.last_error {
-                msg.push_str(&format!(" last_error={}", err));
-            }
-            if let Some(ms) = s.next_retry_ms {
-                msg.push_str(&format!(" next_retry_ms={}", ms));
-            }
-            Err(rmcp::ErrorData::internal_error(msg, None))
-        }
+        self.require_ipc_for(None).await
     }
 
-    pub async fn get_bridge_state(&self) -> BridgeState {
-        self.bridge_state.read().await.clone()
+    /// Client of the editor a tool's `project` argument selects (`None` = default editor).
+    pub async fn require_ipc_for(
+        &self,
+        project: Option<&str>,
+    ) -> Result<IpcClient, rmcp::ErrorData> {
+        self.editors.resolve(project).await
+    }
+
+    /// Feature the tool needs that the selected bridge did not accept.
+    ///
+    /// Always `None` while disconnected or for an unknown project; those calls fail in
+    /// `require_ipc_for` instead.
+    async fn missing_feature(&self, tool_name: &str, project: Option<&str>) -> Option<FeatureFlag> {
+        let ipc = self.editors.resolve(project).await.ok()?;
+        tools::missing_feature(tool_name, &ipc.get_negotiated_features().await)
+    }
+
+    pub fn editors(&self) -> &EditorRegistry {
+        &self.editors
+    }
+
+    /// Start connecting to an editor found at runtime (e.g. by discovery).
+    ///
+    /// Settings other than the endpoint come from the environment, as for configured
+    /// editors; a known `project_path` is where the token is looked up when none is
+    /// configured. Already registered endpoints are left alone.
+    pub async fn connect_editor(&self, endpoint: &str, project_path: Option<&str>) {
+        let defaults = IpcConfig::default();
+        let cfg = IpcConfig {
+            endpoint: Some(endpoint.to_string()),
+            unity_project: project_path.map(PathBuf::from).or(defaults.unity_project),
+            ..defaults
+        };
+        let Some(slot) = self
+            .editors
+            .add_if_absent(cfg.resolved_endpoint().to_string())
+            .await
+        else {
+            return;
+        };
+        tracing::info!("Connecting to discovered Unity Editor at {}", endpoint);
+        Self::spawn_bridge_connector(cfg, slot, self.operations.clone(), self.sessions.clone())
+            .await;
     }
 }
 
-// ToolRouter を `tools/list` / `tools/call` に配線
-#[tool_handler]
+// ToolRouter を `tools/list` / `tools/call` に配線（Bridge が受け入れた機能でフィルタ）
 impl ServerHandler for McpService {
     fn get_info(&self) -> InitializeResult {
         // 明示的に tools capability を公開
@@ -435,42 +646,259 @@ impl ServerHandler for McpService {
             },
             protocol_version: ProtocolVersion::V_2024_11_05,
             capabilities: ServerCapabilities {
-                tools: Some(ToolsCapability::default()),
+                tools: Some(ToolsCapability {
+                    list_changed: Some(true),
+                }),
                 logging: Some(Default::default()),
                 ..Default::default()
             },
             instructions: None,
         }
     }
+
+    async fn call_tool(
+        &self,
+        mut request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        // 設定ファイルの [tools.<name>] で、省略された引数を補う
+        let defaults = config::tool_defaults(&request.name);
+        if !defaults.is_empty() {
+            let args = request.arguments.get_or_insert_with(Default::default);
+            for (key, value) in defaults {
+                args.entry(key).or_insert(value);
+            }
+        }
+        let project = request
+            .arguments
+            .as_ref()
+            .and_then(|args| args.get("project"))
+            .and_then(|p| p.as_str())
+            .map(str::to_string);
+        if let Some(missing) = self
+            .missing_feature(&request.name, project.as_deref())
+            .await
+        {
+            return Err(rmcp::ErrorData::invalid_request(
+                format!(
+                    "Tool {} is unavailable: Unity Bridge did not negotiate feature \"{}\"",
+                    request.name, missing
+                ),
+                None,
+            ));
+        }
+        let tcc = ToolCallContext::new(self, request, context);
+        self.tool_router.call(tcc).await
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, rmcp::ErrorData> {
+        // 複数 Editor 接続時は、どれか 1 つでも使えるツールを列挙する
+        let negotiated = self.editors.negotiated_features().await;
+        Ok(ListToolsResult::with_all_items(tools::visible_tools(
+            self.tool_router.list_all(),
+            negotiated.as_ref(),
+            UnavailableTools::from_env(),
+        )))
+    }
+
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        // HTTP sessions only become reachable for notifications once initialized.
+        self.sessions.register(self.session_id, context.peer);
+    }
 }
 
 impl McpService {
-    // Utility method to send MCP notifications via logging
+    /// Choose the op_id of an operation this session's request to `ipc` starts
+    /// (see [`SessionRegistry`]).
+    ///
+    /// If the request carries a `progressToken`, the operation's events are reported
+    /// back to it as `notifications/progress`.
+    pub(crate) fn claim_operation(&self, meta: &Meta, ipc: &IpcClient) -> OperationClaim {
+        self.sessions.claim_operation(
+            self.session_id,
+            &ipc.endpoint().to_string(),
+            meta.get_progress_token(),
+        )
+    }
+
+    /// Send `notifications/progress` for a request of this session (no-op without a token).
+    pub(crate) async fn report_progress(&self, meta: &Meta, progress: f64, message: &str) {
+        if let Some(token) = meta.get_progress_token() {
+            self.sessions
+                .send_progress(self.session_id, token, progress, message)
+                .await;
+        }
+    }
+
+    /// Convert the IPC failure of an operation-starting request into an MCP error.
+    ///
+    /// If the MCP client cancelled the call, the operation the request started is
+    /// cancelled on the bridge too, right away or as soon as it starts. `ipc` must be a
+    /// handle without the cancelled token.
+    pub(crate) async fn operation_ipc_error(
+        &self,
+        ipc: &IpcClient,
+        claim: &OperationClaim,
+        err: IpcError,
+        what: &str,
+    ) -> rmcp::ErrorData {
+        if matches!(err, IpcError::Cancelled) && claim.cancel() {
+            forward_cancel(ipc, claim.op_id()).await;
+        }
+        ipc_error_to_mcp(&err, what)
+    }
+
+    // Utility method to send MCP notifications via logging to the calling session
     pub async fn notify(
         &self,
         method: &str,
         payload: serde_json::Value,
     ) -> Result<(), anyhow::Error> {
-        // Check if notifications are enabled (default: on)
-        let notifications_enabled = std::env::var("UNITY_MCP_NOTIFICATIONS")
-            .unwrap_or_else(|_| "on".to_string())
-            .to_lowercase()
-            == "on";
-
-        if !notifications_enabled {
+        if !notifications_enabled() {
             tracing::debug!("MCP notifications disabled via UNITY_MCP_NOTIFICATIONS=off");
             return Ok(());
         }
 
-        // Send via background channel if available
-        if let Some(sender) = self.notification_sender.lock().await.as_ref() {
-            if let Err(e) = sender.send((method.to_string(), payload)) {
-                tracing::warn!("Failed to enqueue MCP notification {}: {}", method, e);
-            }
-        } else {
-            tracing::warn!("Notification sender not initialized; dropped: {}", method);
+        self.sessions
+            .send_to(self.session_id, method, payload)
+            .await;
+        Ok(())
+    }
+}
+
+/// Map an IPC failure to an MCP error; client cancellation is reported as [`REQUEST_CANCELLED`].
+///
+/// Failures the bridge reported with a status code carry `{"status_code", "kind"}` in
+/// `data`. Bad arguments and missing targets are `invalid_params`, the rest `internal_error`.
+pub(crate) fn ipc_error_to_mcp(err: &IpcError, what: &str) -> rmcp::ErrorData {
+    let message = format!("{} IPC error: {}", what, err);
+    let data = err.status_code().map(|code| {
+        serde_json::json!({
+            "status_code": code,
+            "kind": status_kind(code),
+        })
+    });
+    match err {
+        IpcError::Cancelled => rmcp::ErrorData::new(
+            REQUEST_CANCELLED,
+            format!("{} cancelled by client", what),
+            None,
+        ),
+        IpcError::InvalidArgument(_) | IpcError::NotFound(_) => {
+            rmcp::ErrorData::invalid_params(message, data)
         }
+        _ => rmcp::ErrorData::internal_error(message, data),
+    }
+}
 
-        Ok(())
+/// Ask the bridge to cancel `op_id`; failures are only logged.
+async fn forward_cancel(ipc: &IpcClient, op_id: &str) {
+    match ipc
+        .operation_cancel(op_id.to_string(), CANCEL_FORWARD_TIMEOUT)
+        .await
+    {
+        Ok(resp) => tracing::info!(
+            op_id = %op_id,
+            accepted = resp.accepted,
+            "Forwarded cancellation to Unity Bridge"
+        ),
+        Err(e) => tracing::warn!(
+            op_id = %op_id,
+            "Failed to forward cancellation to Unity Bridge: {}",
+            e
+        ),
+    }
+}
+
+fn status_kind(code: i32) -> &'static str {
+    use crate::ipc::client::status;
+    match code {
+        status::INVALID_ARGUMENT => "invalid_argument",
+        status::NOT_FOUND => "not_found",
+        status::FAILED_PRECONDITION => "failed_precondition",
+        status::INTERNAL => "internal",
+        _ => "unknown",
+    }
+}
+
+// Check if notifications are enabled (default: on)
+fn notifications_enabled() -> bool {
+    config::flag("UNITY_MCP_NOTIFICATIONS").unwrap_or(true)
+}
+
+// Editor-wide operation events go to every session only when opted in (default: off)
+fn broadcast_editor_events_enabled() -> bool {
+    config::flag("UNITY_MCP_BROADCAST_EDITOR_EVENTS").unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancelled_ipc_error_is_not_a_timeout() {
+        let cancelled = ipc_error_to_mcp(&IpcError::Cancelled, "Assets import");
+        assert_eq!(cancelled.code, REQUEST_CANCELLED);
+        assert_eq!(cancelled.message, "Assets import cancelled by client");
+
+        let timeout = ipc_error_to_mcp(&IpcError::RequestTimeout, "Assets import");
+        assert_eq!(timeout.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(timeout.message, "Assets import IPC error: request timeout");
+    }
+
+    #[test]
+    fn test_bridge_status_codes_map_to_typed_mcp_errors() {
+        let cases = [
+            (
+                status_code_error(2),
+                ErrorCode::INVALID_PARAMS,
+                "invalid_argument",
+            ),
+            (status_code_error(5), ErrorCode::INVALID_PARAMS, "not_found"),
+            (
+                status_code_error(9),
+                ErrorCode::INTERNAL_ERROR,
+                "failed_precondition",
+            ),
+            (status_code_error(13), ErrorCode::INTERNAL_ERROR, "internal"),
+            (status_code_error(14), ErrorCode::INTERNAL_ERROR, "unknown"),
+        ];
+        for (err, code, kind) in cases {
+            let mcp = ipc_error_to_mcp(&err, "Assets move");
+            assert_eq!(mcp.code, code, "{err}");
+            let data = mcp.data.unwrap();
+            assert_eq!(data["kind"], kind);
+            assert_eq!(data["status_code"], err.status_code().unwrap());
+        }
+
+        let not_found = ipc_error_to_mcp(&status_code_error(5), "Assets move");
+        assert_eq!(
+            not_found.message,
+            "Assets move IPC error: not found: asset missing"
+        );
+        assert!(
+            ipc_error_to_mcp(&IpcError::RequestTimeout, "x")
+                .data
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_unexpected_response_is_internal_error() {
+        let err = ipc_error_to_mcp(&status_code_error(0), "Assets move");
+        assert_eq!(err.code, ErrorCode::INTERNAL_ERROR);
+        assert_eq!(
+            err.message,
+            "Assets move IPC error: unexpected response: asset missing"
+        );
+        assert!(err.data.is_none());
+    }
+
+    fn status_code_error(code: i32) -> IpcError {
+        IpcError::from_status(code, "asset missing".to_string())
     }
 }
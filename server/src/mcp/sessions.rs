@@ -0,0 +1,576 @@
+//! MCP セッションの登録とUnity通知のルーティング。
+//!
+//! stdio は常に単一セッションだが、Streamable HTTP では複数クライアントが同じ
+//! `McpService`（＝同じ Unity Bridge 接続）を共有する。各セッションは初期化時に
+//! `Peer` を登録し、通知は作業を開始したセッションにだけ届ける。Operation を開始する
+//! リクエストには op_id をこちらで決めて載せ、Bridge はその id でイベントを送る。
+
+use rmcp::{
+    Peer, RoleServer,
+    model::{
+        LoggingLevel, LoggingMessageNotificationParam, ProgressNotificationParam, ProgressToken,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+/// Identifier of one MCP session (one stdio connection or one HTTP session).
+pub type SessionId = u64;
+
+/// How long a claim whose request returned may still see its START event.
+///
+/// The bridge emits START before it sends the response, but the event processor
+/// may observe it after the tool handler already returned.
+const CLAIM_GRACE: Duration = Duration::from_secs(2);
+
+/// How long a cancelled request's claim waits for its operation to start.
+///
+/// The bridge may still be working through earlier requests when the call is cancelled;
+/// the operation is cancelled as soon as it shows up.
+const CANCELLED_CLAIM_GRACE: Duration = Duration::from_secs(60);
+
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Allocate a process-wide unique session id.
+pub fn next_session_id() -> SessionId {
+    NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Debug)]
+struct Owner {
+    session: SessionId,
+    /// An event of the operation has been seen
+    started: bool,
+    /// When the request that chose the op_id returned
+    returned_at: Option<Instant>,
+    /// Progress token of the request and last reported progress
+    progress: Option<(ProgressToken, Option<f64>)>,
+    /// The request was cancelled before the operation started
+    cancelled: bool,
+}
+
+#[derive(Default)]
+struct Inner {
+    peers: HashMap<SessionId, Peer<RoleServer>>,
+    /// (editor endpoint, op_id chosen by a request) -> the session that sent it
+    owners: HashMap<(String, String), Owner>,
+}
+
+impl Inner {
+    /// Forget claims whose request returned without the operation ever starting.
+    fn prune_unstarted(&mut self, now: Instant) {
+        self.owners.retain(|_, owner| {
+            let grace = match owner.cancelled {
+                true => CANCELLED_CLAIM_GRACE,
+                false => CLAIM_GRACE,
+            };
+            owner.started
+                || owner
+                    .returned_at
+                    .is_none_or(|t| now.duration_since(t) <= grace)
+        });
+    }
+}
+
+/// Shared registry of initialized MCP sessions and the operations they own.
+#[derive(Clone, Default)]
+pub struct SessionRegistry {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl SessionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the peer for an initialized session.
+    pub fn register(&self, session: SessionId, peer: Peer<RoleServer>) {
+        let mut inner = self.lock();
+        inner.peers.insert(session, peer);
+        tracing::debug!(session, "MCP session registered");
+    }
+
+    /// Forget a session and every operation it owns.
+    pub fn unregister(&self, session: SessionId) {
+        let mut inner = self.lock();
+        inner.peers.remove(&session);
+        inner.owners.retain(|_, owner| owner.session != session);
+        tracing::debug!(session, "MCP session unregistered");
+    }
+
+    pub fn session_count(&self) -> usize {
+        self.lock().peers.len()
+    }
+
+    /// Unregister `session` once the returned lease is dropped.
+    pub fn lease(&self, session: SessionId) -> SessionLease {
+        SessionLease {
+            registry: self.clone(),
+            session,
+        }
+    }
+
+    /// Choose the op_id for a request of `session` to the editor at `endpoint`.
+    ///
+    /// The request carries [`OperationClaim::op_id`] and the bridge reports the operation's
+    /// events under it, so they reach this session and nobody else; events from other
+    /// editors never match. With a `progress_token`, the events are reported as
+    /// `notifications/progress` until the guard is dropped.
+    pub fn claim_operation(
+        &self,
+        session: SessionId,
+        endpoint: &str,
+        progress_token: Option<ProgressToken>,
+    ) -> OperationClaim {
+        let key = (
+            endpoint.to_string(),
+            uuid::Uuid::new_v4().simple().to_string(),
+        );
+        let mut inner = self.lock();
+        inner.prune_unstarted(Instant::now());
+        inner.owners.insert(
+            key.clone(),
+            Owner {
+                session,
+                started: false,
+                returned_at: None,
+                progress: progress_token.map(|token| (token, None)),
+                cancelled: false,
+            },
+        );
+        OperationClaim {
+            registry: self.clone(),
+            key,
+        }
+    }
+
+    /// Owning session of `op_id` reported by the editor at `endpoint`.
+    ///
+    /// Operations no request to that editor claimed are editor-wide (`None`).
+    pub fn attribute_operation(&self, endpoint: &str, op_id: &str) -> Option<SessionId> {
+        let mut inner = self.lock();
+        inner.prune_unstarted(Instant::now());
+        let owner = inner.owners.get_mut(&key(endpoint, op_id))?;
+        owner.started = true;
+        Some(owner.session)
+    }
+
+    /// Whether the operation must be cancelled because its request was cancelled before
+    /// it started. Returns `true` once, after [`Self::attribute_operation`] saw it.
+    pub fn take_cancelled(&self, endpoint: &str, op_id: &str) -> bool {
+        let mut inner = self.lock();
+        match inner.owners.get_mut(&key(endpoint, op_id)) {
+            Some(owner) if owner.started && owner.cancelled => {
+                owner.cancelled = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drop ownership of a completed operation.
+    pub fn finish_operation(&self, endpoint: &str, op_id: &str) {
+        self.lock().owners.remove(&key(endpoint, op_id));
+    }
+
+    /// Report an operation's progress to the request that started it, if it asked for progress.
+    pub async fn report_operation_progress(
+        &self,
+        endpoint: &str,
+        op_id: &str,
+        progress: i32,
+        message: &str,
+    ) {
+        let target = {
+            let mut inner = self.lock();
+            let Some(owner) = inner.owners.get_mut(&key(endpoint, op_id)) else {
+                return;
+            };
+            let progress = f64::from(progress.clamp(0, 100));
+            let Some((token, last)) = owner.progress.as_mut() else {
+                return;
+            };
+            // MCP requires progress to increase with every notification
+            if last.is_some_and(|l| progress <= l) {
+                return;
+            }
+            *last = Some(progress);
+            (owner.session, token.clone(), progress)
+        };
+        let (session, token, progress) = target;
+        self.send_progress(session, token, progress, message).await;
+    }
+
+    /// Send `notifications/progress` (out of 100) to a session.
+    pub async fn send_progress(
+        &self,
+        session: SessionId,
+        progress_token: ProgressToken,
+        progress: f64,
+        message: &str,
+    ) {
+        let peer = self.lock().peers.get(&session).cloned();
+        let Some(peer) = peer else {
+            return;
+        };
+        if let Err(e) = peer
+            .notify_progress(ProgressNotificationParam {
+                progress_token,
+                progress,
+                total: Some(100.0),
+                message: (!message.is_empty()).then(|| message.to_string()),
+            })
+            .await
+        {
+            tracing::warn!(session, "Failed to send MCP progress notification: {}", e);
+        }
+    }
+
+    /// Send a notification to a single session.
+    ///
+    /// Sessions whose transport is gone are pruned from the registry.
+    pub async fn send_to(&self, session: SessionId, method: &str, payload: serde_json::Value) {
+        let peer = self.lock().peers.get(&session).cloned();
+        let Some(peer) = peer else {
+            tracing::warn!(
+                session,
+                "MCP session not initialized or gone; dropped notification: {}",
+                method
+            );
+            return;
+        };
+        if !Self::deliver(&peer, method, payload).await {
+            self.unregister(session);
+        }
+    }
+
+    /// Send a notification to every initialized session.
+    pub async fn broadcast(&self, method: &str, payload: serde_json::Value) {
+        let peers: Vec<(SessionId, Peer<RoleServer>)> = self
+            .lock()
+            .peers
+            .iter()
+            .map(|(id, p)| (*id, p.clone()))
+            .collect();
+        for (session, peer) in peers {
+            if !Self::deliver(&peer, method, payload.clone()).await {
+                self.unregister(session);
+            }
+        }
+    }
+
+    /// Send `notifications/tools/list_changed` to every initialized session.
+    pub async fn notify_tool_list_changed(&self) {
+        let peers: Vec<(SessionId, Peer<RoleServer>)> = self
+            .lock()
+            .peers
+            .iter()
+            .map(|(id, p)| (*id, p.clone()))
+            .collect();
+        for (session, peer) in peers {
+            if peer.is_transport_closed() {
+                self.unregister(session);
+                continue;
+            }
+            if let Err(e) = peer.notify_tool_list_changed().await {
+                tracing::warn!(session, "Failed to send tools/list_changed: {}", e);
+            }
+        }
+    }
+
+    async fn deliver(peer: &Peer<RoleServer>, method: &str, payload: serde_json::Value) -> bool {
+        if peer.is_transport_closed() {
+            return false;
+        }
+        match peer
+            .notify_logging_message(LoggingMessageNotificationParam {
+                level: LoggingLevel::Info,
+                logger: Some(logger_for(method).into()),
+                data: payload,
+            })
+            .await
+        {
+            Ok(()) => {
+                tracing::info!("MCP notification sent: {}", method);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to send MCP notification {}: {}", method, e);
+                false
+            }
+        }
+    }
+
+    fn is_running(&self, key: &(String, String)) -> bool {
+        self.lock().owners.get(key).is_some_and(|o| o.started)
+    }
+
+    fn cancel_claim(&self, key: &(String, String)) -> bool {
+        let mut inner = self.lock();
+        match inner.owners.get_mut(key) {
+            Some(owner) if owner.started => true,
+            Some(owner) => {
+                owner.cancelled = true;
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn finish_claim(&self, key: &(String, String)) {
+        let mut inner = self.lock();
+        if let Some(owner) = inner.owners.get_mut(key) {
+            // The response is on its way; progress after it would be noise
+            owner.progress = None;
+            owner.returned_at = Some(Instant::now());
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn key(endpoint: &str, op_id: &str) -> (String, String) {
+    (endpoint.to_string(), op_id.to_string())
+}
+
+fn logger_for(method: &str) -> &'static str {
+    if method.starts_with("unity.tests.") {
+        "unity-tests"
+    } else {
+        "unity-operations"
+    }
+}
+
+/// Keeps a session registered until it is dropped (see [`SessionRegistry::lease`]).
+pub struct SessionLease {
+    registry: SessionRegistry,
+    session: SessionId,
+}
+
+impl Drop for SessionLease {
+    fn drop(&mut self) {
+        self.registry.unregister(self.session);
+    }
+}
+
+/// Guard returned by [`SessionRegistry::claim_operation`].
+pub struct OperationClaim {
+    registry: SessionRegistry,
+    /// (editor endpoint, op_id)
+    key: (String, String),
+}
+
+impl OperationClaim {
+    /// Id to put in the request's `op_id`.
+    pub fn op_id(&self) -> &str {
+        &self.key.1
+    }
+
+    /// Whether the operation has started and not completed yet.
+    pub fn is_running(&self) -> bool {
+        self.registry.is_running(&self.key)
+    }
+
+    /// Record that the request was cancelled.
+    ///
+    /// Returns `true` if the operation is running and must be cancelled now; an operation
+    /// that has not started yet is reported by [`SessionRegistry::take_cancelled`] when
+    /// it does.
+    pub fn cancel(&self) -> bool {
+        self.registry.cancel_claim(&self.key)
+    }
+}
+
+impl Drop for OperationClaim {
+    fn drop(&mut self) {
+        self.registry.finish_claim(&self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EDITOR: &str = "tcp://127.0.0.1:7777";
+
+    #[test]
+    fn test_session_ids_are_unique() {
+        let a = next_session_id();
+        let b = next_session_id();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_operation_attributed_by_requested_id() {
+        let registry = SessionRegistry::new();
+        let first = registry.claim_operation(1, EDITOR, None);
+        let second = registry.claim_operation(2, EDITOR, None);
+        assert_ne!(first.op_id(), second.op_id());
+
+        // Events arrive in any order and resolve by id
+        assert_eq!(
+            registry.attribute_operation(EDITOR, second.op_id()),
+            Some(2)
+        );
+        assert_eq!(registry.attribute_operation(EDITOR, first.op_id()), Some(1));
+        assert_eq!(
+            registry.attribute_operation(EDITOR, second.op_id()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_same_op_id_from_another_editor_is_not_attributed() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(1, EDITOR, None);
+
+        let other = "tcp://127.0.0.1:7778";
+        assert_eq!(registry.attribute_operation(other, claim.op_id()), None);
+        registry.finish_operation(other, claim.op_id());
+        assert!(!claim.is_running());
+        assert_eq!(registry.attribute_operation(EDITOR, claim.op_id()), Some(1));
+    }
+
+    #[test]
+    fn test_editor_operation_does_not_take_a_pending_claim() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(1, EDITOR, None);
+
+        // Started inside the Editor while the agent's request is in flight
+        assert_eq!(registry.attribute_operation(EDITOR, "editor-op"), None);
+        assert!(!claim.is_running());
+        assert_eq!(registry.attribute_operation(EDITOR, claim.op_id()), Some(1));
+        assert!(claim.is_running());
+    }
+
+    #[test]
+    fn test_returned_claim_still_matches_within_grace() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(7, EDITOR, None);
+        let op_id = claim.op_id().to_string();
+        drop(claim);
+        assert_eq!(registry.attribute_operation(EDITOR, &op_id), Some(7));
+    }
+
+    #[test]
+    fn test_unstarted_claim_expires_after_its_request_returned() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(7, EDITOR, None);
+        let op_id = claim.op_id().to_string();
+        drop(claim);
+        registry
+            .lock()
+            .owners
+            .get_mut(&key(EDITOR, &op_id))
+            .unwrap()
+            .returned_at = Some(Instant::now() - CLAIM_GRACE * 2);
+        assert_eq!(registry.attribute_operation(EDITOR, &op_id), None);
+    }
+
+    #[test]
+    fn test_claim_cancelled_before_start_is_cancelled_once_it_starts() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(1, EDITOR, None);
+        let op_id = claim.op_id().to_string();
+
+        assert!(!claim.cancel());
+        drop(claim);
+        assert!(!registry.take_cancelled(EDITOR, &op_id));
+
+        // Still waiting for the operation well after an ordinary claim would have expired
+        registry
+            .lock()
+            .owners
+            .get_mut(&key(EDITOR, &op_id))
+            .unwrap()
+            .returned_at = Some(Instant::now() - CLAIM_GRACE * 2);
+        assert_eq!(registry.attribute_operation(EDITOR, &op_id), Some(1));
+        assert!(registry.take_cancelled(EDITOR, &op_id));
+        assert!(!registry.take_cancelled(EDITOR, &op_id));
+    }
+
+    #[test]
+    fn test_claim_cancelled_while_running_is_cancelled_immediately() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(1, EDITOR, None);
+
+        registry.attribute_operation(EDITOR, claim.op_id());
+        assert!(claim.cancel());
+        assert!(!registry.take_cancelled(EDITOR, claim.op_id()));
+    }
+
+    #[test]
+    fn test_finish_operation_releases_owner() {
+        let registry = SessionRegistry::new();
+        let claim = registry.claim_operation(3, EDITOR, None);
+        assert_eq!(registry.attribute_operation(EDITOR, claim.op_id()), Some(3));
+        registry.finish_operation(EDITOR, claim.op_id());
+        assert_eq!(registry.attribute_operation(EDITOR, claim.op_id()), None);
+        assert!(!claim.is_running());
+    }
+
+    #[test]
+    fn test_dropping_lease_unregisters_session() {
+        let registry = SessionRegistry::new();
+        let lease = registry.lease(5);
+        let _claim = registry.claim_operation(5, EDITOR, None);
+
+        drop(lease);
+        assert!(registry.lock().owners.is_empty());
+    }
+
+    #[test]
+    fn test_unregister_drops_claims_and_owners() {
+        let registry = SessionRegistry::new();
+        let a = registry.claim_operation(4, EDITOR, None);
+        assert_eq!(registry.attribute_operation(EDITOR, a.op_id()), Some(4));
+        let b = registry.claim_operation(4, EDITOR, None);
+        registry.unregister(4);
+        assert_eq!(registry.attribute_operation(EDITOR, a.op_id()), None);
+        assert_eq!(registry.attribute_operation(EDITOR, b.op_id()), None);
+        assert_eq!(registry.session_count(), 0);
+    }
+
+    #[test]
+    fn test_progress_is_tracked_per_claim() {
+        let registry = SessionRegistry::new();
+        let token = ProgressToken(rmcp::model::NumberOrString::Number(7));
+        let claim = registry.claim_operation(5, EDITOR, Some(token));
+        let op_id = claim.op_id().to_string();
+        assert!(
+            registry.lock().owners[&key(EDITOR, &op_id)]
+                .progress
+                .is_some()
+        );
+
+        drop(claim);
+        assert!(
+            registry.lock().owners[&key(EDITOR, &op_id)]
+                .progress
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_to_unknown_session_is_noop() {
+        let registry = SessionRegistry::new();
+        registry
+            .send_to(42, "unity.tests.started", serde_json::json!({}))
+            .await;
+        registry
+            .broadcast("unity.operation.start", serde_json::json!({}))
+            .await;
+        registry
+            .report_operation_progress(EDITOR, "op", 50, "half")
+            .await;
+        assert_eq!(registry.session_count(), 0);
+    }
+}
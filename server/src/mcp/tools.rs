@@ -2,44 +2,70 @@ pub mod assets;
 pub mod build;
 pub mod component;
 pub mod diagnostics;
+pub mod discovery;
 pub mod editor;
 pub mod health;
+pub mod operations;
 pub mod prefab;
 pub mod project_settings;
 pub mod scene;
 pub mod status;
 pub mod tests;
 
+use crate::ipc::features::{FeatureFlag, FeatureSet};
 use crate::mcp::service::McpService;
 use crate::mcp::tools::build::{UnityBuildAssetBundlesRequest, UnityBuildPlayerRequest};
 use crate::mcp::tools::diagnostics::UnityGetCompileDiagnosticsRequest;
 use crate::mcp::tools::tests::{UnityGetTestResultsRequest, UnityRunTestsRequest};
 use rmcp::{
-    ErrorData as McpError, handler::server::tool::Parameters, model::CallToolResult, tool,
-    tool_router,
+    ErrorData as McpError,
+    handler::server::tool::Parameters,
+    model::{CallToolResult, Meta, Tool},
+    tool, tool_router,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 
 #[tool_router]
 impl McpService {
-    #[tool(description = "Unity Bridge connection status (always available)")]
-    pub async fn unity_bridge_status(&self) -> Result<CallToolResult, McpError> {
-        self.do_unity_bridge_status().await
+    #[tool(
+        description = "Unity Bridge connection status of every configured editor (always available)"
+    )]
+    pub async fn unity_bridge_status(
+        &self,
+        Parameters(req): Parameters<UnityBridgeStatusRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.do_unity_bridge_status(req.project.as_deref()).await
+    }
+
+    #[tool(
+        description = "Find running Unity Editors (discovery descriptors and MCP_DISCOVERY_PORTS) and probe each with a Health handshake (always available)"
+    )]
+    pub async fn unity_list_editors(
+        &self,
+        Parameters(req): Parameters<UnityListEditorsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.do_unity_list_editors(req.ports, req.connect.unwrap_or(false))
+            .await
     }
 
     #[tool(description = "Unity Bridge health check")]
-    pub async fn unity_health(&self) -> Result<CallToolResult, McpError> {
-        self.do_unity_health().await
+    pub async fn unity_health(
+        &self,
+        Parameters(req): Parameters<UnityHealthRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.do_unity_health(req.project.as_deref()).await
     }
 
     #[tool(description = "Import Unity assets via Direct IPC")]
     pub async fn unity_assets_import(
         &self,
         Parameters(req): Parameters<UnityAssetsImportRequest>,
+        meta: Meta,
+        ct: CancellationToken,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_assets_import(req.paths, req.recursive, req.auto_refresh, req.timeout_secs)
-            .await
+        self.do_unity_assets_import(req, meta, ct).await
     }
 
     #[tool(description = "Move Unity asset via Direct IPC")]
@@ -47,8 +73,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityAssetsMoveRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_assets_move(req.from_path, req.to_path, req.timeout_secs)
-            .await
+        self.do_unity_assets_move(
+            req.from_path,
+            req.to_path,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Delete Unity assets via Direct IPC")]
@@ -56,8 +87,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityAssetsDeleteRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_assets_delete(req.paths, req.soft, req.timeout_secs)
-            .await
+        self.do_unity_assets_delete(
+            req.paths,
+            req.soft,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Refresh Unity AssetDatabase via Direct IPC")]
@@ -65,7 +101,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityAssetsRefreshRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_assets_refresh(req.force, req.timeout_secs)
+        self.do_unity_assets_refresh(req.force, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -74,7 +110,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityAssetsGuidToPathRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_assets_guid_to_path(req.guids, req.timeout_secs)
+        self.do_unity_assets_guid_to_path(req.guids, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -83,24 +119,30 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityAssetsPathToGuidRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_assets_path_to_guid(req.paths, req.timeout_secs)
+        self.do_unity_assets_path_to_guid(req.paths, req.timeout_secs, req.project.as_deref())
             .await
     }
 
-    #[tool(description = "Build Unity player via Direct IPC")]
+    #[tool(
+        description = "Build Unity player via Direct IPC; cancellable only until the build pipeline starts (after the platform switch)"
+    )]
     pub async fn unity_build_player(
         &self,
         Parameters(req): Parameters<UnityBuildPlayerRequest>,
+        meta: Meta,
+        ct: CancellationToken,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_build_player(req).await
+        self.do_unity_build_player(req, meta, ct).await
     }
 
-    #[tool(description = "Build Unity AssetBundles via Direct IPC")]
+    #[tool(description = "Build Unity AssetBundles via Direct IPC; not cancellable once started")]
     pub async fn unity_build_asset_bundles(
         &self,
         Parameters(req): Parameters<UnityBuildAssetBundlesRequest>,
+        meta: Meta,
+        ct: CancellationToken,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_build_asset_bundles(req).await
+        self.do_unity_build_asset_bundles(req, meta, ct).await
     }
 
     #[tool(description = "Add Unity component via Direct IPC")]
@@ -108,8 +150,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityComponentAddRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_component_add(req.game_object, req.component, req.timeout_secs)
-            .await
+        self.do_unity_component_add(
+            req.game_object,
+            req.component,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Get Unity components via Direct IPC")]
@@ -117,7 +164,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityGetComponentsRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_get_components(req.game_object, req.timeout_secs)
+        self.do_unity_get_components(req.game_object, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -126,8 +173,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityComponentRemoveRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_component_remove(req.game_object, req.component, req.timeout_secs)
-            .await
+        self.do_unity_component_remove(
+            req.game_object,
+            req.component,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Create Unity prefab via Direct IPC")]
@@ -135,8 +187,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityPrefabCreateRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_prefab_create(req.game_object_path, req.prefab_path, req.timeout_secs)
-            .await
+        self.do_unity_prefab_create(
+            req.game_object_path,
+            req.prefab_path,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Update Unity prefab via Direct IPC")]
@@ -144,8 +201,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityPrefabUpdateRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_prefab_update(req.game_object_path, req.prefab_path, req.timeout_secs)
-            .await
+        self.do_unity_prefab_update(
+            req.game_object_path,
+            req.prefab_path,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Apply overrides to Unity prefab instance via Direct IPC")]
@@ -153,8 +215,12 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityPrefabApplyOverridesRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_prefab_apply_overrides(req.instance_path, req.timeout_secs)
-            .await
+        self.do_unity_prefab_apply_overrides(
+            req.instance_path,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Get Unity C# compile diagnostics (errors, warnings, info)")]
@@ -167,6 +233,7 @@ impl McpService {
             req.severity,
             req.changed_only,
             req.assembly,
+            req.project.as_deref(),
         )
         .await
     }
@@ -175,16 +242,9 @@ impl McpService {
     pub async fn unity_run_tests(
         &self,
         Parameters(req): Parameters<UnityRunTestsRequest>,
+        meta: Meta,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_run_tests(
-            req.mode,
-            req.test_filter,
-            req.categories,
-            req.timeout_sec,
-            req.max_items,
-            req.include_passed,
-        )
-        .await
+        self.do_unity_run_tests(req, meta).await
     }
 
     #[tool(description = "Get Unity test results from previous runs")]
@@ -200,7 +260,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityGetProjectSettingsRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_get_project_settings(req.keys, req.timeout_secs)
+        self.do_unity_get_project_settings(req.keys, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -209,7 +269,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnitySetProjectSettingsRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_set_project_settings(req.settings, req.timeout_secs)
+        self.do_unity_set_project_settings(req.settings, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -218,8 +278,13 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnitySceneOpenRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_scene_open(req.path, req.additive, req.timeout_secs)
-            .await
+        self.do_unity_scene_open(
+            req.path,
+            req.additive,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
     }
 
     #[tool(description = "Save Unity scene via Direct IPC")]
@@ -227,7 +292,8 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnitySceneSaveRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_scene_save(req.path, req.timeout_secs).await
+        self.do_unity_scene_save(req.path, req.timeout_secs, req.project.as_deref())
+            .await
     }
 
     #[tool(description = "Get list of open Unity scenes via Direct IPC")]
@@ -235,7 +301,8 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnitySceneGetOpenRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_scene_get_open(req.timeout_secs).await
+        self.do_unity_scene_get_open(req.timeout_secs, req.project.as_deref())
+            .await
     }
 
     #[tool(description = "Set active Unity scene via Direct IPC")]
@@ -243,7 +310,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnitySceneSetActiveRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_scene_set_active(req.path, req.timeout_secs)
+        self.do_unity_scene_set_active(req.path, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -252,7 +319,38 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityExecuteMenuItemRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_execute_menu_item(req.path, req.timeout_secs)
+        self.do_unity_execute_menu_item(req.path, req.timeout_secs, req.project.as_deref())
+            .await
+    }
+
+    #[tool(description = "List Unity operations (builds, imports) with progress and bridge state")]
+    pub async fn unity_operation_list(
+        &self,
+        Parameters(req): Parameters<UnityOperationListRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.do_unity_operation_list(
+            req.include_completed,
+            req.timeout_secs,
+            req.project.as_deref(),
+        )
+        .await
+    }
+
+    #[tool(description = "Get a Unity operation by id, merged with the bridge's view")]
+    pub async fn unity_operation_get(
+        &self,
+        Parameters(req): Parameters<UnityOperationGetRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.do_unity_operation_get(req.op_id, req.timeout_secs, req.project.as_deref())
+            .await
+    }
+
+    #[tool(description = "Request cancellation of a running Unity operation via Direct IPC")]
+    pub async fn unity_operation_cancel(
+        &self,
+        Parameters(req): Parameters<UnityOperationCancelRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.do_unity_operation_cancel(req.op_id, req.timeout_secs, req.project.as_deref())
             .await
     }
 
@@ -261,7 +359,7 @@ impl McpService {
         &self,
         Parameters(req): Parameters<UnityFocusWindowRequest>,
     ) -> Result<CallToolResult, McpError> {
-        self.do_unity_focus_window(req.window_type, req.timeout_secs)
+        self.do_unity_focus_window(req.window_type, req.timeout_secs, req.project.as_deref())
             .await
     }
 }
@@ -272,12 +370,119 @@ pub(crate) fn make_tool_router() -> rmcp::handler::server::tool::ToolRouter<McpS
     McpService::tool_router()
 }
 
+/// Bridge feature a tool needs; `None` for tools that work with any bridge.
+pub(crate) fn required_feature(tool_name: &str) -> Option<FeatureFlag> {
+    match tool_name {
+        "unity_assets_import"
+        | "unity_assets_move"
+        | "unity_assets_delete"
+        | "unity_assets_refresh"
+        | "unity_assets_guid_to_path"
+        | "unity_assets_path_to_guid" => Some(FeatureFlag::AssetsBasic),
+        "unity_build_player" | "unity_build_asset_bundles" => Some(FeatureFlag::BuildMin),
+        "unity_component_add" | "unity_get_components" | "unity_component_remove" => {
+            Some(FeatureFlag::ComponentsBasic)
+        }
+        "unity_prefab_create" | "unity_prefab_update" | "unity_prefab_apply_overrides" => {
+            Some(FeatureFlag::PrefabsBasic)
+        }
+        _ => None,
+    }
+}
+
+/// The feature `tool_name` needs but `negotiated` lacks, if any.
+pub(crate) fn missing_feature(tool_name: &str, negotiated: &FeatureSet) -> Option<FeatureFlag> {
+    required_feature(tool_name).filter(|f| !negotiated.contains(f))
+}
+
+/// How `tools/list` presents tools whose feature the bridge did not accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnavailableTools {
+    /// Leave them out of the list (default)
+    Hide,
+    /// Keep them, with a note in the description
+    Annotate,
+}
+
+impl UnavailableTools {
+    /// Read `tools.unavailable` / `UNITY_MCP_UNAVAILABLE_TOOLS` (`hide` / `annotate`).
+    pub(crate) fn from_env() -> Self {
+        match crate::config::var("UNITY_MCP_UNAVAILABLE_TOOLS") {
+            Some(v) if v.eq_ignore_ascii_case("annotate") => Self::Annotate,
+            _ => Self::Hide,
+        }
+    }
+}
+
+/// Tools to advertise for the given negotiated feature set.
+///
+/// While no bridge is connected (`negotiated` is `None`) every tool is listed; the
+/// list is narrowed once the handshake tells us what the bridge supports.
+pub(crate) fn visible_tools(
+    tools: Vec<Tool>,
+    negotiated: Option<&FeatureSet>,
+    mode: UnavailableTools,
+) -> Vec<Tool> {
+    let Some(negotiated) = negotiated else {
+        return tools;
+    };
+    tools
+        .into_iter()
+        .filter_map(|mut tool| {
+            let Some(missing) = missing_feature(&tool.name, negotiated) else {
+                return Some(tool);
+            };
+            match mode {
+                UnavailableTools::Hide => None,
+                UnavailableTools::Annotate => {
+                    let note = format!(
+                        "[unavailable: Unity Bridge did not negotiate feature \"{}\"]",
+                        missing
+                    );
+                    tool.description = Some(match tool.description.take() {
+                        Some(desc) => format!("{} {}", desc, note).into(),
+                        None => note.into(),
+                    });
+                    Some(tool)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Schema description of the `project` selector every tool accepts.
+pub(crate) const PROJECT_DESCRIPTION: &str = "Unity project to target when several editors are connected: \
+     project name, project path or bridge endpoint. Defaults to the first configured editor.";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UnityBridgeStatusRequest {
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UnityListEditorsRequest {
+    /// Ports to scan instead of MCP_DISCOVERY_PORTS, e.g. "7777-7787"
+    pub ports: Option<String>,
+    /// Connect to every reachable editor found via a descriptor so other tools can select it
+    /// by `project`; port-scan results are only listed
+    pub connect: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct UnityHealthRequest {
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityAssetsImportRequest {
     pub paths: Vec<String>,
     pub recursive: Option<bool>,
     pub auto_refresh: Option<bool>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -285,6 +490,8 @@ pub struct UnityAssetsMoveRequest {
     pub from_path: String,
     pub to_path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -292,36 +499,48 @@ pub struct UnityAssetsDeleteRequest {
     pub paths: Vec<String>,
     pub soft: Option<bool>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityAssetsRefreshRequest {
     pub force: Option<bool>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityAssetsGuidToPathRequest {
     pub guids: Vec<String>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityAssetsPathToGuidRequest {
     pub paths: Vec<String>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityGetProjectSettingsRequest {
     pub keys: Vec<String>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnitySetProjectSettingsRequest {
     pub settings: std::collections::HashMap<String, String>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -329,35 +548,47 @@ pub struct UnitySceneOpenRequest {
     pub path: String,
     pub additive: Option<bool>,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnitySceneSaveRequest {
     pub path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnitySceneGetOpenRequest {
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnitySceneSetActiveRequest {
     pub path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityExecuteMenuItemRequest {
     pub path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityFocusWindowRequest {
     pub window_type: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -365,12 +596,16 @@ pub struct UnityComponentAddRequest {
     pub game_object: String,
     pub component: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityGetComponentsRequest {
     pub game_object: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -378,6 +613,8 @@ pub struct UnityComponentRemoveRequest {
     pub game_object: String,
     pub component: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -385,6 +622,8 @@ pub struct UnityPrefabCreateRequest {
     pub game_object_path: String,
     pub prefab_path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -392,12 +631,40 @@ pub struct UnityPrefabUpdateRequest {
     pub game_object_path: String,
     pub prefab_path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnityPrefabApplyOverridesRequest {
     pub instance_path: String,
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UnityOperationListRequest {
+    pub include_completed: Option<bool>,
+    pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UnityOperationGetRequest {
+    pub op_id: String,
+    pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UnityOperationCancelRequest {
+    pub op_id: String,
+    pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[cfg(test)]
@@ -409,6 +676,7 @@ mod tool_tests {
         let router = make_tool_router();
         assert!(router.has_route("unity_bridge_status"));
         assert!(router.has_route("unity_health"));
+        assert!(router.has_route("unity_list_editors"));
         assert!(router.has_route("unity_assets_import"));
         assert!(router.has_route("unity_assets_move"));
         assert!(router.has_route("unity_assets_delete"));
@@ -434,5 +702,129 @@ mod tool_tests {
         assert!(router.has_route("unity_scene_set_active"));
         assert!(router.has_route("unity_execute_menu_item"));
         assert!(router.has_route("unity_focus_window"));
+        assert!(router.has_route("unity_operation_list"));
+        assert!(router.has_route("unity_operation_get"));
+        assert!(router.has_route("unity_operation_cancel"));
+    }
+
+    fn names(tools: &[Tool]) -> Vec<&str> {
+        tools.iter().map(|t| t.name.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_required_features() {
+        assert_eq!(
+            required_feature("unity_assets_import"),
+            Some(FeatureFlag::AssetsBasic)
+        );
+        assert_eq!(
+            required_feature("unity_build_player"),
+            Some(FeatureFlag::BuildMin)
+        );
+        assert_eq!(
+            required_feature("unity_get_components"),
+            Some(FeatureFlag::ComponentsBasic)
+        );
+        assert_eq!(
+            required_feature("unity_prefab_update"),
+            Some(FeatureFlag::PrefabsBasic)
+        );
+        assert_eq!(required_feature("unity_bridge_status"), None);
+        assert_eq!(required_feature("unity_scene_open"), None);
+
+        // Gated names are real tools
+        let router = make_tool_router();
+        for name in [
+            "unity_assets_path_to_guid",
+            "unity_build_asset_bundles",
+            "unity_component_remove",
+            "unity_prefab_apply_overrides",
+        ] {
+            assert!(router.has_route(name), "{name}");
+        }
+    }
+
+    /// Tools that work with any bridge, so `required_feature` leaves them out on purpose.
+    const ALWAYS_AVAILABLE: &[&str] = &[
+        "unity_bridge_status",
+        "unity_health",
+        "unity_list_editors",
+        "unity_get_compile_diagnostics",
+        "unity_run_tests",
+        "unity_get_test_results",
+        "unity_get_project_settings",
+        "unity_set_project_settings",
+        "unity_scene_open",
+        "unity_scene_save",
+        "unity_scene_get_open",
+        "unity_scene_set_active",
+        "unity_execute_menu_item",
+        "unity_focus_window",
+        "unity_operation_list",
+        "unity_operation_get",
+        "unity_operation_cancel",
+    ];
+
+    #[test]
+    fn test_every_tool_is_gated_or_always_available() {
+        let router = make_tool_router();
+        for tool in router.list_all() {
+            let name = tool.name.as_ref();
+            let gated = required_feature(name).is_some();
+            let always = ALWAYS_AVAILABLE.contains(&name);
+            assert!(
+                gated != always,
+                "{name}: map it in required_feature or list it in ALWAYS_AVAILABLE (not both)"
+            );
+        }
+        for name in ALWAYS_AVAILABLE {
+            assert!(router.has_route(name), "{name} is not a tool");
+        }
+    }
+
+    #[test]
+    fn test_visible_tools_hides_tools_without_negotiated_feature() {
+        let all = make_tool_router().list_all();
+        let total = all.len();
+
+        // Not connected yet: everything is listed
+        assert_eq!(
+            visible_tools(all.clone(), None, UnavailableTools::Hide).len(),
+            total
+        );
+
+        let mut negotiated = FeatureSet::new();
+        negotiated.insert(FeatureFlag::AssetsBasic);
+        let visible = visible_tools(all.clone(), Some(&negotiated), UnavailableTools::Hide);
+        let visible = names(&visible);
+        assert!(visible.contains(&"unity_assets_import"));
+        assert!(visible.contains(&"unity_bridge_status"));
+        assert!(!visible.contains(&"unity_component_add"));
+        assert!(!visible.contains(&"unity_prefab_create"));
+        assert!(!visible.contains(&"unity_build_player"));
+
+        let all_features = FeatureSet::supported_by_client();
+        assert_eq!(
+            visible_tools(all, Some(&all_features), UnavailableTools::Hide).len(),
+            total
+        );
+    }
+
+    #[test]
+    fn test_visible_tools_annotates_unavailable_tools() {
+        let all = make_tool_router().list_all();
+        let total = all.len();
+        let tools = visible_tools(all, Some(&FeatureSet::new()), UnavailableTools::Annotate);
+        assert_eq!(tools.len(), total);
+
+        let desc = |name: &str| {
+            tools
+                .iter()
+                .find(|t| t.name == name)
+                .and_then(|t| t.description.clone())
+                .unwrap()
+        };
+        assert!(desc("unity_component_add").contains("\"components.basic\""));
+        assert!(!desc("unity_health").contains("unavailable"));
     }
 }
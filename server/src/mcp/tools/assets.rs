@@ -1,31 +1,45 @@
-use crate::mcp::service::McpService;
-use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
+use crate::generated::mcp::unity::v1 as pb;
+use crate::mcp::service::{McpService, ipc_error_to_mcp};
+use crate::mcp::tools::UnityAssetsImportRequest;
+use rmcp::{
+    ErrorData as McpError,
+    model::{CallToolResult, Content, Meta},
+};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
 
 impl McpService {
     pub(super) async fn do_unity_assets_import(
         &self,
-        paths: Vec<String>,
-        recursive: Option<bool>,
-        auto_refresh: Option<bool>,
-        timeout_secs: Option<u64>,
+        req: UnityAssetsImportRequest,
+        meta: Meta,
+        ct: CancellationToken,
     ) -> Result<CallToolResult, McpError> {
-        let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
-        let response = ipc
-            .assets_import(
-                paths,
-                recursive.unwrap_or(false),
-                auto_refresh.unwrap_or(true),
-                timeout,
-            )
+        let timeout = Duration::from_secs(req.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+        let ipc = self.require_ipc_for(req.project.as_deref()).await?;
+        // 開始される Operation の通知をこのセッションへ届ける
+        let claim = self.claim_operation(&meta, &ipc);
+        let import = pb::ImportAssetRequest {
+            paths: req.paths,
+            recursive: req.recursive.unwrap_or(false),
+            auto_refresh: req.auto_refresh.unwrap_or(true),
+            op_id: claim.op_id().to_string(),
+        };
+        let response = match ipc
+            .with_cancellation(ct)
+            .call_with_timeout(import, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Assets import IPC error: {}", e), None)
-            })?;
+        {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(self
+                    .operation_ipc_error(&ipc, &claim, e, "Assets import")
+                    .await);
+            }
+        };
 
         let results: Vec<ImportResult> = response
             .results
@@ -58,13 +72,14 @@ impl McpService {
         from_path: String,
         to_path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .assets_move(from_path, to_path, timeout)
             .await
-            .map_err(|e| McpError::internal_error(format!("Assets move IPC error: {}", e), None))?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Assets move"))?;
 
         let output = MoveAssetOutput {
             ok: response.ok,
@@ -91,15 +106,14 @@ impl McpService {
         paths: Vec<String>,
         soft: Option<bool>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .assets_delete(paths, soft.unwrap_or(true), timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Assets delete IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Assets delete"))?;
 
         let output = DeleteAssetsOutput {
             deleted: response.deleted,
@@ -116,15 +130,14 @@ impl McpService {
         &self,
         force: Option<bool>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .assets_refresh(force.unwrap_or(false), timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Assets refresh IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Assets refresh"))?;
 
         let output = RefreshAssetsOutput { ok: response.ok };
 
@@ -138,12 +151,14 @@ impl McpService {
         &self,
         guids: Vec<String>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
-        let response = ipc.assets_guid_to_path(guids, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Assets GUID to path IPC error: {}", e), None)
-        })?;
+        let ipc = self.require_ipc_for(project).await?;
+        let response = ipc
+            .assets_guid_to_path(guids, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Assets GUID to path"))?;
 
         let output = GuidToPathOutput {
             mapping: response.map,
@@ -159,12 +174,14 @@ impl McpService {
         &self,
         paths: Vec<String>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
-        let response = ipc.assets_path_to_guid(paths, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Assets path to GUID IPC error: {}", e), None)
-        })?;
+        let ipc = self.require_ipc_for(project).await?;
+        let response = ipc
+            .assets_path_to_guid(paths, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Assets path to GUID"))?;
 
         let output = PathToGuidOutput {
             mapping: response.map,
@@ -1,9 +1,16 @@
-use crate::{generated::mcp::unity::v1 as pb, ipc::client::IpcClient, mcp::service::McpService};
+use crate::{
+    generated::mcp::unity::v1 as pb, ipc::client::IpcClient, mcp::service::McpService,
+    mcp::tools::PROJECT_DESCRIPTION,
+};
 use anyhow::Result;
-use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
+use rmcp::{
+    ErrorData as McpError,
+    model::{CallToolResult, Content, Meta},
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, time::Duration};
+use tokio_util::sync::CancellationToken;
 
 pub struct BuildTool {
     pub ipc: IpcClient,
@@ -33,6 +40,7 @@ impl BuildTool {
                 strip_symbols: false,
             }),
             define_symbols: HashMap::new(),
+            op_id: String::new(),
         };
 
         let response = self
@@ -63,6 +71,7 @@ impl BuildTool {
                 strip_symbols: false,
             })),
             define_symbols,
+            op_id: String::new(),
         };
 
         let response = self
@@ -85,6 +94,7 @@ impl BuildTool {
             deterministic,
             chunk_based,
             force_rebuild,
+            op_id: String::new(),
         };
 
         let response = self
@@ -254,6 +264,8 @@ pub struct UnityBuildPlayerRequest {
     pub development: Option<bool>,
     #[serde(rename = "timeoutSecs")]
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -267,6 +279,8 @@ pub struct UnityBuildAssetBundlesRequest {
     pub force_rebuild: Option<bool>,
     #[serde(rename = "timeoutSecs")]
     pub timeout_secs: Option<u64>,
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -298,14 +312,18 @@ impl McpService {
     pub(super) async fn do_unity_build_player(
         &self,
         req: UnityBuildPlayerRequest,
+        meta: Meta,
+        ct: CancellationToken,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(req.timeout_secs.unwrap_or(DEFAULT_BUILD_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(req.project.as_deref()).await?;
 
         let platform = pb::BuildPlatform::from_str_name(&req.platform).ok_or_else(|| {
             McpError::invalid_params(format!("invalid platform: {}", req.platform), None)
         })?;
 
+        // 開始される Operation の通知をこのセッションへ届ける
+        let claim = self.claim_operation(&meta, &ipc);
         let pb_req = pb::BuildPlayerRequest {
             platform: platform as i32,
             output_path: req.output_path,
@@ -316,11 +334,20 @@ impl McpService {
                 BuildTool::release_variants()
             }),
             define_symbols: HashMap::new(),
+            op_id: claim.op_id().to_string(),
+        };
+        let resp = match ipc
+            .with_cancellation(ct)
+            .build_player(pb_req, timeout)
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(self
+                    .operation_ipc_error(&ipc, &claim, e, "Build player")
+                    .await);
+            }
         };
-
-        let resp = ipc.build_player(pb_req, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Build player IPC error: {}", e), None)
-        })?;
 
         let output = BuildPlayerOutput {
             status_code: resp.status_code,
@@ -340,20 +367,33 @@ impl McpService {
     pub(super) async fn do_unity_build_asset_bundles(
         &self,
         req: UnityBuildAssetBundlesRequest,
+        meta: Meta,
+        ct: CancellationToken,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(req.timeout_secs.unwrap_or(DEFAULT_BUILD_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(req.project.as_deref()).await?;
 
+        // 開始される Operation の通知をこのセッションへ届ける
+        let claim = self.claim_operation(&meta, &ipc);
         let pb_req = pb::BuildAssetBundlesRequest {
             output_directory: req.output_directory,
             deterministic: req.deterministic.unwrap_or(true),
             chunk_based: req.chunk_based.unwrap_or(false),
             force_rebuild: req.force_rebuild.unwrap_or(false),
+            op_id: claim.op_id().to_string(),
+        };
+        let resp = match ipc
+            .with_cancellation(ct)
+            .build_bundles(pb_req, timeout)
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                return Err(self
+                    .operation_ipc_error(&ipc, &claim, e, "Build bundles")
+                    .await);
+            }
         };
-
-        let resp = ipc.build_bundles(pb_req, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Build bundles IPC error: {}", e), None)
-        })?;
 
         let output = BuildAssetBundlesOutput {
             status_code: resp.status_code,
@@ -1,4 +1,4 @@
-use crate::mcp::service::McpService;
+use crate::mcp::service::{McpService, ipc_error_to_mcp};
 use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -11,15 +11,14 @@ impl McpService {
         game_object: String,
         component: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .component_add(game_object, component, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Component add IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Component add"))?;
 
         let output = ComponentAddOutput {
             ok: response.ok,
@@ -38,12 +37,14 @@ impl McpService {
         &self,
         game_object: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
-        let response = ipc.component_get(game_object, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Component get IPC error: {}", e), None)
-        })?;
+        let ipc = self.require_ipc_for(project).await?;
+        let response = ipc
+            .component_get(game_object, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Component get"))?;
 
         let output = GetComponentsOutput {
             components: response.components,
@@ -58,15 +59,14 @@ impl McpService {
         game_object: String,
         component: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .component_remove(game_object, component, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Component remove IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Component remove"))?;
 
         let output = ComponentRemoveOutput {
             ok: response.ok,
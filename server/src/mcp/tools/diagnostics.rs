@@ -1,4 +1,5 @@
 use crate::mcp::service::McpService;
+use crate::mcp::tools::PROJECT_DESCRIPTION;
 use rmcp::{ErrorData as McpError, model::CallToolResult};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -16,6 +17,10 @@ pub struct UnityGetCompileDiagnosticsRequest {
     pub changed_only: bool,
 
     pub assembly: Option<String>,
+
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    #[serde(default)]
+    pub project: Option<String>,
 }
 
 fn default_max_items() -> u32 {
@@ -83,6 +88,7 @@ impl McpService {
         severity: String,
         changed_only: bool,
         assembly: Option<String>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         tracing::info!(
             "Getting Unity compile diagnostics with max_items={}, severity={}, changed_only={}, assembly={:?}",
@@ -94,7 +100,7 @@ impl McpService {
 
         // Request diagnostics via IPC
         let compile_diagnostics = match self
-            .request_diagnostics_via_ipc(max_items, &severity, changed_only, &assembly)
+            .request_diagnostics_via_ipc(max_items, &severity, changed_only, &assembly, project)
             .await
         {
             Ok(diagnostics) => diagnostics,
@@ -142,12 +148,13 @@ impl McpService {
         severity: &str,
         changed_only: bool,
         assembly: &Option<String>,
+        project: Option<&str>,
     ) -> anyhow::Result<CompileDiagnostics> {
-        use crate::generated::mcp::unity::v1::{GetCompileDiagnosticsRequest, ipc_request};
+        use crate::generated::mcp::unity::v1::GetCompileDiagnosticsRequest;
 
         // Get IPC client
         let client = self
-            .require_ipc()
+            .require_ipc_for(project)
             .await
             .map_err(|e| anyhow::anyhow!("IPC client not available: {}", e.message))?;
 
@@ -159,11 +166,6 @@ impl McpService {
             assembly: assembly.clone().unwrap_or_default(),
         };
 
-        // Send IPC request
-        let ipc_request = crate::generated::mcp::unity::v1::IpcRequest {
-            payload: Some(ipc_request::Payload::GetCompileDiagnostics(request)),
-        };
-
         tracing::debug!(
             "Sending GetCompileDiagnostics request via IPC: max_items={}, severity={}, assembly={:?}",
             max_items,
@@ -171,21 +173,12 @@ impl McpService {
             assembly
         );
 
-        let response = client
-            .request(ipc_request, std::time::Duration::from_secs(30))
+        // Send IPC request
+        let diagnostics_response = client
+            .call(request)
             .await
             .map_err(|e| anyhow::anyhow!("IPC request failed: {}", e))?;
 
-        // Extract diagnostics response
-        let diagnostics_response = match response.payload {
-            Some(
-                crate::generated::mcp::unity::v1::ipc_response::Payload::GetCompileDiagnostics(
-                    resp,
-                ),
-            ) => resp,
-            _ => return Err(anyhow::anyhow!("Unexpected IPC response type")),
-        };
-
         // Check if request was successful
         if !diagnostics_response.success {
             return Err(anyhow::anyhow!(
@@ -403,6 +396,7 @@ mod tests {
             severity: default_severity(),
             changed_only: false,
             assembly: None,
+            project: None,
         };
 
         assert_eq!(req.max_items, 500);
@@ -0,0 +1,51 @@
+use crate::ipc::discovery::{self, CandidateSource, DiscoveredEditor, DiscoveryConfig};
+use crate::mcp::service::McpService;
+use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
+use serde::{Deserialize, Serialize};
+
+/// A discovered editor and whether the server is already connected to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListedEditorOut {
+    #[serde(flatten)]
+    pub editor: DiscoveredEditor,
+    /// Selectable with the `project` argument of other tools
+    pub registered: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListEditorsOut {
+    pub editors: Vec<ListedEditorOut>,
+}
+
+impl McpService {
+    pub(super) async fn do_unity_list_editors(
+        &self,
+        ports: Option<String>,
+        connect: bool,
+    ) -> Result<CallToolResult, McpError> {
+        let mut cfg = DiscoveryConfig::default();
+        if let Some(raw) = ports {
+            cfg.ports = discovery::parse_port_ranges(&raw)
+                .map_err(|e| McpError::invalid_params(format!("ports: {}", e), None))?;
+        }
+
+        let mut editors = Vec::new();
+        for editor in discovery::discover(&cfg).await {
+            // 到達できた記述子の Editor だけを接続先に加える。ポート走査で見つかった相手は
+            // 誰が待ち受けているか分からないので、トークンを送る接続はしない
+            let registered =
+                if connect && editor.reachable && editor.source == CandidateSource::Descriptor {
+                    self.connect_editor(&editor.endpoint, editor.project_path.as_deref())
+                        .await;
+                    true
+                } else {
+                    self.editors().contains(&editor.endpoint).await
+                };
+            editors.push(ListedEditorOut { editor, registered });
+        }
+
+        let content = serde_json::to_string(&ListEditorsOut { editors })
+            .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
+        Ok(CallToolResult::success(vec![Content::text(content)]))
+    }
+}
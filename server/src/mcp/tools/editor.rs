@@ -1,4 +1,4 @@
-use crate::mcp::service::McpService;
+use crate::mcp::service::{McpService, ipc_error_to_mcp};
 use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -10,12 +10,14 @@ impl McpService {
         &self,
         path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
-        let response = ipc.execute_menu_item(path, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Menu execution IPC error: {}", e), None)
-        })?;
+        let ipc = self.require_ipc_for(project).await?;
+        let response = ipc
+            .execute_menu_item(path, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Menu execution"))?;
 
         let output = ExecuteMenuItemOutput {
             ok: response.ok,
@@ -34,12 +36,14 @@ impl McpService {
         &self,
         window_type: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
-        let response = ipc.focus_window(window_type, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Window focus IPC error: {}", e), None)
-        })?;
+        let ipc = self.require_ipc_for(project).await?;
+        let response = ipc
+            .focus_window(window_type, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Window focus"))?;
 
         let output = FocusWindowOutput { ok: response.ok };
         let content = serde_json::to_string(&output)
@@ -1,15 +1,27 @@
-use crate::{mcp::service::McpService, mcp_types::HealthOut};
+use crate::{
+    mcp::service::{McpService, ipc_error_to_mcp},
+    mcp_types::HealthOut,
+};
 use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
 use std::time::Duration;
 
 impl McpService {
-    pub(super) async fn do_unity_health(&self) -> Result<CallToolResult, McpError> {
+    pub(super) async fn do_unity_health(
+        &self,
+        project: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
         // IPCクライアント（未接続時は待機状態のエラーを返す）
-        let ipc = self.require_ipc().await?;
+        let editor = self.editors().find(project).await?;
+        let ipc = editor.require_ipc().await?;
         let timeout = Duration::from_millis(1500);
-        let health_response = ipc.health(timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Unity Bridge IPC error: {}", e), None)
-        })?;
+        let health_response = ipc
+            .health(timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Unity Bridge"))?;
+        // Editor が別プロジェクトを開き直していても project 指定で追えるように更新
+        editor
+            .set_project(&health_response.project_name, &health_response.project_path)
+            .await;
 
         // IPC HealthResponse から HealthOut に変換
         let health = HealthOut {
@@ -0,0 +1,243 @@
+use crate::mcp::service::{McpService, OperationState, ipc_error_to_mcp};
+use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+
+/// Merged view of one operation: what we saw via events plus what the bridge reports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationOutput {
+    #[serde(rename = "opId")]
+    pub op_id: String,
+    /// Last observed event kind (Start / Progress / Complete)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(rename = "payloadJson", skip_serializing_if = "Option::is_none")]
+    pub payload_json: Option<String>,
+    #[serde(rename = "lastUpdatedMsAgo", skip_serializing_if = "Option::is_none")]
+    pub last_updated_ms_ago: Option<u64>,
+    #[serde(rename = "bridgeState", skip_serializing_if = "Option::is_none")]
+    pub bridge_state: Option<String>,
+    #[serde(rename = "bridgeMessage", skip_serializing_if = "Option::is_none")]
+    pub bridge_message: Option<String>,
+    #[serde(rename = "bridgeError", skip_serializing_if = "Option::is_none")]
+    pub bridge_error: Option<String>,
+    /// Editor that reported the operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationListOutput {
+    pub operations: Vec<OperationOutput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelOperationOutput {
+    #[serde(rename = "opId")]
+    pub op_id: String,
+    pub accepted: bool,
+    pub message: String,
+}
+
+impl OperationOutput {
+    fn from_local(op_id: &str, local: Option<&OperationState>) -> Self {
+        Self {
+            op_id: op_id.to_string(),
+            phase: local.map(|s| s.kind.clone()),
+            progress: local.map(|s| s.progress),
+            code: local.map(|s| s.code),
+            message: local.map(|s| s.message.clone()),
+            payload_json: local
+                .map(|s| s.payload_json.clone())
+                .filter(|p| !p.is_empty()),
+            last_updated_ms_ago: local.map(|s| s.last_updated.elapsed().as_millis() as u64),
+            bridge_state: None,
+            bridge_message: None,
+            bridge_error: None,
+            endpoint: local.map(|s| s.endpoint.clone()),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.phase.as_deref() == Some("Complete")
+    }
+}
+
+impl McpService {
+    pub(super) async fn do_unity_operation_list(
+        &self,
+        include_completed: Option<bool>,
+        timeout_secs: Option<u64>,
+        project: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+        let include_completed = include_completed.unwrap_or(true);
+        // project 指定時はその Editor が報告した Operation だけに絞る
+        let endpoint = match project {
+            Some(_) => Some(self.editors().find(project).await?.state().await.endpoint),
+            None => None,
+        };
+
+        let mut operations: Vec<OperationOutput> = self
+            .get_operations()
+            .await
+            .iter()
+            .filter(|(_, state)| endpoint.as_ref().is_none_or(|e| *e == state.endpoint))
+            .map(|(id, state)| OperationOutput::from_local(id, Some(state)))
+            .filter(|op| include_completed || !op.is_complete())
+            .collect();
+        operations.sort_by_key(|op| op.last_updated_ms_ago.unwrap_or(0));
+
+        // 完了済みはイベントが確定情報なので、実行中のものだけ Bridge に問い合わせる
+        for op in operations.iter_mut().filter(|op| !op.is_complete()) {
+            let editor = op.endpoint.clone();
+            self.merge_bridge_view(op, timeout, editor.as_deref()).await;
+        }
+
+        let content = serde_json::to_string(&OperationListOutput { operations })
+            .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
+        Ok(CallToolResult::success(vec![Content::text(content)]))
+    }
+
+    pub(super) async fn do_unity_operation_get(
+        &self,
+        op_id: String,
+        timeout_secs: Option<u64>,
+        project: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+        let local = self.get_operation(&op_id).await;
+        let mut op = OperationOutput::from_local(&op_id, local.as_ref());
+        let editor = project.map(str::to_string).or(op.endpoint.clone());
+        self.merge_bridge_view(&mut op, timeout, editor.as_deref())
+            .await;
+
+        let bridge_knows = op
+            .bridge_state
+            .as_deref()
+            .is_some_and(|state| state != "unknown");
+        if local.is_none() && !bridge_knows {
+            return Err(McpError::invalid_params(
+                format!("operation not found: {}", op_id),
+                None,
+            ));
+        }
+
+        let content = serde_json::to_string(&op)
+            .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
+        Ok(CallToolResult::success(vec![Content::text(content)]))
+    }
+
+    pub(super) async fn do_unity_operation_cancel(
+        &self,
+        op_id: String,
+        timeout_secs: Option<u64>,
+        project: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
+
+        let local = self.get_operation(&op_id).await;
+        let output = if local.as_ref().is_some_and(|s| s.kind == "Complete") {
+            CancelOperationOutput {
+                op_id,
+                accepted: false,
+                message: "operation already completed".to_string(),
+            }
+        } else {
+            // 既知の Operation は、それを報告した Editor に取り消しを送る
+            let editor = project.or(local.as_ref().map(|s| s.endpoint.as_str()));
+            let ipc = self.require_ipc_for(editor).await?;
+            let resp = ipc
+                .operation_cancel(op_id.clone(), timeout)
+                .await
+                .map_err(|e| ipc_error_to_mcp(&e, "Operation cancel"))?;
+            CancelOperationOutput {
+                op_id,
+                accepted: resp.accepted,
+                message: if resp.accepted {
+                    "cancellation requested".to_string()
+                } else {
+                    "bridge rejected cancellation (unknown, finished or not cancellable, e.g. a build past its preparation)"
+                        .to_string()
+                },
+            }
+        };
+
+        let content = serde_json::to_string(&output)
+            .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
+        Ok(CallToolResult::success(vec![Content::text(content)]))
+    }
+
+    /// Fill in the bridge-side fields; failures are reported in `bridgeError`, not as tool errors.
+    async fn merge_bridge_view(
+        &self,
+        op: &mut OperationOutput,
+        timeout: Duration,
+        editor: Option<&str>,
+    ) {
+        let ipc = match self.require_ipc_for(editor).await {
+            Ok(ipc) => ipc,
+            Err(e) => {
+                op.bridge_error = Some(e.message.to_string());
+                return;
+            }
+        };
+        match ipc.operation_get(op.op_id.clone(), timeout).await {
+            Ok(resp) => {
+                op.bridge_state = Some(resp.state);
+                op.bridge_message = Some(resp.message).filter(|m| !m.is_empty());
+            }
+            Err(e) => op.bridge_error = Some(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn state(kind: &str) -> OperationState {
+        OperationState {
+            op_id: "op-1".to_string(),
+            kind: kind.to_string(),
+            progress: 40,
+            code: 0,
+            message: "importing".to_string(),
+            payload_json: String::new(),
+            last_updated: Instant::now(),
+            endpoint: "tcp://127.0.0.1:7777".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_output_from_local_state() {
+        let s = state("Progress");
+        let out = OperationOutput::from_local("op-1", Some(&s));
+        assert_eq!(out.phase.as_deref(), Some("Progress"));
+        assert_eq!(out.progress, Some(40));
+        assert!(out.payload_json.is_none());
+        assert_eq!(out.endpoint.as_deref(), Some("tcp://127.0.0.1:7777"));
+        assert!(!out.is_complete());
+
+        let json = serde_json::to_value(&out).unwrap();
+        assert_eq!(json["opId"], "op-1");
+        assert!(json.get("bridgeState").is_none());
+    }
+
+    #[test]
+    fn test_output_without_local_state() {
+        let out = OperationOutput::from_local("op-x", None);
+        assert!(out.phase.is_none());
+        assert!(!out.is_complete());
+        assert!(OperationOutput::from_local("op-1", Some(&state("Complete"))).is_complete());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::mcp::service::McpService;
+use crate::mcp::service::{McpService, ipc_error_to_mcp};
 use rmcp::{
     ErrorData as McpError,
     model::{CallToolResult, Content},
@@ -14,15 +14,14 @@ impl McpService {
         game_object_path: String,
         prefab_path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .prefab_create(game_object_path, prefab_path, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Prefab create IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Prefab create"))?;
 
         let output = PrefabCreateOutput {
             ok: response.ok,
@@ -49,15 +48,14 @@ impl McpService {
         game_object_path: String,
         prefab_path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .prefab_update(game_object_path, prefab_path, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Prefab update IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Prefab update"))?;
 
         let output = PrefabUpdateOutput {
             ok: response.ok,
@@ -78,15 +76,14 @@ impl McpService {
         &self,
         instance_path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .prefab_apply_overrides(instance_path, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Prefab apply overrides IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Prefab apply overrides"))?;
 
         let output = PrefabApplyOverridesOutput {
             ok: response.ok,
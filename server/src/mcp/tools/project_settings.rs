@@ -1,4 +1,4 @@
-use crate::mcp::service::McpService;
+use crate::mcp::service::{McpService, ipc_error_to_mcp};
 use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -11,34 +11,17 @@ impl McpService {
         &self,
         keys: Vec<String>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
-        use crate::generated::mcp::unity::v1::{
-            GetProjectSettingsRequest, IpcRequest, ipc_request,
-        };
+        use crate::generated::mcp::unity::v1::GetProjectSettingsRequest;
 
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let client = self.require_ipc().await?;
-
-        let request = GetProjectSettingsRequest { keys };
-        let ipc_request = IpcRequest {
-            payload: Some(ipc_request::Payload::GetProjectSettings(request)),
-        };
+        let client = self.require_ipc_for(project).await?;
 
-        let response = client.request(ipc_request, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Project settings get IPC error: {}", e), None)
-        })?;
-
-        let resp = match response.payload {
-            Some(crate::generated::mcp::unity::v1::ipc_response::Payload::GetProjectSettings(
-                r,
-            )) => r,
-            _ => {
-                return Err(McpError::internal_error(
-                    "Unexpected IPC response for project settings get".to_string(),
-                    None,
-                ));
-            }
-        };
+        let resp = client
+            .call_with_timeout(GetProjectSettingsRequest { keys }, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Project settings get"))?;
 
         if !resp.success {
             return Err(McpError::internal_error(
@@ -59,34 +42,17 @@ impl McpService {
         &self,
         settings: HashMap<String, String>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
-        use crate::generated::mcp::unity::v1::{
-            IpcRequest, SetProjectSettingsRequest, ipc_request,
-        };
+        use crate::generated::mcp::unity::v1::SetProjectSettingsRequest;
 
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let client = self.require_ipc().await?;
-
-        let request = SetProjectSettingsRequest { settings };
-        let ipc_request = IpcRequest {
-            payload: Some(ipc_request::Payload::SetProjectSettings(request)),
-        };
+        let client = self.require_ipc_for(project).await?;
 
-        let response = client.request(ipc_request, timeout).await.map_err(|e| {
-            McpError::internal_error(format!("Project settings set IPC error: {}", e), None)
-        })?;
-
-        let resp = match response.payload {
-            Some(crate::generated::mcp::unity::v1::ipc_response::Payload::SetProjectSettings(
-                r,
-            )) => r,
-            _ => {
-                return Err(McpError::internal_error(
-                    "Unexpected IPC response for project settings set".to_string(),
-                    None,
-                ));
-            }
-        };
+        let resp = client
+            .call_with_timeout(SetProjectSettingsRequest { settings }, timeout)
+            .await
+            .map_err(|e| ipc_error_to_mcp(&e, "Project settings set"))?;
 
         let output = SetProjectSettingsOutput { ok: resp.ok };
         let content = serde_json::to_string(&output)
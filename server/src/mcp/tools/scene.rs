@@ -1,4 +1,4 @@
-use crate::mcp::service::McpService;
+use crate::mcp::service::{McpService, ipc_error_to_mcp};
 use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
@@ -22,13 +22,14 @@ impl McpService {
         path: String,
         additive: Option<bool>,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .scenes_open(path, additive.unwrap_or(false), timeout)
             .await
-            .map_err(|e| McpError::internal_error(format!("Scene open IPC error: {}", e), None))?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Scene open"))?;
         let output = SceneOpOutput { ok: response.ok };
         let content = serde_json::to_string(&output)
             .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
@@ -39,13 +40,14 @@ impl McpService {
         &self,
         path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .scenes_save(path, timeout)
             .await
-            .map_err(|e| McpError::internal_error(format!("Scene save IPC error: {}", e), None))?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Scene save"))?;
         let output = SceneOpOutput { ok: response.ok };
         let content = serde_json::to_string(&output)
             .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
@@ -55,13 +57,14 @@ impl McpService {
     pub(super) async fn do_unity_scene_get_open(
         &self,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .scenes_get_open_scenes(timeout)
             .await
-            .map_err(|e| McpError::internal_error(format!("Scene list IPC error: {}", e), None))?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Scene list"))?;
         let output = OpenScenesOutput {
             scenes: response.scenes,
             active_scene: if response.active_scene.is_empty() {
@@ -79,15 +82,14 @@ impl McpService {
         &self,
         path: String,
         timeout_secs: Option<u64>,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
         let timeout = Duration::from_secs(timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS));
-        let ipc = self.require_ipc().await?;
+        let ipc = self.require_ipc_for(project).await?;
         let response = ipc
             .scenes_set_active_scene(path, timeout)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Scene set active IPC error: {}", e), None)
-            })?;
+            .map_err(|e| ipc_error_to_mcp(&e, "Scene set active"))?;
         let output = SceneOpOutput { ok: response.ok };
         let content = serde_json::to_string(&output)
             .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
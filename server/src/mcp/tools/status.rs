@@ -1,38 +1,67 @@
+use crate::mcp::editors::EditorSlot;
 use crate::mcp::service::McpService;
 use rmcp::{ErrorData as McpError, model::CallToolResult, model::Content};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BridgeStatusOut {
+pub struct EditorStatusOut {
     pub connected: bool,
     pub attempt: u32,
     pub last_error: Option<String>,
     pub next_retry_ms: Option<u64>,
     pub negotiated_features: Option<Vec<String>>, // 接続済みなら公開
     pub endpoint: String,
+    pub latency_ms: Option<f64>, // 直近の heartbeat 往復時間（ipc.heartbeat 交渉時のみ）
+    pub project_name: Option<String>,
+    pub project_path: Option<String>,
 }
 
-impl McpService {
-    pub(super) async fn do_unity_bridge_status(&self) -> Result<CallToolResult, McpError> {
-        let mut out = {
-            let s = self.get_bridge_state().await;
-            BridgeStatusOut {
-                connected: s.connected,
-                attempt: s.attempt,
-                last_error: s.last_error,
-                next_retry_ms: s.next_retry_ms,
-                negotiated_features: None,
-                endpoint: s.endpoint,
-            }
+/// Status of the selected editor (default: the first configured one) plus every editor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeStatusOut {
+    #[serde(flatten)]
+    pub selected: EditorStatusOut,
+    pub editors: Vec<EditorStatusOut>,
+}
+
+impl EditorStatusOut {
+    async fn of(editor: &EditorSlot) -> Self {
+        let s = editor.state().await;
+        let mut out = EditorStatusOut {
+            connected: s.connected,
+            attempt: s.attempt,
+            last_error: s.last_error,
+            next_retry_ms: s.next_retry_ms,
+            negotiated_features: None,
+            endpoint: s.endpoint,
+            latency_ms: None,
+            project_name: s.project_name,
+            project_path: s.project_path,
         };
 
         // 接続済みなら交渉済み機能も返す（情報価値向上）
         if out.connected
-            && let Ok(ipc) = self.require_ipc().await
+            && let Some(ipc) = editor.ipc().await
         {
             let features = ipc.get_negotiated_features().await.to_strings();
             out.negotiated_features = Some(features);
+            out.latency_ms = ipc.last_rtt().await.map(|rtt| rtt.as_secs_f64() * 1000.0);
+        }
+        out
+    }
+}
+
+impl McpService {
+    pub(super) async fn do_unity_bridge_status(
+        &self,
+        project: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let selected = EditorStatusOut::of(&*self.editors().find(project).await?).await;
+        let mut editors = Vec::new();
+        for editor in self.editors().slots().await {
+            editors.push(EditorStatusOut::of(&editor).await);
         }
+        let out = BridgeStatusOut { selected, editors };
 
         let content = serde_json::to_string(&out)
             .map_err(|e| McpError::internal_error(format!("Serialization error: {}", e), None))?;
@@ -2,11 +2,16 @@ use crate::generated::mcp::unity::v1::{
     GetTestResultsRequest, GetTestResultsResponse, GetTestRunStatusRequest,
     GetTestRunStatusResponse, RunStatus, RunTestsRequest, RunTestsResponse, TestMode, TestStatus,
 };
+use crate::ipc::path::{IpcConfig, parse_endpoint};
 use crate::mcp::service::McpService;
-use rmcp::{ErrorData as McpError, model::CallToolResult};
+use crate::mcp::tools::PROJECT_DESCRIPTION;
+use rmcp::{
+    ErrorData as McpError,
+    model::{CallToolResult, Meta},
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -28,6 +33,10 @@ pub struct UnityRunTestsRequest {
 
     #[serde(default = "default_include_passed")]
     pub include_passed: bool,
+
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    #[serde(default)]
+    pub project: Option<String>,
 }
 
 fn default_mode() -> String {
@@ -56,6 +65,10 @@ pub struct UnityGetTestResultsRequest {
 
     #[serde(default = "default_include_passed")]
     pub include_passed: bool,
+
+    #[schemars(description = PROJECT_DESCRIPTION)]
+    #[serde(default)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -125,6 +138,9 @@ pub struct TestRequest {
     pub max_items: u32,
     #[serde(rename = "includePassed")]
     pub include_passed: bool,
+    /// Operation McpTestRunner reports the run's progress under; empty without an editor
+    #[serde(rename = "opId", default)]
+    pub op_id: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -138,13 +154,19 @@ pub struct StatusFile {
 impl McpService {
     pub async fn do_unity_run_tests(
         &self,
-        mode: String,
-        test_filter: Option<String>,
-        categories: Option<Vec<String>>,
-        timeout_sec: u32,
-        max_items: u32,
-        include_passed: bool,
+        req: UnityRunTestsRequest,
+        meta: Meta,
     ) -> Result<CallToolResult, McpError> {
+        let UnityRunTestsRequest {
+            mode,
+            test_filter,
+            categories,
+            timeout_sec,
+            max_items,
+            include_passed,
+            project,
+        } = req;
+        let tests_dir = self.project_tests_dir(project.as_deref()).await?;
         tracing::info!(
             "Running Unity tests with mode={}, filter={:?}, categories={:?}, timeout={}s",
             mode,
@@ -156,6 +178,12 @@ impl McpService {
         // Generate unique run ID
         let run_id = self.generate_run_id();
 
+        // Editor に接続していれば、McpTestRunner が報告する Operation の通知をこのセッションへ届ける
+        let claim = match self.require_ipc_for(project.as_deref()).await {
+            Ok(ipc) => Some(self.claim_operation(&meta, &ipc)),
+            Err(_) => None,
+        };
+
         // Create test request
         let request = TestRequest {
             run_id: run_id.clone(),
@@ -165,10 +193,14 @@ impl McpService {
             timeout_sec,
             max_items,
             include_passed,
+            op_id: claim
+                .as_ref()
+                .map(|c| c.op_id().to_string())
+                .unwrap_or_default(),
         };
 
         // Write request file
-        let request_path = self.get_requests_path();
+        let request_path = self.get_requests_path(tests_dir.as_deref());
         let request_file = request_path.join(format!("runTests-{}.json", run_id));
 
         match self.write_request_file(&request_file, &request).await {
@@ -187,9 +219,17 @@ impl McpService {
         // Send MCP notification for test started
         self.send_test_started_notification(&run_id, &mode, &test_filter, &categories)
             .await;
+        // Without an editor connection no OperationEvent arrives; report start and finish only
+        if claim.is_none() {
+            self.report_progress(&meta, 0.0, &format!("Test run {} started", run_id))
+                .await;
+        }
 
         // Wait for test completion
-        match self.wait_for_test_completion(&run_id, timeout_sec).await {
+        match self
+            .wait_for_test_completion(&run_id, timeout_sec, tests_dir.as_deref())
+            .await
+        {
             Ok(results) => {
                 tracing::info!(
                     "Test run completed: {} tests ({} passed, {} failed, {} skipped)",
@@ -207,6 +247,17 @@ impl McpService {
                     results.truncated,
                 )
                 .await;
+                if claim.is_none() {
+                    self.report_progress(
+                        &meta,
+                        100.0,
+                        &format!(
+                            "Test run {} finished: {} passed, {} failed",
+                            run_id, results.summary.passed, results.summary.failed
+                        ),
+                    )
+                    .await;
+                }
 
                 // Apply filters and limits
                 let filtered_tests =
@@ -245,7 +296,9 @@ impl McpService {
         run_id: Option<String>,
         max_items: u32,
         include_passed: bool,
+        project: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
+        let tests_dir = self.project_tests_dir(project).await?;
         tracing::info!(
             "Getting Unity test results for run_id={:?}, max_items={}, include_passed={}",
             run_id,
@@ -254,9 +307,11 @@ impl McpService {
         );
 
         let results_path = if let Some(id) = run_id.as_ref() {
-            self.get_tests_path().join(format!("run-{}.json", id))
+            self.get_tests_path(tests_dir.as_deref())
+                .join(format!("run-{}.json", id))
         } else {
-            self.get_tests_path().join("latest.json")
+            self.get_tests_path(tests_dir.as_deref())
+                .join("latest.json")
         };
 
         match self.read_test_results_file(&results_path).await {
@@ -341,8 +396,74 @@ impl McpService {
         format!("{}-{}", now.format("%Y-%m-%dT%H:%M:%SZ"), uuid_short)
     }
 
-    fn get_requests_path(&self) -> std::path::PathBuf {
-        if let Ok(env_path) = std::env::var("UNITY_MCP_REQ_PATH") {
+    /// `UnityMCP/tests` of the project a tool selected; `None` keeps the default paths.
+    ///
+    /// Unity 側の McpTestRunner はプロジェクトルート直下の `UnityMCP/tests` を監視する。
+    async fn project_tests_dir(&self, project: Option<&str>) -> Result<Option<PathBuf>, McpError> {
+        if project.is_none() {
+            return Ok(None);
+        }
+        let state = self.editors().find(project).await?.state().await;
+        match state.project_path {
+            Some(root) => Ok(Some(PathBuf::from(root).join("UnityMCP").join("tests"))),
+            None => Err(McpError::internal_error(
+                format!(
+                    "Unity Editor at {} has not reported its project path yet",
+                    state.endpoint
+                ),
+                None,
+            )),
+        }
+    }
+
+    /// Directories test request/result files may live in: the bundled bridge project,
+    /// the configured Unity project and the `UnityMCP` directory of each project opened
+    /// by an editor on this machine.
+    ///
+    /// リモート（tls/ws など）の Bridge が名乗る project_path は信用しない。
+    async fn allowed_test_dirs(&self) -> Vec<PathBuf> {
+        let bridge = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("bridge");
+        let mut dirs = vec![bridge];
+        if let Some(root) = IpcConfig::default().unity_project {
+            dirs.push(root.join("UnityMCP"));
+        }
+        for editor in self.editors().slots().await {
+            let state = editor.state().await;
+            if !parse_endpoint(&state.endpoint).is_loopback() {
+                continue;
+            }
+            if let Some(root) = state.project_path {
+                dirs.push(PathBuf::from(root).join("UnityMCP"));
+            }
+        }
+        dirs.into_iter()
+            .map(|d| d.canonicalize().unwrap_or(d))
+            .collect()
+    }
+
+    async fn check_test_path(&self, canonical_path: &Path) -> anyhow::Result<()> {
+        if self
+            .allowed_test_dirs()
+            .await
+            .iter()
+            .any(|dir| canonical_path.starts_with(dir))
+        {
+            return Ok(());
+        }
+        Err(anyhow::anyhow!(
+            "Access denied: path outside bridge directory: {}",
+            canonical_path.display()
+        ))
+    }
+
+    fn get_requests_path(&self, tests_dir: Option<&Path>) -> std::path::PathBuf {
+        if let Some(dir) = tests_dir {
+            return dir.join("requests");
+        }
+        if let Some(env_path) = crate::config::var("UNITY_MCP_REQ_PATH") {
             return std::path::PathBuf::from(env_path);
         }
 
@@ -355,8 +476,11 @@ impl McpService {
             .join("requests")
     }
 
-    fn get_tests_path(&self) -> std::path::PathBuf {
-        if let Ok(env_path) = std::env::var("UNITY_MCP_TESTS_PATH") {
+    fn get_tests_path(&self, tests_dir: Option<&Path>) -> std::path::PathBuf {
+        if let Some(dir) = tests_dir {
+            return dir.to_path_buf();
+        }
+        if let Some(env_path) = crate::config::var("UNITY_MCP_TESTS_PATH") {
             return std::path::PathBuf::from(env_path);
         }
 
@@ -376,24 +500,7 @@ impl McpService {
 
         // Security check: ensure the path is within our allowed directory
         let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
-        let bridge_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap_or_else(|| std::path::Path::new("."))
-            .join("bridge")
-            .canonicalize()
-            .unwrap_or_else(|_| {
-                std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                    .parent()
-                    .unwrap_or_else(|| std::path::Path::new("."))
-                    .join("bridge")
-            });
-
-        if !canonical_path.starts_with(&bridge_path) {
-            return Err(anyhow::anyhow!(
-                "Access denied: path outside bridge directory: {}",
-                canonical_path.display()
-            ));
-        }
+        self.check_test_path(&canonical_path).await?;
 
         let json_content = serde_json::to_string(request)?;
         tokio::fs::write(path, json_content).await?;
@@ -411,24 +518,7 @@ impl McpService {
 
         // Security check: ensure the path is within our allowed directory
         let canonical_path = path.canonicalize()?;
-        let bridge_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap_or_else(|| std::path::Path::new("."))
-            .join("bridge")
-            .canonicalize()
-            .unwrap_or_else(|_| {
-                std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                    .parent()
-                    .unwrap_or_else(|| std::path::Path::new("."))
-                    .join("bridge")
-            });
-
-        if !canonical_path.starts_with(&bridge_path) {
-            return Err(anyhow::anyhow!(
-                "Access denied: path outside bridge directory: {}",
-                canonical_path.display()
-            ));
-        }
+        self.check_test_path(&canonical_path).await?;
 
         let content = tokio::fs::read_to_string(path).await?;
 
@@ -451,12 +541,13 @@ impl McpService {
         &self,
         run_id: &str,
         timeout_sec: u32,
+        tests_dir: Option<&Path>,
     ) -> anyhow::Result<TestResults> {
-        let status_path = self.get_tests_path().join("status.json");
+        let status_path = self.get_tests_path(tests_dir).join("status.json");
         let per_run_status_path = self
-            .get_tests_path()
+            .get_tests_path(tests_dir)
             .join(format!("status-{}.json", run_id));
-        let results_path = self.get_tests_path().join("latest.json");
+        let results_path = self.get_tests_path(tests_dir).join("latest.json");
 
         let timeout_duration = tokio::time::Duration::from_secs(timeout_sec as u64);
         let start_time = tokio::time::Instant::now();
@@ -618,7 +709,10 @@ impl McpService {
         };
 
         // Send via IPC
-        match self.send_ipc_run_tests(proto_request).await {
+        match self
+            .send_ipc_run_tests(proto_request, request.project.as_deref())
+            .await
+        {
             Ok(response) => {
                 let result_json = serde_json::json!({
                     "runId": response.run_id,
@@ -638,15 +732,7 @@ impl McpService {
                 tracing::error!("IPC test run failed: {}", e);
                 // Fallback to file-based approach
                 tracing::info!("Falling back to file-based test execution");
-                self.do_unity_run_tests(
-                    request.mode,
-                    request.test_filter,
-                    request.categories,
-                    request.timeout_sec,
-                    request.max_items,
-                    request.include_passed,
-                )
-                .await
+                self.do_unity_run_tests(request, Meta::default()).await
             }
         }
     }
@@ -668,7 +754,10 @@ impl McpService {
             include_passed: request.include_passed,
         };
 
-        match self.send_ipc_get_test_results(proto_request).await {
+        match self
+            .send_ipc_get_test_results(proto_request, request.project.as_deref())
+            .await
+        {
             Ok(response) => {
                 if response.found {
                     if let Some(results) = response.results {
@@ -686,6 +775,7 @@ impl McpService {
                         request.run_id,
                         request.max_items,
                         request.include_passed,
+                        request.project.as_deref(),
                     )
                     .await
                 }
@@ -698,6 +788,7 @@ impl McpService {
                     request.run_id,
                     request.max_items,
                     request.include_passed,
+                    request.project.as_deref(),
                 )
                 .await
             }
@@ -759,9 +850,10 @@ impl McpService {
     async fn send_ipc_run_tests(
         &self,
         request: RunTestsRequest,
+        project: Option<&str>,
     ) -> anyhow::Result<RunTestsResponse> {
         let _ipc = self
-            .require_ipc()
+            .require_ipc_for(project)
             .await
             .map_err(|e| anyhow::anyhow!("IPC client not available: {}", e))?;
 
@@ -780,9 +872,10 @@ impl McpService {
     async fn send_ipc_get_test_results(
         &self,
         _request: GetTestResultsRequest,
+        project: Option<&str>,
     ) -> anyhow::Result<GetTestResultsResponse> {
         let _ipc = self
-            .require_ipc()
+            .require_ipc_for(project)
             .await
             .map_err(|e| anyhow::anyhow!("IPC client not available: {}", e))?;
 
@@ -992,6 +1085,7 @@ mod test_runner_tests {
             timeout_sec: default_timeout_sec(),
             max_items: default_max_items(),
             include_passed: default_include_passed(),
+            project: None,
         };
 
         assert_eq!(req.mode, "edit");
@@ -1063,6 +1157,27 @@ mod test_runner_tests {
         assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
 
+    #[tokio::test]
+    async fn test_only_local_editors_extend_allowed_dirs() {
+        let service = create_test_service().await;
+        let local = TempDir::new().unwrap();
+        let remote = TempDir::new().unwrap();
+        for (endpoint, root) in [
+            ("tcp://127.0.0.1:18992", &local),
+            ("tls://editor.example:7777", &remote),
+        ] {
+            let slot = service.editors().add(endpoint.to_string()).await;
+            slot.set_project("Game", root.path().to_str().unwrap())
+                .await;
+        }
+
+        let dirs = service.allowed_test_dirs().await;
+        let local_dir = local.path().join("UnityMCP");
+        let remote_dir = remote.path().join("UnityMCP");
+        assert!(dirs.iter().any(|d| local_dir.starts_with(d)), "{dirs:?}");
+        assert!(!dirs.iter().any(|d| remote_dir.starts_with(d)), "{dirs:?}");
+    }
+
     #[tokio::test]
     async fn test_security_path_outside_bridge() {
         let service = create_test_service().await;
@@ -1,7 +1,10 @@
 use tracing_subscriber::{EnvFilter, fmt};
 
 pub fn init_tracing() {
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    // logging.level（RUST_LOG が優先）。不正な指定は info にフォールバック
+    let filter = crate::config::var("RUST_LOG")
+        .and_then(|level| EnvFilter::try_new(level).ok())
+        .unwrap_or_else(|| EnvFilter::new("info"));
     // MCP stdioトランスポートと干渉しないよう、ログはstderrへ出力
     fmt()
         .with_env_filter(filter)
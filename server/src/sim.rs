@@ -0,0 +1,28 @@
+//! Unity bridge simulator for offline development.
+//!
+//! 実際の Unity Editor の代わりに IPC を話すインメモリのブリッジ。ハンドシェイク（トークン・
+//! スキーマハッシュ・機能ネゴシエーション・圧縮・チャンク分割）は C# Bridge と同じ規則で行い、
+//! リクエストは [`Project`] のアセット・シーン・GameObject を実際に書き換える。ビルドとインポートは
+//! `op_step` ごとに進捗イベントを出すオペレーションとして実行される。
+//!
+//! `unity-bridge-sim` バイナリから起動するほか、テストでは [`BridgeSim::bind`] で直接使える。
+//! クライアントと Bridge の間に障害を注入するには [`proxy::FaultProxy`] を挟む。
+//! 記録したセッションを Unity なしで再生するには [`replay::ReplayBridge`] を使う。
+
+mod bridge;
+mod ops;
+pub mod project;
+pub mod proxy;
+pub mod replay;
+
+use std::sync::LazyLock;
+use std::time::Instant;
+
+pub use bridge::{BridgeSim, SimHandle, SimOptions};
+pub use project::Project;
+
+/// Sender clock for event and pong timestamps.
+pub(crate) fn monotonic_ns() -> i64 {
+    static START: LazyLock<Instant> = LazyLock::new(Instant::now);
+    START.elapsed().as_nanos() as i64
+}
@@ -0,0 +1,679 @@
+//! IPC side of the simulator: accepts connections and runs the bridge protocol.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use futures::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc};
+
+use super::ops::Operations;
+use super::project::{BuildRecord, PLATFORM, Project};
+use crate::generated::mcp::unity::v1 as pb;
+use crate::ipc::{
+    chunking,
+    codec::{self, Compression},
+    features::{FeatureFlag, FeatureSet},
+    framing,
+    path::DEFAULT_COMPRESSION_THRESHOLD,
+};
+
+/// Version the bridge speaks; the client's major version must match.
+const IPC_VERSION: &str = "1.0";
+
+/// Chunk size for "ipc.chunking" when the client does not send `max_frame_bytes`.
+const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Algorithms the simulator can compress with, in order of preference.
+const COMPRESSIONS: [Compression; 2] = [Compression::Zstd, Compression::Deflate];
+
+/// Stages a fake player build goes through, one `op_step` each. Like `BuildHandler`, the
+/// build is cancellable while the platform switches and not once these stages run.
+const PLAYER_STAGES: &[&str] = &[
+    "Compiling scripts",
+    "Processing scenes",
+    "Building resources",
+    "Packaging",
+    "Postprocessing",
+];
+
+const BUNDLE_STAGES: &[&str] = &["Collecting assets", "Writing bundles"];
+
+#[derive(Debug, Clone)]
+pub struct SimOptions {
+    /// Token clients must send; `None` accepts any token
+    pub token: Option<String>,
+    /// Time each step of a build or import takes
+    pub op_step: Duration,
+    /// Features the simulated bridge offers
+    pub features: FeatureSet,
+}
+
+impl Default for SimOptions {
+    fn default() -> Self {
+        Self {
+            token: None,
+            op_step: Duration::from_millis(100),
+            features: FeatureSet::supported_by_client(),
+        }
+    }
+}
+
+struct Shared {
+    project: Mutex<Project>,
+    ops: Operations,
+    events: broadcast::Sender<pb::IpcEvent>,
+    options: SimOptions,
+}
+
+impl Shared {
+    fn project(&self) -> MutexGuard<'_, Project> {
+        self.project.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn log(&self, level: pb::log_event::Level, category: &str, message: &str) {
+        let ts = super::monotonic_ns();
+        let _ = self.events.send(pb::IpcEvent {
+            monotonic_ts_ns: ts,
+            payload: Some(pb::ipc_event::Payload::Log(pb::LogEvent {
+                monotonic_ts_ns: ts,
+                level: level as i32,
+                message: message.to_string(),
+                category: category.to_string(),
+                stack_trace: String::new(),
+            })),
+        });
+    }
+}
+
+/// Access to a running simulator, e.g. to inspect or script the project from a test.
+#[derive(Clone)]
+pub struct SimHandle {
+    shared: Arc<Shared>,
+}
+
+impl SimHandle {
+    pub fn project(&self) -> MutexGuard<'_, Project> {
+        self.shared.project()
+    }
+
+    /// Emit a Unity log event to every connected client.
+    pub fn log(&self, level: pb::log_event::Level, category: &str, message: &str) {
+        self.shared.log(level, category, message);
+    }
+}
+
+/// A simulated Unity bridge listening on TCP.
+pub struct BridgeSim {
+    listener: TcpListener,
+    shared: Arc<Shared>,
+}
+
+impl BridgeSim {
+    pub async fn bind(addr: &str, project: Project, options: SimOptions) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let (events, _) = broadcast::channel(1024);
+        Ok(Self {
+            listener,
+            shared: Arc::new(Shared {
+                project: Mutex::new(project),
+                ops: Operations::new(events.clone()),
+                events,
+                options,
+            }),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Endpoint to put in `ipc.endpoint`.
+    pub fn endpoint(&self) -> String {
+        match self.local_addr() {
+            Ok(addr) => format!("tcp://{addr}"),
+            Err(_) => String::new(),
+        }
+    }
+
+    pub fn handle(&self) -> SimHandle {
+        SimHandle {
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Accept connections until the task is dropped.
+    pub async fn run(self) {
+        loop {
+            let (stream, peer) = match self.listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    tracing::warn!("accept failed: {}", e);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            let shared = self.shared.clone();
+            tokio::spawn(async move {
+                tracing::debug!("connection from {}", peer);
+                serve(stream, shared).await;
+                tracing::debug!("connection from {} closed", peer);
+            });
+        }
+    }
+}
+
+/// What the handshake agreed on for one connection.
+struct Session {
+    features: FeatureSet,
+    compression: Option<Compression>,
+    chunk_size: Option<usize>,
+}
+
+async fn serve(stream: TcpStream, shared: Arc<Shared>) {
+    let _ = stream.set_nodelay(true);
+    let mut framed = framing::into_framed(stream);
+
+    let Some(Ok(first)) = framed.next().await else {
+        return;
+    };
+    let hello = match codec::decode_control(first.freeze()) {
+        Ok(pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Hello(hello)),
+        }) => hello,
+        _ => {
+            let reject = reject(
+                pb::ipc_reject::Code::Internal,
+                "Expected hello control message",
+            );
+            let _ = framed.send(reject).await;
+            return;
+        }
+    };
+    let (session, welcome) = match handshake(&shared, &hello) {
+        Ok(accepted) => accepted,
+        Err((code, message)) => {
+            tracing::info!("rejecting {}: {}", hello.client_name, message);
+            let _ = framed.send(reject(code, &message)).await;
+            return;
+        }
+    };
+    let welcome = pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Welcome(welcome)),
+    };
+    let Ok(bytes) = codec::encode_control(&welcome) else {
+        return;
+    };
+    if framed.send(bytes).await.is_err() {
+        return;
+    }
+    tracing::info!(
+        "session with {} {}: features={:?}",
+        hello.client_name,
+        hello.client_version,
+        session.features.to_strings()
+    );
+
+    let session = Arc::new(session);
+    let (mut sink, mut frames) = framed.split();
+    let (tx, mut rx) = mpsc::channel::<pb::IpcEnvelope>(256);
+
+    let compression = session.compression;
+    let writer = tokio::spawn(async move {
+        while let Some(env) = rx.recv().await {
+            let bytes = match compression {
+                Some(c) => {
+                    codec::encode_envelope_compressed(&env, c, DEFAULT_COMPRESSION_THRESHOLD)
+                }
+                None => codec::encode_envelope(&env),
+            };
+            let Ok(bytes) = bytes else { continue };
+            if sink.send(bytes).await.is_err() {
+                break;
+            }
+        }
+    });
+    tokio::spawn(forward_events(
+        shared.events.subscribe(),
+        session.clone(),
+        tx.clone(),
+    ));
+
+    while let Some(Ok(frame)) = frames.next().await {
+        let env = match codec::decode_envelope(frame.freeze())
+            .and_then(|env| codec::inflate_envelope(env, framing::DEFAULT_MAX_FRAME_LEN))
+        {
+            Ok(env) => env,
+            Err(e) => {
+                tracing::warn!("dropping undecodable frame: {}", e);
+                continue;
+            }
+        };
+        match env.kind {
+            Some(pb::ipc_envelope::Kind::Request(req)) => {
+                let cid = env.correlation_id;
+                if is_long_running(&req) {
+                    let (shared, session, tx) = (shared.clone(), session.clone(), tx.clone());
+                    tokio::spawn(async move {
+                        let payload = handle(&shared, &session, req).await;
+                        respond(&session, &tx, cid, payload).await;
+                    });
+                } else {
+                    let payload = handle(&shared, &session, req).await;
+                    respond(&session, &tx, cid, payload).await;
+                }
+            }
+            Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Ping(ping)),
+            })) => {
+                let pong = pb::IpcEnvelope {
+                    correlation_id: String::new(),
+                    kind: Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                        kind: Some(pb::ipc_control::Kind::Pong(pb::IpcPong {
+                            nonce: ping.nonce,
+                            ping_sent_monotonic_ns: ping.sent_monotonic_ns,
+                        })),
+                    })),
+                };
+                let _ = tx.send(pong).await;
+            }
+            _ => {}
+        }
+    }
+    drop(tx);
+    let _ = writer.await;
+}
+
+fn reject(code: pb::ipc_reject::Code, message: &str) -> bytes::Bytes {
+    let control = pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Reject(pb::IpcReject {
+            code: code as i32,
+            message: message.to_string(),
+        })),
+    };
+    codec::encode_control(&control).unwrap_or_default()
+}
+
+/// Validate a hello like `EditorIpcServer` and build the welcome.
+fn handshake(
+    shared: &Shared,
+    hello: &pb::IpcHello,
+) -> Result<(Session, pb::IpcWelcome), (pb::ipc_reject::Code, String)> {
+    use pb::ipc_reject::Code;
+
+    if let Some(expected) = &shared.options.token {
+        if hello.token.is_empty() {
+            return Err((Code::Unauthenticated, "Missing or empty token".into()));
+        }
+        if hello.token != *expected {
+            return Err((Code::Unauthenticated, "Invalid token".into()));
+        }
+    }
+    if hello.ipc_version.is_empty() {
+        return Err((Code::FailedPrecondition, "missing ipc_version".into()));
+    }
+    let major = hello
+        .ipc_version
+        .split_once('.')
+        .and_then(|(major, _)| major.parse::<u32>().ok());
+    match major {
+        None => return Err((Code::OutOfRange, "invalid version format".into())),
+        Some(1) => {}
+        Some(_) => {
+            return Err((
+                Code::OutOfRange,
+                format!(
+                    "ipc_version {} not supported; server={IPC_VERSION}",
+                    hello.ipc_version
+                ),
+            ));
+        }
+    }
+    if hello.schema_hash.is_empty() {
+        return Err((Code::FailedPrecondition, "Schema hash missing".into()));
+    }
+    if hello.schema_hash != codec::schema_hash() {
+        return Err((Code::FailedPrecondition, "Schema hash mismatch".into()));
+    }
+
+    let mut features =
+        FeatureSet::from_strings(&hello.features).intersect(&shared.options.features);
+    let mut meta =
+        std::collections::HashMap::from([("platform".to_string(), PLATFORM.to_string())]);
+    // An absent "compression" meta means the client only speaks deflate
+    let offered = hello
+        .meta
+        .get("compression")
+        .map(String::as_str)
+        .unwrap_or("deflate");
+    let compression = offered
+        .split(',')
+        .filter_map(Compression::from_name)
+        .find(|c| COMPRESSIONS.contains(c));
+    let compression = match (features.contains(&FeatureFlag::IpcCompression), compression) {
+        (true, Some(c)) => {
+            meta.insert("compression".to_string(), c.as_str().to_string());
+            Some(c)
+        }
+        (true, None) => {
+            features.remove(&FeatureFlag::IpcCompression);
+            None
+        }
+        (false, _) => None,
+    };
+    let chunk_size = features.contains(&FeatureFlag::IpcChunking).then(|| {
+        match hello
+            .meta
+            .get("max_frame_bytes")
+            .and_then(|v| v.parse::<usize>().ok())
+        {
+            Some(max_frame) => DEFAULT_CHUNK_SIZE
+                .min(max_frame.saturating_sub(1024))
+                .max(1024),
+            None => DEFAULT_CHUNK_SIZE,
+        }
+    });
+
+    let project = shared.project();
+    let welcome = pb::IpcWelcome {
+        ipc_version: IPC_VERSION.to_string(),
+        accepted_features: features.to_strings(),
+        schema_hash: codec::schema_hash(),
+        server_name: "unity-bridge-sim".to_string(),
+        server_version: env!("CARGO_PKG_VERSION").to_string(),
+        editor_version: format!("Unity {}", project.unity_version),
+        session_id: uuid::Uuid::new_v4().to_string(),
+        meta,
+    };
+    let session = Session {
+        features,
+        compression,
+        chunk_size,
+    };
+    Ok((session, welcome))
+}
+
+/// Log events need "events.log", operation events "ops.progress".
+async fn forward_events(
+    mut events: broadcast::Receiver<pb::IpcEvent>,
+    session: Arc<Session>,
+    tx: mpsc::Sender<pb::IpcEnvelope>,
+) {
+    loop {
+        let event = tokio::select! {
+            _ = tx.closed() => return,
+            event = events.recv() => match event {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+        };
+        let wanted = match &event.payload {
+            Some(pb::ipc_event::Payload::Log(_)) => FeatureFlag::EventsLog,
+            Some(pb::ipc_event::Payload::Op(_)) => FeatureFlag::OpsProgress,
+            None => continue,
+        };
+        if !session.features.contains(&wanted) {
+            continue;
+        }
+        let env = pb::IpcEnvelope {
+            correlation_id: String::new(),
+            kind: Some(pb::ipc_envelope::Kind::Event(event)),
+        };
+        if tx.send(env).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Builds and imports take `op_step`s; they must not hold up other requests.
+fn is_long_running(req: &pb::IpcRequest) -> bool {
+    use pb::ipc_request::Payload;
+    match &req.payload {
+        Some(Payload::Build(_)) => true,
+        Some(Payload::Assets(assets)) => {
+            matches!(assets.payload, Some(pb::assets_request::Payload::Import(_)))
+        }
+        _ => false,
+    }
+}
+
+/// Send a response, split into chunks when "ipc.chunking" calls for it.
+async fn respond(
+    session: &Session,
+    tx: &mpsc::Sender<pb::IpcEnvelope>,
+    correlation_id: String,
+    payload: Option<pb::ipc_response::Payload>,
+) {
+    use prost::Message;
+
+    let response = pb::IpcResponse {
+        correlation_id: correlation_id.clone(),
+        payload,
+    };
+    let envelopes = match session.chunk_size {
+        Some(size) if response.encoded_len() > size => {
+            chunking::split_response(&correlation_id, &response, size)
+        }
+        _ => vec![pb::IpcEnvelope {
+            correlation_id,
+            kind: Some(pb::ipc_envelope::Kind::Response(response)),
+        }],
+    };
+    for env in envelopes {
+        if tx.send(env).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Answer one request. `None` is an empty response, which is what the bridge sends
+/// for a request whose feature was not negotiated.
+async fn handle(
+    shared: &Shared,
+    session: &Session,
+    req: pb::IpcRequest,
+) -> Option<pb::ipc_response::Payload> {
+    use pb::ipc_request::Payload as Req;
+    use pb::ipc_response::Payload as Resp;
+
+    let required = match &req.payload {
+        Some(Req::Assets(_)) => Some(FeatureFlag::AssetsBasic),
+        Some(Req::Prefab(_)) => Some(FeatureFlag::PrefabsBasic),
+        Some(Req::Build(_)) => Some(FeatureFlag::BuildMin),
+        Some(Req::Component(_)) => Some(FeatureFlag::ComponentsBasic),
+        _ => None,
+    };
+    if required.is_some_and(|f| !session.features.contains(&f)) {
+        return None;
+    }
+
+    let payload = match req.payload? {
+        Req::Health(_) => Resp::Health(shared.project().health()),
+        Req::GetPlayMode(_) => Resp::GetPlayMode(pb::GetPlayModeResponse {
+            is_playing: shared.project().is_playing,
+        }),
+        Req::SetPlayMode(r) => Resp::SetPlayMode(shared.project().set_play_mode(r.play)),
+        Req::GetCompileDiagnostics(r) => {
+            Resp::GetCompileDiagnostics(shared.project().get_compile_diagnostics(&r))
+        }
+        Req::GetProjectSettings(r) => {
+            Resp::GetProjectSettings(shared.project().get_project_settings(&r))
+        }
+        Req::SetProjectSettings(r) => {
+            Resp::SetProjectSettings(shared.project().set_project_settings(r))
+        }
+        Req::Scenes(r) => Resp::Scenes(shared.project().scenes_request(r)),
+        Req::ExecuteMenuItem(r) => {
+            Resp::ExecuteMenuItem(shared.project().execute_menu_item(&r.path))
+        }
+        Req::FocusWindow(r) => Resp::FocusWindow(shared.project().focus_window(&r.window_type)),
+        Req::Assets(pb::AssetsRequest {
+            payload: Some(pb::assets_request::Payload::Import(r)),
+        }) => Resp::Assets(import(shared, r).await),
+        Req::Assets(r) => Resp::Assets(shared.project().assets(r)),
+        Req::Prefab(r) => Resp::Prefab(shared.project().prefab_request(r)),
+        Req::Component(r) => Resp::Component(shared.project().component_request(r)),
+        Req::Build(r) => Resp::Build(pb::BuildResponse {
+            payload: match r.payload {
+                Some(pb::build_request::Payload::Player(r)) => Some(
+                    pb::build_response::Payload::Player(build_player(shared, r).await),
+                ),
+                Some(pb::build_request::Payload::Bundles(r)) => Some(
+                    pb::build_response::Payload::Bundles(build_bundles(shared, r).await),
+                ),
+                None => None,
+            },
+        }),
+        Req::OperationGet(r) => Resp::OperationGet(shared.ops.get(&r.id)),
+        Req::OperationCancel(r) => Resp::OperationCancel(pb::OperationCancelResponse {
+            accepted: shared.ops.cancel(&r.id),
+        }),
+    };
+    Some(payload)
+}
+
+/// `AssetsHandler` import: cancellable, one `op_step` per path.
+async fn import(shared: &Shared, req: pb::ImportAssetRequest) -> pb::AssetsResponse {
+    let total = req.paths.len();
+    let op = shared
+        .ops
+        .start("Import", &format!("Import {total} items"), true, &req.op_id);
+    let mut results = Vec::with_capacity(total);
+    for (done, path) in req.paths.iter().enumerate() {
+        tokio::time::sleep(shared.options.op_step).await;
+        if shared.ops.is_cancel_requested(&op) {
+            shared.ops.complete(&op, 1, "cancelled");
+            return pb::AssetsResponse {
+                status_code: 1,
+                message: "cancelled".to_string(),
+                payload: Some(pb::assets_response::Payload::Import(
+                    pb::ImportAssetResponse { results, op_id: op },
+                )),
+            };
+        }
+        let result = shared.project().import(path);
+        if result.ok {
+            let pct = 100 * (done + 1) / total.max(1);
+            shared.ops.progress(&op, pct as i32, "");
+        }
+        results.push(result);
+    }
+    shared.ops.complete(&op, 0, "OK");
+    pb::AssetsResponse {
+        status_code: 0,
+        message: String::new(),
+        payload: Some(pb::assets_response::Payload::Import(
+            pb::ImportAssetResponse { results, op_id: op },
+        )),
+    }
+}
+
+async fn run_stages(shared: &Shared, op: &str, stages: &[&str]) {
+    for (i, stage) in stages.iter().enumerate() {
+        shared
+            .ops
+            .progress(op, (100 * i / stages.len()) as i32, stage);
+        shared.log(pb::log_event::Level::Info, "Build", stage);
+        tokio::time::sleep(shared.options.op_step).await;
+    }
+}
+
+async fn build_player(shared: &Shared, req: pb::BuildPlayerRequest) -> pb::BuildPlayerResponse {
+    let plan = match shared.project().plan_player_build(&req) {
+        Ok(plan) => plan,
+        Err(resp) => return resp,
+    };
+    let op = shared.ops.start(
+        "BuildPlayer",
+        &format!("{} -> {}", plan.target, plan.output_path),
+        true,
+        &req.op_id,
+    );
+    let started = Instant::now();
+    shared.ops.progress(&op, 0, "Switching platform");
+    tokio::time::sleep(shared.options.op_step).await;
+    if !shared.ops.enter_uncancellable(&op) {
+        shared.ops.complete(&op, 1, "cancelled");
+        return pb::BuildPlayerResponse {
+            status_code: 1,
+            message: "cancelled".to_string(),
+            output_path: plan.output_path,
+            build_time_ms: started.elapsed().as_millis() as u64,
+            op_id: op,
+            ..Default::default()
+        };
+    }
+    run_stages(shared, &op, PLAYER_STAGES).await;
+
+    let ok = plan.compile_errors == 0;
+    let result = if ok { "Succeeded" } else { "Failed" };
+    let level = match ok {
+        true => pb::log_event::Level::Info,
+        false => pb::log_event::Level::Error,
+    };
+    shared.log(
+        level,
+        "Build",
+        &format!("Build completed with a result of '{result}'"),
+    );
+    shared.ops.complete(&op, if ok { 0 } else { 13 }, result);
+    if !ok {
+        return pb::BuildPlayerResponse {
+            status_code: 13,
+            message: result.to_string(),
+            output_path: plan.output_path,
+            build_time_ms: started.elapsed().as_millis() as u64,
+            op_id: op,
+            ..Default::default()
+        };
+    }
+    shared.project().builds.push(BuildRecord {
+        target: plan.target.to_string(),
+        output_path: plan.output_path.clone(),
+        scenes: plan.scenes,
+        size_bytes: plan.size_bytes,
+    });
+    pb::BuildPlayerResponse {
+        status_code: 0,
+        message: "OK".to_string(),
+        output_path: plan.output_path,
+        build_time_ms: started.elapsed().as_millis() as u64,
+        size_bytes: plan.size_bytes,
+        warnings: plan.warnings,
+        op_id: op,
+    }
+}
+
+async fn build_bundles(
+    shared: &Shared,
+    req: pb::BuildAssetBundlesRequest,
+) -> pb::BuildAssetBundlesResponse {
+    let out_dir = match shared
+        .project()
+        .resolve_bundles_output(&req.output_directory)
+    {
+        Ok(dir) => dir,
+        Err(message) => {
+            return pb::BuildAssetBundlesResponse {
+                status_code: 7,
+                message,
+                ..Default::default()
+            };
+        }
+    };
+    let op = shared
+        .ops
+        .start("BuildBundles", &out_dir, false, &req.op_id);
+    let started = Instant::now();
+    run_stages(shared, &op, BUNDLE_STAGES).await;
+    shared.ops.complete(&op, 0, "OK");
+    pb::BuildAssetBundlesResponse {
+        status_code: 0,
+        message: "OK".to_string(),
+        output_directory: out_dir,
+        build_time_ms: started.elapsed().as_millis() as u64,
+        op_id: op,
+    }
+}
@@ -0,0 +1,182 @@
+//! Operation tracking for long-running simulator requests (C# `OperationTracker`).
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tokio::sync::broadcast;
+
+use crate::generated::mcp::unity::v1 as pb;
+
+/// Finished operations kept for `OperationGet` before the oldest are pruned.
+const MAX_FINISHED: usize = 256;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    kind: &'static str,
+    state: &'static str, // running | completed | failed | cancelled
+    message: String,
+    cancellable: bool,
+    cancel_requested: bool,
+    seq: u64,
+}
+
+/// Operations of one simulated editor; every change is published as an `OperationEvent`.
+pub struct Operations {
+    entries: Mutex<(u64, HashMap<String, Entry>)>,
+    events: broadcast::Sender<pb::IpcEvent>,
+}
+
+impl Operations {
+    pub fn new(events: broadcast::Sender<pb::IpcEvent>) -> Self {
+        Self {
+            entries: Mutex::new((0, HashMap::new())),
+            events,
+        }
+    }
+
+    /// Start an operation under `requested_id` (the request's `op_id`), or a new id when
+    /// that is empty or already used.
+    pub fn start(
+        &self,
+        kind: &'static str,
+        message: &str,
+        cancellable: bool,
+        requested_id: &str,
+    ) -> String {
+        let id = {
+            let mut guard = self.entries.lock().unwrap();
+            let (seq, entries) = &mut *guard;
+            *seq += 1;
+            let id = if requested_id.is_empty() || entries.contains_key(requested_id) {
+                uuid::Uuid::new_v4().simple().to_string()
+            } else {
+                requested_id.to_string()
+            };
+            entries.insert(
+                id.clone(),
+                Entry {
+                    kind,
+                    state: "running",
+                    message: message.to_string(),
+                    cancellable,
+                    cancel_requested: false,
+                    seq: *seq,
+                },
+            );
+            id
+        };
+        self.publish(&id, pb::operation_event::Kind::Start, 0, 0, message);
+        id
+    }
+
+    pub fn progress(&self, id: &str, progress: i32, message: &str) {
+        self.publish(
+            id,
+            pb::operation_event::Kind::Progress,
+            progress,
+            0,
+            message,
+        );
+    }
+
+    pub fn complete(&self, id: &str, code: i32, message: &str) {
+        {
+            let mut guard = self.entries.lock().unwrap();
+            let (_, entries) = &mut *guard;
+            if let Some(entry) = entries.get_mut(id) {
+                entry.state = match (entry.cancel_requested, code) {
+                    (true, _) => "cancelled",
+                    (false, 0) => "completed",
+                    (false, _) => "failed",
+                };
+                entry.message = message.to_string();
+            }
+            prune(entries);
+        }
+        self.publish(id, pb::operation_event::Kind::Complete, 100, code, message);
+    }
+
+    /// Snapshot for `OperationGet`; unknown ids report state "unknown".
+    pub fn get(&self, id: &str) -> pb::OperationGetResponse {
+        let guard = self.entries.lock().unwrap();
+        match guard.1.get(id) {
+            Some(entry) => pb::OperationGetResponse {
+                id: id.to_string(),
+                state: entry.state.to_string(),
+                message: format!("{}: {}", entry.kind, entry.message),
+            },
+            None => pb::OperationGetResponse {
+                id: id.to_string(),
+                state: "unknown".to_string(),
+                message: "operation not found".to_string(),
+            },
+        }
+    }
+
+    /// Request cooperative cancellation; only running, cancellable operations accept it.
+    pub fn cancel(&self, id: &str) -> bool {
+        let mut guard = self.entries.lock().unwrap();
+        match guard.1.get_mut(id) {
+            Some(entry) if entry.state == "running" && entry.cancellable => {
+                entry.cancel_requested = true;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Enter the part of an operation that cannot be interrupted; `false` when cancellation
+    /// was already requested. Later `cancel` calls are refused.
+    pub fn enter_uncancellable(&self, id: &str) -> bool {
+        let mut guard = self.entries.lock().unwrap();
+        match guard.1.get_mut(id) {
+            Some(entry) if entry.cancel_requested => false,
+            Some(entry) => {
+                entry.cancellable = false;
+                true
+            }
+            None => true,
+        }
+    }
+
+    pub fn is_cancel_requested(&self, id: &str) -> bool {
+        let guard = self.entries.lock().unwrap();
+        guard.1.get(id).is_some_and(|e| e.cancel_requested)
+    }
+
+    fn publish(
+        &self,
+        id: &str,
+        kind: pb::operation_event::Kind,
+        progress: i32,
+        code: i32,
+        message: &str,
+    ) {
+        let _ = self.events.send(pb::IpcEvent {
+            monotonic_ts_ns: super::monotonic_ns(),
+            payload: Some(pb::ipc_event::Payload::Op(pb::OperationEvent {
+                op_id: id.to_string(),
+                kind: kind as i32,
+                progress,
+                code,
+                message: message.to_string(),
+                payload_json: String::new(),
+            })),
+        });
+    }
+}
+
+fn prune(entries: &mut HashMap<String, Entry>) {
+    let mut finished: Vec<(u64, String)> = entries
+        .iter()
+        .filter(|(_, e)| e.state != "running")
+        .map(|(id, e)| (e.seq, id.clone()))
+        .collect();
+    if finished.len() <= MAX_FINISHED {
+        return;
+    }
+    finished.sort();
+    for (_, id) in &finished[..finished.len() - MAX_FINISHED] {
+        entries.remove(id);
+    }
+}
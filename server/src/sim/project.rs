@@ -0,0 +1,1205 @@
+//! In-memory Unity project the simulator edits.
+//!
+//! AssetDatabase（パス ↔ GUID）、シーンと GameObject、Prefab、Project Settings、コンパイル診断を持つ。
+//! 検証とステータスコードは Bridge の C# ハンドラ（`AssetsHandler`、`SceneHandler` など）に合わせる。
+//! ディスクには何も書かない。
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::generated::mcp::unity::v1 as pb;
+use crate::ipc::client::status;
+
+/// Editor version reported by default.
+pub const UNITY_VERSION: &str = "6000.0.23f1";
+
+/// `Application.platform` of the simulated editor.
+pub const PLATFORM: &str = "LinuxEditor";
+
+/// Built-in component types, without the `UnityEngine.` namespace.
+const BUILTIN_COMPONENTS: &[&str] = &[
+    "Transform",
+    "RectTransform",
+    "Camera",
+    "Light",
+    "AudioListener",
+    "AudioSource",
+    "MeshFilter",
+    "MeshRenderer",
+    "SkinnedMeshRenderer",
+    "SpriteRenderer",
+    "Rigidbody",
+    "Rigidbody2D",
+    "BoxCollider",
+    "SphereCollider",
+    "CapsuleCollider",
+    "MeshCollider",
+    "CharacterController",
+    "Animator",
+    "ParticleSystem",
+    "Canvas",
+];
+
+/// Components marked `[DisallowMultipleComponent]` (or unique by nature).
+const SINGLE_COMPONENTS: &[&str] = &[
+    "Transform",
+    "RectTransform",
+    "Camera",
+    "AudioListener",
+    "Rigidbody",
+    "Rigidbody2D",
+    "CharacterController",
+    "Animator",
+    "Canvas",
+];
+
+/// Windows `FocusWindow` knows about.
+const WINDOW_TYPES: &[&str] = &[
+    "UnityEditor.SceneView",
+    "UnityEditor.GameView",
+    "UnityEditor.InspectorWindow",
+    "UnityEditor.SceneHierarchyWindow",
+    "UnityEditor.ProjectBrowser",
+    "UnityEditor.ConsoleWindow",
+];
+
+/// Menu items every simulated editor has; see [`Project::execute_menu_item`] for their effects.
+const BUILTIN_MENU_ITEMS: &[&str] = &[
+    "Assets/Refresh",
+    "Edit/Play",
+    "Edit/Pause",
+    "File/Save",
+    "File/Save Project",
+    "GameObject/Create Empty",
+    "Window/General/Console",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    Folder,
+    Scene,
+    Prefab,
+    Script,
+    Material,
+    Texture,
+    Other,
+}
+
+impl AssetKind {
+    pub fn from_path(path: &str) -> Self {
+        let file = path.rsplit('/').next().unwrap_or(path);
+        match file
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+        {
+            None => Self::Folder,
+            Some(ext) => match ext.as_str() {
+                "unity" => Self::Scene,
+                "prefab" => Self::Prefab,
+                "cs" => Self::Script,
+                "mat" => Self::Material,
+                "png" | "jpg" | "jpeg" | "tga" | "psd" => Self::Texture,
+                _ => Self::Other,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    pub guid: String,
+    pub kind: AssetKind,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameObject {
+    /// Full type names in the order they were added
+    pub components: Vec<String>,
+    /// Prefab asset this object is an instance of
+    pub prefab: Option<String>,
+}
+
+/// GameObjects of a scene keyed by hierarchy path, e.g. `Environment/Ground`.
+pub type Scene = BTreeMap<String, GameObject>;
+
+/// A finished fake build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildRecord {
+    pub target: String,
+    pub output_path: String,
+    pub scenes: Vec<String>,
+    pub size_bytes: u64,
+}
+
+/// A player build that passed validation and can be run.
+#[derive(Debug, Clone)]
+pub struct PlayerBuild {
+    pub target: &'static str,
+    pub output_path: String,
+    pub scenes: Vec<String>,
+    pub size_bytes: u64,
+    /// Compile errors make the build fail like in Unity
+    pub compile_errors: usize,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub name: String,
+    /// Absolute project root reported by Health; used to resolve build output paths
+    pub path: String,
+    pub unity_version: String,
+    pub assets: BTreeMap<String, Asset>,
+    /// Contents of every scene asset
+    pub scenes: HashMap<String, Scene>,
+    /// Components of every prefab asset
+    pub prefabs: HashMap<String, Vec<String>>,
+    pub open_scenes: Vec<String>,
+    pub active_scene: String,
+    /// `EditorBuildSettings.scenes`, used when a build names no scenes
+    pub build_scenes: Vec<String>,
+    pub settings: BTreeMap<String, String>,
+    pub is_playing: bool,
+    pub is_paused: bool,
+    pub menu_items: BTreeSet<String>,
+    pub focused_window: Option<String>,
+    pub diagnostics: Vec<pb::CompileDiagnostic>,
+    pub builds: Vec<BuildRecord>,
+    compile_id: u64,
+    reported: HashSet<String>,
+}
+
+fn objects(entries: &[(&str, &[&str])]) -> Scene {
+    entries
+        .iter()
+        .map(|(path, components)| {
+            let components = components.iter().map(|c| qualified(c)).collect();
+            (
+                path.to_string(),
+                GameObject {
+                    components,
+                    prefab: None,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Full type name of a built-in or script component.
+fn qualified(name: &str) -> String {
+    if BUILTIN_COMPONENTS.contains(&name) {
+        format!("UnityEngine.{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn new_guid() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+impl Project {
+    /// A project with nothing but the `Assets` folder.
+    pub fn empty(name: &str, path: &str) -> Self {
+        let mut project = Self {
+            name: name.to_string(),
+            path: path.trim_end_matches('/').to_string(),
+            unity_version: UNITY_VERSION.to_string(),
+            assets: BTreeMap::new(),
+            scenes: HashMap::new(),
+            prefabs: HashMap::new(),
+            open_scenes: Vec::new(),
+            active_scene: String::new(),
+            build_scenes: Vec::new(),
+            settings: BTreeMap::from([
+                ("companyName".to_string(), "DefaultCompany".to_string()),
+                ("productName".to_string(), name.to_string()),
+            ]),
+            is_playing: false,
+            is_paused: false,
+            menu_items: BUILTIN_MENU_ITEMS.iter().map(|m| m.to_string()).collect(),
+            focused_window: None,
+            diagnostics: Vec::new(),
+            builds: Vec::new(),
+            compile_id: 1,
+            reported: HashSet::new(),
+        };
+        project.add_asset("Assets");
+        project
+    }
+
+    /// A small 3D game: two scenes, a player, an enemy prefab and one compiler warning.
+    pub fn sample(name: &str, path: &str) -> Self {
+        let mut project = Self::empty(name, path);
+        for asset in [
+            "Assets/Scenes/Main.unity",
+            "Assets/Scenes/Level1.unity",
+            "Assets/Scripts/Player.cs",
+            "Assets/Scripts/Enemy.cs",
+            "Assets/Prefabs/Enemy.prefab",
+            "Assets/Materials/Ground.mat",
+            "Assets/Textures/Grass.png",
+        ] {
+            project.add_asset(asset);
+        }
+        let enemy = ["Transform", "MeshFilter", "MeshRenderer", "Enemy"];
+        project.prefabs.insert(
+            "Assets/Prefabs/Enemy.prefab".to_string(),
+            enemy.iter().map(|c| qualified(c)).collect(),
+        );
+
+        let mut main = objects(&[
+            ("Main Camera", &["Transform", "Camera", "AudioListener"]),
+            ("Directional Light", &["Transform", "Light"]),
+            (
+                "Player",
+                &[
+                    "Transform",
+                    "MeshFilter",
+                    "MeshRenderer",
+                    "CapsuleCollider",
+                    "Player",
+                ],
+            ),
+            ("Environment", &["Transform"]),
+            (
+                "Environment/Ground",
+                &["Transform", "MeshFilter", "MeshRenderer", "BoxCollider"],
+            ),
+            ("Enemy", &enemy),
+        ]);
+        main.get_mut("Enemy").unwrap().prefab = Some("Assets/Prefabs/Enemy.prefab".to_string());
+        project
+            .scenes
+            .insert("Assets/Scenes/Main.unity".to_string(), main);
+        project.scenes.insert(
+            "Assets/Scenes/Level1.unity".to_string(),
+            objects(&[
+                ("Main Camera", &["Transform", "Camera", "AudioListener"]),
+                ("Directional Light", &["Transform", "Light"]),
+            ]),
+        );
+        project.open_scenes = vec!["Assets/Scenes/Main.unity".to_string()];
+        project.active_scene = "Assets/Scenes/Main.unity".to_string();
+        project.build_scenes = vec![
+            "Assets/Scenes/Main.unity".to_string(),
+            "Assets/Scenes/Level1.unity".to_string(),
+        ];
+        project.add_diagnostic(
+            "Assets/Scripts/Player.cs",
+            (12, 17),
+            "warning",
+            "CS0414",
+            "The field 'Player.speed' is assigned but its value is never used",
+        );
+        project
+    }
+
+    /// Register `path` (and its parent folders) in the AssetDatabase; returns its GUID.
+    pub fn add_asset(&mut self, path: &str) -> String {
+        let mut parent = String::new();
+        for segment in path.split('/') {
+            if !parent.is_empty() {
+                parent.push('/');
+            }
+            parent.push_str(segment);
+            if parent.len() < path.len() {
+                self.assets.entry(parent.clone()).or_insert_with(|| Asset {
+                    guid: new_guid(),
+                    kind: AssetKind::Folder,
+                });
+            }
+        }
+        let kind = AssetKind::from_path(path);
+        let asset = self
+            .assets
+            .entry(path.to_string())
+            .or_insert_with(|| Asset {
+                guid: new_guid(),
+                kind,
+            });
+        if kind == AssetKind::Scene {
+            self.scenes.entry(path.to_string()).or_default();
+        }
+        asset.guid.clone()
+    }
+
+    pub fn guid(&self, path: &str) -> Option<&str> {
+        self.assets.get(path).map(|a| a.guid.as_str())
+    }
+
+    /// Add a compile diagnostic (line and column are 0-based).
+    pub fn add_diagnostic(
+        &mut self,
+        file: &str,
+        (line, column): (u32, u32),
+        severity: &str,
+        code: &str,
+        message: &str,
+    ) {
+        let now = chrono::Utc::now().to_rfc3339();
+        self.diagnostics.push(pb::CompileDiagnostic {
+            file_uri: format!("file://{}/{}", self.path, file),
+            range: Some(pb::DiagnosticRange { line, column }),
+            severity: severity.to_string(),
+            message: message.to_string(),
+            code: code.to_string(),
+            assembly: "Assembly-CSharp".to_string(),
+            source: "Unity".to_string(),
+            fingerprint: format!("{code}:{file}:{line}:{column}"),
+            first_seen: now.clone(),
+            last_seen: now,
+        });
+        self.compile_id += 1;
+    }
+
+    pub fn health(&self) -> pb::HealthResponse {
+        pb::HealthResponse {
+            ready: true,
+            version: self.unity_version.clone(),
+            status: "OK".to_string(),
+            project_name: self.name.clone(),
+            project_path: self.path.clone(),
+        }
+    }
+
+    pub fn set_play_mode(&mut self, play: bool) -> pb::SetPlayModeResponse {
+        self.is_playing = play;
+        if !play {
+            self.is_paused = false;
+        }
+        pb::SetPlayModeResponse { applied: true }
+    }
+
+    pub fn get_project_settings(
+        &self,
+        req: &pb::GetProjectSettingsRequest,
+    ) -> pb::GetProjectSettingsResponse {
+        // Unknown keys are left out, as in the bridge
+        let settings = req
+            .keys
+            .iter()
+            .filter_map(|k| self.settings.get(k).map(|v| (k.clone(), v.clone())))
+            .collect();
+        pb::GetProjectSettingsResponse {
+            settings,
+            success: true,
+            error_message: String::new(),
+        }
+    }
+
+    pub fn set_project_settings(
+        &mut self,
+        req: pb::SetProjectSettingsRequest,
+    ) -> pb::SetProjectSettingsResponse {
+        self.settings.extend(req.settings);
+        pb::SetProjectSettingsResponse {
+            ok: true,
+            error_message: String::new(),
+        }
+    }
+
+    pub fn get_compile_diagnostics(
+        &mut self,
+        req: &pb::GetCompileDiagnosticsRequest,
+    ) -> pb::GetCompileDiagnosticsResponse {
+        let severity = match req.severity.as_str() {
+            "" => "all",
+            s => s,
+        };
+        let max_items = match req.max_items {
+            0 => 2000,
+            n => n as usize,
+        };
+        let mut matching: Vec<pb::CompileDiagnostic> = self
+            .diagnostics
+            .iter()
+            .filter(|d| severity == "all" || d.severity == severity)
+            .filter(|d| req.assembly.is_empty() || d.assembly == req.assembly)
+            .filter(|d| !req.changed_only || !self.reported.contains(&d.fingerprint))
+            .cloned()
+            .collect();
+        let truncated = matching.len() > max_items;
+        matching.truncate(max_items);
+        self.reported
+            .extend(matching.iter().map(|d| d.fingerprint.clone()));
+
+        let count = |s: &str| self.diagnostics.iter().filter(|d| d.severity == s).count() as u32;
+        let assemblies: BTreeSet<String> = self
+            .diagnostics
+            .iter()
+            .map(|d| d.assembly.clone())
+            .collect();
+        pb::GetCompileDiagnosticsResponse {
+            success: true,
+            error_message: String::new(),
+            compile_id: format!("sim-{}", self.compile_id),
+            summary: Some(pb::DiagnosticSummary {
+                errors: count("error"),
+                warnings: count("warning"),
+                infos: count("info"),
+                assemblies: assemblies.into_iter().collect(),
+            }),
+            diagnostics: matching,
+            truncated,
+        }
+    }
+
+    /// Run a menu item. Besides `menu_items` being known, a few have effects:
+    /// `Edit/Play` and `Edit/Pause` toggle play mode, `GameObject/Create Empty` adds an
+    /// object to the active scene and `Window/General/Console` focuses the console.
+    pub fn execute_menu_item(&mut self, path: &str) -> pb::ExecuteMenuItemResponse {
+        if !self.menu_items.contains(path) {
+            return pb::ExecuteMenuItemResponse {
+                ok: false,
+                message: "Menu item failed or not found".to_string(),
+            };
+        }
+        match path {
+            "Edit/Play" => {
+                self.set_play_mode(!self.is_playing);
+            }
+            "Edit/Pause" => self.is_paused = !self.is_paused,
+            "GameObject/Create Empty" => {
+                if let Some(scene) = self.scenes.get_mut(&self.active_scene) {
+                    let name = (0..)
+                        .map(|i| match i {
+                            0 => "GameObject".to_string(),
+                            i => format!("GameObject ({i})"),
+                        })
+                        .find(|n| !scene.contains_key(n))
+                        .unwrap();
+                    scene.insert(
+                        name,
+                        GameObject {
+                            components: vec![qualified("Transform")],
+                            prefab: None,
+                        },
+                    );
+                }
+            }
+            "Window/General/Console" => {
+                self.focused_window = Some("UnityEditor.ConsoleWindow".to_string());
+            }
+            _ => {}
+        }
+        pb::ExecuteMenuItemResponse {
+            ok: true,
+            message: String::new(),
+        }
+    }
+
+    pub fn focus_window(&mut self, window_type: &str) -> pb::FocusWindowResponse {
+        let ok = WINDOW_TYPES.contains(&window_type);
+        if ok {
+            self.focused_window = Some(window_type.to_string());
+        }
+        pb::FocusWindowResponse { ok }
+    }
+
+    // ---- Assets ----
+
+    /// Import one path. Paths unknown to the AssetDatabase are treated as new files.
+    pub fn import(&mut self, path: &str) -> pb::ImportAssetResult {
+        if !is_valid_asset_path(path) {
+            return pb::ImportAssetResult {
+                path: path.to_string(),
+                ok: false,
+                message: "invalid path".to_string(),
+                ..Default::default()
+            };
+        }
+        pb::ImportAssetResult {
+            path: path.to_string(),
+            guid: self.add_asset(path),
+            ok: true,
+            message: String::new(),
+        }
+    }
+
+    /// Every request but `Import`, which runs as an operation (see [`super::bridge`]).
+    pub fn assets(&mut self, req: pb::AssetsRequest) -> pb::AssetsResponse {
+        use pb::assets_request::Payload as Req;
+        use pb::assets_response::Payload as Resp;
+
+        let payload = match req.payload {
+            Some(Req::Move(r)) => match self.move_asset(&r.from_path, &r.to_path) {
+                Ok(guid) => Resp::Move(pb::MoveAssetResponse {
+                    ok: true,
+                    message: String::new(),
+                    new_guid: guid,
+                }),
+                Err((code, message)) => return assets_error(code, message),
+            },
+            Some(Req::Delete(r)) => {
+                let (mut deleted, mut failed) = (Vec::new(), Vec::new());
+                for path in r.paths {
+                    if is_valid_asset_path(&path) && self.delete_asset(&path) {
+                        deleted.push(path);
+                    } else {
+                        failed.push(path);
+                    }
+                }
+                Resp::Delete(pb::DeleteAssetResponse { deleted, failed })
+            }
+            Some(Req::Refresh(_)) => Resp::Refresh(pb::RefreshResponse { ok: true }),
+            Some(Req::G2p(r)) => {
+                let map = r
+                    .guids
+                    .into_iter()
+                    .map(|g| {
+                        let path = self
+                            .assets
+                            .iter()
+                            .find(|(_, a)| a.guid == g)
+                            .map(|(p, _)| p.clone())
+                            .unwrap_or_default();
+                        (g, path)
+                    })
+                    .collect();
+                Resp::G2p(pb::GuidToPathResponse { map })
+            }
+            Some(Req::P2g(r)) => {
+                let map = r
+                    .paths
+                    .into_iter()
+                    .map(|p| {
+                        let guid = self.guid(&p).unwrap_or_default().to_string();
+                        (p, guid)
+                    })
+                    .collect();
+                Resp::P2g(pb::PathToGuidResponse { map })
+            }
+            Some(Req::Import(_)) | None => {
+                return assets_error(status::INVALID_ARGUMENT, "invalid request".into());
+            }
+        };
+        pb::AssetsResponse {
+            status_code: status::OK,
+            message: String::new(),
+            payload: Some(payload),
+        }
+    }
+
+    fn move_asset(&mut self, from: &str, to: &str) -> Result<String, (i32, String)> {
+        if !is_valid_asset_path(from) || !is_valid_asset_path(to) {
+            return Err((status::INVALID_ARGUMENT, "invalid path".into()));
+        }
+        let Some(asset) = self.assets.get(from) else {
+            return Err((
+                status::INTERNAL,
+                format!("Source asset '{from}' does not exist"),
+            ));
+        };
+        if self.assets.contains_key(to) {
+            return Err((
+                status::INTERNAL,
+                format!("Destination path name '{to}' does already exist"),
+            ));
+        }
+        let guid = asset.guid.clone();
+        // Folders move with their contents; GUIDs stay the same
+        let moved: Vec<String> = self
+            .assets
+            .keys()
+            .filter(|p| *p == from || p.starts_with(&format!("{from}/")))
+            .cloned()
+            .collect();
+        for old in moved {
+            let new = format!("{to}{}", &old[from.len()..]);
+            self.rename(&old, &new);
+        }
+        self.add_asset(to);
+        Ok(guid)
+    }
+
+    /// Move one asset and update everything that refers to it by path.
+    fn rename(&mut self, old: &str, new: &str) {
+        if let Some(asset) = self.assets.remove(old) {
+            self.assets.insert(new.to_string(), asset);
+        }
+        if let Some(scene) = self.scenes.remove(old) {
+            self.scenes.insert(new.to_string(), scene);
+        }
+        if let Some(prefab) = self.prefabs.remove(old) {
+            self.prefabs.insert(new.to_string(), prefab);
+        }
+        for path in self
+            .open_scenes
+            .iter_mut()
+            .chain(self.build_scenes.iter_mut())
+            .chain(std::iter::once(&mut self.active_scene))
+        {
+            if path == old {
+                *path = new.to_string();
+            }
+        }
+        for object in self.scenes.values_mut().flat_map(|s| s.values_mut()) {
+            if object.prefab.as_deref() == Some(old) {
+                object.prefab = Some(new.to_string());
+            }
+        }
+    }
+
+    fn delete_asset(&mut self, path: &str) -> bool {
+        if !self.assets.contains_key(path) {
+            return false;
+        }
+        let prefix = format!("{path}/");
+        self.assets
+            .retain(|p, _| p != path && !p.starts_with(&prefix));
+        self.scenes
+            .retain(|p, _| p != path && !p.starts_with(&prefix));
+        self.prefabs
+            .retain(|p, _| p != path && !p.starts_with(&prefix));
+        self.build_scenes
+            .retain(|p| p != path && !p.starts_with(&prefix));
+        true
+    }
+
+    // ---- Scenes ----
+
+    pub fn scenes_request(&mut self, req: pb::ScenesRequest) -> pb::ScenesResponse {
+        use pb::scenes_request::Payload as Req;
+        use pb::scenes_response::Payload as Resp;
+
+        let path = match &req.payload {
+            Some(Req::Open(r)) => Some(&r.path),
+            Some(Req::Save(r)) => Some(&r.path),
+            Some(Req::SetActive(r)) => Some(&r.path),
+            _ => None,
+        };
+        if path.is_some_and(|p| !is_valid_scene_path(p)) {
+            return scenes_error(status::INVALID_ARGUMENT, "invalid path".into());
+        }
+        let payload = match req.payload {
+            Some(Req::Open(r)) => {
+                if !self.scenes.contains_key(&r.path) {
+                    return scenes_error(
+                        status::INTERNAL,
+                        format!("Scene file not found: '{}'", r.path),
+                    );
+                }
+                if r.additive {
+                    if !self.open_scenes.contains(&r.path) {
+                        self.open_scenes.push(r.path);
+                    }
+                } else {
+                    self.open_scenes = vec![r.path.clone()];
+                    self.active_scene = r.path;
+                }
+                Resp::Open(pb::OpenSceneResponse { ok: true })
+            }
+            Some(Req::Save(r)) => {
+                if !self.open_scenes.contains(&r.path) {
+                    // Saving under a new path renames the active scene
+                    let contents = self
+                        .scenes
+                        .get(&self.active_scene)
+                        .cloned()
+                        .unwrap_or_default();
+                    self.add_asset(&r.path);
+                    self.scenes.insert(r.path.clone(), contents);
+                    let active = std::mem::replace(&mut self.active_scene, r.path.clone());
+                    for open in self.open_scenes.iter_mut().filter(|p| **p == active) {
+                        *open = r.path.clone();
+                    }
+                }
+                Resp::Save(pb::SaveSceneResponse { ok: true })
+            }
+            Some(Req::GetOpen(_)) => Resp::GetOpen(pb::GetOpenScenesResponse {
+                scenes: self.open_scenes.clone(),
+                active_scene: self.active_scene.clone(),
+            }),
+            Some(Req::SetActive(r)) => {
+                if !self.open_scenes.contains(&r.path) {
+                    return pb::ScenesResponse {
+                        status_code: status::INTERNAL,
+                        message: format!("Scene '{}' is not loaded", r.path),
+                        payload: Some(Resp::SetActive(pb::SetActiveSceneResponse { ok: false })),
+                    };
+                }
+                self.active_scene = r.path;
+                Resp::SetActive(pb::SetActiveSceneResponse { ok: true })
+            }
+            None => return scenes_error(status::INVALID_ARGUMENT, "invalid request".into()),
+        };
+        pb::ScenesResponse {
+            status_code: status::OK,
+            message: String::new(),
+            payload: Some(payload),
+        }
+    }
+
+    // ---- GameObjects ----
+
+    /// Find an object in the open scenes by hierarchy path or name (`GameObject.Find`).
+    pub fn find_object(&self, name: &str) -> Option<(String, String)> {
+        let name = name.trim_start_matches('/');
+        let mut by_name = None;
+        for scene_path in &self.open_scenes {
+            let Some(scene) = self.scenes.get(scene_path) else {
+                continue;
+            };
+            if scene.contains_key(name) {
+                return Some((scene_path.clone(), name.to_string()));
+            }
+            if by_name.is_none() {
+                by_name = scene
+                    .keys()
+                    .find(|p| p.rsplit('/').next() == Some(name))
+                    .map(|p| (scene_path.clone(), p.clone()));
+            }
+        }
+        by_name
+    }
+
+    fn object_mut(&mut self, name: &str) -> Option<&mut GameObject> {
+        let (scene, path) = self.find_object(name)?;
+        self.scenes.get_mut(&scene)?.get_mut(&path)
+    }
+
+    /// Full type name of a component, or `None` if no such component type exists.
+    pub fn component_type(&self, name: &str) -> Option<String> {
+        let short = name.strip_prefix("UnityEngine.").unwrap_or(name);
+        if BUILTIN_COMPONENTS.contains(&short) {
+            return Some(qualified(short));
+        }
+        let is_script = self.assets.iter().any(|(path, asset)| {
+            asset.kind == AssetKind::Script
+                && path.rsplit('/').next().and_then(|f| f.strip_suffix(".cs")) == Some(name)
+        });
+        is_script.then(|| name.to_string())
+    }
+
+    pub fn component_request(&mut self, req: pb::ComponentRequest) -> pb::ComponentResponse {
+        use pb::component_request::Payload as Req;
+        use pb::component_response::Payload as Resp;
+
+        let payload = match req.payload {
+            Some(Req::Add(r)) => {
+                let Some(ty) = self.component_type(&r.component) else {
+                    return component_error(status::INVALID_ARGUMENT, "invalid component type");
+                };
+                let Some(object) = self.object_mut(&r.game_object) else {
+                    return component_error(status::NOT_FOUND, "game object not found");
+                };
+                let short = ty.strip_prefix("UnityEngine.").unwrap_or(&ty);
+                if SINGLE_COMPONENTS.contains(&short) && object.components.contains(&ty) {
+                    Resp::Add(pb::AddComponentResponse {
+                        ok: false,
+                        message: format!(
+                            "Can't add component '{short}' to {} because such a component is already added to the game object!",
+                            r.game_object
+                        ),
+                    })
+                } else {
+                    object.components.push(ty);
+                    Resp::Add(pb::AddComponentResponse {
+                        ok: true,
+                        message: String::new(),
+                    })
+                }
+            }
+            Some(Req::Get(r)) => {
+                let Some(object) = self.object_mut(&r.game_object) else {
+                    return component_error(status::NOT_FOUND, "game object not found");
+                };
+                Resp::Get(pb::GetComponentsResponse {
+                    components: object.components.clone(),
+                })
+            }
+            Some(Req::Remove(r)) => {
+                let Some(ty) = self.component_type(&r.component) else {
+                    return component_error(status::INVALID_ARGUMENT, "invalid component type");
+                };
+                let Some(object) = self.object_mut(&r.game_object) else {
+                    return component_error(status::NOT_FOUND, "game object not found");
+                };
+                let Some(index) = object.components.iter().position(|c| *c == ty) else {
+                    return component_error(status::NOT_FOUND, "component not found");
+                };
+                if ty == "UnityEngine.Transform" {
+                    return component_error(
+                        status::INTERNAL,
+                        "Can't destroy Transform component. Destroy the game object instead.",
+                    );
+                }
+                object.components.remove(index);
+                Resp::Remove(pb::RemoveComponentResponse {
+                    ok: true,
+                    message: String::new(),
+                })
+            }
+            None => return component_error(status::INVALID_ARGUMENT, "invalid request"),
+        };
+        pb::ComponentResponse {
+            status_code: status::OK,
+            message: String::new(),
+            payload: Some(payload),
+        }
+    }
+
+    // ---- Prefabs ----
+
+    pub fn prefab_request(&mut self, req: pb::PrefabRequest) -> pb::PrefabResponse {
+        use pb::prefab_request::Payload as Req;
+        use pb::prefab_response::Payload as Resp;
+
+        let payload = match req.payload {
+            Some(Req::Create(r)) => match self.save_prefab(&r.game_object_path, &r.prefab_path) {
+                Ok(guid) => Resp::Create(pb::CreatePrefabResponse {
+                    ok: true,
+                    guid,
+                    message: String::new(),
+                }),
+                Err((code, message)) => return prefab_error(code, message),
+            },
+            Some(Req::Update(r)) => match self.save_prefab(&r.game_object_path, &r.prefab_path) {
+                Ok(_) => Resp::Update(pb::UpdatePrefabResponse {
+                    ok: true,
+                    message: String::new(),
+                }),
+                Err((code, message)) => return prefab_error(code, message),
+            },
+            Some(Req::ApplyOverrides(r)) => {
+                let Some(object) = self.object_mut(&r.instance_path) else {
+                    return prefab_error(status::NOT_FOUND, "instance not found".into());
+                };
+                let Some(prefab) = object.prefab.clone() else {
+                    return prefab_error(
+                        status::INTERNAL,
+                        "Calling apply or revert methods on an object which is not part of a Prefab instance is not supported.".into(),
+                    );
+                };
+                let components = object.components.clone();
+                // Every instance picks up the applied overrides
+                for instance in self
+                    .scenes
+                    .values_mut()
+                    .flat_map(|s| s.values_mut())
+                    .filter(|o| o.prefab.as_ref() == Some(&prefab))
+                {
+                    instance.components = components.clone();
+                }
+                self.prefabs.insert(prefab, components);
+                Resp::ApplyOverrides(pb::ApplyPrefabOverridesResponse {
+                    ok: true,
+                    message: String::new(),
+                })
+            }
+            None => return prefab_error(status::INVALID_ARGUMENT, "invalid request".into()),
+        };
+        pb::PrefabResponse {
+            status_code: status::OK,
+            message: String::new(),
+            payload: Some(payload),
+        }
+    }
+
+    /// `PrefabUtility.SaveAsPrefabAsset`: the scene object does not become an instance.
+    fn save_prefab(&mut self, object: &str, prefab_path: &str) -> Result<String, (i32, String)> {
+        if !is_valid_asset_path(prefab_path) {
+            return Err((status::INVALID_ARGUMENT, "invalid path".into()));
+        }
+        let Some(components) = self.object_mut(object).map(|o| o.components.clone()) else {
+            return Err((status::NOT_FOUND, "game object not found".into()));
+        };
+        if AssetKind::from_path(prefab_path) != AssetKind::Prefab {
+            return Err((
+                status::INTERNAL,
+                format!("Can't save prefab to '{prefab_path}': the file extension must be .prefab"),
+            ));
+        }
+        let guid = self.add_asset(prefab_path);
+        self.prefabs.insert(prefab_path.to_string(), components);
+        Ok(guid)
+    }
+
+    // ---- Builds ----
+
+    /// Validate a player build the way `BuildHandler` and `PathPolicy` do.
+    pub fn plan_player_build(
+        &self,
+        req: &pb::BuildPlayerRequest,
+    ) -> Result<PlayerBuild, pb::BuildPlayerResponse> {
+        let fail = |status_code, message: String| pb::BuildPlayerResponse {
+            status_code,
+            message,
+            ..Default::default()
+        };
+        let target = match req.platform() {
+            pb::BuildPlatform::BpStandaloneWindows64 => "StandaloneWindows64",
+            pb::BuildPlatform::BpStandaloneOsx => "StandaloneOSX",
+            pb::BuildPlatform::BpStandaloneLinux64 => "StandaloneLinux64",
+            pb::BuildPlatform::BpAndroid => "Android",
+            pb::BuildPlatform::BpIos => "iOS",
+            pb::BuildPlatform::BpUnspecified => {
+                return Err(fail(status::INTERNAL, "unsupported platform".into()));
+            }
+        };
+        let output_path = self
+            .resolve_player_output(&req.output_path)
+            .map_err(|e| fail(7, e))?;
+        let scenes = match req.scenes.is_empty() {
+            true => self.build_scenes.clone(),
+            false => req.scenes.clone(),
+        };
+        if scenes.is_empty() {
+            return Err(fail(status::INVALID_ARGUMENT, "no scenes".into()));
+        }
+        if let Some(missing) = scenes.iter().find(|s| !self.scenes.contains_key(*s)) {
+            return Err(fail(
+                status::INTERNAL,
+                format!("Scene '{missing}' couldn't be loaded"),
+            ));
+        }
+        // Rough player size: a runtime plus something per scene and asset
+        let size_bytes = 24 * 1024 * 1024
+            + scenes.len() as u64 * 512 * 1024
+            + self.assets.len() as u64 * 64 * 1024;
+        Ok(PlayerBuild {
+            target,
+            output_path,
+            scenes,
+            size_bytes,
+            compile_errors: self
+                .diagnostics
+                .iter()
+                .filter(|d| d.severity == "error")
+                .count(),
+            warnings: self
+                .diagnostics
+                .iter()
+                .filter(|d| d.severity == "warning")
+                .map(|d| d.message.clone())
+                .collect(),
+        })
+    }
+
+    fn resolve_player_output(&self, input: &str) -> Result<String, String> {
+        if input.trim().is_empty() {
+            return Err("output_path required".into());
+        }
+        let full = self.resolve(input);
+        if is_system_path(&full) {
+            return Err("system path not allowed".into());
+        }
+        if is_under(&full, &self.join("Assets")) || is_under(&full, &self.join("Library")) {
+            return Err("output under Assets/Library is forbidden".into());
+        }
+        let builds = self.join("Builds");
+        if !is_under(&full, &builds) && is_under(&full, &self.path) {
+            return Err(format!("must be under {builds}/ or outside project root"));
+        }
+        Ok(full)
+    }
+
+    pub fn resolve_bundles_output(&self, input: &str) -> Result<String, String> {
+        if input.trim().is_empty() {
+            return Err("output_directory required".into());
+        }
+        let full = self.resolve(input);
+        if is_system_path(&full) {
+            return Err("system path not allowed".into());
+        }
+        let bundles = self.join("AssetBundles");
+        let builds_bundles = self.join("Builds/AssetBundles");
+        if !is_under(&full, &bundles)
+            && !is_under(&full, &builds_bundles)
+            && is_under(&full, &self.path)
+        {
+            return Err(format!(
+                "must be under {bundles}/ or {builds_bundles}/ or outside project root"
+            ));
+        }
+        Ok(full)
+    }
+
+    fn join(&self, relative: &str) -> String {
+        format!("{}/{}", self.path, relative)
+    }
+
+    /// Absolute, normalized path; relative paths are taken from the project root.
+    fn resolve(&self, input: &str) -> String {
+        let input = input.replace('\\', "/");
+        let joined = match input.starts_with('/') {
+            true => input,
+            false => format!("{}/{}", self.path, input),
+        };
+        let mut parts: Vec<&str> = Vec::new();
+        for part in joined.split('/') {
+            match part {
+                "" | "." => {}
+                ".." => {
+                    parts.pop();
+                }
+                p => parts.push(p),
+            }
+        }
+        format!("/{}", parts.join("/"))
+    }
+}
+
+fn is_valid_asset_path(p: &str) -> bool {
+    !p.is_empty() && !p.starts_with("..") && !p.starts_with('/') && p.starts_with("Assets/")
+}
+
+fn is_valid_scene_path(p: &str) -> bool {
+    is_valid_asset_path(p) && p.ends_with(".unity")
+}
+
+fn is_under(child: &str, parent: &str) -> bool {
+    child == parent || child.starts_with(&format!("{}/", parent.trim_end_matches('/')))
+}
+
+fn is_system_path(full: &str) -> bool {
+    full == "/"
+        || ["/usr/", "/bin/", "/etc/"]
+            .iter()
+            .any(|p| full.starts_with(p))
+}
+
+fn assets_error(status_code: i32, message: String) -> pb::AssetsResponse {
+    pb::AssetsResponse {
+        status_code,
+        message,
+        payload: None,
+    }
+}
+
+fn scenes_error(status_code: i32, message: String) -> pb::ScenesResponse {
+    pb::ScenesResponse {
+        status_code,
+        message,
+        payload: None,
+    }
+}
+
+fn component_error(status_code: i32, message: &str) -> pb::ComponentResponse {
+    pb::ComponentResponse {
+        status_code,
+        message: message.to_string(),
+        payload: None,
+    }
+}
+
+fn prefab_error(status_code: i32, message: String) -> pb::PrefabResponse {
+    pb::PrefabResponse {
+        status_code,
+        message,
+        payload: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> Project {
+        Project::sample("Game", "/work/Game")
+    }
+
+    #[test]
+    fn test_move_keeps_guid_and_references() {
+        let mut p = project();
+        let guid = p.guid("Assets/Scenes/Main.unity").unwrap().to_string();
+        let enemy = p.guid("Assets/Prefabs/Enemy.prefab").unwrap().to_string();
+        assert_eq!(
+            p.move_asset("Assets/Scenes", "Assets/Levels").unwrap(),
+            p.guid("Assets/Levels").unwrap()
+        );
+        assert_eq!(p.guid("Assets/Levels/Main.unity"), Some(guid.as_str()));
+        assert_eq!(p.active_scene, "Assets/Levels/Main.unity");
+        assert!(
+            p.build_scenes
+                .iter()
+                .all(|s| s.starts_with("Assets/Levels/"))
+        );
+
+        p.move_asset("Assets/Prefabs/Enemy.prefab", "Assets/Prefabs/Boss.prefab")
+            .unwrap();
+        assert_eq!(p.guid("Assets/Prefabs/Boss.prefab"), Some(enemy.as_str()));
+        let (scene, path) = p.find_object("Enemy").unwrap();
+        assert_eq!(
+            p.scenes[&scene][&path].prefab.as_deref(),
+            Some("Assets/Prefabs/Boss.prefab")
+        );
+
+        let (code, _) = p
+            .move_asset("Assets/Missing.png", "Assets/Other.png")
+            .unwrap_err();
+        assert_eq!(code, status::INTERNAL);
+        let (code, _) = p.move_asset("Packages/a.png", "Assets/a.png").unwrap_err();
+        assert_eq!(code, status::INVALID_ARGUMENT);
+    }
+
+    #[test]
+    fn test_find_object_by_path_or_name() {
+        let p = project();
+        assert_eq!(
+            p.find_object("Environment/Ground").unwrap().1,
+            "Environment/Ground"
+        );
+        assert_eq!(p.find_object("Ground").unwrap().1, "Environment/Ground");
+        // Objects of scenes that are not open are not found
+        assert!(p.find_object("Missing").is_none());
+        assert_eq!(
+            p.component_type("Rigidbody").as_deref(),
+            Some("UnityEngine.Rigidbody")
+        );
+        assert_eq!(p.component_type("Player").as_deref(), Some("Player"));
+        assert_eq!(p.component_type("NoSuchBehaviour"), None);
+    }
+
+    #[test]
+    fn test_build_output_policy() {
+        let p = project();
+        let req = |output: &str| pb::BuildPlayerRequest {
+            platform: pb::BuildPlatform::BpStandaloneLinux64 as i32,
+            output_path: output.to_string(),
+            ..Default::default()
+        };
+        let build = p.plan_player_build(&req("Builds/Linux/Game")).unwrap();
+        assert_eq!(build.output_path, "/work/Game/Builds/Linux/Game");
+        assert_eq!(build.scenes, p.build_scenes);
+        assert!(p.plan_player_build(&req("/tmp/out/Game")).is_ok());
+        for bad in [
+            "Assets/Out",
+            "Library/../Assets/x",
+            "Temp/Game",
+            "/usr/game",
+            "",
+        ] {
+            assert_eq!(
+                p.plan_player_build(&req(bad)).unwrap_err().status_code,
+                7,
+                "{bad}"
+            );
+        }
+        let unspecified = pb::BuildPlayerRequest {
+            output_path: "Builds/x".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            p.plan_player_build(&unspecified).unwrap_err().status_code,
+            status::INTERNAL
+        );
+    }
+
+    #[test]
+    fn test_changed_only_diagnostics() {
+        let mut p = project();
+        let all = pb::GetCompileDiagnosticsRequest::default();
+        let changed = pb::GetCompileDiagnosticsRequest {
+            changed_only: true,
+            ..Default::default()
+        };
+        assert_eq!(p.get_compile_diagnostics(&changed).diagnostics.len(), 1);
+        assert!(p.get_compile_diagnostics(&changed).diagnostics.is_empty());
+        p.add_diagnostic("Assets/Scripts/Enemy.cs", (3, 5), "error", "CS0103", "oops");
+        let resp = p.get_compile_diagnostics(&changed);
+        assert_eq!(resp.diagnostics.len(), 1);
+        assert_eq!(resp.summary.unwrap().errors, 1);
+        assert_eq!(p.get_compile_diagnostics(&all).diagnostics.len(), 2);
+    }
+}
@@ -0,0 +1,446 @@
+//! Fault-injecting TCP proxy between `IpcClient` and a bridge.
+//!
+//! IPC フレーム（4 バイト長プレフィックス）単位で中継し、シナリオのルールに一致したフレームに
+//! 遅延・破棄・破損・途中切断・順序入れ替え・接続断を起こす。ドメインリロードや応答の遅い
+//! Editor を再現して、再接続・タイムアウト・保留中リクエストの後始末を検証するためのもの。
+//!
+//! ```toml
+//! # 2 回目の接続では welcome を 1.5 秒遅らせる
+//! [[rule]]
+//! direction = "to_client"
+//! frame = "welcome"
+//! connection = 2
+//! action = "delay"
+//! ms = 1500
+//!
+//! # 3 つ目のリクエストで接続を切る（ドメインリロード）
+//! [[rule]]
+//! direction = "to_bridge"
+//! frame = "request"
+//! nth = 3
+//! action = "disconnect"
+//! ```
+
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::codec::{FramedRead, FramedWrite};
+use tokio_util::sync::CancellationToken;
+
+use crate::generated::mcp::unity::v1 as pb;
+pub use crate::ipc::tap::Direction;
+use crate::ipc::{codec, framing};
+
+/// Frames are relayed up to this size; larger ones end the connection.
+const MAX_RELAY_FRAME_LEN: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error("failed to read {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("invalid scenario: {0}")]
+    Parse(String),
+}
+
+/// What a frame carries. The first frame in each direction is the hello / welcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameKind {
+    #[default]
+    Any,
+    Hello,
+    /// Welcome or reject
+    Welcome,
+    Request,
+    /// Responses and response chunks
+    Response,
+    Event,
+    /// Ping / pong
+    Control,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    /// Hold the frame (and everything behind it) for `ms`
+    Delay { ms: u64 },
+    /// Swallow the frame
+    Drop,
+    /// Overwrite the payload so it no longer decodes; the length is kept
+    Corrupt,
+    /// Send only the first `keep` bytes of the payload, then close the connection
+    Truncate { keep: usize },
+    /// Hold the frame until the next frame in the same direction has been sent
+    Reorder,
+    /// Close both sides instead of forwarding the frame
+    Disconnect,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+    /// Both directions if omitted
+    pub direction: Option<Direction>,
+    #[serde(default)]
+    pub frame: FrameKind,
+    /// Only on this connection (1-based)
+    pub connection: Option<u32>,
+    /// Only the nth matching frame (1-based, counted across connections); every match if omitted
+    pub nth: Option<u32>,
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+impl Rule {
+    fn matches(&self, direction: Direction, frame: FrameKind, connection: u32) -> bool {
+        self.direction.is_none_or(|d| d == direction)
+            && (self.frame == FrameKind::Any || self.frame == frame)
+            && self.connection.is_none_or(|c| c == connection)
+    }
+}
+
+/// Rules applied to every frame in order; all rules that fire act on the frame.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Scenario {
+    #[serde(default, rename = "rule")]
+    pub rules: Vec<Rule>,
+}
+
+impl Scenario {
+    pub fn from_toml(raw: &str) -> Result<Self, ScenarioError> {
+        toml::from_str(raw).map_err(|e| ScenarioError::Parse(e.message().to_string()))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ScenarioError> {
+        let raw = std::fs::read_to_string(path).map_err(|source| ScenarioError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml(&raw)
+    }
+}
+
+struct State {
+    scenario: Scenario,
+    /// Matching frames seen per rule
+    matched: Mutex<Vec<u32>>,
+    /// Times each rule fired
+    fired: Vec<AtomicU32>,
+    connections: AtomicU32,
+}
+
+impl State {
+    /// Actions of the rules that fire on this frame.
+    fn actions(&self, direction: Direction, frame: FrameKind, connection: u32) -> Vec<Action> {
+        let mut matched = self.matched.lock().unwrap_or_else(|e| e.into_inner());
+        let mut actions = Vec::new();
+        for (i, rule) in self.scenario.rules.iter().enumerate() {
+            if !rule.matches(direction, frame, connection) {
+                continue;
+            }
+            matched[i] += 1;
+            if rule.nth.is_none_or(|n| n == matched[i]) {
+                self.fired[i].fetch_add(1, Ordering::Relaxed);
+                actions.push(rule.action.clone());
+            }
+        }
+        actions
+    }
+}
+
+/// Counters of a running proxy.
+#[derive(Clone)]
+pub struct ProxyHandle {
+    state: Arc<State>,
+}
+
+impl ProxyHandle {
+    /// Client connections accepted so far.
+    pub fn connections(&self) -> u32 {
+        self.state.connections.load(Ordering::Relaxed)
+    }
+
+    /// How often rule `index` of the scenario fired.
+    pub fn fired(&self, index: usize) -> u32 {
+        self.state
+            .fired
+            .get(index)
+            .map_or(0, |n| n.load(Ordering::Relaxed))
+    }
+}
+
+pub struct FaultProxy {
+    listener: TcpListener,
+    upstream: String,
+    state: Arc<State>,
+}
+
+impl FaultProxy {
+    /// Listen on `addr` and relay to the bridge at `upstream` (`host:port` or `tcp://host:port`).
+    pub async fn bind(addr: &str, upstream: &str, scenario: Scenario) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let rules = scenario.rules.len();
+        Ok(Self {
+            listener,
+            upstream: upstream.trim_start_matches("tcp://").to_string(),
+            state: Arc::new(State {
+                scenario,
+                matched: Mutex::new(vec![0; rules]),
+                fired: (0..rules).map(|_| AtomicU32::new(0)).collect(),
+                connections: AtomicU32::new(0),
+            }),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Endpoint for the client's `ipc.endpoint`.
+    pub fn endpoint(&self) -> String {
+        match self.local_addr() {
+            Ok(addr) => format!("tcp://{addr}"),
+            Err(_) => String::new(),
+        }
+    }
+
+    pub fn handle(&self) -> ProxyHandle {
+        ProxyHandle {
+            state: self.state.clone(),
+        }
+    }
+
+    /// Accept connections until the task is dropped.
+    pub async fn run(self) {
+        loop {
+            let client = match self.listener.accept().await {
+                Ok((client, _)) => client,
+                Err(e) => {
+                    tracing::warn!("accept failed: {}", e);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            let connection = self.state.connections.fetch_add(1, Ordering::Relaxed) + 1;
+            let bridge = match TcpStream::connect(&self.upstream).await {
+                Ok(bridge) => bridge,
+                Err(e) => {
+                    tracing::warn!(
+                        "connection {}: upstream {}: {}",
+                        connection,
+                        self.upstream,
+                        e
+                    );
+                    continue;
+                }
+            };
+            let _ = client.set_nodelay(true);
+            let _ = bridge.set_nodelay(true);
+            tokio::spawn(relay(client, bridge, self.state.clone(), connection));
+        }
+    }
+}
+
+async fn relay(client: TcpStream, bridge: TcpStream, state: Arc<State>, connection: u32) {
+    let (client_read, client_write) = client.into_split();
+    let (bridge_read, bridge_write) = bridge.into_split();
+    let closed = CancellationToken::new();
+    tokio::join!(
+        pump(
+            client_read,
+            bridge_write,
+            Direction::ToBridge,
+            state.clone(),
+            connection,
+            closed.clone(),
+        ),
+        pump(
+            bridge_read,
+            client_write,
+            Direction::ToClient,
+            state,
+            connection,
+            closed,
+        ),
+    );
+    tracing::debug!("connection {} closed", connection);
+}
+
+/// Relay frames one way until either side goes away or a rule closes the connection.
+async fn pump<R, W>(
+    read: R,
+    write: W,
+    direction: Direction,
+    state: Arc<State>,
+    connection: u32,
+    closed: CancellationToken,
+) where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let codec = framing::codec_with_max_frame_len(MAX_RELAY_FRAME_LEN);
+    let mut frames = FramedRead::new(read, codec.clone());
+    let mut sink = FramedWrite::new(write, codec);
+    let mut first = true;
+    let mut held: Option<Bytes> = None;
+
+    'frames: loop {
+        let frame = tokio::select! {
+            _ = closed.cancelled() => break,
+            frame = frames.next() => match frame {
+                Some(Ok(frame)) => frame,
+                _ => break,
+            },
+        };
+        let kind = classify(direction, first, &frame);
+        first = false;
+        let mut frame = frame.freeze();
+        let mut reorder = false;
+        for action in state.actions(direction, kind, connection) {
+            tracing::debug!(
+                "connection {}: {:?} {:?} frame: {:?}",
+                connection,
+                direction,
+                kind,
+                action
+            );
+            match action {
+                Action::Delay { ms } => {
+                    tokio::select! {
+                        _ = closed.cancelled() => break 'frames,
+                        _ = tokio::time::sleep(Duration::from_millis(ms)) => {}
+                    }
+                }
+                Action::Drop => continue 'frames,
+                Action::Corrupt => {
+                    // 0xFF never ends a varint, so the payload cannot decode
+                    frame = Bytes::from(vec![0xFF; frame.len()]);
+                }
+                Action::Truncate { keep } => {
+                    let mut inner = sink.into_inner();
+                    let mut partial = BytesMut::with_capacity(4 + keep);
+                    partial.extend_from_slice(&(frame.len() as u32).to_be_bytes());
+                    partial.extend_from_slice(&frame[..keep.min(frame.len())]);
+                    let _ = inner.write_all(&partial).await;
+                    let _ = inner.flush().await;
+                    closed.cancel();
+                    return;
+                }
+                Action::Reorder => reorder = true,
+                Action::Disconnect => break 'frames,
+            }
+        }
+        if reorder && held.is_none() {
+            held = Some(frame);
+            continue;
+        }
+        if sink.send(frame).await.is_err() {
+            break;
+        }
+        if let Some(frame) = held.take()
+            && sink.send(frame).await.is_err()
+        {
+            break;
+        }
+    }
+    closed.cancel();
+}
+
+fn classify(direction: Direction, first: bool, frame: &[u8]) -> FrameKind {
+    use pb::ipc_envelope::Kind;
+
+    match (direction, first) {
+        (Direction::ToBridge, true) => return FrameKind::Hello,
+        (Direction::ToClient, true) => return FrameKind::Welcome,
+        _ => {}
+    }
+    let Ok(env) = codec::decode_envelope(Bytes::copy_from_slice(frame))
+        .and_then(|env| codec::inflate_envelope(env, MAX_RELAY_FRAME_LEN))
+    else {
+        return FrameKind::Any;
+    };
+    match env.kind {
+        Some(Kind::Request(_)) => FrameKind::Request,
+        Some(Kind::Response(_) | Kind::ResponseChunk(_)) => FrameKind::Response,
+        Some(Kind::Event(_)) => FrameKind::Event,
+        Some(Kind::Control(_)) => FrameKind::Control,
+        _ => FrameKind::Any,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scenario_from_toml() {
+        let scenario = Scenario::from_toml(
+            r#"
+            [[rule]]
+            direction = "to_client"
+            frame = "welcome"
+            connection = 2
+            action = "delay"
+            ms = 1500
+
+            [[rule]]
+            frame = "request"
+            nth = 3
+            action = "truncate"
+            keep = 8
+            "#,
+        )
+        .unwrap();
+        assert_eq!(scenario.rules.len(), 2);
+        assert_eq!(scenario.rules[0].action, Action::Delay { ms: 1500 });
+        assert_eq!(scenario.rules[1].direction, None);
+        assert_eq!(scenario.rules[1].action, Action::Truncate { keep: 8 });
+
+        assert!(Scenario::from_toml("[[rule]]\naction = \"explode\"").is_err());
+        assert!(Scenario::from_toml("").unwrap().rules.is_empty());
+    }
+
+    #[test]
+    fn test_nth_counts_across_connections() {
+        let scenario = Scenario::from_toml(
+            "[[rule]]\ndirection = \"to_bridge\"\nframe = \"request\"\nnth = 2\naction = \"drop\"",
+        )
+        .unwrap();
+        let state = State {
+            matched: Mutex::new(vec![0]),
+            fired: vec![AtomicU32::new(0)],
+            connections: AtomicU32::new(0),
+            scenario,
+        };
+        assert!(
+            state
+                .actions(Direction::ToBridge, FrameKind::Request, 1)
+                .is_empty()
+        );
+        assert!(
+            state
+                .actions(Direction::ToClient, FrameKind::Response, 1)
+                .is_empty()
+        );
+        assert_eq!(
+            state.actions(Direction::ToBridge, FrameKind::Request, 2),
+            vec![Action::Drop]
+        );
+        assert!(
+            state
+                .actions(Direction::ToBridge, FrameKind::Request, 2)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Bridge that replays a captured session (`ipc.record` or `ipc-tap --capture`).
+//!
+//! キャプチャの welcome で応答し、届いたリクエストは correlation_id ではなくペイロード
+//! （JSON 表現）が一致する記録済みリクエストに対応付けて、そのとき記録された応答とイベントを
+//! 記録順に返す。同じペイロードが複数回記録されていれば順に使い、尽きたら最後のものを
+//! 繰り返す。記録にないリクエストには空の応答を返し、[`ReplayHandle::misses`] に残す。
+//!
+//! メッセージは JSON から現在のスキーマで再エンコードするので、フィールドの追加程度の
+//! スキーマ変更ではキャプチャを撮り直す必要はない。
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use thiserror::Error;
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::generated::mcp::unity::v1 as pb;
+use crate::ipc::{
+    codec,
+    features::FeatureSet,
+    framing,
+    tap::{self, CaptureError, RecordKind, TapRecord},
+};
+
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error(transparent)]
+    Capture(#[from] CaptureError),
+    #[error("capture has no welcome")]
+    NoWelcome,
+    #[error("record {index}: {message}")]
+    Record { index: usize, message: String },
+    #[error("io: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    Response(pb::IpcResponse),
+    Event(pb::IpcEvent),
+    Pong(pb::IpcPong),
+}
+
+/// What the bridge sent for one recorded request, in order.
+type Exchange = Vec<Step>;
+
+struct Script {
+    welcome: pb::IpcWelcome,
+    /// Events recorded before the first request
+    preamble: Vec<pb::IpcEvent>,
+    /// Recorded request payload → its exchanges, oldest first
+    exchanges: Mutex<Vec<(serde_json::Value, VecDeque<Exchange>)>>,
+    misses: Mutex<Vec<serde_json::Value>>,
+}
+
+impl Script {
+    fn from_records(records: &[TapRecord]) -> Result<Self, ReplayError> {
+        let mut welcome = None;
+        let mut preamble = Vec::new();
+        let mut exchanges: Vec<(serde_json::Value, VecDeque<Exchange>)> = Vec::new();
+        // (connection, correlation id) → (payload index, exchange index)
+        let mut requests: HashMap<(u32, &str), (usize, usize)> = HashMap::new();
+        let mut last = None;
+
+        for (index, record) in records.iter().enumerate() {
+            let Some(message) = &record.message else {
+                continue;
+            };
+            let invalid = |message: String| ReplayError::Record { index, message };
+            match record.kind {
+                RecordKind::Welcome if welcome.is_none() => {
+                    let control: pb::IpcControl =
+                        tap::message_from_json("IpcControl", message).map_err(invalid)?;
+                    if let Some(pb::ipc_control::Kind::Welcome(w)) = control.kind {
+                        welcome = Some(w);
+                    }
+                }
+                RecordKind::Request => {
+                    let i = match exchanges.iter().position(|(payload, _)| payload == message) {
+                        Some(i) => i,
+                        None => {
+                            exchanges.push((message.clone(), VecDeque::new()));
+                            exchanges.len() - 1
+                        }
+                    };
+                    exchanges[i].1.push_back(Vec::new());
+                    let at = (i, exchanges[i].1.len() - 1);
+                    if let Some(cid) = &record.correlation_id {
+                        requests.insert((record.connection, cid), at);
+                    }
+                    last = Some(at);
+                }
+                RecordKind::Response => {
+                    let Some(&(i, j)) = record
+                        .correlation_id
+                        .as_deref()
+                        .and_then(|cid| requests.get(&(record.connection, cid)))
+                    else {
+                        continue;
+                    };
+                    let resp = tap::message_from_json("IpcResponse", message).map_err(invalid)?;
+                    exchanges[i].1[j].push(Step::Response(resp));
+                }
+                RecordKind::Event => {
+                    let event = tap::message_from_json("IpcEvent", message).map_err(invalid)?;
+                    match last {
+                        Some((i, j)) => exchanges[i].1[j].push(Step::Event(event)),
+                        None => preamble.push(event),
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(Self {
+            welcome: welcome.ok_or(ReplayError::NoWelcome)?,
+            preamble,
+            exchanges: Mutex::new(exchanges),
+            misses: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Next recorded exchange for this payload; the last one repeats.
+    fn take(&self, payload: &serde_json::Value) -> Option<Exchange> {
+        let mut exchanges = self.exchanges.lock().unwrap_or_else(|e| e.into_inner());
+        let (_, queue) = exchanges.iter_mut().find(|(p, _)| p == payload)?;
+        if queue.len() > 1 {
+            queue.pop_front()
+        } else {
+            queue.front().cloned()
+        }
+    }
+}
+
+/// Requests the replay could not answer.
+#[derive(Clone)]
+pub struct ReplayHandle {
+    script: Arc<Script>,
+}
+
+impl ReplayHandle {
+    /// Payloads of requests that matched nothing in the capture, in arrival order.
+    pub fn misses(&self) -> Vec<serde_json::Value> {
+        self.script
+            .misses
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+/// Serves a capture to clients on TCP.
+pub struct ReplayBridge {
+    listener: TcpListener,
+    script: Arc<Script>,
+}
+
+impl ReplayBridge {
+    pub async fn bind(addr: &str, records: &[TapRecord]) -> Result<Self, ReplayError> {
+        let script = Script::from_records(records)?;
+        let listener = TcpListener::bind(addr).await?;
+        Ok(Self {
+            listener,
+            script: Arc::new(script),
+        })
+    }
+
+    /// [`bind`](Self::bind) with the records of a capture file.
+    pub async fn load(addr: &str, capture: &Path) -> Result<Self, ReplayError> {
+        Self::bind(addr, &tap::load_capture(capture)?).await
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Endpoint to put in `ipc.endpoint`.
+    pub fn endpoint(&self) -> String {
+        match self.local_addr() {
+            Ok(addr) => format!("tcp://{addr}"),
+            Err(_) => String::new(),
+        }
+    }
+
+    pub fn handle(&self) -> ReplayHandle {
+        ReplayHandle {
+            script: self.script.clone(),
+        }
+    }
+
+    /// Accept connections until the task is dropped.
+    pub async fn run(self) {
+        loop {
+            let stream = match self.listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    tracing::warn!("accept failed: {}", e);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            tokio::spawn(serve(stream, self.script.clone()));
+        }
+    }
+}
+
+async fn serve(stream: TcpStream, script: Arc<Script>) {
+    let _ = stream.set_nodelay(true);
+    let mut framed = framing::into_framed(stream);
+
+    let Some(Ok(first)) = framed.next().await else {
+        return;
+    };
+    let Ok(pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Hello(hello)),
+    }) = codec::decode_control(first.freeze())
+    else {
+        return;
+    };
+    // Responses go out whole and uncompressed, which every client accepts
+    let offered = FeatureSet::from_strings(&hello.features);
+    let recorded = FeatureSet::from_strings(&script.welcome.accepted_features);
+    let welcome = pb::IpcWelcome {
+        accepted_features: recorded.intersect(&offered).to_strings(),
+        schema_hash: codec::schema_hash(),
+        ..script.welcome.clone()
+    };
+    let welcome = pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Welcome(welcome)),
+    };
+    let Ok(bytes) = codec::encode_control(&welcome) else {
+        return;
+    };
+    if framed.send(bytes).await.is_err() {
+        return;
+    }
+    for event in &script.preamble {
+        if send(&mut framed, String::new(), Step::Event(event.clone()))
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+
+    while let Some(Ok(frame)) = framed.next().await {
+        let env = match codec::decode_envelope(frame.freeze())
+            .and_then(|env| codec::inflate_envelope(env, framing::DEFAULT_MAX_FRAME_LEN))
+        {
+            Ok(env) => env,
+            Err(e) => {
+                tracing::warn!("dropping undecodable frame: {}", e);
+                continue;
+            }
+        };
+        let steps = match env.kind {
+            Some(pb::ipc_envelope::Kind::Request(req)) => {
+                match tap::message_to_json("IpcRequest", &req) {
+                    Ok(payload) => script.take(&payload).unwrap_or_else(|| {
+                        tracing::warn!("no recorded response for {}", payload);
+                        let mut misses = script.misses.lock().unwrap_or_else(|e| e.into_inner());
+                        misses.push(payload);
+                        vec![Step::Response(pb::IpcResponse::default())]
+                    }),
+                    Err(e) => {
+                        tracing::warn!("dropping request: {}", e);
+                        continue;
+                    }
+                }
+            }
+            Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Ping(ping)),
+            })) => vec![Step::Pong(pb::IpcPong {
+                nonce: ping.nonce,
+                ping_sent_monotonic_ns: ping.sent_monotonic_ns,
+            })],
+            _ => continue,
+        };
+        for step in steps {
+            if send(&mut framed, env.correlation_id.clone(), step)
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+async fn send<S>(framed: &mut S, correlation_id: String, step: Step) -> Result<(), ()>
+where
+    S: futures::Sink<bytes::Bytes> + Unpin,
+{
+    let env = match step {
+        Step::Response(resp) => pb::IpcEnvelope {
+            correlation_id: correlation_id.clone(),
+            kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                correlation_id,
+                ..resp
+            })),
+        },
+        Step::Event(event) => pb::IpcEnvelope {
+            correlation_id: String::new(),
+            kind: Some(pb::ipc_envelope::Kind::Event(event)),
+        },
+        Step::Pong(pong) => pb::IpcEnvelope {
+            correlation_id: String::new(),
+            kind: Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Pong(pong)),
+            })),
+        },
+    };
+    let bytes = codec::encode_envelope(&env).map_err(|_| ())?;
+    framed.send(bytes).await.map_err(|_| ())
+}
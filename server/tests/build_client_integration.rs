@@ -16,6 +16,7 @@ async fn test_ipc_client_build_player() -> anyhow::Result<()> {
 
     // Build Player request
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/TestIntegration/TestApp.exe".to_string(),
         scenes: vec![],
@@ -75,6 +76,7 @@ async fn test_ipc_client_build_bundles() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildAssetBundlesRequest {
+        op_id: String::new(),
         output_directory: "AssetBundles/TestIntegration".to_string(),
         deterministic: true,
         chunk_based: false,
@@ -294,6 +296,7 @@ async fn test_invalid_platform() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: 999, // 無効なプラットフォーム
         output_path: "Builds/Invalid.exe".to_string(),
         scenes: vec![],
@@ -332,6 +335,7 @@ async fn test_invalid_output_path() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Assets/BadLocation.exe".to_string(), // 禁止されたパス
         scenes: vec![],
@@ -365,6 +369,7 @@ async fn test_library_output_path_forbidden() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Library/BadLocation.exe".to_string(), // Library は禁止
         scenes: vec![],
@@ -391,6 +396,7 @@ async fn test_asset_bundles_invalid_path() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildAssetBundlesRequest {
+        op_id: String::new(),
         output_directory: "Assets/InvalidAssetBundles".to_string(), // Assets は禁止
         deterministic: true,
         chunk_based: false,
@@ -416,6 +422,7 @@ async fn test_empty_scenes_list() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/EmptyScenes/TestApp.exe".to_string(),
         scenes: vec![], // 空のシーンリスト（デフォルトシーンを使用）
@@ -455,6 +462,7 @@ async fn test_build_timeout() -> anyhow::Result<()> {
     let client = IpcClient::connect(config).await?;
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/TimeoutTest/TestApp.exe".to_string(),
         scenes: vec![],
@@ -500,6 +508,7 @@ async fn test_build_progress_events() -> anyhow::Result<()> {
     let client_clone = client.clone();
     let build_task = tokio::spawn(async move {
         let req = pb::BuildPlayerRequest {
+            op_id: String::new(),
             platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
             output_path: "Builds/ProgressTest/TestApp.exe".to_string(),
             scenes: vec![],
@@ -594,6 +603,7 @@ async fn test_multiple_build_progress() -> anyhow::Result<()> {
     let client1 = client.clone();
     let build1 = tokio::spawn(async move {
         let req = pb::BuildPlayerRequest {
+            op_id: String::new(),
             platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
             output_path: "Builds/Multi1/TestApp.exe".to_string(),
             scenes: vec![],
@@ -611,6 +621,7 @@ async fn test_multiple_build_progress() -> anyhow::Result<()> {
         // 少し遅らせて開始
         tokio::time::sleep(Duration::from_secs(5)).await;
         let req = pb::BuildAssetBundlesRequest {
+            op_id: String::new(),
             output_directory: "AssetBundles/Multi".to_string(),
             deterministic: true,
             chunk_based: false,
@@ -665,6 +676,7 @@ async fn test_build_performance() -> anyhow::Result<()> {
     let start = std::time::Instant::now();
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/PerfTest/TestApp.exe".to_string(),
         scenes: vec![],
@@ -726,6 +738,7 @@ async fn test_asset_bundles_performance() -> anyhow::Result<()> {
     let start = std::time::Instant::now();
 
     let req = pb::BuildAssetBundlesRequest {
+        op_id: String::new(),
         output_directory: "AssetBundles/PerfTest".to_string(),
         deterministic: true,
         chunk_based: false,
@@ -764,6 +777,7 @@ async fn test_concurrent_build_performance() -> anyhow::Result<()> {
 
     // Player ビルド
     let req1 = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/Concurrent1/TestApp.exe".to_string(),
         scenes: vec![],
@@ -777,6 +791,7 @@ async fn test_concurrent_build_performance() -> anyhow::Result<()> {
 
     // AssetBundles ビルド
     let req2 = pb::BuildAssetBundlesRequest {
+        op_id: String::new(),
         output_directory: "AssetBundles/Concurrent".to_string(),
         deterministic: true,
         chunk_based: false,
@@ -819,6 +834,7 @@ async fn test_memory_usage_during_build() -> anyhow::Result<()> {
     println!("Baseline memory: {} KB", baseline_memory);
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/MemoryTest/TestApp.exe".to_string(),
         scenes: vec![],
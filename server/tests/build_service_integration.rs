@@ -1,5 +1,6 @@
-use rmcp::handler::server::tool::Parameters;
+use rmcp::{handler::server::tool::Parameters, model::Meta};
 use server::mcp::service::{McpService, UnityBuildPlayerRequest};
+use tokio_util::sync::CancellationToken;
 
 /// MCP サービスのビルドエンドポイント統合テスト
 #[tokio::test]
@@ -19,9 +20,12 @@ async fn test_unity_build_player_tool() -> anyhow::Result<()> {
         scenes: None,
         development: Some(true),
         timeout_secs: Some(60),
+        project: None,
     };
 
-    let result = service.unity_build_player(Parameters(req)).await?;
+    let result = service
+        .unity_build_player(Parameters(req), Meta::default(), CancellationToken::new())
+        .await?;
     println!("Build tool result: {:?}", result);
     Ok(())
 }
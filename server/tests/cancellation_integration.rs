@@ -0,0 +1,239 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use std::time::Duration;
+use tokio::{net::TcpListener, sync::mpsc};
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+/// Tests configure their service through the environment, one at a time.
+static ENV: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Mock bridge whose import never finishes: it emits START after `start_delay` and then
+/// waits for a cancel.
+///
+/// Reports `(started op_id, cancelled op_id)` for every cancel it receives.
+async fn mock_slow_import_bridge(
+    port: u16,
+    start_delay: Duration,
+    cancelled_tx: mpsc::UnboundedSender<(String, String)>,
+) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let cancelled_tx = cancelled_tx.clone();
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-slow-import".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "cancel-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            let mut started = String::new();
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                    continue;
+                };
+                match req.payload {
+                    Some(pb::ipc_request::Payload::Assets(pb::AssetsRequest {
+                        payload: Some(pb::assets_request::Payload::Import(import)),
+                    })) => {
+                        started = import.op_id;
+                        tokio::time::sleep(start_delay).await;
+                        let start = pb::IpcEnvelope {
+                            correlation_id: String::new(),
+                            kind: Some(pb::ipc_envelope::Kind::Event(pb::IpcEvent {
+                                monotonic_ts_ns: 0,
+                                payload: Some(pb::ipc_event::Payload::Op(pb::OperationEvent {
+                                    op_id: started.clone(),
+                                    kind: pb::operation_event::Kind::Start as i32,
+                                    message: "Import 1 items".to_string(),
+                                    ..Default::default()
+                                })),
+                            })),
+                        };
+                        let _ = framed.send(codec::encode_envelope(&start).unwrap()).await;
+                        // No response: the import "runs" until cancelled
+                    }
+                    Some(pb::ipc_request::Payload::OperationCancel(r)) => {
+                        let _ = cancelled_tx.send((started.clone(), r.id));
+                        let resp = pb::IpcEnvelope {
+                            correlation_id: env.correlation_id.clone(),
+                            kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                                correlation_id: env.correlation_id,
+                                payload: Some(pb::ipc_response::Payload::OperationCancel(
+                                    pb::OperationCancelResponse { accepted: true },
+                                )),
+                            })),
+                        };
+                        let _ = framed.send(codec::encode_envelope(&resp).unwrap()).await;
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: Option<&str>,
+    body: serde_json::Value,
+) -> anyhow::Result<reqwest::Response> {
+    let mut req = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json");
+    if let Some(id) = session_id {
+        req = req.header("mcp-session-id", id);
+    }
+    Ok(req.body(body.to_string()).send().await?)
+}
+
+/// Start an import through MCP, cancel it after `cancel_after` and return
+/// `(started op_id, cancelled op_id)` seen by the bridge.
+async fn cancel_import(
+    bridge_port: u16,
+    http_port: u16,
+    start_delay: Duration,
+    cancel_after: Duration,
+) -> anyhow::Result<(String, String)> {
+    let (cancelled_tx, mut cancelled_rx) = mpsc::unbounded_channel();
+    tokio::spawn(mock_slow_import_bridge(
+        bridge_port,
+        start_delay,
+        cancelled_tx,
+    ));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let env = ENV.lock().await;
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINT", format!("tcp://127.0.0.1:{bridge_port}"));
+    }
+    let svc = McpService::new().await?;
+    drop(env);
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "cancel-test", "version": "0.0.1" }
+        }
+    });
+    let resp = post(&client, &url, None, init).await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    resp.text().await?;
+    post(
+        &client,
+        &url,
+        Some(&session_id),
+        serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await?;
+
+    let call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "unity_assets_import",
+            "arguments": { "paths": ["Assets/Big.fbx"], "timeout_secs": 60 }
+        }
+    });
+    let call_client = client.clone();
+    let call_url = url.clone();
+    let call_session = session_id.clone();
+    let call_task = tokio::spawn(async move {
+        let resp = post(&call_client, &call_url, Some(&call_session), call).await?;
+        Ok::<_, anyhow::Error>(resp.text().await?)
+    });
+
+    tokio::time::sleep(cancel_after).await;
+    post(
+        &client,
+        &url,
+        Some(&session_id),
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/cancelled",
+            "params": { "requestId": 2, "reason": "user abort" }
+        }),
+    )
+    .await?;
+
+    let ids = tokio::time::timeout(Duration::from_secs(5), cancelled_rx.recv())
+        .await?
+        .unwrap();
+
+    // The cancelled call must end promptly instead of running into its 60s timeout.
+    // Per MCP, no response is delivered for a cancelled request.
+    let body = tokio::time::timeout(Duration::from_secs(5), call_task).await???;
+    assert!(
+        !body.contains("timeout"),
+        "should not report a timeout: {body}"
+    );
+
+    Ok(ids)
+}
+
+#[tokio::test]
+async fn test_cancelled_import_is_forwarded_to_bridge() -> anyhow::Result<()> {
+    // Let the START event reach the server before cancelling
+    let (started, cancelled) =
+        cancel_import(18970, 18971, Duration::ZERO, Duration::from_millis(500)).await?;
+    assert!(!started.is_empty(), "import request carried no op_id");
+    assert_eq!(cancelled, started);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_cancelled_before_start_is_cancelled_once_started() -> anyhow::Result<()> {
+    // The MCP cancel arrives while the bridge has not reported START yet
+    let (started, cancelled) = cancel_import(
+        18968,
+        18969,
+        Duration::from_millis(1000),
+        Duration::from_millis(300),
+    )
+    .await?;
+    assert!(!started.is_empty(), "import request carried no op_id");
+    assert_eq!(cancelled, started);
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    chunking::{self, ChunkError},
+    client::{IpcClient, IpcError, ResponsePart},
+    codec, framing,
+    path::IpcConfig,
+};
+use std::sync::Arc;
+use tokio::{net::TcpListener, sync::Notify, time::Duration};
+
+const FRAME_LIMIT: usize = 1024 * 1024;
+const CHUNK_SIZE: usize = 256 * 1024;
+const PAYLOAD_LEN: usize = 6 * 1024 * 1024;
+
+/// Mock bridge answering every request with a multi-megabyte chunked response.
+///
+/// For menu item "Tools/Stream" it pauses after the first chunk until `resume` is
+/// notified, so the test can observe chunks before the response is complete.
+async fn mock_chunking_bridge(port: u16, resume: Arc<Notify>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let resume = resume.clone();
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            // The client tells us how big a frame it accepts
+            assert_eq!(
+                hello.meta.get("max_frame_bytes").map(String::as_str),
+                Some(FRAME_LIMIT.to_string().as_str())
+            );
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-chunking".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "chunking-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                    continue;
+                };
+                let (payload, pause) = match req.payload {
+                    Some(pb::ipc_request::Payload::Health(_)) => (
+                        pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "h".repeat(PAYLOAD_LEN),
+                            ..Default::default()
+                        }),
+                        false,
+                    ),
+                    Some(pb::ipc_request::Payload::ExecuteMenuItem(r)) => (
+                        pb::ipc_response::Payload::ExecuteMenuItem(pb::ExecuteMenuItemResponse {
+                            ok: true,
+                            message: "m".repeat(PAYLOAD_LEN),
+                        }),
+                        r.path == "Tools/Stream",
+                    ),
+                    _ => continue,
+                };
+                let resp = pb::IpcResponse {
+                    correlation_id: env.correlation_id.clone(),
+                    payload: Some(payload),
+                };
+                let chunks = chunking::split_response(&env.correlation_id, &resp, CHUNK_SIZE);
+                for (i, chunk) in chunks.iter().enumerate() {
+                    let bytes = codec::encode_envelope(chunk).unwrap();
+                    assert!(bytes.len() <= FRAME_LIMIT);
+                    if framed.send(bytes).await.is_err() {
+                        return;
+                    }
+                    if pause && i == 0 {
+                        resume.notified().await;
+                    }
+                }
+            }
+        });
+    }
+}
+
+fn test_config(port: u16) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+        token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        handshake_timeout: Duration::from_secs(2),
+        total_handshake_timeout: Duration::from_secs(4),
+        call_timeout: Duration::from_secs(5),
+        max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::ZERO,
+        heartbeat_miss_threshold: 3,
+        max_frame_len: FRAME_LIMIT,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
+    }
+}
+
+#[tokio::test]
+async fn test_chunked_responses_are_reassembled_and_streamed() -> anyhow::Result<()> {
+    let port = 18920;
+    let resume = Arc::new(Notify::new());
+    tokio::spawn(mock_chunking_bridge(port, resume.clone()));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let client = IpcClient::connect(test_config(port)).await?;
+
+    // Plain request: reassembled transparently, far beyond the frame limit
+    let health = client.health(Duration::from_secs(5)).await?;
+    assert_eq!(health.version.len(), PAYLOAD_LEN);
+
+    // Streaming request: the first chunk arrives while the bridge is still holding the rest
+    let req = pb::IpcRequest {
+        payload: Some(pb::ipc_request::Payload::ExecuteMenuItem(
+            pb::ExecuteMenuItemRequest {
+                path: "Tools/Stream".to_string(),
+            },
+        )),
+    };
+    let mut stream = client
+        .request_streaming(req, Duration::from_secs(5))
+        .await?;
+    let total = match stream.next().await {
+        Some(Ok(ResponsePart::Chunk {
+            index: 0,
+            data,
+            received,
+            total_size,
+        })) => {
+            assert_eq!(data.len(), CHUNK_SIZE);
+            assert_eq!(received, CHUNK_SIZE as u64);
+            total_size
+        }
+        other => panic!("expected first chunk, got {other:?}"),
+    };
+    resume.notify_one();
+
+    let mut received = CHUNK_SIZE as u64;
+    let mut chunks = 1;
+    let response = loop {
+        match stream.next().await {
+            Some(Ok(ResponsePart::Chunk { index, data, .. })) => {
+                assert_eq!(index, chunks);
+                received += data.len() as u64;
+                chunks += 1;
+            }
+            Some(Ok(ResponsePart::Complete(resp))) => break resp,
+            other => panic!("unexpected part {other:?}"),
+        }
+    };
+    assert!(stream.next().await.is_none());
+    assert_eq!(received, total);
+    assert!(chunks as usize > PAYLOAD_LEN / CHUNK_SIZE);
+    match response.payload {
+        Some(pb::ipc_response::Payload::ExecuteMenuItem(r)) => {
+            assert_eq!(r.message.len(), PAYLOAD_LEN)
+        }
+        other => panic!("unexpected payload {other:?}"),
+    }
+
+    // Same connection, collected in one go
+    let menu = client
+        .execute_menu_item("Tools/Other".into(), Duration::from_secs(5))
+        .await?;
+    assert_eq!(menu.message.len(), PAYLOAD_LEN);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunked_response_over_limit_fails_request_only() -> anyhow::Result<()> {
+    let port = 18921;
+    tokio::spawn(mock_chunking_bridge(port, Arc::new(Notify::new())));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut cfg = test_config(port);
+    cfg.max_response_len = PAYLOAD_LEN / 2;
+    let client = IpcClient::connect(cfg).await?;
+
+    for _ in 0..2 {
+        let err = client.health(Duration::from_secs(5)).await.unwrap_err();
+        assert!(
+            matches!(err, IpcError::Chunk(ChunkError::TooLarge { .. })),
+            "{err}"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use std::process::Output;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+/// Mock bridge answering Health and menu items for the project "CliGame".
+async fn mock_bridge(port: u16) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-cli".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "cli-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                    continue;
+                };
+                let payload = match req.payload {
+                    Some(pb::ipc_request::Payload::Health(_)) => {
+                        pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "6000.0.test".to_string(),
+                            status: "OK".to_string(),
+                            project_name: "CliGame".to_string(),
+                            project_path: "/work/cli-game".to_string(),
+                        })
+                    }
+                    Some(pb::ipc_request::Payload::ExecuteMenuItem(r)) => {
+                        pb::ipc_response::Payload::ExecuteMenuItem(pb::ExecuteMenuItemResponse {
+                            ok: true,
+                            message: format!("CliGame:{}", r.path),
+                        })
+                    }
+                    _ => continue,
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(payload),
+                    })),
+                };
+                if framed
+                    .send(codec::encode_envelope(&resp).unwrap())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+/// Run the server binary against the bridge at `port`, isolated from config files.
+async fn run_server(port: u16, args: &[&str]) -> Output {
+    let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    tokio::task::spawn_blocking(move || {
+        let home = tempfile::tempdir().unwrap();
+        std::process::Command::new(env!("CARGO_BIN_EXE_server"))
+            .current_dir(home.path())
+            .arg("--endpoint")
+            .arg(format!("tcp://127.0.0.1:{port}"))
+            .args(&args)
+            .env("XDG_CONFIG_HOME", home.path())
+            .env("APPDATA", home.path())
+            .env_remove("UNITY_MCP_CONFIG")
+            .env_remove("MCP_IPC_ENDPOINTS")
+            .env("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0")
+            .env("RUST_LOG", "off")
+            .output()
+            .unwrap()
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_status_reports_connected_editor_and_features() {
+    let port = 18998;
+    tokio::spawn(mock_bridge(port));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let out = run_server(port, &["status", "--json"]).await;
+    assert!(out.status.success(), "{:?}", out);
+    let status: serde_json::Value = serde_json::from_slice(&out.stdout).unwrap();
+    let editor = &status["editors"][0];
+    assert_eq!(editor["connected"], true);
+    assert_eq!(editor["project_name"], "CliGame");
+    assert_eq!(editor["endpoint"], format!("tcp://127.0.0.1:{port}"));
+    assert!(
+        !editor["negotiated_features"].as_array().unwrap().is_empty(),
+        "{status}"
+    );
+
+    let out = run_server(port, &["status"]).await;
+    assert!(out.status.success(), "{:?}", out);
+    let line = String::from_utf8(out.stdout).unwrap();
+    assert!(!line.contains("disconnected"), "{line}");
+    assert!(line.contains("CliGame (/work/cli-game)"), "{line}");
+}
+
+#[tokio::test]
+async fn test_status_fails_when_bridge_is_unreachable() {
+    // Nothing listens on this port
+    let out = run_server(19000, &["status", "--wait-ms", "300"]).await;
+    assert!(!out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.contains("disconnected"), "{stdout}");
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(
+        stderr.contains("0 of 1 Unity Editors connected"),
+        "{stderr}"
+    );
+}
+
+#[tokio::test]
+async fn test_call_invokes_tool_once() {
+    let port = 18999;
+    tokio::spawn(mock_bridge(port));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let out = run_server(
+        port,
+        &[
+            "call",
+            "unity_execute_menu_item",
+            "--json",
+            r#"{"path":"Tools/Ping"}"#,
+        ],
+    )
+    .await;
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8(out.stdout).unwrap();
+    assert!(stdout.contains("CliGame:Tools/Ping"), "{stdout}");
+
+    // Unknown tools and malformed arguments fail the command
+    let out = run_server(port, &["call", "unity_no_such_tool"]).await;
+    assert!(!out.status.success(), "{:?}", out);
+    let out = run_server(port, &["call", "unity_execute_menu_item", "--json", "[]"]).await;
+    assert!(!out.status.success(), "{:?}", out);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.contains("must be a JSON object"), "{stderr}");
+}
@@ -0,0 +1,345 @@
+use futures::{SinkExt, StreamExt};
+use server::config::{self, Config, ConfigFile, FileLayer};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+/// Run `server config show --json` in `cwd` and index the output by key.
+fn config_show(
+    cwd: &Path,
+    user_config_home: &Path,
+    envs: &[(&str, &str)],
+    args: &[&str],
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut cmd = std::process::Command::new(env!("CARGO_BIN_EXE_server"));
+    cmd.current_dir(cwd)
+        .args(args)
+        .args(["config", "show", "--json"])
+        .env("XDG_CONFIG_HOME", user_config_home)
+        .env("APPDATA", user_config_home)
+        .env_remove("UNITY_MCP_CONFIG")
+        .env_remove("MCP_IPC_CALL_TIMEOUT_MS")
+        .env_remove("MCP_IPC_HEARTBEAT_INTERVAL_MS")
+        .env_remove("MCP_DISCOVERY_HOST")
+        .env("RUST_LOG", "off");
+    for (k, v) in envs {
+        cmd.env(k, v);
+    }
+    let out = cmd.output().unwrap();
+    assert!(out.status.success(), "{:?}", out);
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&out.stdout).unwrap();
+    entries
+        .into_iter()
+        .map(|e| (e["key"].as_str().unwrap().to_string(), e))
+        .collect()
+}
+
+#[test]
+fn test_config_show_reports_precedence_and_sources() {
+    let root = tempfile::tempdir().unwrap();
+    let user_home = root.path().join("home-config");
+    std::fs::create_dir_all(user_home.join("unity-mcp")).unwrap();
+    let user_file = user_home.join("unity-mcp").join("config.toml");
+    std::fs::write(
+        &user_file,
+        r#"
+        [ipc]
+        call_timeout_ms = 1000
+        heartbeat_interval_ms = 1000
+        token = "user-secret"
+        [discovery]
+        host = "10.0.0.1"
+        "#,
+    )
+    .unwrap();
+    let project = root.path().join("game");
+    std::fs::create_dir_all(project.join("Assets")).unwrap();
+    let project_file = project.join("unity-mcp.toml");
+    std::fs::write(
+        &project_file,
+        r#"
+        [ipc]
+        call_timeout_ms = 2000
+        heartbeat_interval_ms = 2000
+        [tools.unity_build_player]
+        timeout_secs = 3600
+        "#,
+    )
+    .unwrap();
+
+    // Project file over user file; env over files; CLI over env
+    let shown = config_show(
+        &project.join("Assets"),
+        &user_home,
+        &[("MCP_IPC_HEARTBEAT_INTERVAL_MS", "3000")],
+        &[
+            "--set",
+            "ipc.heartbeat_interval_ms=4000",
+            "--log-level",
+            "debug",
+        ],
+    );
+    let source = |key: &str| shown[key]["source"].as_str().unwrap().to_string();
+
+    assert_eq!(shown["discovery.host"]["value"], "10.0.0.1");
+    assert_eq!(
+        source("discovery.host"),
+        format!("user file {}", user_file.display())
+    );
+    assert_eq!(shown["ipc.call_timeout_ms"]["value"], "2000");
+    assert_eq!(
+        source("ipc.call_timeout_ms"),
+        format!("project file {}", project_file.display())
+    );
+    assert_eq!(shown["ipc.heartbeat_interval_ms"]["value"], "4000");
+    assert_eq!(source("ipc.heartbeat_interval_ms"), "cli");
+    assert_eq!(shown["logging.level"]["value"], "debug");
+    assert_eq!(shown["ipc.token"]["value"], "<redacted>");
+    assert_eq!(shown["reconnect.max_backoff_ms"]["value"], "5000");
+    assert_eq!(source("reconnect.max_backoff_ms"), "default");
+    assert_eq!(
+        shown["tools.unity_build_player.timeout_secs"]["value"],
+        3600
+    );
+
+    // Without CLI flags the environment wins
+    let shown = config_show(
+        &project,
+        &user_home,
+        &[("MCP_IPC_HEARTBEAT_INTERVAL_MS", "3000")],
+        &[],
+    );
+    assert_eq!(shown["ipc.heartbeat_interval_ms"]["value"], "3000");
+    assert_eq!(
+        shown["ipc.heartbeat_interval_ms"]["source"],
+        "env MCP_IPC_HEARTBEAT_INTERVAL_MS"
+    );
+
+    // --config replaces the project file search
+    let other = root.path().join("other.toml");
+    std::fs::write(&other, "[ipc]\ncall_timeout_ms = 7000\n").unwrap();
+    let shown = config_show(
+        &project,
+        &user_home,
+        &[],
+        &["--config", other.to_str().unwrap()],
+    );
+    assert_eq!(shown["ipc.call_timeout_ms"]["value"], "7000");
+    assert_eq!(shown["discovery.host"]["value"], "10.0.0.1");
+}
+
+#[test]
+fn test_invalid_config_fails_startup() {
+    let root = tempfile::tempdir().unwrap();
+    let bad = root.path().join("bad.toml");
+    std::fs::write(&bad, "[ipc\n").unwrap();
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_server"))
+        .args(["--config", bad.to_str().unwrap(), "config", "show"])
+        .output()
+        .unwrap();
+    assert!(!out.status.success());
+    assert!(
+        String::from_utf8_lossy(&out.stderr).contains("invalid TOML"),
+        "{:?}",
+        out
+    );
+
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_server"))
+        .args(["--set", "ipc.bogus=1", "config", "show"])
+        .output()
+        .unwrap();
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("unknown setting `ipc.bogus`"));
+
+    // A misspelt transport must not silently fall back to stdio
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_server"))
+        .args(["config", "show"])
+        .env("MCP_TRANSPORT", "htpp")
+        .output()
+        .unwrap();
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("`htpp`") && stderr.contains("stdio, http, streamable-http"),
+        "{stderr}"
+    );
+
+    // Secrets stay off the command line
+    let out = std::process::Command::new(env!("CARGO_BIN_EXE_server"))
+        .args(["--set", "ipc.token=secret", "config", "show"])
+        .output()
+        .unwrap();
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("`ipc.token` is secret"));
+}
+
+/// Mock bridge answering menu items with the requested path.
+async fn mock_menu_bridge(port: u16) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-config".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "config-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::ExecuteMenuItem(r)),
+                })) = env.kind
+                else {
+                    continue;
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::ExecuteMenuItem(
+                            pb::ExecuteMenuItemResponse {
+                                ok: true,
+                                message: format!("ran:{}", r.path),
+                            },
+                        )),
+                    })),
+                };
+                if framed
+                    .send(codec::encode_envelope(&resp).unwrap())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+#[tokio::test]
+async fn test_tool_defaults_fill_missing_arguments() -> anyhow::Result<()> {
+    let bridge_port = 18996;
+    let http_port = 18997;
+    tokio::spawn(mock_menu_bridge(bridge_port));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // The endpoint comes from the file too; this test binary owns the process config
+    let file = ConfigFile::parse(
+        FileLayer::Project,
+        PathBuf::from("unity-mcp.toml"),
+        &format!(
+            r#"
+            [ipc]
+            endpoint = "tcp://127.0.0.1:{bridge_port}"
+            heartbeat_interval_ms = 0
+            [tools.unity_execute_menu_item]
+            path = "Tools/FromConfig"
+            "#
+        ),
+    )?;
+    assert!(config::install(Config::new(vec![file])));
+
+    let svc = McpService::new().await?;
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "config-test", "version": "0.0.1" }
+        }
+    });
+    let resp = client
+        .post(&url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .body(init.to_string())
+        .send()
+        .await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    resp.text().await?;
+    client
+        .post(&url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", &session_id)
+        .body(
+            serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })
+                .to_string(),
+        )
+        .send()
+        .await?;
+
+    let call = |arguments: serde_json::Value| {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": "unity_execute_menu_item", "arguments": arguments }
+        });
+        client
+            .post(&url)
+            .header("accept", ACCEPT)
+            .header("content-type", "application/json")
+            .header("mcp-session-id", &session_id)
+            .body(body.to_string())
+            .send()
+    };
+
+    // The configured default fills the omitted argument once the bridge is up
+    let body = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let body = call(serde_json::json!({})).await?.text().await?;
+            if body.contains("ran:") {
+                return anyhow::Ok(body);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await??;
+    assert!(body.contains("ran:Tools/FromConfig"), "{body}");
+
+    // Explicit arguments win over the default
+    let body = call(serde_json::json!({ "path": "Tools/Explicit" }))
+        .await?
+        .text()
+        .await?;
+    assert!(body.contains("ran:Tools/Explicit"), "{body}");
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    chunking,
+    client::{IpcClient, IpcError},
+    codec, framing,
+    path::IpcConfig,
+};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use tokio::{
+    net::TcpListener,
+    time::{Duration, Instant},
+};
+
+/// Mock bridge that drops the first connection after receiving a request
+/// (like a Unity domain reload) and serves health checks afterwards.
+async fn mock_flaky_bridge(port: u16) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let nth = connections.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-flaky".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: format!("flaky-{nth}"),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                if nth == 0 {
+                    // Domain reload: the request is never answered
+                    return;
+                }
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "mock".to_string(),
+                            status: "ok".to_string(),
+                            project_name: String::new(),
+                            project_path: String::new(),
+                        })),
+                    })),
+                };
+                let _ = framed.send(codec::encode_envelope(&resp).unwrap()).await;
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_in_flight_request_fails_with_closed_and_reconnects() -> anyhow::Result<()> {
+    let port = 18980;
+    tokio::spawn(mock_flaky_bridge(port));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let cfg = IpcConfig {
+        endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+        token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        handshake_timeout: Duration::from_secs(2),
+        total_handshake_timeout: Duration::from_secs(4),
+        call_timeout: Duration::from_secs(2),
+        max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
+    };
+    let client = IpcClient::connect(cfg).await?;
+
+    // The in-flight request fails as soon as the bridge goes away, not at its timeout
+    let started = Instant::now();
+    let result = client.health(Duration::from_secs(10)).await;
+    assert!(
+        matches!(result, Err(IpcError::Closed)),
+        "expected Closed, got {result:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    // Reconnection starts right away; the next connection answers
+    let deadline = Instant::now() + Duration::from_secs(3);
+    loop {
+        match client.health(Duration::from_millis(500)).await {
+            Ok(health) => {
+                assert_eq!(health.status, "ok");
+                break;
+            }
+            Err(e) if Instant::now() < deadline => {
+                tracing::debug!("waiting for reconnect: {e}");
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            Err(e) => panic!("client did not reconnect: {e}"),
+        }
+    }
+
+    Ok(())
+}
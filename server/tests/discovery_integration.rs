@@ -0,0 +1,431 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::discovery::{self, CandidateSource, DiscoveryConfig, EditorDescriptor};
+use server::ipc::path::IpcConfig;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+/// Ports of one test: each test runs its own runtime, so mocks cannot be shared.
+struct Ports {
+    described: u16,
+    scanned: u16,
+    stale: u16,
+    /// Covers `scanned`, `stale` and one port nobody listens on
+    scan_range: &'static str,
+}
+
+/// Mock bridge whose Health reports `project_name`; menu items echo the project name.
+async fn mock_project_bridge(port: u16, project_name: &'static str) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: format!("mock-{project_name}"),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: format!("{project_name}-session"),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                    continue;
+                };
+                let payload = match req.payload {
+                    Some(pb::ipc_request::Payload::Health(_)) => {
+                        pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "6000.0.test".to_string(),
+                            status: "OK".to_string(),
+                            project_name: project_name.to_string(),
+                            project_path: format!("/work/{project_name}"),
+                        })
+                    }
+                    Some(pb::ipc_request::Payload::ExecuteMenuItem(r)) => {
+                        pb::ipc_response::Payload::ExecuteMenuItem(pb::ExecuteMenuItemResponse {
+                            ok: true,
+                            message: format!("{project_name}:{}", r.path),
+                        })
+                    }
+                    _ => continue,
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(payload),
+                    })),
+                };
+                if framed
+                    .send(codec::encode_envelope(&resp).unwrap())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+/// Descriptor directory with one live editor and one left behind by a crashed editor.
+fn descriptor_dir(ports: &Ports) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let descriptors = [
+        EditorDescriptor {
+            endpoint: format!("tcp://127.0.0.1:{}", ports.described),
+            pid: Some(1001),
+            project_name: "FromDescriptor".to_string(),
+            unity_version: "6000.0.test".to_string(),
+            ..Default::default()
+        },
+        EditorDescriptor {
+            endpoint: format!("tcp://127.0.0.1:{}", ports.stale),
+            pid: Some(1002),
+            project_name: "Crashed".to_string(),
+            ..Default::default()
+        },
+    ];
+    for descriptor in &descriptors {
+        discovery::write_descriptor(dir.path(), descriptor).unwrap();
+    }
+    dir
+}
+
+async fn start_bridges(ports: &Ports) {
+    tokio::spawn(mock_project_bridge(ports.described, "DescribedGame"));
+    tokio::spawn(mock_project_bridge(ports.scanned, "ScannedGame"));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+}
+
+async fn call_tool(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+    name: &str,
+    arguments: serde_json::Value,
+) -> anyhow::Result<String> {
+    let call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments }
+    });
+    Ok(client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", session_id)
+        .body(call.to_string())
+        .send()
+        .await?
+        .text()
+        .await?)
+}
+
+fn tool_text(body: &str) -> anyhow::Result<serde_json::Value> {
+    let text = body
+        .lines()
+        .find_map(|l| l.strip_prefix("data: "))
+        .map(serde_json::from_str::<serde_json::Value>)
+        .transpose()?
+        .and_then(|v| v["result"]["content"][0]["text"].as_str().map(String::from))
+        .ok_or_else(|| anyhow::anyhow!("no tool result text in {body}"))?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+#[tokio::test]
+async fn test_discovery_probes_descriptors_and_port_range() -> anyhow::Result<()> {
+    let ports = Ports {
+        described: 18985,
+        scanned: 18986,
+        stale: 18987,
+        scan_range: "18986-18988",
+    };
+    start_bridges(&ports).await;
+    let dir = descriptor_dir(&ports);
+
+    let cfg = DiscoveryConfig {
+        dir: Some(dir.path().to_path_buf()),
+        ports: discovery::parse_port_ranges(ports.scan_range).map_err(anyhow::Error::msg)?,
+        host: "127.0.0.1".to_string(),
+        probe_timeout: Duration::from_millis(500),
+        ipc: IpcConfig::default(),
+    };
+    let editors = discovery::discover(&cfg).await;
+    let endpoints: Vec<_> = editors.iter().map(|e| e.endpoint.as_str()).collect();
+    // The stale descriptor is reported; unanswered scanned ports are not
+    assert_eq!(
+        endpoints,
+        vec![
+            format!("tcp://127.0.0.1:{}", ports.described),
+            format!("tcp://127.0.0.1:{}", ports.stale),
+            format!("tcp://127.0.0.1:{}", ports.scanned),
+        ]
+    );
+
+    let described = &editors[0];
+    assert!(described.reachable);
+    assert_eq!(described.source, CandidateSource::Descriptor);
+    assert_eq!(described.pid, Some(1001));
+    // The editor's own Health wins over the descriptor
+    assert_eq!(described.project_name.as_deref(), Some("DescribedGame"));
+    assert_eq!(described.server_name.as_deref(), Some("mock-DescribedGame"));
+
+    let stale = &editors[1];
+    assert!(!stale.reachable);
+    assert_eq!(stale.project_name.as_deref(), Some("Crashed"));
+    assert!(stale.error.is_some());
+
+    let scanned = &editors[2];
+    assert!(scanned.reachable);
+    assert_eq!(scanned.source, CandidateSource::PortScan);
+    assert_eq!(scanned.project_path.as_deref(), Some("/work/ScannedGame"));
+    assert_eq!(scanned.ready, Some(true));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_probe_never_sends_the_token() -> anyhow::Result<()> {
+    let port = 18943;
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}")).await?;
+    // Bridge that requires a token: records what the probe sent and rejects it
+    let hello = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut framed = framing::into_framed(stream);
+        let bytes = framed.next().await.unwrap().unwrap();
+        let Some(pb::ipc_control::Kind::Hello(hello)) =
+            codec::decode_control(bytes.freeze()).unwrap().kind
+        else {
+            panic!("expected hello");
+        };
+        let reject = pb::IpcControl {
+            kind: Some(pb::ipc_control::Kind::Reject(pb::IpcReject {
+                code: pb::ipc_reject::Code::Unauthenticated as i32,
+                message: "Missing or empty token".to_string(),
+            })),
+        };
+        let _ = framed.send(codec::encode_control(&reject).unwrap()).await;
+        hello
+    });
+
+    let cfg = DiscoveryConfig {
+        dir: None,
+        ports: vec![port],
+        host: "127.0.0.1".to_string(),
+        probe_timeout: Duration::from_millis(500),
+        ipc: IpcConfig {
+            token: Some("s3cret".to_string()),
+            ..IpcConfig::default()
+        },
+    };
+    let editors = discovery::discover(&cfg).await;
+    assert!(hello.await?.token.is_empty());
+    // The rejection still shows a bridge listens there
+    assert_eq!(editors.len(), 1);
+    assert!(editors[0].reachable);
+    assert_eq!(editors[0].ready, None);
+    assert!(editors[0].error.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_editors_subcommand_prints_json() -> anyhow::Result<()> {
+    let ports = Ports {
+        described: 18992,
+        scanned: 18993,
+        stale: 18994,
+        scan_range: "18993-18995",
+    };
+    start_bridges(&ports).await;
+    let dir = descriptor_dir(&ports);
+
+    // The mock bridges run on this runtime, so wait for the binary off it
+    let dir_path = dir.path().to_path_buf();
+    let scan_range = ports.scan_range;
+    let out = tokio::task::spawn_blocking(move || {
+        std::process::Command::new(env!("CARGO_BIN_EXE_server"))
+            .arg("editors")
+            .arg("--json")
+            .arg("--dir")
+            .arg(dir_path)
+            .arg("--ports")
+            .arg(scan_range)
+            .env("RUST_LOG", "off")
+            .output()
+    })
+    .await??;
+    assert!(out.status.success(), "{:?}", out);
+    let editors: serde_json::Value = serde_json::from_slice(&out.stdout)?;
+    let editors = editors.as_array().unwrap();
+    assert_eq!(editors.len(), 3);
+    assert_eq!(editors[0]["project_name"], "DescribedGame");
+    assert_eq!(editors[0]["source"], "descriptor");
+    assert_eq!(editors[1]["reachable"], false);
+    assert_eq!(editors[2]["source"], "port_scan");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_editors_tool_connects_discovered_editors() -> anyhow::Result<()> {
+    let ports = Ports {
+        described: 18979,
+        scanned: 18981,
+        stale: 18982,
+        scan_range: "18981-18982",
+    };
+    start_bridges(&ports).await;
+    let dir = descriptor_dir(&ports);
+    let http_port = 18983;
+
+    // This test binary owns its environment; the configured editor is never reachable
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINT", "tcp://127.0.0.1:18984");
+        std::env::set_var("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0");
+        std::env::set_var("MCP_DISCOVERY_DIR", dir.path());
+        std::env::set_var("MCP_DISCOVERY_PROBE_TIMEOUT_MS", "500");
+    }
+    let svc = McpService::new().await?;
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "discovery-test", "version": "0.0.1" }
+        }
+    });
+    let resp = client
+        .post(&url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .body(init.to_string())
+        .send()
+        .await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    resp.text().await?;
+    client
+        .post(&url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", &session_id)
+        .body(
+            serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })
+                .to_string(),
+        )
+        .send()
+        .await?;
+
+    // Listing alone does not register anything
+    let body = call_tool(
+        &client,
+        &url,
+        &session_id,
+        "unity_list_editors",
+        serde_json::json!({ "ports": ports.scan_range }),
+    )
+    .await?;
+    let listed = tool_text(&body)?;
+    let editors = listed["editors"].as_array().unwrap();
+    // The scanned stale port is the descriptor's endpoint, listed once
+    assert_eq!(editors.len(), 3, "{listed}");
+    assert!(editors.iter().all(|e| e["registered"] == false), "{listed}");
+
+    // Invalid port ranges are rejected
+    let body = call_tool(
+        &client,
+        &url,
+        &session_id,
+        "unity_list_editors",
+        serde_json::json!({ "ports": "9-1" }),
+    )
+    .await?;
+    assert!(body.contains("\"error\""), "{body}");
+
+    // connect=true registers the reachable descriptor editor, but neither the stale
+    // descriptor nor the editor only found by the port scan
+    let body = call_tool(
+        &client,
+        &url,
+        &session_id,
+        "unity_list_editors",
+        serde_json::json!({ "ports": ports.scan_range, "connect": true }),
+    )
+    .await?;
+    let listed = tool_text(&body)?;
+    let registered: Vec<_> = listed["editors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["registered"].as_bool().unwrap())
+        .collect();
+    assert_eq!(registered, vec![true, false, false], "{listed}");
+
+    // Discovered editors become selectable by project once connected
+    let body = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let body = call_tool(
+                &client,
+                &url,
+                &session_id,
+                "unity_execute_menu_item",
+                serde_json::json!({ "path": "Tools/Ping", "project": "DescribedGame" }),
+            )
+            .await?;
+            if body.contains("DescribedGame:Tools/Ping") {
+                return anyhow::Ok(body);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await??;
+    assert!(body.contains("DescribedGame:Tools/Ping"), "{body}");
+
+    let body = call_tool(
+        &client,
+        &url,
+        &session_id,
+        "unity_bridge_status",
+        serde_json::json!({}),
+    )
+    .await?;
+    let status = tool_text(&body)?;
+    assert_eq!(status["editors"].as_array().unwrap().len(), 2, "{status}");
+    Ok(())
+}
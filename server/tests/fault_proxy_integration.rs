@@ -0,0 +1,264 @@
+use server::ipc::{
+    client::{IpcClient, IpcError},
+    path::IpcConfig,
+};
+use server::sim::{
+    BridgeSim, Project, SimOptions,
+    proxy::{FaultProxy, ProxyHandle, Scenario},
+};
+use std::time::{Duration, Instant};
+
+const MAIN: &str = "Assets/Scenes/Main.unity";
+const LEVEL1: &str = "Assets/Scenes/Level1.unity";
+
+/// Simulated bridge behind a proxy running `scenario`; returns the proxy endpoint.
+async fn start(scenario: Scenario) -> (String, ProxyHandle) {
+    let sim = BridgeSim::bind(
+        "127.0.0.1:0",
+        Project::sample("FaultGame", "/work/FaultGame"),
+        SimOptions::default(),
+    )
+    .await
+    .unwrap();
+    let upstream = sim.local_addr().unwrap().to_string();
+    tokio::spawn(sim.run());
+    let proxy = FaultProxy::bind("127.0.0.1:0", &upstream, scenario)
+        .await
+        .unwrap();
+    let (endpoint, handle) = (proxy.endpoint(), proxy.handle());
+    tokio::spawn(proxy.run());
+    (endpoint, handle)
+}
+
+fn scenario(raw: &str) -> Scenario {
+    Scenario::from_toml(raw).unwrap()
+}
+
+fn config(endpoint: &str) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(endpoint.to_string()),
+        token: Some("fault-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        handshake_timeout: Duration::from_millis(200),
+        reconnect_initial_backoff: Duration::from_millis(50),
+        reconnect_max_backoff: Duration::from_millis(200),
+        // Pings would count as frames in the scenarios
+        heartbeat_interval: Duration::ZERO,
+        ..IpcConfig::default()
+    }
+}
+
+/// GUID lookup for one path; the response echoes the path back.
+async fn lookup(
+    client: &IpcClient,
+    path: &str,
+    timeout: Duration,
+) -> Result<Vec<String>, IpcError> {
+    let resp = client
+        .assets_path_to_guid(vec![path.to_string()], timeout)
+        .await?;
+    Ok(resp.map.into_keys().collect())
+}
+
+async fn wait_healthy(client: &IpcClient) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client.health(Duration::from_millis(300)).await.is_err() {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("client recovers");
+}
+
+#[tokio::test]
+async fn test_latency_times_out_and_late_response_is_ignored() {
+    let (endpoint, proxy) = start(scenario(
+        r#"
+        [[rule]]
+        direction = "to_client"
+        frame = "response"
+        nth = 1
+        action = "delay"
+        ms = 400
+        "#,
+    ))
+    .await;
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+
+    let started = Instant::now();
+    let err = lookup(&client, MAIN, Duration::from_millis(100))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, IpcError::RequestTimeout), "{err:?}");
+    assert!(started.elapsed() < Duration::from_millis(350));
+
+    // The late answer to the first lookup must not be handed to the second one
+    let keys = lookup(&client, LEVEL1, Duration::from_secs(2))
+        .await
+        .unwrap();
+    assert_eq!(keys, vec![LEVEL1.to_string()]);
+    assert_eq!(proxy.connections(), 1);
+}
+
+#[tokio::test]
+async fn test_disconnect_mid_request_fails_pending_and_reconnects() {
+    let (endpoint, proxy) = start(scenario(
+        r#"
+        [[rule]]
+        direction = "to_bridge"
+        frame = "request"
+        nth = 2
+        action = "disconnect"
+        "#,
+    ))
+    .await;
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+    client.health(Duration::from_secs(2)).await.unwrap();
+
+    // The in-flight request fails as soon as the connection drops, not at its timeout
+    let started = Instant::now();
+    let err = lookup(&client, MAIN, Duration::from_secs(5))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, IpcError::Closed), "{err:?}");
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    wait_healthy(&client).await;
+    let keys = lookup(&client, MAIN, Duration::from_secs(2)).await.unwrap();
+    assert_eq!(keys, vec![MAIN.to_string()]);
+    assert_eq!(proxy.connections(), 2);
+    assert_eq!(proxy.fired(0), 1);
+}
+
+#[tokio::test]
+async fn test_corrupt_response_is_dropped_and_connection_kept() {
+    let (endpoint, proxy) = start(scenario(
+        r#"
+        [[rule]]
+        direction = "to_client"
+        frame = "response"
+        nth = 1
+        action = "corrupt"
+        "#,
+    ))
+    .await;
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+
+    // The client logs and drops the frame; with no readable correlation id the
+    // caller can only time out
+    let err = client.health(Duration::from_millis(300)).await.unwrap_err();
+    assert!(matches!(err, IpcError::RequestTimeout), "{err:?}");
+    client.health(Duration::from_secs(2)).await.unwrap();
+    assert_eq!(proxy.connections(), 1);
+}
+
+#[tokio::test]
+async fn test_truncated_frame_fails_pending_and_reconnects() {
+    let (endpoint, proxy) = start(scenario(
+        r#"
+        [[rule]]
+        direction = "to_client"
+        frame = "response"
+        nth = 1
+        action = "truncate"
+        keep = 4
+        "#,
+    ))
+    .await;
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+
+    // The bridge dies halfway through writing the response
+    let started = Instant::now();
+    let err = client.health(Duration::from_secs(5)).await.unwrap_err();
+    assert!(matches!(err, IpcError::Closed), "{err:?}");
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    wait_healthy(&client).await;
+    assert_eq!(proxy.connections(), 2);
+}
+
+#[tokio::test]
+async fn test_reordered_responses_reach_their_callers() {
+    let (endpoint, proxy) = start(scenario(
+        r#"
+        [[rule]]
+        direction = "to_client"
+        frame = "response"
+        nth = 1
+        action = "reorder"
+        "#,
+    ))
+    .await;
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+
+    let timeout = Duration::from_secs(2);
+    let (main, level1) = tokio::join!(
+        lookup(&client, MAIN, timeout),
+        lookup(&client, LEVEL1, timeout)
+    );
+    assert_eq!(main.unwrap(), vec![MAIN.to_string()]);
+    assert_eq!(level1.unwrap(), vec![LEVEL1.to_string()]);
+    assert_eq!(proxy.fired(0), 1);
+}
+
+#[tokio::test]
+async fn test_delayed_welcome_respects_handshake_timeout() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("slow-welcome.toml");
+    std::fs::write(
+        &path,
+        r#"
+        # Only the first connection gets a slow editor
+        [[rule]]
+        direction = "to_client"
+        frame = "welcome"
+        connection = 1
+        action = "delay"
+        ms = 800
+        "#,
+    )
+    .unwrap();
+    let (endpoint, proxy) = start(Scenario::load(&path).unwrap()).await;
+
+    let started = Instant::now();
+    let err = IpcClient::connect(config(&endpoint)).await.unwrap_err();
+    assert!(matches!(err, IpcError::ConnectTimeout), "{err:?}");
+    assert!(started.elapsed() < Duration::from_millis(700));
+
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+    client.health(Duration::from_secs(2)).await.unwrap();
+    assert_eq!(proxy.connections(), 2);
+}
+
+#[tokio::test]
+async fn test_slow_welcome_after_domain_reload_is_retried() {
+    let (endpoint, proxy) = start(scenario(
+        r#"
+        # Domain reload during the second request ...
+        [[rule]]
+        direction = "to_bridge"
+        frame = "request"
+        nth = 2
+        action = "disconnect"
+
+        # ... and the editor is still busy when the client comes back
+        [[rule]]
+        direction = "to_client"
+        frame = "welcome"
+        connection = 2
+        action = "delay"
+        ms = 500
+        "#,
+    ))
+    .await;
+    let client = IpcClient::connect(config(&endpoint)).await.unwrap();
+    client.health(Duration::from_secs(2)).await.unwrap();
+    let err = client.health(Duration::from_secs(5)).await.unwrap_err();
+    assert!(matches!(err, IpcError::Closed), "{err:?}");
+
+    wait_healthy(&client).await;
+    assert_eq!(proxy.fired(1), 1);
+    assert!(proxy.connections() >= 3, "{}", proxy.connections());
+}
@@ -0,0 +1,142 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    chunking,
+    client::{IpcClient, IpcError},
+    codec, framing,
+    path::IpcConfig,
+};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use tokio::{net::TcpListener, time::Duration};
+
+const LIMIT: usize = 16 * 1024;
+
+/// Mock bridge whose first health response is far over the client's frame limit.
+async fn mock_large_response_bridge(port: u16, connections: Arc<AtomicUsize>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        connections.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-large".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "large-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            let mut served = 0;
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(_)) = env.kind else {
+                    continue;
+                };
+                let version = if served == 0 {
+                    "x".repeat(4 * LIMIT)
+                } else {
+                    "1.0".to_string()
+                };
+                served += 1;
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version,
+                            status: "OK".to_string(),
+                            ..Default::default()
+                        })),
+                    })),
+                };
+                let _ = framed.send(codec::encode_envelope(&resp).unwrap()).await;
+            }
+        });
+    }
+}
+
+fn test_config(port: u16) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+        token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        handshake_timeout: Duration::from_secs(2),
+        total_handshake_timeout: Duration::from_secs(4),
+        call_timeout: Duration::from_secs(2),
+        max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::ZERO,
+        heartbeat_miss_threshold: 3,
+        max_frame_len: LIMIT,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
+    }
+}
+
+#[tokio::test]
+async fn test_oversized_frames_fail_request_but_keep_connection() -> anyhow::Result<()> {
+    let port = 18991;
+    let connections = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(mock_large_response_bridge(port, connections.clone()));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let client = IpcClient::connect(test_config(port)).await?;
+
+    // Oversized response: fails fast with the request's correlation id and size
+    match client.health(Duration::from_secs(2)).await {
+        Err(IpcError::FrameTooLarge {
+            correlation_id,
+            size,
+            limit,
+        }) => {
+            assert!(!correlation_id.is_empty());
+            assert!(size > LIMIT);
+            assert_eq!(limit, LIMIT);
+        }
+        other => panic!("expected FrameTooLarge, got {other:?}"),
+    }
+
+    // Oversized request: rejected locally before it is sent
+    let err = client
+        .execute_menu_item("M".repeat(2 * LIMIT), Duration::from_secs(2))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, IpcError::FrameTooLarge { .. }), "{err}");
+
+    // The same connection keeps working
+    let health = client.health(Duration::from_secs(2)).await?;
+    assert_eq!(health.version, "1.0");
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
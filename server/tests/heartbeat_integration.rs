@@ -0,0 +1,252 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{chunking, client::IpcClient, codec, framing, path::IpcConfig};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use tokio::{net::TcpListener, sync::mpsc, time::Duration};
+
+/// Mock bridge answering `answered_pings` pings on the first connection, then going
+/// silent without closing the socket (a half-open connection). Later connections
+/// answer every ping.
+async fn mock_heartbeat_bridge(port: u16, answered_pings: usize, connections: Arc<AtomicUsize>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let n = connections.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-heartbeat".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: format!("heartbeat-session-{n}"),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            let mut answered = 0;
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                    kind: Some(pb::ipc_control::Kind::Ping(ping)),
+                })) = env.kind
+                else {
+                    continue;
+                };
+                if n == 0 && answered >= answered_pings {
+                    // Keep reading but never answer again
+                    continue;
+                }
+                answered += 1;
+                let pong = pb::IpcEnvelope {
+                    correlation_id: String::new(),
+                    kind: Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                        kind: Some(pb::ipc_control::Kind::Pong(pb::IpcPong {
+                            nonce: ping.nonce,
+                            ping_sent_monotonic_ns: ping.sent_monotonic_ns,
+                        })),
+                    })),
+                };
+                let _ = framed.send(codec::encode_envelope(&pong).unwrap()).await;
+            }
+        });
+    }
+}
+
+/// Mock bridge that takes `delay` to answer a health request but keeps answering pings
+/// while it works, as the bridge does by not awaiting handlers in its read loop.
+async fn mock_slow_request_bridge(port: u16, delay: Duration, connections: Arc<AtomicUsize>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let n = connections.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-slow".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: format!("slow-session-{n}"),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            let (mut writer, mut reader) = framed.split();
+            let (tx, mut rx) = mpsc::unbounded_channel::<pb::IpcEnvelope>();
+            tokio::spawn(async move {
+                while let Some(env) = rx.recv().await {
+                    if writer
+                        .send(codec::encode_envelope(&env).unwrap())
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            });
+
+            while let Some(Ok(bytes)) = reader.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                match env.kind {
+                    Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                        kind: Some(pb::ipc_control::Kind::Ping(ping)),
+                    })) => {
+                        let _ = tx.send(pb::IpcEnvelope {
+                            correlation_id: String::new(),
+                            kind: Some(pb::ipc_envelope::Kind::Control(pb::IpcControl {
+                                kind: Some(pb::ipc_control::Kind::Pong(pb::IpcPong {
+                                    nonce: ping.nonce,
+                                    ping_sent_monotonic_ns: ping.sent_monotonic_ns,
+                                })),
+                            })),
+                        });
+                    }
+                    Some(pb::ipc_envelope::Kind::Request(_)) => {
+                        let tx = tx.clone();
+                        let correlation_id = env.correlation_id;
+                        tokio::spawn(async move {
+                            tokio::time::sleep(delay).await;
+                            let _ = tx.send(pb::IpcEnvelope {
+                                correlation_id: correlation_id.clone(),
+                                kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                                    correlation_id,
+                                    payload: Some(pb::ipc_response::Payload::Health(
+                                        pb::HealthResponse {
+                                            ready: true,
+                                            status: "OK".to_string(),
+                                            ..Default::default()
+                                        },
+                                    )),
+                                })),
+                            });
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+}
+
+fn test_config(port: u16) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+        token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        handshake_timeout: Duration::from_secs(2),
+        total_handshake_timeout: Duration::from_secs(4),
+        call_timeout: Duration::from_secs(2),
+        max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_millis(50),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
+    }
+}
+
+#[tokio::test]
+async fn test_heartbeat_measures_rtt_and_reconnects_half_open_connection() -> anyhow::Result<()> {
+    let port = 18990;
+    let connections = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(mock_heartbeat_bridge(port, 2, connections.clone()));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let client = IpcClient::connect(test_config(port)).await?;
+    assert!(client.last_rtt().await.is_none());
+
+    // The first pongs give us a latency figure
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while client.last_rtt().await.is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    // Then the bridge goes silent; missed pongs must trigger a reconnect
+    tokio::time::timeout(Duration::from_secs(3), async {
+        while connections.load(Ordering::SeqCst) < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    // The new connection answers again
+    tokio::time::timeout(Duration::from_secs(2), async {
+        while client.last_rtt().await.is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_slow_request_does_not_starve_heartbeat() -> anyhow::Result<()> {
+    let port = 18991;
+    let connections = Arc::new(AtomicUsize::new(0));
+    let cfg = test_config(port);
+    // Well past the miss window of interval × threshold
+    let delay = cfg.heartbeat_interval * cfg.heartbeat_miss_threshold * 5;
+    tokio::spawn(mock_slow_request_bridge(port, delay, connections.clone()));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let client = IpcClient::connect(cfg).await?;
+    let health = client.health(Duration::from_secs(5)).await?;
+    assert_eq!(health.status, "OK");
+    assert!(client.last_rtt().await.is_some());
+    assert_eq!(
+        connections.load(Ordering::SeqCst),
+        1,
+        "healthy connection was dropped"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use server::mcp::service::McpService;
+use std::time::Duration;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+const TOKEN: &str = "http-s3cret";
+
+fn set_env() {
+    // This test binary owns its environment; every test here runs with the same token
+    unsafe {
+        std::env::set_var("MCP_HTTP_TOKEN", TOKEN);
+        std::env::set_var("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0");
+        std::env::remove_var("MCP_ALLOW_REMOTE");
+    }
+}
+
+async fn start_http_server(port: u16) -> anyhow::Result<String> {
+    set_env();
+    let svc = McpService::new().await?;
+    let bind_addr = format!("127.0.0.1:{port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    Ok(format!("http://127.0.0.1:{port}/mcp"))
+}
+
+fn initialize(client: &reqwest::Client, url: &str) -> reqwest::RequestBuilder {
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "http-guard-test", "version": "0.0.1" }
+        }
+    });
+    client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .body(init.to_string())
+}
+
+#[tokio::test]
+async fn test_http_requires_bearer_token() -> anyhow::Result<()> {
+    let url = start_http_server(18940).await?;
+    let client = reqwest::Client::new();
+
+    let resp = initialize(&client, &url).send().await?;
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let resp = initialize(&client, &url)
+        .bearer_auth("wrong")
+        .send()
+        .await?;
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let resp = initialize(&client, &url).bearer_auth(TOKEN).send().await?;
+    assert!(resp.status().is_success(), "{}", resp.status());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_rejects_foreign_origin() -> anyhow::Result<()> {
+    let url = start_http_server(18941).await?;
+    let client = reqwest::Client::new();
+
+    let resp = initialize(&client, &url)
+        .bearer_auth(TOKEN)
+        .header("origin", "https://evil.example")
+        .send()
+        .await?;
+    assert_eq!(resp.status(), reqwest::StatusCode::FORBIDDEN);
+
+    let resp = initialize(&client, &url)
+        .bearer_auth(TOKEN)
+        .header("origin", "http://localhost:5173")
+        .send()
+        .await?;
+    assert!(resp.status().is_success(), "{}", resp.status());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_refuses_non_loopback_bind() -> anyhow::Result<()> {
+    set_env();
+    let svc = McpService::new().await?;
+    let err = tokio::time::timeout(Duration::from_secs(5), svc.serve_http("0.0.0.0:18942"))
+        .await?
+        .unwrap_err();
+    assert!(err.to_string().contains("server.allow_remote"), "{err}");
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use futures::{SinkExt, StreamExt};
 use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{chunking, codec, framing};
 use server::ipc::{
     client::{IpcClient, IpcError},
     features::{FeatureFlag, FeatureSet},
     path::IpcConfig,
 };
-use server::ipc::{codec, framing};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
 use tokio::{net::TcpListener, time::Duration};
 
 /// T01-compliant mock Unity server for testing IPC handshake
@@ -132,11 +136,22 @@ async fn test_t01_basic_handshake_success() -> anyhow::Result<()> {
     let cfg = IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{port}")),
         token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_secs(5),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(1), // Don't retry for test
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     // Test T01 handshake
@@ -162,11 +177,22 @@ async fn test_t01_basic_token_rejection() -> anyhow::Result<()> {
     let cfg = IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{port}")),
         token: Some("".to_string()), // Empty token should trigger rejection
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_secs(5),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(1), // Don't retry for test
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     // Should fail with authentication error
@@ -189,11 +215,22 @@ async fn test_connection_timeout() {
         // 宛先は正しい形式だが応答しない（TEST-NET-3）
         endpoint: Some("tcp://203.0.113.1:12345".to_string()),
         token: None,
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_millis(100),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(3),
         call_timeout: Duration::from_secs(1),
         max_reconnect_attempts: Some(1), // Don't retry for test
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     let result = IpcClient::connect(cfg).await;
@@ -216,11 +253,22 @@ async fn test_handshake_authentication_failure() -> anyhow::Result<()> {
     let cfg = IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{port}")),
         token: Some("wrong-token".to_string()),
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_secs(5),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     let result = IpcClient::connect(cfg).await;
@@ -262,11 +310,22 @@ async fn test_connect_with_retry_success() -> anyhow::Result<()> {
     let cfg = IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{port}")),
         token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_millis(200),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(5), // Allow retries
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     // Should succeed after retries
@@ -288,11 +347,22 @@ async fn test_connect_with_retry_permanent_failure() -> anyhow::Result<()> {
     let cfg = IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{port}")),
         token: Some("".to_string()), // Empty token will be permanently rejected
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_secs(5),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(3),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     // Should fail immediately without retries for authentication errors
@@ -310,6 +380,9 @@ async fn test_connect_with_retry_permanent_failure() -> anyhow::Result<()> {
 struct MockUnityServer {
     supported_features: Vec<String>,
     override_schema_hash_for_test: Option<Vec<u8>>,
+    // Algorithm announced in welcome meta when "ipc.compression" is accepted
+    compression: Option<codec::Compression>,
+    compressed_requests: Arc<AtomicUsize>,
     // project_root removed from protocol; no expectation needed
 }
 
@@ -323,10 +396,22 @@ impl MockUnityServer {
                 "ops.progress".to_string(),
             ],
             override_schema_hash_for_test: None,
+            compression: None,
+            compressed_requests: Arc::new(AtomicUsize::new(0)),
             // no project_root expectation
         }
     }
 
+    pub fn with_compression(mut self, compression: codec::Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Number of requests that arrived as a `CompressedEnvelope`
+    pub fn compressed_requests(&self) -> Arc<AtomicUsize> {
+        self.compressed_requests.clone()
+    }
+
     pub fn with_supported_features(mut self, features: Vec<&str>) -> Self {
         self.supported_features = features.into_iter().map(|s| s.to_string()).collect();
         self
@@ -343,10 +428,12 @@ impl MockUnityServer {
         let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
         let supported_features = self.supported_features.clone();
         let override_schema_hash = self.override_schema_hash_for_test.clone();
+        let compression = self.compression;
 
         while let Ok((stream, _)) = listener.accept().await {
             let features_clone = supported_features.clone();
             let hash_override = override_schema_hash.clone();
+            let compressed_requests = self.compressed_requests.clone();
             // Clone expected project_root per-connection to avoid moving a shared Option across iterations
             // no project_root expectation
             tokio::spawn(async move {
@@ -383,7 +470,16 @@ impl MockUnityServer {
                         .into_iter()
                         .filter(|f| features_clone.contains(f))
                         .collect();
+                    let compression = compression
+                        .filter(|_| accepted_features.iter().any(|f| f == "ipc.compression"));
 
+                    let mut meta = std::collections::HashMap::from([(
+                        "platform".to_string(),
+                        "test".to_string(),
+                    )]);
+                    if let Some(c) = compression {
+                        meta.insert("compression".to_string(), c.as_str().to_string());
+                    }
                     let welcome = pb::IpcWelcome {
                         ipc_version: hello.ipc_version,
                         accepted_features,
@@ -392,16 +488,83 @@ impl MockUnityServer {
                         server_version: "0.1.0".to_string(),
                         editor_version: "Unity 6000.0.test".to_string(),
                         session_id: "test-session-123".to_string(),
-                        meta: std::collections::HashMap::from([(
-                            "platform".to_string(),
-                            "test".to_string(),
-                        )]),
+                        meta,
                     };
                     let welcome_control = pb::IpcControl {
                         kind: Some(pb::ipc_control::Kind::Welcome(welcome)),
                     };
                     let welcome_bytes = codec::encode_control(&welcome_control).unwrap();
                     let _ = framed.send(welcome_bytes).await;
+
+                    // Answer Health with a large (compressible) payload, echo menu paths and
+                    // fail asset moves / component adds / prefab creates with a status code
+                    while let Some(Ok(bytes)) = framed.next().await {
+                        let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                            continue;
+                        };
+                        if matches!(env.kind, Some(pb::ipc_envelope::Kind::Compressed(_))) {
+                            compressed_requests.fetch_add(1, Ordering::SeqCst);
+                        }
+                        let Ok(env) = codec::inflate_envelope(env, framing::DEFAULT_MAX_FRAME_LEN)
+                        else {
+                            continue;
+                        };
+                        let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                            continue;
+                        };
+                        let payload = match req.payload {
+                            Some(pb::ipc_request::Payload::Health(_)) => {
+                                pb::ipc_response::Payload::Health(pb::HealthResponse {
+                                    ready: true,
+                                    version: "mock-unity-server ".repeat(1024),
+                                    status: "ok".to_string(),
+                                    ..Default::default()
+                                })
+                            }
+                            Some(pb::ipc_request::Payload::ExecuteMenuItem(r)) => {
+                                pb::ipc_response::Payload::ExecuteMenuItem(
+                                    pb::ExecuteMenuItemResponse {
+                                        ok: true,
+                                        message: r.path,
+                                    },
+                                )
+                            }
+                            Some(pb::ipc_request::Payload::Assets(_)) => {
+                                pb::ipc_response::Payload::Assets(pb::AssetsResponse {
+                                    status_code: 5,
+                                    message: "asset not found".to_string(),
+                                    payload: None,
+                                })
+                            }
+                            Some(pb::ipc_request::Payload::Component(_)) => {
+                                pb::ipc_response::Payload::Component(pb::ComponentResponse {
+                                    status_code: 2,
+                                    message: "unknown component type".to_string(),
+                                    payload: None,
+                                })
+                            }
+                            Some(pb::ipc_request::Payload::Prefab(_)) => {
+                                pb::ipc_response::Payload::Prefab(pb::PrefabResponse {
+                                    status_code: 13,
+                                    message: "prefab save failed".to_string(),
+                                    payload: None,
+                                })
+                            }
+                            _ => continue,
+                        };
+                        let resp = pb::IpcEnvelope {
+                            correlation_id: env.correlation_id.clone(),
+                            kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                                correlation_id: env.correlation_id,
+                                payload: Some(payload),
+                            })),
+                        };
+                        let resp_bytes = match compression {
+                            Some(c) => codec::encode_envelope_compressed(&resp, c, 1024).unwrap(),
+                            None => codec::encode_envelope(&resp).unwrap(),
+                        };
+                        let _ = framed.send(resp_bytes).await;
+                    }
                 }
             });
         }
@@ -414,11 +577,22 @@ fn test_config(port: u16) -> IpcConfig {
     IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{}", port)),
         token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_secs(5),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     }
 }
 
@@ -554,11 +728,22 @@ async fn test_schema_hash_mismatch_rejection() -> anyhow::Result<()> {
     let cfg = IpcConfig {
         endpoint: Some(format!("tcp://127.0.0.1:{port}")),
         token: Some("test-token".to_string()), // Valid token - schema hash is the issue
+        token_file: None,
+        unity_project: None,
         connect_timeout: Duration::from_secs(5),
         handshake_timeout: Duration::from_secs(2),
         total_handshake_timeout: Duration::from_secs(8),
         call_timeout: Duration::from_secs(5),
         max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
     };
 
     let result = IpcClient::connect(cfg).await;
@@ -575,4 +760,141 @@ async fn test_schema_hash_mismatch_rejection() -> anyhow::Result<()> {
     Ok(())
 }
 
+async fn assert_compressed_roundtrip(port: u16, compression: codec::Compression) {
+    let server = MockUnityServer::new()
+        .with_supported_features(vec!["assets.basic", "ipc.compression"])
+        .with_compression(compression);
+    let compressed_requests = server.compressed_requests();
+    tokio::spawn(async move {
+        let _ = server.start(port).await;
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut cfg = test_config(port);
+    cfg.compression_threshold = Some(1024);
+    let client = IpcClient::connect(cfg).await.unwrap();
+    assert!(client.has_feature(FeatureFlag::IpcCompression).await);
+
+    // Compressed response is inflated transparently
+    let health = client.health(Duration::from_secs(2)).await.unwrap();
+    assert_eq!(health.version, "mock-unity-server ".repeat(1024));
+
+    // Small requests stay plain, large ones go out compressed
+    let small = client
+        .execute_menu_item("Window/General/Console".into(), Duration::from_secs(2))
+        .await
+        .unwrap();
+    assert_eq!(small.message, "Window/General/Console");
+    assert_eq!(compressed_requests.load(Ordering::SeqCst), 0);
+
+    let path = format!("Tools/{}", "Nested/".repeat(1024));
+    let large = client
+        .execute_menu_item(path.clone(), Duration::from_secs(2))
+        .await
+        .unwrap();
+    assert_eq!(large.message, path);
+    assert_eq!(compressed_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_bridge_status_codes_become_typed_errors() -> anyhow::Result<()> {
+    let port = 18930;
+
+    let server = MockUnityServer::new().with_supported_features(vec![
+        "assets.basic",
+        "components.basic",
+        "prefabs.basic",
+    ]);
+    tokio::spawn(async move {
+        let _ = server.start(port).await;
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let client = IpcClient::connect(test_config(port)).await?;
+    let timeout = Duration::from_secs(2);
+
+    let err = client
+        .assets_move("Assets/Missing.png".into(), "Assets/B.png".into(), timeout)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, IpcError::NotFound(m) if m.contains("asset not found")),
+        "{err}"
+    );
+    assert_eq!(err.status_code(), Some(5));
+
+    let err = client
+        .component_add("Player".into(), "NoSuchComponent".into(), timeout)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, IpcError::InvalidArgument(_)), "{err}");
+
+    let err = client
+        .prefab_create("Player".into(), "Assets/P.prefab".into(), timeout)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, IpcError::Internal(_)), "{err}");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_compression_zstd_negotiated() {
+    assert_compressed_roundtrip(18910, codec::Compression::Zstd).await;
+}
+
+#[tokio::test]
+async fn test_compression_deflate_negotiated() {
+    assert_compressed_roundtrip(18911, codec::Compression::Deflate).await;
+}
+
+#[tokio::test]
+async fn test_compression_not_accepted_falls_back_to_plain_frames() -> anyhow::Result<()> {
+    let port = 18912;
+
+    // Bridge without "ipc.compression": the client offers it but must not use it
+    let server = MockUnityServer::new().with_supported_features(vec!["assets.basic"]);
+    let compressed_requests = server.compressed_requests();
+    tokio::spawn(async move {
+        let _ = server.start(port).await;
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut cfg = test_config(port);
+    cfg.compression_threshold = Some(1024);
+    let client = IpcClient::connect(cfg).await?;
+    assert!(!client.has_feature(FeatureFlag::IpcCompression).await);
+
+    let health = client.health(Duration::from_secs(2)).await?;
+    assert_eq!(health.version, "mock-unity-server ".repeat(1024));
+
+    let path = format!("Tools/{}", "Nested/".repeat(1024));
+    let resp = client
+        .execute_menu_item(path.clone(), Duration::from_secs(2))
+        .await?;
+    assert_eq!(resp.message, path);
+    assert_eq!(compressed_requests.load(Ordering::SeqCst), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_compression_disabled_is_not_offered() -> anyhow::Result<()> {
+    let port = 18913;
+
+    let server = MockUnityServer::new()
+        .with_supported_features(vec!["assets.basic", "ipc.compression"])
+        .with_compression(codec::Compression::Zstd);
+    tokio::spawn(async move {
+        let _ = server.start(port).await;
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // test_config leaves compression off
+    let client = IpcClient::connect(test_config(port)).await?;
+    assert!(!client.has_feature(FeatureFlag::IpcCompression).await);
+
+    Ok(())
+}
+
 // project_root mismatch test removed: field no longer in protocol
@@ -0,0 +1,386 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use server::sim::{BridgeSim, Project, SimOptions};
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+/// Tests configure their service through the environment, one at a time.
+static ENV: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// Mock bridge for one Unity project.
+///
+/// Health reports the project's identity and menu items answer with the project name,
+/// so the test can tell which editor a tool call was routed to.
+async fn mock_project_bridge(port: u16, project_name: &'static str, project_path: &'static str) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: format!("mock-{project_name}"),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: format!("{project_name}-session"),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                    continue;
+                };
+                let payload = match req.payload {
+                    Some(pb::ipc_request::Payload::Health(_)) => {
+                        pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "6000.0.test".to_string(),
+                            status: "OK".to_string(),
+                            project_name: project_name.to_string(),
+                            project_path: project_path.to_string(),
+                        })
+                    }
+                    Some(pb::ipc_request::Payload::ExecuteMenuItem(r)) => {
+                        pb::ipc_response::Payload::ExecuteMenuItem(pb::ExecuteMenuItemResponse {
+                            ok: true,
+                            message: format!("{project_name}:{}", r.path),
+                        })
+                    }
+                    _ => continue,
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(payload),
+                    })),
+                };
+                if framed
+                    .send(codec::encode_envelope(&resp).unwrap())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+async fn initialize(client: &reqwest::Client, url: &str) -> anyhow::Result<String> {
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "multi-editor-test", "version": "0.0.1" }
+        }
+    });
+    let resp = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .body(init.to_string())
+        .send()
+        .await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    resp.text().await?;
+    client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", &session_id)
+        .body(
+            serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })
+                .to_string(),
+        )
+        .send()
+        .await?;
+    Ok(session_id)
+}
+
+async fn call_tool(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+    name: &str,
+    arguments: serde_json::Value,
+) -> anyhow::Result<String> {
+    call_tool_with_meta(client, url, session_id, name, arguments, None).await
+}
+
+async fn call_tool_with_meta(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+    name: &str,
+    arguments: serde_json::Value,
+    meta: Option<serde_json::Value>,
+) -> anyhow::Result<String> {
+    let mut call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments }
+    });
+    if let Some(meta) = meta {
+        call["params"]["_meta"] = meta;
+    }
+    Ok(client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", session_id)
+        .body(call.to_string())
+        .send()
+        .await?
+        .text()
+        .await?)
+}
+
+#[tokio::test]
+async fn test_tools_are_routed_by_project() -> anyhow::Result<()> {
+    let client_port = 18976;
+    let shared_port = 18977;
+    let http_port = 18978;
+    tokio::spawn(mock_project_bridge(
+        client_port,
+        "ClientGame",
+        "/work/client-game",
+    ));
+    tokio::spawn(mock_project_bridge(
+        shared_port,
+        "SharedPackages",
+        "/work/shared-packages",
+    ));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let env = ENV.lock().await;
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var(
+            "MCP_IPC_ENDPOINTS",
+            format!("tcp://127.0.0.1:{client_port}, tcp://127.0.0.1:{shared_port}"),
+        );
+        std::env::set_var("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0");
+    }
+    let svc = McpService::new().await?;
+    drop(env);
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let session_id = initialize(&client, &url).await?;
+
+    // Both editors connect and report which project they have open
+    let status = tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let body = call_tool(
+                &client,
+                &url,
+                &session_id,
+                "unity_bridge_status",
+                serde_json::json!({}),
+            )
+            .await?;
+            if body.contains("ClientGame") && body.contains("SharedPackages") {
+                return anyhow::Ok(body);
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await??;
+    assert!(status.contains("/work/shared-packages"), "{status}");
+    assert!(
+        status.contains(&format!("tcp://127.0.0.1:{shared_port}")),
+        "{status}"
+    );
+
+    let menu = |project: Option<&str>| {
+        let mut args = serde_json::json!({ "path": "Tools/Ping" });
+        if let Some(p) = project {
+            args["project"] = p.into();
+        }
+        call_tool(&client, &url, &session_id, "unity_execute_menu_item", args)
+    };
+
+    // Without a selector the first configured editor answers
+    let body = menu(None).await?;
+    assert!(body.contains("ClientGame:Tools/Ping"), "{body}");
+
+    // Select by project name, project path or endpoint
+    let body = menu(Some("SharedPackages")).await?;
+    assert!(body.contains("SharedPackages:Tools/Ping"), "{body}");
+    let body = menu(Some("/work/client-game/")).await?;
+    assert!(body.contains("ClientGame:Tools/Ping"), "{body}");
+    let body = menu(Some(&format!("tcp://127.0.0.1:{shared_port}"))).await?;
+    assert!(body.contains("SharedPackages:Tools/Ping"), "{body}");
+
+    // Unknown projects are rejected with the list of known editors
+    let body = menu(Some("Elsewhere")).await?;
+    assert!(body.contains("\"error\""), "{body}");
+    assert!(body.contains("Unknown Unity project"), "{body}");
+    assert!(body.contains("ClientGame, SharedPackages"), "{body}");
+
+    // Status of a selected editor is flattened next to the full list
+    let body = call_tool(
+        &client,
+        &url,
+        &session_id,
+        "unity_bridge_status",
+        serde_json::json!({ "project": "SharedPackages" }),
+    )
+    .await?;
+    let text = body
+        .lines()
+        .find_map(|l| l.strip_prefix("data: "))
+        .map(serde_json::from_str::<serde_json::Value>)
+        .transpose()?
+        .and_then(|v| v["result"]["content"][0]["text"].as_str().map(String::from))
+        .expect("tool result text");
+    let status: serde_json::Value = serde_json::from_str(&text)?;
+    assert_eq!(status["project_name"], "SharedPackages");
+    assert_eq!(status["connected"], true);
+    assert_eq!(status["editors"].as_array().unwrap().len(), 2);
+    assert_eq!(status["editors"][0]["project_name"], "ClientGame");
+
+    Ok(())
+}
+
+async fn build_player(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+    project: &str,
+    progress_token: &str,
+) -> anyhow::Result<String> {
+    let args = serde_json::json!({
+        "platform": "BP_STANDALONE_LINUX64",
+        "outputPath": "Builds/Linux/Game",
+        "project": project,
+    });
+    let meta = serde_json::json!({ "progressToken": progress_token });
+    call_tool_with_meta(
+        client,
+        url,
+        session_id,
+        "unity_build_player",
+        args,
+        Some(meta),
+    )
+    .await
+}
+
+async fn start_sim(name: &str, path: &str) -> String {
+    let options = SimOptions {
+        op_step: Duration::from_millis(100),
+        ..SimOptions::default()
+    };
+    let sim = BridgeSim::bind("127.0.0.1:0", Project::sample(name, path), options)
+        .await
+        .unwrap();
+    let endpoint = sim.endpoint();
+    tokio::spawn(sim.run());
+    endpoint
+}
+
+#[tokio::test]
+async fn test_concurrent_operations_report_progress_to_their_own_session() -> anyhow::Result<()> {
+    let http_port = 18979;
+    let alpha = start_sim("Alpha", "/work/alpha").await;
+    let beta = start_sim("Beta", "/work/beta").await;
+
+    let env = ENV.lock().await;
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINTS", format!("{alpha}, {beta}"));
+        std::env::set_var("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0");
+    }
+    let svc = McpService::new().await?;
+    drop(env);
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let alpha_session = initialize(&client, &url).await?;
+    let beta_session = initialize(&client, &url).await?;
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        loop {
+            let body = call_tool(
+                &client,
+                &url,
+                &alpha_session,
+                "unity_bridge_status",
+                serde_json::json!({}),
+            )
+            .await?;
+            if body.contains("/work/alpha") && body.contains("/work/beta") {
+                return anyhow::Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await??;
+
+    // Each session builds on its own editor; both builds run at the same time
+    let (alpha_body, beta_body) = tokio::try_join!(
+        build_player(&client, &url, &alpha_session, "Alpha", "alpha-build"),
+        build_player(&client, &url, &beta_session, "Beta", "beta-build"),
+    )?;
+
+    for (body, own, other) in [
+        (
+            &alpha_body,
+            ("alpha-build", "/work/alpha"),
+            ("beta-build", "/work/beta"),
+        ),
+        (
+            &beta_body,
+            ("beta-build", "/work/beta"),
+            ("alpha-build", "/work/alpha"),
+        ),
+    ] {
+        assert!(body.contains("notifications/progress"), "{body}");
+        assert!(body.contains(own.0), "{body}");
+        assert!(body.contains(own.1), "{body}");
+        assert!(!body.contains(other.0), "{body}");
+        assert!(!body.contains(other.1), "{body}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{chunking, client::IpcClient, codec, framing, path::IpcConfig};
+use tokio::{net::TcpListener, time::Duration};
+
+/// Mock bridge that knows a single running operation "op-1"
+async fn mock_operations_bridge(port: u16) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-operations".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "ops-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(req)) = env.kind else {
+                    continue;
+                };
+                let payload = match req.payload {
+                    Some(pb::ipc_request::Payload::OperationGet(r)) => {
+                        let known = r.id == "op-1";
+                        pb::ipc_response::Payload::OperationGet(pb::OperationGetResponse {
+                            state: if known { "running" } else { "unknown" }.to_string(),
+                            message: if known { "Import: 3 items" } else { "" }.to_string(),
+                            id: r.id,
+                        })
+                    }
+                    Some(pb::ipc_request::Payload::OperationCancel(r)) => {
+                        pb::ipc_response::Payload::OperationCancel(pb::OperationCancelResponse {
+                            accepted: r.id == "op-1",
+                        })
+                    }
+                    _ => continue,
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(payload),
+                    })),
+                };
+                let _ = framed.send(codec::encode_envelope(&resp).unwrap()).await;
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn test_config(port: u16) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+        token: Some("test-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        handshake_timeout: Duration::from_secs(2),
+        total_handshake_timeout: Duration::from_secs(4),
+        call_timeout: Duration::from_secs(2),
+        max_reconnect_attempts: Some(1),
+        reconnect_initial_backoff: Duration::from_millis(200),
+        reconnect_max_backoff: Duration::from_secs(5),
+        heartbeat_interval: Duration::from_secs(5),
+        heartbeat_miss_threshold: 3,
+        max_frame_len: framing::DEFAULT_MAX_FRAME_LEN,
+        compression_threshold: None,
+        max_response_len: chunking::DEFAULT_MAX_RESPONSE_LEN,
+        tls: Default::default(),
+        record: None,
+    }
+}
+
+#[tokio::test]
+async fn test_operation_get_and_cancel() -> anyhow::Result<()> {
+    let port = 18960;
+    tokio::spawn(mock_operations_bridge(port));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let client = IpcClient::connect(test_config(port)).await?;
+
+    let running = client
+        .operation_get("op-1".to_string(), Duration::from_secs(1))
+        .await?;
+    assert_eq!(running.id, "op-1");
+    assert_eq!(running.state, "running");
+
+    let unknown = client
+        .operation_get("op-missing".to_string(), Duration::from_secs(1))
+        .await?;
+    assert_eq!(unknown.state, "unknown");
+
+    let accepted = client
+        .operation_cancel("op-1".to_string(), Duration::from_secs(1))
+        .await?;
+    assert!(accepted.accepted);
+
+    let rejected = client
+        .operation_cancel("op-missing".to_string(), Duration::from_secs(1))
+        .await?;
+    assert!(!rejected.accepted);
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+fn op_event(
+    op_id: &str,
+    kind: pb::operation_event::Kind,
+    progress: i32,
+    message: &str,
+) -> bytes::Bytes {
+    let env = pb::IpcEnvelope {
+        correlation_id: String::new(),
+        kind: Some(pb::ipc_envelope::Kind::Event(pb::IpcEvent {
+            monotonic_ts_ns: 0,
+            payload: Some(pb::ipc_event::Payload::Op(pb::OperationEvent {
+                op_id: op_id.to_string(),
+                kind: kind as i32,
+                progress,
+                message: message.to_string(),
+                ..Default::default()
+            })),
+        })),
+    };
+    codec::encode_envelope(&env).unwrap()
+}
+
+/// Mock bridge whose import reports START / PROGRESS 50% before answering
+async fn mock_progress_bridge(port: u16) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-progress".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "progress-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                    payload:
+                        Some(pb::ipc_request::Payload::Assets(pb::AssetsRequest {
+                            payload: Some(pb::assets_request::Payload::Import(import)),
+                        })),
+                })) = env.kind
+                else {
+                    continue;
+                };
+                // Events are reported under the op_id the request chose
+                let op_id = import.op_id;
+                use pb::operation_event::Kind;
+                let _ = framed
+                    .send(op_event(&op_id, Kind::Start, 0, "Import 2 items"))
+                    .await;
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                let _ = framed
+                    .send(op_event(&op_id, Kind::Progress, 50, "Assets/A.png"))
+                    .await;
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                let _ = framed
+                    .send(op_event(&op_id, Kind::Complete, 100, "OK"))
+                    .await;
+
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::Assets(pb::AssetsResponse {
+                            status_code: 0,
+                            message: String::new(),
+                            payload: Some(pb::assets_response::Payload::Import(
+                                pb::ImportAssetResponse {
+                                    results: vec![],
+                                    op_id,
+                                },
+                            )),
+                        })),
+                    })),
+                };
+                let _ = framed.send(codec::encode_envelope(&resp).unwrap()).await;
+            }
+        });
+    }
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: Option<&str>,
+    body: serde_json::Value,
+) -> anyhow::Result<reqwest::Response> {
+    let mut req = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json");
+    if let Some(id) = session_id {
+        req = req.header("mcp-session-id", id);
+    }
+    Ok(req.body(body.to_string()).send().await?)
+}
+
+#[tokio::test]
+async fn test_import_reports_progress_for_progress_token() -> anyhow::Result<()> {
+    let bridge_port = 18972;
+    let http_port = 18973;
+    tokio::spawn(mock_progress_bridge(bridge_port));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINT", format!("tcp://127.0.0.1:{bridge_port}"));
+    }
+    let svc = McpService::new().await?;
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "progress-test", "version": "0.0.1" }
+        }
+    });
+    let resp = post(&client, &url, None, init).await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    resp.text().await?;
+    post(
+        &client,
+        &url,
+        Some(&session_id),
+        serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await?;
+
+    let call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "unity_assets_import",
+            "arguments": { "paths": ["Assets/A.png", "Assets/B.png"] },
+            "_meta": { "progressToken": "import-1" }
+        }
+    });
+    let resp = post(&client, &url, Some(&session_id), call).await?;
+    let body = tokio::time::timeout(Duration::from_secs(5), resp.text()).await??;
+
+    assert!(
+        body.contains("notifications/progress"),
+        "expected progress notifications, got: {body}"
+    );
+    assert!(body.contains("import-1"), "progress token missing: {body}");
+    assert!(
+        body.contains("Assets/A.png"),
+        "progress message missing: {body}"
+    );
+    assert!(
+        body.contains("\"id\":2,\"result\""),
+        "tool result missing: {body}"
+    );
+
+    Ok(())
+}
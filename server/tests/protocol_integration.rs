@@ -5,6 +5,7 @@ use std::collections::HashMap;
 fn test_build_request_creation() {
     // BuildPlayerRequest 作成
     let player_req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/TestApp.exe".to_string(),
         scenes: vec!["Assets/Scenes/Main.unity".to_string()],
@@ -49,6 +50,7 @@ fn test_build_request_creation() {
 fn test_build_asset_bundles_request_creation() {
     // BuildAssetBundlesRequest 作成
     let bundles_req = pb::BuildAssetBundlesRequest {
+        op_id: String::new(),
         output_directory: "AssetBundles/Test".to_string(),
         deterministic: true,
         chunk_based: false,
@@ -84,6 +86,7 @@ fn test_build_asset_bundles_request_creation() {
 fn test_build_response_parsing() {
     // BuildPlayerResponse 作成
     let player_resp = pb::BuildPlayerResponse {
+        op_id: String::new(),
         status_code: 0,
         message: "OK".to_string(),
         output_path: "Builds/TestApp.exe".to_string(),
@@ -124,6 +127,7 @@ fn test_build_response_parsing() {
 fn test_build_asset_bundles_response_parsing() {
     // BuildAssetBundlesResponse 作成
     let bundles_resp = pb::BuildAssetBundlesResponse {
+        op_id: String::new(),
         status_code: 0,
         message: "AssetBundles built successfully".to_string(),
         output_directory: "AssetBundles/Test".to_string(),
@@ -224,6 +228,7 @@ fn test_build_platform_enum() {
 fn test_error_response_creation() {
     // エラーレスポンス作成
     let error_resp = pb::BuildPlayerResponse {
+        op_id: String::new(),
         status_code: 7, // PERMISSION_DENIED
         message: "Output path is forbidden by security policy".to_string(),
         output_path: String::new(),
@@ -244,6 +249,7 @@ fn test_define_symbols_handling() {
     define_symbols.insert("ENABLE_LOGGING".to_string(), "true".to_string());
 
     let req = pb::BuildPlayerRequest {
+        op_id: String::new(),
         platform: pb::BuildPlatform::BpStandaloneWindows64 as i32,
         output_path: "Builds/TestApp.exe".to_string(),
         scenes: vec![],
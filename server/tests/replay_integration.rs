@@ -0,0 +1,165 @@
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    client::IpcClient,
+    path::IpcConfig,
+    record::Recorder,
+    tap::{RecordKind, load_capture},
+};
+use server::sim::{BridgeSim, Project, SimOptions, replay::ReplayBridge};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn config(endpoint: String, record: Option<PathBuf>) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(endpoint),
+        token: Some("replay-secret".to_string()),
+        token_file: None,
+        unity_project: None,
+        heartbeat_interval: Duration::from_millis(50),
+        record,
+        ..IpcConfig::default()
+    }
+}
+
+/// Answers the client got for a fixed sequence of calls, plus the operation events seen.
+#[derive(Debug, PartialEq)]
+struct Session {
+    health: pb::HealthResponse,
+    guids: pb::PathToGuidResponse,
+    first_add: pb::AddComponentResponse,
+    second_add: pb::AddComponentResponse,
+    build: pb::BuildPlayerResponse,
+    op_kinds: Vec<i32>,
+}
+
+async fn run_session(client: &IpcClient) -> Session {
+    let mut events = client.events();
+    let health = client.health(TIMEOUT).await.unwrap();
+    let guids = client
+        .assets_path_to_guid(vec!["Assets/Scenes/Main.unity".into()], TIMEOUT)
+        .await
+        .unwrap();
+    // Same payload twice, different answers
+    let first_add = client
+        .component_add("Player".into(), "Rigidbody".into(), TIMEOUT)
+        .await
+        .unwrap();
+    let second_add = client
+        .component_add("Player".into(), "Rigidbody".into(), TIMEOUT)
+        .await
+        .unwrap();
+    let build = client
+        .build_player(
+            pb::BuildPlayerRequest {
+                platform: pb::BuildPlatform::BpStandaloneLinux64 as i32,
+                output_path: "Builds/Linux/ReplayGame".into(),
+                ..Default::default()
+            },
+            TIMEOUT,
+        )
+        .await
+        .unwrap();
+    let mut op_kinds = Vec::new();
+    while let Ok(Ok(event)) = tokio::time::timeout(Duration::from_millis(200), events.recv()).await
+    {
+        if let Some(pb::ipc_event::Payload::Op(op)) = event.payload {
+            op_kinds.push(op.kind);
+        }
+    }
+    Session {
+        health,
+        guids,
+        first_add,
+        second_add,
+        build,
+        op_kinds,
+    }
+}
+
+async fn record_session(path: &Path) -> Session {
+    let options = SimOptions {
+        op_step: Duration::from_millis(10),
+        ..SimOptions::default()
+    };
+    let sim = BridgeSim::bind(
+        "127.0.0.1:0",
+        Project::sample("ReplayGame", "/work/ReplayGame"),
+        options,
+    )
+    .await
+    .unwrap();
+    let endpoint = sim.endpoint();
+    tokio::spawn(sim.run());
+
+    let client = IpcClient::connect(config(endpoint, Some(path.to_path_buf())))
+        .await
+        .unwrap();
+    let session = run_session(&client).await;
+    Recorder::open(path).unwrap().flush().await;
+    session
+}
+
+#[tokio::test]
+async fn test_recorded_session_replays_without_unity() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.jsonl");
+    let recorded = record_session(&path).await;
+    assert!(recorded.first_add.ok && !recorded.second_add.ok);
+    assert!(!recorded.op_kinds.is_empty());
+
+    let records = load_capture(&path).unwrap();
+    assert_eq!(records[0].kind, RecordKind::Hello);
+    assert_eq!(records[1].kind, RecordKind::Welcome);
+    assert!(
+        records
+            .iter()
+            .all(|r| !matches!(r.kind, RecordKind::Ping | RecordKind::Pong))
+    );
+    let raw = std::fs::read_to_string(&path).unwrap();
+    assert!(!raw.contains("replay-secret"));
+    let requests = records
+        .iter()
+        .filter(|r| r.kind == RecordKind::Request)
+        .count();
+    assert_eq!(requests, 5);
+
+    // The simulator is gone; only the capture answers now
+    let replay = ReplayBridge::load("127.0.0.1:0", &path).await.unwrap();
+    let (endpoint, handle) = (replay.endpoint(), replay.handle());
+    tokio::spawn(replay.run());
+    let client = IpcClient::connect(config(endpoint, None)).await.unwrap();
+    let replayed = run_session(&client).await;
+    assert_eq!(replayed, recorded);
+    assert!(handle.misses().is_empty(), "{:?}", handle.misses());
+
+    // Requests outside the capture get an empty answer and are reported
+    let err = client
+        .assets_path_to_guid(vec!["Assets/Unknown.asset".into()], TIMEOUT)
+        .await;
+    assert!(err.is_err(), "{err:?}");
+    let misses = handle.misses();
+    assert_eq!(misses.len(), 1);
+    assert!(misses[0].to_string().contains("Assets/Unknown.asset"));
+}
+
+/// A capture checked into the repo, as a reported session would be.
+#[tokio::test]
+async fn test_checked_in_capture_replays() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/captures/sim_session.jsonl");
+    let replay = ReplayBridge::load("127.0.0.1:0", &path).await.unwrap();
+    let (endpoint, handle) = (replay.endpoint(), replay.handle());
+    tokio::spawn(replay.run());
+
+    let client = IpcClient::connect(config(endpoint, None)).await.unwrap();
+    let health = client.health(TIMEOUT).await.unwrap();
+    assert_eq!(health.project_name, "ReplayGame");
+    let components = client
+        .component_get("Player".into(), TIMEOUT)
+        .await
+        .unwrap()
+        .components;
+    assert!(components.contains(&"UnityEngine.Transform".to_string()));
+    assert!(handle.misses().is_empty(), "{:?}", handle.misses());
+}
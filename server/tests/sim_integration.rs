@@ -0,0 +1,419 @@
+use rmcp::model::CallToolRequestParam;
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    client::{IpcClient, IpcError},
+    path::IpcConfig,
+};
+use server::mcp::service::McpService;
+use server::sim::{BridgeSim, Project, SimHandle, SimOptions};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+async fn start_sim(token: Option<&str>) -> (String, SimHandle) {
+    let options = SimOptions {
+        token: token.map(String::from),
+        op_step: Duration::from_millis(20),
+        ..SimOptions::default()
+    };
+    let sim = BridgeSim::bind(
+        "127.0.0.1:0",
+        Project::sample("SimGame", "/work/SimGame"),
+        options,
+    )
+    .await
+    .unwrap();
+    let (endpoint, handle) = (sim.endpoint(), sim.handle());
+    tokio::spawn(sim.run());
+    (endpoint, handle)
+}
+
+fn config(endpoint: &str, token: &str) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(endpoint.to_string()),
+        token: Some(token.to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(2),
+        heartbeat_interval: Duration::from_millis(100),
+        ..IpcConfig::default()
+    }
+}
+
+fn op_event(event: pb::IpcEvent) -> Option<pb::OperationEvent> {
+    match event.payload {
+        Some(pb::ipc_event::Payload::Op(op)) => Some(op),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn test_sim_assets_scenes_components_and_prefabs() {
+    let (endpoint, sim) = start_sim(Some("sim-token")).await;
+
+    // Wrong token is rejected like the real bridge does
+    match IpcClient::connect(config(&endpoint, "wrong")).await {
+        Err(e) => assert!(e.to_string().contains("Invalid token"), "{e}"),
+        Ok(_) => panic!("expected a rejected handshake"),
+    }
+    let client = IpcClient::connect(config(&endpoint, "sim-token"))
+        .await
+        .unwrap();
+    let health = client.health(TIMEOUT).await.unwrap();
+    assert_eq!(health.project_name, "SimGame");
+    assert_eq!(health.project_path, "/work/SimGame");
+
+    // Path ↔ GUID, and a move keeps the GUID
+    let main = "Assets/Scenes/Main.unity".to_string();
+    let guid = client
+        .assets_path_to_guid(vec![main.clone()], TIMEOUT)
+        .await
+        .unwrap()
+        .map[&main]
+        .clone();
+    assert_eq!(guid.len(), 32);
+    let paths = client
+        .assets_guid_to_path(vec![guid.clone()], TIMEOUT)
+        .await
+        .unwrap();
+    assert_eq!(paths.map[&guid], main);
+    let moved = client
+        .assets_move(main.clone(), "Assets/Scenes/Start.unity".into(), TIMEOUT)
+        .await
+        .unwrap();
+    assert_eq!(moved.new_guid, guid);
+    let open = client.scenes_get_open_scenes(TIMEOUT).await.unwrap();
+    assert_eq!(open.active_scene, "Assets/Scenes/Start.unity");
+
+    // Components on a scene object
+    let added = client
+        .component_add("Player".into(), "Rigidbody".into(), TIMEOUT)
+        .await
+        .unwrap();
+    assert!(added.ok);
+    let components = client
+        .component_get("Player".into(), TIMEOUT)
+        .await
+        .unwrap()
+        .components;
+    assert!(components.contains(&"UnityEngine.Rigidbody".to_string()));
+    let twice = client
+        .component_add("Player".into(), "Rigidbody".into(), TIMEOUT)
+        .await
+        .unwrap();
+    assert!(!twice.ok, "Rigidbody is unique per object");
+    assert!(matches!(
+        client
+            .component_remove("Player".into(), "Transform".into(), TIMEOUT)
+            .await,
+        Err(IpcError::Internal(_))
+    ));
+    assert!(matches!(
+        client
+            .component_add("Nobody".into(), "Rigidbody".into(), TIMEOUT)
+            .await,
+        Err(IpcError::NotFound(_))
+    ));
+
+    // Prefabs and additive scenes
+    let prefab = client
+        .prefab_create(
+            "Player".into(),
+            "Assets/Prefabs/Player.prefab".into(),
+            TIMEOUT,
+        )
+        .await
+        .unwrap();
+    assert!(prefab.ok && !prefab.guid.is_empty());
+    assert!(matches!(
+        client
+            .prefab_create("Player".into(), "Packages/Player.prefab".into(), TIMEOUT)
+            .await,
+        Err(IpcError::InvalidArgument(_))
+    ));
+    client
+        .scenes_open("Assets/Scenes/Level1.unity".into(), true, TIMEOUT)
+        .await
+        .unwrap();
+    client
+        .scenes_set_active_scene("Assets/Scenes/Level1.unity".into(), TIMEOUT)
+        .await
+        .unwrap();
+    let open = client.scenes_get_open_scenes(TIMEOUT).await.unwrap();
+    assert_eq!(open.scenes.len(), 2);
+    assert_eq!(open.active_scene, "Assets/Scenes/Level1.unity");
+
+    // Changes land in the shared project state
+    let project = sim.project();
+    assert!(project.prefabs.contains_key("Assets/Prefabs/Player.prefab"));
+    assert!(project.guid("Assets/Scenes/Main.unity").is_none());
+}
+
+#[tokio::test]
+async fn test_sim_operations_emit_events_and_cancel() {
+    let (endpoint, sim) = start_sim(None).await;
+    let client = IpcClient::connect(config(&endpoint, "any")).await.unwrap();
+    let mut events = client.events();
+
+    let req = pb::BuildPlayerRequest {
+        platform: pb::BuildPlatform::BpStandaloneLinux64 as i32,
+        output_path: "Builds/Linux/SimGame".to_string(),
+        ..Default::default()
+    };
+    let resp = client.build_player(req.clone(), TIMEOUT).await.unwrap();
+    assert_eq!(resp.status_code, 0, "{}", resp.message);
+    assert_eq!(resp.output_path, "/work/SimGame/Builds/Linux/SimGame");
+    assert_eq!(resp.warnings.len(), 1);
+
+    let mut kinds = Vec::new();
+    let mut op_id = String::new();
+    while let Ok(Ok(event)) = tokio::time::timeout(Duration::from_millis(200), events.recv()).await
+    {
+        if let Some(op) = op_event(event) {
+            kinds.push(op.kind());
+            op_id = op.op_id;
+        }
+    }
+    assert_eq!(kinds.first(), Some(&pb::operation_event::Kind::Start));
+    assert_eq!(kinds.last(), Some(&pb::operation_event::Kind::Complete));
+    assert!(kinds.contains(&pb::operation_event::Kind::Progress));
+    let op = client.operation_get(op_id, TIMEOUT).await.unwrap();
+    assert_eq!(op.state, "completed");
+    assert_eq!(sim.project().builds.len(), 1);
+
+    // Output inside Assets/ is refused by the path policy
+    let into_assets = pb::BuildPlayerRequest {
+        output_path: "Assets/Build".to_string(),
+        ..req
+    };
+    let resp = client.build_player(into_assets, TIMEOUT).await.unwrap();
+    assert_eq!(resp.status_code, 7);
+
+    // Cancel an import while it runs
+    let paths = (0..50).map(|i| format!("Assets/Data/{i}.asset")).collect();
+    let importer = client.clone();
+    let import = tokio::spawn(async move {
+        importer
+            .assets_import(paths, false, false, Duration::from_secs(10))
+            .await
+    });
+    let op_id = loop {
+        let event = events.recv().await.unwrap();
+        if let Some(op) = op_event(event)
+            && op.kind() == pb::operation_event::Kind::Start
+        {
+            break op.op_id;
+        }
+    };
+    let cancel = client
+        .operation_cancel(op_id.clone(), TIMEOUT)
+        .await
+        .unwrap();
+    assert!(cancel.accepted);
+    match import.await.unwrap() {
+        Err(IpcError::Status { code: 1, message }) => {
+            assert!(message.ends_with("cancelled"), "{message}")
+        }
+        other => panic!("expected a cancelled import, got {other:?}"),
+    }
+    let op = client.operation_get(op_id, TIMEOUT).await.unwrap();
+    assert_eq!(op.state, "cancelled");
+}
+
+#[tokio::test]
+async fn test_sim_operation_subscription_follows_build() {
+    let (endpoint, _sim) = start_sim(None).await;
+    let client = IpcClient::connect(config(&endpoint, "any")).await.unwrap();
+    let build = |op_id: &str| pb::BuildPlayerRequest {
+        platform: pb::BuildPlatform::BpStandaloneLinux64 as i32,
+        output_path: "Builds/Linux/SimGame".to_string(),
+        op_id: op_id.to_string(),
+        ..Default::default()
+    };
+
+    // Subscribed before the request, it picks up the operation the request starts
+    let next = client.subscribe_next_operation();
+    let resp = client.build_player(build(""), TIMEOUT).await.unwrap();
+    assert_eq!(resp.status_code, 0, "{}", resp.message);
+    let done = tokio::time::timeout(TIMEOUT, next.completion())
+        .await
+        .unwrap()
+        .expect("operation completes");
+    assert_eq!(done.op_id, resp.op_id);
+    assert_eq!(done.code, 0);
+
+    // With an op_id chosen by the caller, events are followed while the request runs
+    let sub = client.subscribe_operation("sim-build");
+    let (resp, done) = tokio::join!(
+        client.build_player(build("sim-build"), TIMEOUT),
+        tokio::time::timeout(TIMEOUT, sub.completion()),
+    );
+    assert_eq!(resp.unwrap().op_id, "sim-build");
+    let done = done.unwrap().expect("operation completes");
+    assert_eq!(done.op_id, "sim-build");
+    assert_eq!(done.code, 0);
+
+    // A subscription taken after COMPLETE ends right away
+    let mut late = client.subscribe_operation("sim-build");
+    let next = tokio::time::timeout(TIMEOUT, late.next()).await.unwrap();
+    assert!(next.is_none(), "{next:?}");
+}
+
+#[tokio::test]
+async fn test_sim_build_cancellable_until_pipeline_starts() {
+    let (endpoint, sim) = start_sim(None).await;
+    let client = IpcClient::connect(config(&endpoint, "any")).await.unwrap();
+    let mut events = client.events();
+    let build = |op_id: &str| pb::BuildPlayerRequest {
+        platform: pb::BuildPlatform::BpStandaloneLinux64 as i32,
+        output_path: "Builds/Linux/SimGame".to_string(),
+        op_id: op_id.to_string(),
+        ..Default::default()
+    };
+    let spawn_build = |op_id: &str| {
+        let (client, req) = (client.clone(), build(op_id));
+        tokio::spawn(async move { client.build_player(req, TIMEOUT).await })
+    };
+
+    // Cancelled while the platform switches, the build never reaches the pipeline
+    let running = spawn_build("sim-cancel");
+    loop {
+        let op = op_event(events.recv().await.unwrap());
+        if op.is_some_and(|op| op.op_id == "sim-cancel") {
+            break;
+        }
+    }
+    let cancel = client
+        .operation_cancel("sim-cancel".to_string(), TIMEOUT)
+        .await
+        .unwrap();
+    assert!(cancel.accepted);
+    let resp = running.await.unwrap().unwrap();
+    assert_eq!(resp.status_code, 1, "{}", resp.message);
+    assert_eq!(resp.message, "cancelled");
+    let op = client
+        .operation_get("sim-cancel".to_string(), TIMEOUT)
+        .await
+        .unwrap();
+    assert_eq!(op.state, "cancelled");
+    assert!(sim.project().builds.is_empty());
+
+    // Once the pipeline runs, cancellation is refused and the build finishes
+    let running = spawn_build("sim-late");
+    loop {
+        let op = op_event(events.recv().await.unwrap());
+        if op.is_some_and(|op| op.op_id == "sim-late" && op.message == "Compiling scripts") {
+            break;
+        }
+    }
+    let cancel = client
+        .operation_cancel("sim-late".to_string(), TIMEOUT)
+        .await
+        .unwrap();
+    assert!(!cancel.accepted);
+    let resp = running.await.unwrap().unwrap();
+    assert_eq!(resp.status_code, 0, "{}", resp.message);
+    let op = client
+        .operation_get("sim-late".to_string(), TIMEOUT)
+        .await
+        .unwrap();
+    assert_eq!(op.state, "completed");
+}
+
+#[tokio::test]
+async fn test_sim_chunks_large_responses() {
+    let (endpoint, _sim) = start_sim(None).await;
+    let cfg = IpcConfig {
+        max_frame_len: 16 * 1024,
+        ..config(&endpoint, "any")
+    };
+    let client = IpcClient::connect(cfg).await.unwrap();
+
+    // Far over the frame limit once the paths are echoed back
+    let paths: Vec<String> = (0..2000)
+        .map(|i| format!("Assets/Generated/Item{i:05}.asset"))
+        .collect();
+    let resp = client
+        .assets_path_to_guid(paths.clone(), TIMEOUT)
+        .await
+        .unwrap();
+    assert_eq!(resp.map.len(), paths.len());
+    assert!(resp.map.values().all(String::is_empty));
+}
+
+#[tokio::test]
+async fn test_sim_backs_mcp_tools() -> anyhow::Result<()> {
+    let (endpoint, _sim) = start_sim(Some("mcp-token")).await;
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINT", &endpoint);
+        std::env::set_var("MCP_IPC_TOKEN", "mcp-token");
+    }
+    let svc = McpService::new().await?;
+    let editors = svc.editors().clone();
+    let client = svc.serve_in_process().await?;
+    editors.wait_connected(Duration::from_secs(5)).await;
+
+    let call = |name: &'static str, arguments: serde_json::Value| {
+        let client = &client;
+        async move {
+            let result = client
+                .call_tool(CallToolRequestParam {
+                    name: name.into(),
+                    arguments: arguments.as_object().cloned(),
+                })
+                .await?;
+            let text = result
+                .content
+                .as_ref()
+                .and_then(|c| c.first())
+                .and_then(|c| c.as_text())
+                .map(|t| t.text.clone())
+                // Structured results (diagnostics) carry their JSON separately
+                .or_else(|| result.structured_content.as_ref().map(|v| v.to_string()))
+                .unwrap_or_default();
+            anyhow::Ok(text)
+        }
+    };
+
+    let text = call("unity_health", serde_json::json!({})).await?;
+    assert!(text.contains("SimGame"), "{text}");
+    let text = call(
+        "unity_component_add",
+        serde_json::json!({ "game_object": "Main Camera", "component": "AudioSource" }),
+    )
+    .await?;
+    assert!(text.contains("true"), "{text}");
+    let text = call(
+        "unity_get_components",
+        serde_json::json!({ "game_object": "Main Camera" }),
+    )
+    .await?;
+    assert!(text.contains("UnityEngine.AudioSource"), "{text}");
+    let text = call(
+        "unity_scene_open",
+        serde_json::json!({ "path": "Assets/Scenes/Level1.unity", "additive": true }),
+    )
+    .await?;
+    assert!(text.contains("true"), "{text}");
+    let text = call(
+        "unity_get_compile_diagnostics",
+        serde_json::json!({ "severity": "warning" }),
+    )
+    .await?;
+    assert!(text.contains("CS0414"), "{text}");
+    let text = call(
+        "unity_build_player",
+        serde_json::json!({
+            "platform": "BP_STANDALONE_LINUX64",
+            "outputPath": "Builds/Linux/SimGame",
+        }),
+    )
+    .await?;
+    let out: serde_json::Value = serde_json::from_str(&text)?;
+    assert_eq!(out["statusCode"], 0, "{text}");
+    assert_eq!(out["outputPath"], "/work/SimGame/Builds/Linux/SimGame");
+
+    let _ = client.cancel().await;
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use server::mcp::service::McpService;
+use std::time::Duration;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+async fn start_http_server(port: u16) -> anyhow::Result<String> {
+    let svc = McpService::new().await?;
+    let bind_addr = format!("127.0.0.1:{port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    Ok(format!("http://127.0.0.1:{port}/mcp"))
+}
+
+/// Send `initialize` + `notifications/initialized` and return the session id.
+async fn initialize_session(client: &reqwest::Client, url: &str) -> anyhow::Result<String> {
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "http-test", "version": "0.0.1" }
+        }
+    });
+    let resp = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .body(init.to_string())
+        .send()
+        .await?;
+    assert!(resp.status().is_success());
+    let session_id = resp
+        .headers()
+        .get("mcp-session-id")
+        .expect("initialize response should carry a session id")
+        .to_str()?
+        .to_string();
+    let body = tokio::time::timeout(Duration::from_secs(5), resp.text()).await??;
+    assert!(body.contains("unity-mcp-server"), "unexpected body: {body}");
+
+    let initialized = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/initialized"
+    });
+    let resp = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", &session_id)
+        .body(initialized.to_string())
+        .send()
+        .await?;
+    assert!(resp.status().is_success());
+
+    Ok(session_id)
+}
+
+#[tokio::test]
+async fn test_http_tools_list_over_session() -> anyhow::Result<()> {
+    let url = start_http_server(18950).await?;
+    let client = reqwest::Client::new();
+    let session_id = initialize_session(&client, &url).await?;
+
+    let list = serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" });
+    let resp = client
+        .post(&url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", &session_id)
+        .body(list.to_string())
+        .send()
+        .await?;
+    assert!(resp.status().is_success());
+    let body = tokio::time::timeout(Duration::from_secs(5), resp.text()).await??;
+    assert!(
+        body.contains("unity_bridge_status"),
+        "unexpected body: {body}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_sessions_are_independent() -> anyhow::Result<()> {
+    let url = start_http_server(18951).await?;
+    let client = reqwest::Client::new();
+
+    let first = initialize_session(&client, &url).await?;
+    let second = initialize_session(&client, &url).await?;
+    assert_ne!(first, second);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_rejects_unknown_session() -> anyhow::Result<()> {
+    let url = start_http_server(18952).await?;
+    let client = reqwest::Client::new();
+
+    let list = serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" });
+    let resp = client
+        .post(&url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json")
+        .header("mcp-session-id", "no-such-session")
+        .body(list.to_string())
+        .send()
+        .await?;
+    assert_eq!(resp.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_session_is_unregistered_when_deleted() -> anyhow::Result<()> {
+    let svc = McpService::new().await?;
+    let server = svc.clone();
+    let bind_addr = "127.0.0.1:18953";
+    tokio::spawn(async move {
+        let _ = server.serve_http(bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let url = format!("http://{bind_addr}/mcp");
+    let client = reqwest::Client::new();
+
+    let session_id = initialize_session(&client, &url).await?;
+    let sessions = |expected: usize| {
+        let svc = &svc;
+        async move {
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while svc.session_count() != expected {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+            })
+            .await
+        }
+    };
+    sessions(1).await.expect("session was never registered");
+
+    let resp = client
+        .delete(&url)
+        .header("mcp-session-id", &session_id)
+        .send()
+        .await?;
+    assert!(resp.status().is_success());
+    sessions(0)
+        .await
+        .expect("session stayed registered after DELETE");
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use server::ipc::{
+    client::IpcClient,
+    path::IpcConfig,
+    tap::{CaptureWriter, Direction, IpcTap, RecordKind, TapRecord, load_capture},
+};
+use server::sim::{BridgeSim, Project, SimOptions};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn test_tap_decodes_and_captures_session() {
+    let sim = BridgeSim::bind(
+        "127.0.0.1:0",
+        Project::sample("TapGame", "/work/TapGame"),
+        SimOptions::default(),
+    )
+    .await
+    .unwrap();
+    let upstream = sim.endpoint();
+    tokio::spawn(sim.run());
+    let tap = IpcTap::bind("127.0.0.1:0", &upstream).await.unwrap();
+    let endpoint = tap.endpoint();
+    let (tx, mut records) = mpsc::unbounded_channel();
+    tokio::spawn(tap.run(tx));
+
+    let client = IpcClient::connect(IpcConfig {
+        endpoint: Some(endpoint),
+        token: Some("tap-secret".to_string()),
+        token_file: None,
+        unity_project: None,
+        heartbeat_interval: Duration::ZERO,
+        ..IpcConfig::default()
+    })
+    .await
+    .unwrap();
+    client.health(TIMEOUT).await.unwrap();
+    let main = "Assets/Scenes/Main.unity".to_string();
+    client
+        .assets_path_to_guid(vec![main.clone()], TIMEOUT)
+        .await
+        .unwrap();
+
+    let mut seen = Vec::new();
+    while seen.len() < 6 {
+        let record = tokio::time::timeout(TIMEOUT, records.recv())
+            .await
+            .expect("record")
+            .unwrap();
+        seen.push(record);
+    }
+    let kinds: Vec<_> = seen.iter().map(|r| (r.direction, r.kind)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (Direction::ToBridge, RecordKind::Hello),
+            (Direction::ToClient, RecordKind::Welcome),
+            (Direction::ToBridge, RecordKind::Request),
+            (Direction::ToClient, RecordKind::Response),
+            (Direction::ToBridge, RecordKind::Request),
+            (Direction::ToClient, RecordKind::Response),
+        ]
+    );
+
+    let hello = seen[0].message.as_ref().unwrap();
+    assert_eq!(hello["hello"]["token"], "***");
+    let welcome = seen[1].message.as_ref().unwrap();
+    assert_eq!(welcome["welcome"]["serverName"], "unity-bridge-sim");
+
+    let (req, resp) = (&seen[4], &seen[5]);
+    assert_eq!(req.method.as_deref(), Some("assets"));
+    assert_eq!(resp.method.as_deref(), Some("assets"));
+    assert!(req.correlation_id.is_some());
+    assert_eq!(req.correlation_id, resp.correlation_id);
+    assert!(resp.latency_ms.is_some_and(|ms| ms >= 0.0));
+    let guid = &resp.message.as_ref().unwrap()["assets"]["p2g"]["map"][&main];
+    assert_eq!(guid.as_str().map(str::len), Some(32), "{resp:?}");
+    assert!(seen.iter().all(|r| r.connection == 1));
+
+    // Captures round-trip through JSON Lines
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("session.jsonl");
+    let mut capture = CaptureWriter::create(&path).unwrap();
+    for record in &seen {
+        capture.write(record).unwrap();
+    }
+    drop(capture);
+    let raw = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(raw.lines().count(), seen.len());
+    assert!(!raw.contains("tap-secret"));
+    let loaded: Vec<TapRecord> = load_capture(&path).unwrap();
+    assert_eq!(loaded, seen);
+}
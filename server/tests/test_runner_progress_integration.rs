@@ -0,0 +1,244 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use std::path::Path;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+fn op_event(
+    op_id: &str,
+    kind: pb::operation_event::Kind,
+    progress: i32,
+    message: &str,
+) -> bytes::Bytes {
+    let env = pb::IpcEnvelope {
+        correlation_id: String::new(),
+        kind: Some(pb::ipc_envelope::Kind::Event(pb::IpcEvent {
+            monotonic_ts_ns: 0,
+            payload: Some(pb::ipc_event::Payload::Op(pb::OperationEvent {
+                op_id: op_id.to_string(),
+                kind: kind as i32,
+                progress,
+                message: message.to_string(),
+                ..Default::default()
+            })),
+        })),
+    };
+    codec::encode_envelope(&env).unwrap()
+}
+
+/// Mock bridge that answers the handshake and then only forwards the events it is given,
+/// the way McpTestRunner publishes them outside any IPC request
+async fn mock_event_bridge(port: u16, mut events: mpsc::Receiver<bytes::Bytes>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut framed = framing::into_framed(stream);
+
+    let Some(Ok(bytes)) = framed.next().await else {
+        return;
+    };
+    let Ok(pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Hello(hello)),
+    }) = codec::decode_control(bytes.freeze())
+    else {
+        return;
+    };
+    let welcome = pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+            ipc_version: hello.ipc_version,
+            accepted_features: hello.features,
+            schema_hash: hello.schema_hash,
+            server_name: "mock-test-runner".to_string(),
+            server_version: "0.1.0".to_string(),
+            editor_version: "Unity 6000.0.test".to_string(),
+            session_id: "test-runner-session".to_string(),
+            meta: Default::default(),
+        })),
+    };
+    let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+    loop {
+        tokio::select! {
+            frame = framed.next() => {
+                if !matches!(frame, Some(Ok(_))) {
+                    return;
+                }
+            }
+            Some(event) = events.recv() => {
+                let _ = framed.send(event).await;
+            }
+        }
+    }
+}
+
+/// Play McpTestRunner: pick up the request file, report progress under its `opId` and
+/// write the results the tool waits for.
+async fn fake_test_runner(tests_dir: &Path, events: mpsc::Sender<bytes::Bytes>) -> String {
+    let requests = tests_dir.join("requests");
+    let request: serde_json::Value = loop {
+        let found = std::fs::read_dir(&requests)
+            .ok()
+            .and_then(|mut entries| entries.next())
+            .and_then(|entry| std::fs::read_to_string(entry.ok()?.path()).ok());
+        if let Some(json) = found {
+            break serde_json::from_str(&json).unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    };
+    let run_id = request["runId"].as_str().unwrap().to_string();
+    let op_id = request["opId"].as_str().unwrap().to_string();
+    assert!(!op_id.is_empty(), "request carries no opId: {request}");
+
+    use pb::operation_event::Kind;
+    for (kind, progress, message) in [
+        (Kind::Start, 0, "Test run"),
+        (Kind::Progress, 50, "Game.Tests.FirstTest"),
+        (Kind::Progress, 99, "Game.Tests.SecondTest"),
+    ] {
+        events
+            .send(op_event(&op_id, kind, progress, message))
+            .await
+            .unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let results = serde_json::json!({
+        "runId": run_id,
+        "startedAt": "2026-01-01T00:00:00.000Z",
+        "finishedAt": "2026-01-01T00:00:01.000Z",
+        "mode": "edit",
+        "filter": "",
+        "categories": [],
+        "summary": { "total": 2, "passed": 2, "failed": 0, "skipped": 0, "durationSec": 1.0 },
+        "tests": [],
+        "truncated": false
+    });
+    std::fs::write(tests_dir.join("latest.json"), results.to_string()).unwrap();
+    let status = serde_json::json!({
+        "status": "finished",
+        "runId": run_id,
+        "timestamp": "2026-01-01T00:00:01.000Z"
+    });
+    std::fs::write(
+        tests_dir.join(format!("status-{run_id}.json")),
+        status.to_string(),
+    )
+    .unwrap();
+    events
+        .send(op_event(
+            &op_id,
+            Kind::Complete,
+            100,
+            "2 passed, 0 failed, 0 skipped",
+        ))
+        .await
+        .unwrap();
+    run_id
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: Option<&str>,
+    body: serde_json::Value,
+) -> anyhow::Result<reqwest::Response> {
+    let mut req = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json");
+    if let Some(id) = session_id {
+        req = req.header("mcp-session-id", id);
+    }
+    Ok(req.body(body.to_string()).send().await?)
+}
+
+#[tokio::test]
+async fn test_run_tests_reports_progress_from_test_runner_events() -> anyhow::Result<()> {
+    let bridge_port = 18944;
+    let http_port = 18945;
+    let (events_tx, events_rx) = mpsc::channel(16);
+    tokio::spawn(mock_event_bridge(bridge_port, events_rx));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let project = tempfile::tempdir()?;
+    let project_path = project.path().canonicalize()?;
+    let tests_dir = project_path.join("UnityMCP").join("tests");
+    std::fs::create_dir_all(tests_dir.join("requests"))?;
+
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINT", format!("tcp://127.0.0.1:{bridge_port}"));
+        std::env::set_var("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0");
+        std::env::set_var("MCP_UNITY_PROJECT", &project_path);
+        std::env::set_var("UNITY_MCP_REQ_PATH", tests_dir.join("requests"));
+        std::env::set_var("UNITY_MCP_TESTS_PATH", &tests_dir);
+    }
+    let svc = McpService::new().await?;
+    let editors = svc.editors().clone();
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    editors.wait_connected(Duration::from_secs(5)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "test-runner-progress", "version": "0.0.1" }
+        }
+    });
+    let resp = post(&client, &url, None, init).await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    resp.text().await?;
+    post(
+        &client,
+        &url,
+        Some(&session_id),
+        serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await?;
+
+    let runner = tokio::spawn({
+        let tests_dir = tests_dir.clone();
+        async move { fake_test_runner(&tests_dir, events_tx).await }
+    });
+    let call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "unity_run_tests",
+            "arguments": { "timeout_sec": 10 },
+            "_meta": { "progressToken": "tests-1" }
+        }
+    });
+    let resp = post(&client, &url, Some(&session_id), call).await?;
+    let body = tokio::time::timeout(Duration::from_secs(10), resp.text()).await??;
+    let run_id = runner.await?;
+
+    assert!(body.contains("tests-1"), "progress token missing: {body}");
+    for message in ["Game.Tests.FirstTest", "Game.Tests.SecondTest"] {
+        assert!(body.contains(message), "{message} not reported: {body}");
+    }
+    assert!(
+        !body.contains(&format!("Test run {run_id} started")),
+        "synthetic start progress sent alongside runner events: {body}"
+    );
+    assert!(
+        body.contains("\"id\":2,\"result\""),
+        "tool result missing: {body}"
+    );
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use futures::{SinkExt, StreamExt};
+use rcgen::{BasicConstraints, CertificateParams, IsCa, Issuer, KeyPair};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    client::{IpcClient, IpcError},
+    codec, framing,
+    path::IpcConfig,
+    tls::TlsOptions,
+};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::{net::TcpListener, time::Duration};
+use tokio_rustls::{
+    TlsAcceptor,
+    rustls::{self, RootCertStore, server::WebPkiClientVerifier},
+};
+
+/// A CA plus the bridge and client certificates it issued, written as PEM files.
+struct Pki {
+    _dir: tempfile::TempDir,
+    ca: PathBuf,
+    server_cert: PathBuf,
+    server_key: PathBuf,
+    client_cert: PathBuf,
+    client_key: PathBuf,
+}
+
+fn ca(name: &str) -> (rcgen::Certificate, Issuer<'static, KeyPair>) {
+    let mut params = CertificateParams::new(Vec::new()).unwrap();
+    params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    params
+        .distinguished_name
+        .push(rcgen::DnType::CommonName, name);
+    let key = KeyPair::generate().unwrap();
+    let cert = params.self_signed(&key).unwrap();
+    (cert, Issuer::new(params, key))
+}
+
+fn leaf(names: &[&str], issuer: &Issuer<'static, KeyPair>) -> (String, String) {
+    let params =
+        CertificateParams::new(names.iter().map(|n| n.to_string()).collect::<Vec<_>>()).unwrap();
+    let key = KeyPair::generate().unwrap();
+    let cert = params.signed_by(&key, issuer).unwrap();
+    (cert.pem(), key.serialize_pem())
+}
+
+fn issue_pki() -> Pki {
+    let dir = tempfile::tempdir().unwrap();
+    let write = |name: &str, pem: &str| {
+        let path = dir.path().join(name);
+        std::fs::write(&path, pem).unwrap();
+        path
+    };
+    let (ca_cert, issuer) = ca("unity-mcp test CA");
+    // Issued for a DNS name only, so connecting by IP needs a server name override
+    let (server_cert, server_key) = leaf(&["unity-editor.test"], &issuer);
+    let (client_cert, client_key) = leaf(&["unity-mcp-client"], &issuer);
+    Pki {
+        ca: write("ca.pem", &ca_cert.pem()),
+        server_cert: write("server.pem", &server_cert),
+        server_key: write("server.key", &server_key),
+        client_cert: write("client.pem", &client_cert),
+        client_key: write("client.key", &client_key),
+        _dir: dir,
+    }
+}
+
+/// TLS bridge that requires a client certificate issued by the test CA.
+fn acceptor(pki: &Pki) -> TlsAcceptor {
+    use rustls_pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
+
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let mut roots = RootCertStore::empty();
+    roots
+        .add(CertificateDer::from_pem_file(&pki.ca).unwrap())
+        .unwrap();
+    let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+        .build()
+        .unwrap();
+    let config = rustls::ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(
+            vec![CertificateDer::from_pem_file(&pki.server_cert).unwrap()],
+            PrivateKeyDer::from_pem_file(&pki.server_key).unwrap(),
+        )
+        .unwrap();
+    TlsAcceptor::from(Arc::new(config))
+}
+
+async fn mock_tls_bridge(port: u16, acceptor: TlsAcceptor) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((tcp, _)) = listener.accept().await {
+        let acceptor = acceptor.clone();
+        tokio::spawn(async move {
+            let Ok(stream) = acceptor.accept(tcp).await else {
+                return;
+            };
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            if hello.token != "tls-token" {
+                return;
+            }
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-tls".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "tls-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            while let Some(Ok(bytes)) = framed.next().await {
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::Health(_)),
+                })) = env.kind
+                else {
+                    continue;
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "6000.0.test".to_string(),
+                            status: "OK".to_string(),
+                            project_name: "TlsGame".to_string(),
+                            project_path: String::new(),
+                        })),
+                    })),
+                };
+                if framed
+                    .send(codec::encode_envelope(&resp).unwrap())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+fn config(port: u16, tls: TlsOptions) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(format!("tls://127.0.0.1:{port}")),
+        token: Some("tls-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(5),
+        heartbeat_interval: Duration::ZERO,
+        tls,
+        ..IpcConfig::default()
+    }
+}
+
+fn options(ca: &Path, client: Option<(&Path, &Path)>, server_name: Option<&str>) -> TlsOptions {
+    TlsOptions {
+        ca_file: Some(ca.to_path_buf()),
+        client_cert: client.map(|(cert, _)| cert.to_path_buf()),
+        client_key: client.map(|(_, key)| key.to_path_buf()),
+        server_name: server_name.map(String::from),
+    }
+}
+
+#[tokio::test]
+async fn test_tls_endpoint_with_mutual_authentication() {
+    let port = 19003;
+    let pki = issue_pki();
+    tokio::spawn(mock_tls_bridge(port, acceptor(&pki)));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let client_id = Some((pki.client_cert.as_path(), pki.client_key.as_path()));
+
+    // CA, client certificate and the name the bridge certificate was issued for
+    let tls = options(&pki.ca, client_id, Some("unity-editor.test"));
+    let client = IpcClient::connect(config(port, tls)).await.unwrap();
+    let health = client.health(Duration::from_secs(2)).await.unwrap();
+    assert_eq!(health.project_name, "TlsGame");
+
+    // The certificate is not valid for the IP address in the endpoint
+    let tls = options(&pki.ca, client_id, None);
+    match IpcClient::connect(config(port, tls)).await {
+        Err(IpcError::Tls(msg)) => assert!(msg.contains("certificate"), "{msg}"),
+        other => panic!("expected a TLS error, got {:?}", other.err()),
+    }
+
+    // A bridge certificate from another CA is refused
+    let other = issue_pki();
+    let tls = options(&other.ca, client_id, Some("unity-editor.test"));
+    match IpcClient::connect(config(port, tls)).await {
+        Err(IpcError::Tls(msg)) => assert!(msg.contains("certificate"), "{msg}"),
+        other => panic!("expected a TLS error, got {:?}", other.err()),
+    }
+
+    // The bridge requires a client certificate
+    let tls = options(&pki.ca, None, Some("unity-editor.test"));
+    assert!(IpcClient::connect(config(port, tls)).await.is_err());
+}
@@ -0,0 +1,191 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    client::{IpcClient, IpcError},
+    codec, framing,
+    path::IpcConfig,
+    token::UNITY_USER_SETTINGS,
+};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::{net::TcpListener, sync::Notify, time::Duration};
+
+/// Bridge that accepts only `expected` and records every token it is offered.
+#[derive(Default)]
+struct TokenBridge {
+    expected: Mutex<String>,
+    seen: Mutex<Vec<String>>,
+    // Closes the current connection, like an editor domain reload
+    drop_connection: Notify,
+}
+
+async fn mock_bridge(port: u16, bridge: Arc<TokenBridge>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let bridge = bridge.clone();
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            bridge.seen.lock().unwrap().push(hello.token.clone());
+            let expected = bridge.expected.lock().unwrap().clone();
+            if hello.token != expected {
+                let reject = pb::IpcControl {
+                    kind: Some(pb::ipc_control::Kind::Reject(pb::IpcReject {
+                        code: pb::ipc_reject::Code::Unauthenticated as i32,
+                        message: "invalid token".to_string(),
+                    })),
+                };
+                let _ = framed.send(codec::encode_control(&reject).unwrap()).await;
+                return;
+            }
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features: hello.features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-token".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "token-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            loop {
+                let bytes = tokio::select! {
+                    _ = bridge.drop_connection.notified() => return,
+                    frame = framed.next() => match frame {
+                        Some(Ok(bytes)) => bytes,
+                        _ => return,
+                    },
+                };
+                let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+                    continue;
+                };
+                let Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+                    payload: Some(pb::ipc_request::Payload::Health(_)),
+                })) = env.kind
+                else {
+                    continue;
+                };
+                let resp = pb::IpcEnvelope {
+                    correlation_id: env.correlation_id.clone(),
+                    kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                        correlation_id: env.correlation_id,
+                        payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                            ready: true,
+                            version: "6000.0.test".to_string(),
+                            status: "OK".to_string(),
+                            project_name: "TokenGame".to_string(),
+                            project_path: String::new(),
+                        })),
+                    })),
+                };
+                if framed
+                    .send(codec::encode_envelope(&resp).unwrap())
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+/// Store `token` the way Unity does: hex-encoded under m_ConfigSettings.
+fn write_user_settings(project: &Path, token: &str) {
+    let hex: String = token.bytes().map(|b| format!("{b:02x}")).collect();
+    let path = project.join(UNITY_USER_SETTINGS);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(
+        path,
+        format!(
+            "%YAML 1.1\n--- !u!162 &1\nEditorUserSettings:\n  m_ObjectHideFlags: 0\n  m_ConfigSettings:\n    MCP.IpcToken:\n      value: {hex}\n      flags: 0\n"
+        ),
+    )
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_token_is_reloaded_from_unity_project_after_rejection() {
+    let port = 19001;
+    let bridge = Arc::new(TokenBridge::default());
+    *bridge.expected.lock().unwrap() = "first-token".to_string();
+    tokio::spawn(mock_bridge(port, bridge.clone()));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let project = tempfile::tempdir().unwrap();
+    write_user_settings(project.path(), "first-token");
+    let cfg = IpcConfig {
+        endpoint: Some(format!("tcp://127.0.0.1:{port}")),
+        token: None,
+        token_file: None,
+        unity_project: Some(project.path().to_path_buf()),
+        heartbeat_interval: Duration::ZERO,
+        reconnect_initial_backoff: Duration::from_millis(50),
+        ..IpcConfig::default()
+    };
+    let client = IpcClient::connect(cfg).await.unwrap();
+    client.health(Duration::from_secs(2)).await.unwrap();
+
+    // The token is regenerated in Unity, which also drops the connection
+    write_user_settings(project.path(), "second-token");
+    *bridge.expected.lock().unwrap() = "second-token".to_string();
+    bridge.drop_connection.notify_one();
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while client.health(Duration::from_millis(500)).await.is_err() {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("client reconnects with the new token");
+
+    // The cached token is tried once more; only the rejection triggers a reload
+    assert_eq!(
+        *bridge.seen.lock().unwrap(),
+        ["first-token", "first-token", "second-token"]
+    );
+    assert!(!format!("{:?}", client).contains("second-token"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_token_file_readable_by_others_is_refused() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ipc-token");
+    std::fs::write(&path, "file-token\n").unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    let cfg = IpcConfig {
+        // Nothing listens here; the token is checked before connecting
+        endpoint: Some("tcp://127.0.0.1:19002".to_string()),
+        token: None,
+        token_file: Some(path),
+        unity_project: None,
+        ..IpcConfig::default()
+    };
+    match IpcClient::connect(cfg).await {
+        Err(IpcError::Authentication(msg)) => {
+            assert!(msg.contains("chmod 600"), "{msg}");
+            assert!(!msg.contains("file-token"), "{msg}");
+        }
+        other => panic!("expected an authentication error, got {:?}", other.err()),
+    }
+}
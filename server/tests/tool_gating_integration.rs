@@ -0,0 +1,192 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{codec, framing};
+use server::mcp::service::McpService;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
+use tokio::{net::TcpListener, sync::Notify};
+
+const ACCEPT: &str = "application/json, text/event-stream";
+
+/// Mock bridge that refuses "components.basic" on its first connection.
+///
+/// The first connection is dropped once `drop_first` is notified; the client
+/// reconnects and every later connection accepts all offered features.
+async fn mock_gating_bridge(port: u16, drop_first: Arc<Notify>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    while let Ok((stream, _)) = listener.accept().await {
+        let first = connections.fetch_add(1, Ordering::SeqCst) == 0;
+        let drop_first = drop_first.clone();
+        tokio::spawn(async move {
+            let mut framed = framing::into_framed(stream);
+
+            let Some(Ok(bytes)) = framed.next().await else {
+                return;
+            };
+            let Ok(pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Hello(hello)),
+            }) = codec::decode_control(bytes.freeze())
+            else {
+                return;
+            };
+            let accepted_features = hello
+                .features
+                .into_iter()
+                .filter(|f| !(first && f == "components.basic"))
+                .collect();
+            let welcome = pb::IpcControl {
+                kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+                    ipc_version: hello.ipc_version,
+                    accepted_features,
+                    schema_hash: hello.schema_hash,
+                    server_name: "mock-gating".to_string(),
+                    server_version: "0.1.0".to_string(),
+                    editor_version: "Unity 6000.0.test".to_string(),
+                    session_id: "gating-session".to_string(),
+                    meta: Default::default(),
+                })),
+            };
+            let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+            if first {
+                tokio::select! {
+                    _ = drop_first.notified() => {}
+                    _ = async { while let Some(Ok(_)) = framed.next().await {} } => {}
+                }
+                return;
+            }
+            while let Some(Ok(_)) = framed.next().await {}
+        });
+    }
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: Option<&str>,
+    body: serde_json::Value,
+) -> anyhow::Result<reqwest::Response> {
+    let mut req = client
+        .post(url)
+        .header("accept", ACCEPT)
+        .header("content-type", "application/json");
+    if let Some(id) = session_id {
+        req = req.header("mcp-session-id", id);
+    }
+    Ok(req.body(body.to_string()).send().await?)
+}
+
+async fn list_tools(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+) -> anyhow::Result<String> {
+    let list = serde_json::json!({ "jsonrpc": "2.0", "id": 10, "method": "tools/list" });
+    Ok(post(client, url, Some(session_id), list)
+        .await?
+        .text()
+        .await?)
+}
+
+#[tokio::test]
+async fn test_tools_follow_negotiated_features_across_reconnect() -> anyhow::Result<()> {
+    let bridge_port = 18974;
+    let http_port = 18975;
+    let drop_first = Arc::new(Notify::new());
+    tokio::spawn(mock_gating_bridge(bridge_port, drop_first.clone()));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // This test binary owns its environment
+    unsafe {
+        std::env::set_var("MCP_IPC_ENDPOINT", format!("tcp://127.0.0.1:{bridge_port}"));
+        std::env::set_var("MCP_IPC_HEARTBEAT_INTERVAL_MS", "0");
+    }
+    let svc = McpService::new().await?;
+    let bind_addr = format!("127.0.0.1:{http_port}");
+    tokio::spawn(async move {
+        let _ = svc.serve_http(&bind_addr).await;
+    });
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let url = format!("http://127.0.0.1:{http_port}/mcp");
+    let client = reqwest::Client::new();
+    let init = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": { "name": "gating-test", "version": "0.0.1" }
+        }
+    });
+    let resp = post(&client, &url, None, init).await?;
+    let session_id = resp.headers()["mcp-session-id"].to_str()?.to_string();
+    let body = resp.text().await?;
+    assert!(body.contains("\"listChanged\":true"), "{body}");
+    post(
+        &client,
+        &url,
+        Some(&session_id),
+        serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await?;
+
+    // Component tools are hidden while the bridge has not accepted components.basic
+    let body = list_tools(&client, &url, &session_id).await?;
+    assert!(body.contains("unity_assets_import"), "{body}");
+    assert!(!body.contains("unity_component_add"), "{body}");
+
+    // ... and calling one anyway is rejected up front
+    let call = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {
+            "name": "unity_component_add",
+            "arguments": { "game_object": "Player", "component": "Rigidbody" }
+        }
+    });
+    let body = post(&client, &url, Some(&session_id), call)
+        .await?
+        .text()
+        .await?;
+    assert!(body.contains("\"error\""), "{body}");
+    assert!(body.contains("components.basic"), "{body}");
+
+    // Open the session's notification stream, then force a reconnect
+    let mut events = client
+        .get(&url)
+        .header("accept", "text/event-stream")
+        .header("mcp-session-id", &session_id)
+        .send()
+        .await?;
+    assert!(events.status().is_success());
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    drop_first.notify_one();
+
+    let mut seen = String::new();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !seen.contains("notifications/tools/list_changed") {
+            match events.chunk().await? {
+                Some(chunk) => seen.push_str(&String::from_utf8_lossy(&chunk)),
+                None => break,
+            }
+        }
+        anyhow::Ok(())
+    })
+    .await??;
+    assert!(seen.contains("notifications/tools/list_changed"), "{seen}");
+
+    let body = list_tools(&client, &url, &session_id).await?;
+    assert!(body.contains("unity_component_add"), "{body}");
+
+    Ok(())
+}
@@ -0,0 +1,251 @@
+use futures::{SinkExt, StreamExt};
+use server::generated::mcp::unity::v1 as pb;
+use server::ipc::{
+    client::{IpcClient, IpcError},
+    codec, framing,
+    path::IpcConfig,
+    tls::TlsOptions,
+    ws::WsStream,
+};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::{net::TcpListener, sync::Notify, time::Duration};
+use tokio_rustls::{TlsAcceptor, rustls};
+use tokio_tungstenite::tungstenite::Message;
+
+/// WebSocket bridge: records the first message of each connection and can drop the connection.
+#[derive(Default)]
+struct WsBridge {
+    first_messages: Mutex<Vec<Message>>,
+    drop_connection: Notify,
+}
+
+fn log_event(message: &str) -> bytes::Bytes {
+    let env = pb::IpcEnvelope {
+        correlation_id: String::new(),
+        kind: Some(pb::ipc_envelope::Kind::Event(pb::IpcEvent {
+            monotonic_ts_ns: 0,
+            payload: Some(pb::ipc_event::Payload::Log(pb::LogEvent {
+                message: message.to_string(),
+                category: "Unity".to_string(),
+                ..Default::default()
+            })),
+        })),
+    };
+    codec::encode_envelope(&env).unwrap()
+}
+
+async fn serve_connection<S>(stream: S, bridge: Arc<WsBridge>)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+        return;
+    };
+    // The hello is read at the message level to check how the client frames it
+    let Some(Ok(first)) = ws.next().await else {
+        return;
+    };
+    bridge.first_messages.lock().unwrap().push(first.clone());
+    let Message::Binary(data) = first else {
+        return;
+    };
+    let Ok(pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Hello(hello)),
+    }) = codec::decode_control(data.slice(4..))
+    else {
+        return;
+    };
+
+    let mut framed = framing::into_framed(WsStream::new(ws));
+    let welcome = pb::IpcControl {
+        kind: Some(pb::ipc_control::Kind::Welcome(pb::IpcWelcome {
+            ipc_version: hello.ipc_version,
+            accepted_features: hello.features,
+            schema_hash: hello.schema_hash,
+            server_name: "mock-ws".to_string(),
+            server_version: "0.1.0".to_string(),
+            editor_version: "Unity 6000.0.test".to_string(),
+            session_id: "ws-session".to_string(),
+            meta: Default::default(),
+        })),
+    };
+    let _ = framed.send(codec::encode_control(&welcome).unwrap()).await;
+
+    loop {
+        let bytes = tokio::select! {
+            _ = bridge.drop_connection.notified() => return,
+            frame = framed.next() => match frame {
+                Some(Ok(bytes)) => bytes,
+                _ => return,
+            },
+        };
+        let Ok(env) = codec::decode_envelope(bytes.freeze()) else {
+            continue;
+        };
+        let Some(pb::ipc_envelope::Kind::Request(pb::IpcRequest {
+            payload: Some(pb::ipc_request::Payload::Health(_)),
+        })) = env.kind
+        else {
+            continue;
+        };
+        // An event ahead of the response travels over the same socket
+        let _ = framed.send(log_event("compiling scripts")).await;
+        let resp = pb::IpcEnvelope {
+            correlation_id: env.correlation_id.clone(),
+            kind: Some(pb::ipc_envelope::Kind::Response(pb::IpcResponse {
+                correlation_id: env.correlation_id,
+                payload: Some(pb::ipc_response::Payload::Health(pb::HealthResponse {
+                    ready: true,
+                    version: "6000.0.test".to_string(),
+                    status: "OK".to_string(),
+                    project_name: "WsGame".to_string(),
+                    project_path: String::new(),
+                })),
+            })),
+        };
+        if framed
+            .send(codec::encode_envelope(&resp).unwrap())
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+async fn mock_ws_bridge(port: u16, bridge: Arc<WsBridge>, acceptor: Option<TlsAcceptor>) {
+    let listener = TcpListener::bind(format!("127.0.0.1:{port}"))
+        .await
+        .unwrap();
+
+    while let Ok((tcp, _)) = listener.accept().await {
+        let bridge = bridge.clone();
+        let acceptor = acceptor.clone();
+        tokio::spawn(async move {
+            match acceptor {
+                Some(acceptor) => {
+                    if let Ok(stream) = acceptor.accept(tcp).await {
+                        serve_connection(stream, bridge).await;
+                    }
+                }
+                None => serve_connection(tcp, bridge).await,
+            }
+        });
+    }
+}
+
+fn config(endpoint: String, tls: TlsOptions) -> IpcConfig {
+    IpcConfig {
+        endpoint: Some(endpoint),
+        token: Some("ws-token".to_string()),
+        token_file: None,
+        unity_project: None,
+        connect_timeout: Duration::from_secs(5),
+        heartbeat_interval: Duration::ZERO,
+        reconnect_initial_backoff: Duration::from_millis(50),
+        tls,
+        ..IpcConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_ws_endpoint_carries_frames_events_and_reconnects() {
+    let port = 19004;
+    let bridge = Arc::new(WsBridge::default());
+    tokio::spawn(mock_ws_bridge(port, bridge.clone(), None));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let endpoint = format!("ws://127.0.0.1:{port}/unity");
+    let client = IpcClient::connect(config(endpoint, TlsOptions::default()))
+        .await
+        .unwrap();
+    let mut events = client.events();
+    let health = client.health(Duration::from_secs(2)).await.unwrap();
+    assert_eq!(health.project_name, "WsGame");
+
+    let event = tokio::time::timeout(Duration::from_secs(2), events.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    match event.payload {
+        Some(pb::ipc_event::Payload::Log(log)) => assert_eq!(log.message, "compiling scripts"),
+        other => panic!("expected a log event, got {other:?}"),
+    }
+
+    // The hello is a length-prefixed frame in a binary message
+    match &bridge.first_messages.lock().unwrap()[0] {
+        Message::Binary(data) => {
+            let len = u32::from_be_bytes(data[..4].try_into().unwrap()) as usize;
+            assert_eq!(len, data.len() - 4);
+        }
+        other => panic!("expected a binary message, got {other:?}"),
+    }
+
+    // The bridge goes away (domain reload); the client reconnects over WebSocket
+    bridge.drop_connection.notify_one();
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while bridge.first_messages.lock().unwrap().len() < 2
+            || client.health(Duration::from_millis(500)).await.is_err()
+        {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("client reconnects over WebSocket");
+}
+
+#[tokio::test]
+async fn test_wss_endpoint_verifies_bridge_certificate() {
+    use rcgen::{CertificateParams, KeyPair};
+    use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer};
+
+    let port = 19005;
+    let key = KeyPair::generate().unwrap();
+    let cert = CertificateParams::new(vec!["unity-editor.test".to_string()])
+        .unwrap()
+        .self_signed(&key)
+        .unwrap();
+    let config_tls = rustls::ServerConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .unwrap()
+    .with_no_client_auth()
+    .with_single_cert(
+        vec![CertificateDer::from(cert.der().to_vec())],
+        PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.serialize_der())),
+    )
+    .unwrap();
+    let bridge = Arc::new(WsBridge::default());
+    let acceptor = TlsAcceptor::from(Arc::new(config_tls));
+    tokio::spawn(mock_ws_bridge(port, bridge.clone(), Some(acceptor)));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let ca = dir.path().join("bridge.pem");
+    std::fs::write(&ca, cert.pem()).unwrap();
+    let endpoint = format!("wss://127.0.0.1:{port}/unity");
+
+    // wss:// uses the same ipc.tls_* options as tls://
+    let tls = TlsOptions {
+        ca_file: Some(ca.clone()),
+        server_name: Some("unity-editor.test".to_string()),
+        ..TlsOptions::default()
+    };
+    let client = IpcClient::connect(config(endpoint.clone(), tls))
+        .await
+        .unwrap();
+    let health = client.health(Duration::from_secs(2)).await.unwrap();
+    assert_eq!(health.project_name, "WsGame");
+
+    // Without the override the certificate does not match the IP address
+    let tls = TlsOptions {
+        ca_file: Some(ca),
+        ..TlsOptions::default()
+    };
+    match IpcClient::connect(config(endpoint, tls)).await {
+        Err(IpcError::Tls(msg)) => assert!(msg.contains("certificate"), "{msg}"),
+        other => panic!("expected a TLS error, got {:?}", other.err()),
+    }
+}